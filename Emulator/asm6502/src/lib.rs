@@ -0,0 +1,148 @@
+use proc_macro::{Delimiter, Group, Ident, Literal, Punct, Spacing, Span, TokenStream, TokenTree};
+use emulator::assemble;
+
+//Assembles 6502 source at compile time into a &'static [u8], starting at the origin ($0000 unless the source
+//sets one). Statements are separated by ; or line breaks:
+//
+//asm6502! { LDA #$20; STA $2233,X; loop: DEX; BNE loop }
+//
+//Rust has to be able to split the source into tokens, so hex numbers like $1E that look like a broken float need
+//the source written as a string instead: asm6502!("LDA #$1E"). Errors are compile errors at the offending token.
+#[proc_macro]
+pub fn asm6502(input: TokenStream) -> TokenStream {
+    let tokens: Vec<TokenTree> = input.into_iter().collect();
+    let source = match tokens.as_slice() {
+        [TokenTree::Literal(literal)] if literal.to_string().ends_with('"') => match unquote(&literal.to_string()) {
+            Some(text) => Source { text, spans: Vec::new(), line: 1, column: 1, previous: None, fallback: literal.span() },
+            None => return compile_error("Expected a string or assembly.", literal.span())
+        },
+        _ => {
+            let mut source = Source { text: String::new(), spans: Vec::new(), line: 1, column: 1, previous: None, fallback: Span::call_site() };
+            source.add_tokens(tokens);
+            source
+        }
+    };
+
+    match assemble(&source.text) {
+        Ok(assembly) => {
+            let bytes: Vec<String> = assembly.image.iter().map(|byte| format!("{:#04X}u8", byte)).collect();
+            format!("{{ const BYTES: &[u8] = &[{}]; BYTES }}", bytes.join(", ")).parse().unwrap()
+        }
+        Err(error) => compile_error(&error.message, source.span_at(error.line, error.column))
+    }
+}
+
+//Source text rebuilt from tokens, remembering where each token went.
+struct Source {
+    text: String,
+    //The line and column in text each token starts at, with its span.
+    spans: Vec<(usize, usize, Span)>,
+    line: usize,
+    column: usize,
+    previous: Option<Span>,
+    //For errors that aren't on a token.
+    fallback: Span,
+}
+
+impl Source {
+
+    fn add_tokens(&mut self, tokens: impl IntoIterator<Item = TokenTree>){
+        for token in tokens {
+            match token {
+                TokenTree::Punct(punct) if punct.as_char() == ';' => {
+                    self.new_line();
+                    self.previous = None;
+                }
+                TokenTree::Group(group) => {
+                    let (open, close) = match group.delimiter() {
+                        Delimiter::Parenthesis => ("(", ")"),
+                        Delimiter::Bracket => ("[", "]"),
+                        Delimiter::Brace => ("{", "}"),
+                        Delimiter::None => ("", "")
+                    };
+                    self.add(open, group.span_open());
+                    self.add_tokens(group.stream());
+                    self.add(close, group.span_close());
+                }
+                TokenTree::Punct(punct) => self.add(&punct.as_char().to_string(), punct.span()),
+                TokenTree::Ident(ident) => self.add(&ident.to_string(), ident.span()),
+                TokenTree::Literal(literal) => self.add(&literal.to_string(), literal.span())
+            }
+        }
+    }
+
+    //Tokens on separate lines of the Rust source are separate lines here too, and tokens that touch, like $
+    //and 20, aren't split by a space.
+    fn add(&mut self, text: &str, span: Span){
+        if text.is_empty() {
+            return;
+        }
+        if let Some(previous) = self.previous {
+            if previous.end().line() != span.start().line() {
+                self.new_line();
+            } else if previous.end().column() != span.start().column() {
+                self.text.push(' ');
+                self.column += 1;
+            }
+        }
+        self.spans.push((self.line, self.column, span));
+        self.text.push_str(text);
+        self.column += text.chars().count();
+        self.previous = Some(span);
+    }
+
+    fn new_line(&mut self){
+        self.text.push('\n');
+        self.line += 1;
+        self.column = 1;
+    }
+
+    //The last token starting at or before the column.
+    fn span_at(&self, line: usize, column: usize) -> Span {
+        self.spans.iter()
+            .rfind(|&&(token_line, token_column, _)| token_line == line && token_column <= column.max(1))
+            .or_else(|| self.spans.iter().find(|&&(token_line, _, _)| token_line == line))
+            .map_or(self.fallback, |&(_, _, span)| span)
+    }
+}
+
+//The text of a string literal, plain or raw.
+fn unquote(literal: &str) -> Option<String> {
+    if let Some(raw) = literal.strip_prefix('r') {
+        let hashes = raw.len() - raw.trim_start_matches('#').len();
+        return raw.get(hashes + 1..raw.len() - hashes - 1).map(str::to_string);
+    }
+
+    let mut text = String::new();
+    let mut characters = literal.strip_prefix('"')?.strip_suffix('"')?.chars().peekable();
+    while let Some(character) = characters.next() {
+        if character != '\\' {
+            text.push(character);
+            continue;
+        }
+        match characters.next()? {
+            'n' => text.push('\n'),
+            't' => text.push('\t'),
+            'r' => text.push('\r'),
+            '0' => text.push('\0'),
+            //A line continuation skips the line break and the indentation after it.
+            '\n' => {
+                while characters.next_if(|character| character.is_whitespace()).is_some() {}
+            }
+            other => text.push(other)
+        }
+    }
+
+    Some(text)
+}
+
+fn compile_error(message: &str, span: Span) -> TokenStream {
+    let mut literal = Literal::string(message);
+    literal.set_span(span);
+    let mut bang = Punct::new('!', Spacing::Alone);
+    bang.set_span(span);
+    let mut arguments = Group::new(Delimiter::Parenthesis, TokenTree::Literal(literal).into());
+    arguments.set_span(span);
+
+    [TokenTree::Ident(Ident::new("compile_error", span)), TokenTree::Punct(bang), TokenTree::Group(arguments)].into_iter().collect()
+}
@@ -0,0 +1,99 @@
+use asm6502::asm6502;
+use emulator::{assemble, CPU};
+
+//Loads the program at $0000 and runs it until execution leaves it.
+fn run(cpu: &mut CPU, bytes: &[u8]) {
+    cpu.load_program(bytes, 0x0000);
+    cpu.run_until(|cpu| cpu.registers.pc as usize >= bytes.len()).unwrap();
+}
+
+#[test]
+fn asm6502_test_statements_and_lines() {
+    let bytes = asm6502! {
+        LDA #$20; STA $2233,X
+        loop: DEX
+        BNE loop
+        LDA ($20),Y
+    };
+
+    assert_eq!(bytes, [0xA9, 0x20, 0x9D, 0x33, 0x22, 0xCA, 0xD0, 0xFD, 0xB1, 0x20]);
+}
+
+#[test]
+fn asm6502_test_matches_the_assembler() {
+    let bytes = asm6502! {
+        count = 3
+        .byte "hi", %101, <label, >label
+        @local: JMP @local
+        label: LDX #count * 2
+        BEQ :+
+        NOP
+        : RTS
+    };
+    let source = "
+        count = 3
+        .byte \"hi\", %101, <label, >label
+        @local: JMP @local
+        label: LDX #count * 2
+        BEQ :+
+        NOP
+        : RTS
+    ";
+
+    assert_eq!(bytes, assemble(source).unwrap().image.as_slice());
+}
+
+#[test]
+fn asm6502_test_string_source() {
+    //$1E and $0E aren't Rust tokens, so they go in a string.
+    let bytes = asm6502!("LDA #$1E\nORA #$0E");
+    let raw = asm6502!(r"
+        .org $0600
+        .word *
+    ");
+
+    assert_eq!(bytes, [0xA9, 0x1E, 0x09, 0x0E]);
+    assert_eq!(raw, [0x00, 0x06]);
+}
+
+#[test]
+fn asm6502_test_runs() {
+    let mut cpu = CPU::new();
+
+    run(&mut cpu, asm6502! {
+        LDX #5
+        LDA #0
+        loop: CLC
+        ADC #3
+        DEX
+        BNE loop
+    });
+
+    assert_eq!(cpu.registers.acc, 15);
+    assert_eq!(cpu.registers.xr, 0);
+}
+
+#[test]
+fn asm6502_test_store_modes() {
+    let mut cpu = CPU::new();
+    cpu.memory[0x20] = 0x30;
+    cpu.memory[0x21] = 0x40;
+
+    cpu.registers.xr = 3;
+    cpu.registers.yr = 2;
+    run(&mut cpu, asm6502! {
+        LDA #$11; STA $2233
+        LDA #$22; STA $2240,X
+        LDA #$33; STA $2230,Y
+        LDA #$44; STA $30,X
+        LDA #$55; STA ($1D,X)
+        LDA #$66; STA ($20),Y
+    });
+
+    assert_eq!(cpu.memory[0x2233], 0x11);
+    assert_eq!(cpu.memory[0x2243], 0x22);
+    assert_eq!(cpu.memory[0x2232], 0x33);
+    assert_eq!(cpu.memory[0x33], 0x44);
+    assert_eq!(cpu.memory[0x4030], 0x55);
+    assert_eq!(cpu.memory[0x4032], 0x66);
+}
@@ -0,0 +1,7 @@
+pub mod assembler;
+pub mod error;
+pub mod expression;
+pub mod lexer;
+pub mod linker;
+pub mod object;
+pub mod preprocessor;
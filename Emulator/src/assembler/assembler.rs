@@ -0,0 +1,1149 @@
+use std::collections::{BTreeMap, HashMap};
+use std::fmt;
+use std::fs;
+use std::path::Path;
+use crate::assembler::error::AssemblyError;
+use crate::assembler::expression::{BinaryOperator, EvaluationError, Expression, ExpressionParser, UnaryOperator};
+use crate::assembler::lexer::{Spanned, Token};
+use crate::assembler::object::{signed_hex, Field, ObjectFile, ObjectSegment, Relocation, SymbolValue};
+use crate::assembler::preprocessor::{preprocess, Line};
+use crate::cpu::bus::{Bus, Ram};
+use crate::cpu::cpu::{Variant, CPU};
+use crate::cpu::instructions::AddressingMode;
+use crate::cpu::w65816::instructions::OperandWidth;
+
+//The assembled bytes and every symbol that was defined.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Assembly {
+    //The lowest address anything was assembled to, where image starts.
+    pub origin: u32,
+    //Everything from origin to the highest address assembled to, gaps are zero.
+    pub image: Vec<u8>,
+    pub symbols: BTreeMap<String, i64>,
+    pub listing: Vec<ListingLine>,
+}
+
+//One assembled line: where it went, what it assembled to and the source after macros are expanded.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ListingLine {
+    pub line: usize,
+    pub address: u32,
+    pub bytes: Vec<u8>,
+    pub source: String,
+}
+
+//Four bytes per row like the disassembler's listing, longer data continues on rows of its own.
+impl fmt::Display for ListingLine {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let address = |address: u32| if address > 0xFFFF { format!("{:06X}", address) } else { format!("{:04X}", address) };
+        let hex = |bytes: &[u8]| bytes.iter().map(|byte| format!("{:02X}", byte)).collect::<Vec<_>>().join(" ");
+        let mut rows = self.bytes.chunks(4);
+
+        write!(f, "{}  {:<11} {}", address(self.address), hex(rows.next().unwrap_or_default()), self.source)?;
+        for (index, row) in rows.enumerate() {
+            write!(f, "\n{}  {}", address(self.address + 4 * (index as u32 + 1)), hex(row))?;
+        }
+
+        Ok(())
+    }
+}
+
+impl Assembly {
+
+    //Every line with its address and bytes next to the source, for diffing builds.
+    pub fn listing(&self) -> String {
+        self.listing.iter().map(|line| format!("{}\n", line.to_string().trim_end())).collect()
+    }
+
+    pub fn symbol(&self, name: &str) -> Option<i64> {
+        self.symbols.get(name).copied()
+    }
+
+    //Names by address, for Disassembler::with_symbols. The first name in order wins when several share one.
+    pub fn symbols_by_address(&self) -> HashMap<u32, String> {
+        let mut names = HashMap::new();
+        for (name, &value) in &self.symbols {
+            if (0..=0xFFFFFF).contains(&value) {
+                names.entry(value as u32).or_insert_with(|| name.clone());
+            }
+        }
+
+        names
+    }
+
+    //Copies the image into memory at its origin.
+    pub fn load<B: Bus>(&self, cpu: &mut CPU<B>){
+        cpu.load_program_long(&self.image, self.origin);
+    }
+}
+
+//How the operand was written, before it is matched to an addressing mode.
+#[derive(Debug, Clone, PartialEq)]
+enum OperandSyntax {
+    None,
+    Accumulator,
+    Immediate(Expression),
+    Direct(Expression, Index),
+    //(expr)
+    Indirect(Expression),
+    //(expr,X)
+    IndirectX(Expression),
+    //(expr),Y
+    IndirectY(Expression),
+    //[expr]
+    IndirectLong(Expression),
+    //[expr],Y
+    IndirectLongY(Expression),
+    //(expr,S),Y
+    StackIndirectY(Expression),
+    //expr,expr for BBR, BBS, MVN and MVP.
+    Pair(Expression, Expression),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Index {
+    None,
+    X,
+    Y,
+    S,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Data {
+    Value(Expression),
+    Text(String),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Statement {
+    Instruction { mnemonic: String, operand: OperandSyntax },
+    Assign(String, Expression),
+    Org(Expression),
+    Byte(Vec<Data>),
+    Word(Vec<Expression>),
+    Text(String),
+    Reserve(Expression, Option<Expression>),
+    //.incbin, read when the line is parsed.
+    Binary(Vec<u8>),
+    //65816 only, the size of immediates for the accumulator and the index registers.
+    AccumulatorWidth(bool),
+    IndexWidth(bool),
+    //Object files only.
+    Segment(String),
+    //The names, and whether they are in the zero page.
+    Import(Vec<(String, usize)>, bool),
+    Export(Vec<(String, usize)>),
+}
+
+struct SourceLine {
+    line: Line,
+    label: Option<(String, usize)>,
+    statement: Option<(Statement, usize)>,
+}
+
+//The addressing mode picked for an instruction in the first pass, so both passes agree on its size.
+#[derive(Clone, Copy)]
+struct Encoding {
+    mode: AddressingMode,
+    opcode: u8,
+    length: u8,
+}
+
+type SymbolTable = HashMap<String, i64>;
+
+//Where @ labels belong and how many anonymous labels came before, while lines are parsed in order.
+#[derive(Default)]
+struct LabelScope {
+    scope: String,
+    anonymous: usize,
+}
+
+//A value as far as the assembler can tell. In object files, values that depend on where the linker puts a
+//segment or on an imported symbol are left as an expression for the linker.
+enum Value {
+    Known(i64),
+    Relocated(Expression),
+}
+
+//What the passes keep track of while they walk the source.
+struct PassState {
+    symbols: SymbolTable,
+    program_counter: i64,
+    wide_accumulator: bool,
+    wide_index: bool,
+    //When assembling an object file, the program counter is the offset in the current segment and labels
+    //are offsets in the segment they are defined in.
+    relocatable: bool,
+    segment: String,
+    //Where the other segments left off.
+    offsets: HashMap<String, i64>,
+    labels: HashMap<String, String>,
+    //Constants that depend on labels or imports.
+    aliases: HashMap<String, Expression>,
+    //Whether each import is in the zero page.
+    imports: HashMap<String, bool>,
+}
+
+impl PassState {
+    fn new(relocatable: bool) -> Self {
+        PassState {
+            symbols: HashMap::new(),
+            program_counter: 0,
+            wide_accumulator: false,
+            wide_index: false,
+            relocatable,
+            segment: "CODE".to_string(),
+            offsets: HashMap::new(),
+            labels: HashMap::new(),
+            aliases: HashMap::new(),
+            imports: HashMap::new(),
+        }
+    }
+
+    //Back to the start of the source for the next pass, keeping the symbols.
+    fn restart(&mut self){
+        self.program_counter = 0;
+        self.wide_accumulator = false;
+        self.wide_index = false;
+        self.segment = "CODE".to_string();
+        self.offsets.clear();
+    }
+
+    fn evaluate(&self, expression: &Expression) -> Result<i64, EvaluationError> {
+        expression.evaluate(&|name: &str| self.symbols.get(name).copied(), self.program_counter)
+    }
+
+    fn is_defined(&self, name: &str) -> bool {
+        self.symbols.contains_key(name) || self.aliases.contains_key(name) || self.imports.contains_key(name)
+    }
+
+    fn define(&mut self, name: &str, value: Value) -> Result<(), String> {
+        if self.is_defined(name) {
+            return Err(format!("{} is already defined.", name));
+        }
+        match value {
+            Value::Known(value) => { self.symbols.insert(name.to_string(), value); }
+            Value::Relocated(expression) => { self.aliases.insert(name.to_string(), expression); }
+        }
+        Ok(())
+    }
+
+    fn define_label(&mut self, name: &str) -> Result<(), String> {
+        self.define(name, Value::Known(self.program_counter))?;
+        if self.relocatable {
+            self.labels.insert(name.to_string(), self.segment.clone());
+        }
+        Ok(())
+    }
+
+    fn switch_segment(&mut self, segment: &str){
+        self.offsets.insert(self.segment.clone(), self.program_counter);
+        self.segment = segment.to_string();
+        self.program_counter = self.offsets.get(segment).copied().unwrap_or(0);
+    }
+
+    //Labels become the start of their segment plus their offset, written .CODE+offset.
+    fn relocate(&self, expression: &Expression) -> Expression {
+        match expression {
+            Expression::Symbol(name, _) => match (self.labels.get(name), self.aliases.get(name), self.symbols.get(name)) {
+                (Some(segment), _, Some(&offset)) => segment_offset(segment, offset),
+                (_, Some(alias), _) => alias.clone(),
+                (_, _, Some(&value)) => Expression::Number(value),
+                _ => expression.clone()
+            },
+            Expression::ProgramCounter => segment_offset(&self.segment, self.program_counter),
+            Expression::Unary(operator, operand) => Expression::Unary(*operator, Box::new(self.relocate(operand))),
+            Expression::Binary(operator, left, right) => {
+                Expression::Binary(*operator, Box::new(self.relocate(left)), Box::new(self.relocate(right)))
+            }
+            Expression::Number(_) => expression.clone()
+        }
+    }
+
+    fn resolve(&self, expression: &Expression) -> Result<Value, EvaluationError> {
+        if !self.relocatable {
+            return self.evaluate(expression).map(Value::Known);
+        }
+        let relocated = self.relocate(expression);
+        let placeholder = |name: &str| (name.starts_with('.') || self.imports.contains_key(name)).then_some(1);
+        if let Err(error @ EvaluationError::Undefined { .. }) = relocated.evaluate(&placeholder, 0) {
+            return Err(error);
+        }
+        if let Ok(value) = relocated.evaluate(&|_| None, 0) {
+            return Ok(Value::Known(value));
+        }
+
+        //The difference of two labels in one segment doesn't depend on where the segment goes.
+        match linear(&relocated) {
+            Some((value, terms)) if terms.values().all(|&factor| factor == 0) => Ok(Value::Known(value)),
+            _ => Ok(Value::Relocated(relocated))
+        }
+    }
+
+    //A value that has to be known while assembling, like the size of .res.
+    fn constant(&self, expression: &Expression) -> Option<i64> {
+        match self.resolve(expression) {
+            Ok(Value::Known(value)) => Some(value),
+            _ => None
+        }
+    }
+
+    //What the first pass goes by when it picks an addressing mode. Values the linker fills in are taken as
+    //zero page addresses when they are in the ZEROPAGE segment or imported with .importzp.
+    fn estimate(&self, expression: &Expression) -> Option<i64> {
+        match self.resolve(expression) {
+            Ok(Value::Known(value)) => Some(value),
+            Ok(Value::Relocated(expression)) if self.in_zero_page(&expression) => Some(0),
+            _ => None
+        }
+    }
+
+    fn in_zero_page(&self, expression: &Expression) -> bool {
+        match expression {
+            Expression::Symbol(name, _) => name == ".ZEROPAGE" || self.imports.get(name) == Some(&true),
+            Expression::Unary(_, operand) => self.in_zero_page(operand),
+            Expression::Binary(_, left, right) => self.in_zero_page(left) && self.in_zero_page(right),
+            Expression::Number(_) | Expression::ProgramCounter => true
+        }
+    }
+}
+
+//What the second pass made of the source, before it becomes an Assembly or an ObjectFile.
+#[derive(Default)]
+struct PassOutput {
+    symbols: SymbolTable,
+    //Every byte at its address, when assembling to an image.
+    bytes: Vec<(u32, u8)>,
+    segments: Vec<ObjectSegment>,
+    exports: BTreeMap<String, SymbolValue>,
+    imports: Vec<String>,
+    listing: Vec<ListingLine>,
+}
+
+impl PassOutput {
+
+    //Segments are added when something is first put in them.
+    fn segment(&mut self, name: &str) -> &mut ObjectSegment {
+        let index = match self.segments.iter().position(|segment| segment.name == name) {
+            Some(index) => index,
+            None => {
+                self.segments.push(ObjectSegment { name: name.to_string(), bytes: Vec::new(), relocations: Vec::new() });
+                self.segments.len() - 1
+            }
+        };
+        &mut self.segments[index]
+    }
+
+    fn into_assembly(self) -> Assembly {
+        let origin = self.bytes.iter().map(|&(address, _)| address).min().unwrap_or(0);
+        let end = self.bytes.iter().map(|&(address, _)| address + 1).max().unwrap_or(0);
+        let mut image = vec![0; (end - origin) as usize];
+        for (address, byte) in self.bytes {
+            image[(address - origin) as usize] = byte;
+        }
+
+        //Anonymous labels and the @ labels of macro expansions can't be written in source, so they aren't listed.
+        let symbols = self.symbols.into_iter().filter(|(name, _)| !name.starts_with(':') && !name.contains('#')).collect();
+
+        Assembly { origin, image, symbols, listing: self.listing }
+    }
+
+    fn into_object(self, name: &str) -> ObjectFile {
+        ObjectFile { name: name.to_string(), segments: self.segments, exports: self.exports, imports: self.imports }
+    }
+}
+
+//Assembles source for one variant. Two passes: the first defines the labels and picks every instruction's
+//addressing mode, the second evaluates the operands with every symbol known.
+pub struct Assembler {
+    variant: Variant,
+    //The opcode for a mnemonic in an addressing mode, documented opcodes win over undocumented ones.
+    opcodes: HashMap<(&'static str, AddressingMode), u8>,
+}
+
+impl Default for Assembler {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Assembler {
+
+    pub fn new() -> Self {
+        Self::for_variant(Variant::Nmos6502)
+    }
+
+    pub fn with_variant(self, variant: Variant) -> Self {
+        Self::for_variant(variant)
+    }
+
+    fn for_variant(variant: Variant) -> Self {
+        let mut opcodes = HashMap::new();
+        for opcode in 0..=255u8 {
+            let decoded = if variant == Variant::Wdc65816 {
+                CPU::<Ram>::get_native_instruction(opcode).map(|instruction| (instruction.mnemonic, instruction.address_mode))
+            } else {
+                CPU::<Ram>::get_instruction(variant, opcode).map(|instruction| (instruction.mnemonic, instruction.address_mode))
+            };
+            let Some(key) = decoded else { continue };
+            let documented = !CPU::<Ram>::is_undocumented(variant, opcode);
+            match opcodes.get(&key) {
+                Some(&existing) if documented && CPU::<Ram>::is_undocumented(variant, existing) => { opcodes.insert(key, opcode); }
+                Some(_) => {}
+                None => { opcodes.insert(key, opcode); }
+            }
+        }
+
+        Assembler { variant, opcodes }
+    }
+
+    fn is_mnemonic(&self, name: &str) -> bool {
+        self.opcodes.keys().any(|(mnemonic, _)| *mnemonic == name)
+    }
+
+    fn has_mode(&self, mnemonic: &str, mode: AddressingMode) -> bool {
+        self.opcode(mnemonic, mode).is_some()
+    }
+
+    fn opcode(&self, mnemonic: &str, mode: AddressingMode) -> Option<u8> {
+        self.opcodes.iter().find(|((name, key_mode), _)| *name == mnemonic && *key_mode == mode).map(|(_, &opcode)| opcode)
+    }
+
+    //.include and .incbin paths are relative to the current directory.
+    pub fn assemble(&self, source: &str) -> Result<Assembly, AssemblyError> {
+        self.assemble_lines(preprocess(source, None)?, false).map(PassOutput::into_assembly)
+    }
+
+    //.include and .incbin paths are relative to the file they are in.
+    pub fn assemble_file<P: AsRef<Path>>(&self, path: P) -> Result<Assembly, AssemblyError> {
+        self.assemble_lines(read_source(path.as_ref())?, false).map(PassOutput::into_assembly)
+    }
+
+    //Assembles into segments for the linker. Instead of .org the source picks segments with .segment, and
+    //shares symbols with other object files through .import and .export.
+    pub fn assemble_object(&self, source: &str) -> Result<ObjectFile, AssemblyError> {
+        self.assemble_lines(preprocess(source, None)?, true).map(|output| output.into_object("source"))
+    }
+
+    pub fn assemble_object_file<P: AsRef<Path>>(&self, path: P) -> Result<ObjectFile, AssemblyError> {
+        let path = path.as_ref();
+        self.assemble_lines(read_source(path)?, true).map(|output| output.into_object(&path.display().to_string()))
+    }
+
+    fn assemble_lines(&self, lines: Vec<Line>, relocatable: bool) -> Result<PassOutput, AssemblyError> {
+        let mut labels = LabelScope::default();
+        let lines = lines.into_iter()
+            .map(|line| self.parse_line(line, &mut labels))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let (state, encodings) = self.first_pass(&lines, relocatable)?;
+        self.second_pass(&lines, state, &encodings)
+    }
+
+    fn parse_line(&self, line: Line, labels: &mut LabelScope) -> Result<SourceLine, AssemblyError> {
+        let tokens = resolve_labels(line.tokenize()?, &line, labels);
+        let end_column = line.end_column();
+        let error = |column: usize, message: String| line.error(column, message);
+        let mut label = None;
+        let mut position = 0;
+
+        if let [Spanned { token: Token::Identifier(name), column }, Spanned { token: Token::Symbol(":"), .. }, ..] = tokens.as_slice() {
+            label = Some((name.clone(), *column));
+            position = 2;
+        }
+        let first = match tokens.get(position) {
+            Some(first) if !line.macro_call => first,
+            _ => return Ok(SourceLine { line, label, statement: None })
+        };
+        let column = first.column;
+        let rest = &tokens[position + 1..];
+        let expressions = |tokens: &[Spanned]| parse_list(tokens, end_column).map_err(|(column, message)| error(column, message));
+
+        let statement = match &first.token {
+            Token::Identifier(name) if matches!(rest.first(), Some(Spanned { token: Token::Symbol("="), .. })) => {
+                Statement::Assign(name.clone(), single(expressions(&rest[1..])?).map_err(|message| error(column, message))?)
+            }
+            Token::Symbol("*") if matches!(rest.first(), Some(Spanned { token: Token::Symbol("="), .. })) => {
+                Statement::Org(single(expressions(&rest[1..])?).map_err(|message| error(column, message))?)
+            }
+            Token::Directive(directive) => match directive.as_str() {
+                "org" => Statement::Org(single(expressions(rest)?).map_err(|message| error(column, message))?),
+                "byte" | "db" => Statement::Byte(parse_data(rest, end_column).map_err(|(column, message)| error(column, message))?),
+                "word" | "dw" => Statement::Word(expressions(rest)?),
+                "text" => {
+                    let text = parse_data(rest, end_column).map_err(|(column, message)| error(column, message))?
+                        .into_iter()
+                        .map(|data| match data {
+                            Data::Text(text) => Ok(text),
+                            Data::Value(_) => Err(error(column, ".text takes strings.".to_string()))
+                        })
+                        .collect::<Result<String, _>>()?;
+                    Statement::Text(text)
+                }
+                "res" => {
+                    let mut values = expressions(rest)?.into_iter();
+                    match (values.next(), values.next(), values.next()) {
+                        (Some(count), fill, None) => Statement::Reserve(count, fill),
+                        _ => return Err(error(column, ".res takes a count and an optional fill value.".to_string()))
+                    }
+                }
+                "incbin" => Statement::Binary(read_binary(&line, rest, column)?),
+                "a8" | "a16" | "i8" | "i16" if self.variant != Variant::Wdc65816 => {
+                    return Err(error(column, format!(".{} is only for the 65816.", directive)));
+                }
+                "a8" => Statement::AccumulatorWidth(false),
+                "a16" => Statement::AccumulatorWidth(true),
+                "i8" => Statement::IndexWidth(false),
+                "i16" => Statement::IndexWidth(true),
+                "segment" => match rest {
+                    [Spanned { token: Token::Str(name), .. }] if !name.is_empty() => Statement::Segment(name.clone()),
+                    _ => return Err(error(column, ".segment takes a name in quotes.".to_string()))
+                },
+                "code" | "data" | "bss" | "zeropage" => Statement::Segment(directive.to_ascii_uppercase()),
+                "import" | "importzp" => Statement::Import(parse_names(rest, end_column).map_err(|(column, message)| error(column, message))?, directive == "importzp"),
+                "export" => Statement::Export(parse_names(rest, end_column).map_err(|(column, message)| error(column, message))?),
+                _ => return Err(error(column, format!("Unknown directive .{}.", directive)))
+            },
+            Token::Identifier(name) => {
+                let mnemonic = name.to_ascii_uppercase();
+                if !self.is_mnemonic(&mnemonic) {
+                    return Err(error(column, format!("Unknown instruction {}.", name)));
+                }
+                let operand = parse_operand(rest, end_column).map_err(|(column, message)| error(column, message))?;
+                Statement::Instruction { mnemonic, operand }
+            }
+            _ => return Err(error(column, "Expected a label, an instruction or a directive.".to_string()))
+        };
+
+        Ok(SourceLine { line, label, statement: Some((statement, column)) })
+    }
+
+    //Defines every label and picks the addressing modes. Symbols defined later are taken as 16-bit addresses.
+    fn first_pass(&self, lines: &[SourceLine], relocatable: bool) -> Result<(PassState, Vec<Option<Encoding>>), AssemblyError> {
+        let mut state = PassState::new(relocatable);
+        let mut encodings = vec![None; lines.len()];
+        let mut pending = Vec::new();
+
+        for (index, line) in lines.iter().enumerate() {
+            let error = |column: usize, message: String| line.line.error(column, message);
+            if let Some((name, column)) = &line.label {
+                state.define_label(name).map_err(|message| error(*column, message))?;
+            }
+            let Some((statement, column)) = &line.statement else { continue };
+            match statement {
+                Statement::Org(_) if state.relocatable => {
+                    return Err(error(*column, "Object files can't set the origin, the linker places the segments.".to_string()));
+                }
+                Statement::Segment(_) | Statement::Import(..) | Statement::Export(_) if !state.relocatable => {
+                    return Err(error(*column, "Segments, imports and exports are only for object files.".to_string()));
+                }
+                _ => {}
+            }
+
+            let length = match statement {
+                Statement::Assign(name, expression) => {
+                    match state.resolve(expression) {
+                        Ok(value) => state.define(name, value).map_err(|message| error(*column, message))?,
+                        Err(_) => pending.push((&line.line, *column, name, expression, state.program_counter, state.segment.clone()))
+                    }
+                    0
+                }
+                Statement::Org(expression) => {
+                    state.program_counter = state.constant(expression).ok_or_else(|| error(*column, "The origin has to be known in the first pass.".to_string()))?;
+                    0
+                }
+                Statement::Byte(data) => data.iter().map(|data| match data {
+                    Data::Value(_) => 1,
+                    Data::Text(text) => text.len() as i64
+                }).sum(),
+                Statement::Word(values) => values.len() as i64 * 2,
+                Statement::Text(text) => text.len() as i64,
+                Statement::Binary(bytes) => bytes.len() as i64,
+                Statement::Reserve(count, _) => {
+                    let count = state.constant(count).ok_or_else(|| error(*column, "The size of .res has to be known in the first pass.".to_string()))?;
+                    if count < 0 {
+                        return Err(error(*column, "The size of .res can't be negative.".to_string()));
+                    }
+                    count
+                }
+                Statement::AccumulatorWidth(wide) => { state.wide_accumulator = *wide; 0 }
+                Statement::IndexWidth(wide) => { state.wide_index = *wide; 0 }
+                Statement::Segment(name) => { state.switch_segment(name); 0 }
+                Statement::Import(names, zero_page) => {
+                    for (name, column) in names {
+                        if state.is_defined(name) {
+                            return Err(error(*column, format!("{} is already defined.", name)));
+                        }
+                        state.imports.insert(name.clone(), *zero_page);
+                    }
+                    0
+                }
+                Statement::Export(_) => 0,
+                Statement::Instruction { mnemonic, operand } => {
+                    let value = operand_expressions(operand).first().and_then(|expression| state.estimate(expression));
+                    let encoding = self.choose_encoding(mnemonic, operand, value, &state).map_err(|message| error(*column, message))?;
+                    self.track_register_widths(mnemonic, operand, &mut state);
+                    encodings[index] = Some(encoding);
+                    encoding.length as i64
+                }
+            };
+            state.program_counter += length;
+        }
+
+        //Assignments that use symbols defined after them, in as many rounds as it takes.
+        while !pending.is_empty() {
+            let before = pending.len();
+            let mut still_pending = Vec::new();
+            for (line, column, name, expression, program_counter, segment) in pending {
+                state.program_counter = program_counter;
+                state.segment = segment.clone();
+                match state.resolve(expression) {
+                    Ok(value) => state.define(name, value).map_err(|message| line.error(column, message))?,
+                    Err(EvaluationError::Undefined { .. }) => still_pending.push((line, column, name, expression, program_counter, segment)),
+                    Err(error) => return Err(evaluation_error(error, line, column))
+                }
+            }
+            if still_pending.len() == before {
+                let (line, column, _, expression, program_counter, segment) = &still_pending[0];
+                state.program_counter = *program_counter;
+                state.segment = segment.clone();
+                let error = state.resolve(expression).err().expect("The assignment is still pending.");
+                return Err(evaluation_error(error, line, *column));
+            }
+            pending = still_pending;
+        }
+
+        Ok((state, encodings))
+    }
+
+    fn second_pass(&self, lines: &[SourceLine], mut state: PassState, encodings: &[Option<Encoding>]) -> Result<PassOutput, AssemblyError> {
+        let mut output = PassOutput::default();
+        state.restart();
+
+        for (index, line) in lines.iter().enumerate() {
+            let Some((statement, column)) = &line.statement else {
+                if state.relocatable && line.label.is_some() {
+                    output.segment(&state.segment);
+                }
+                output.listing.push(ListingLine { line: line.line.number, address: state.program_counter as u32, bytes: Vec::new(), source: line.line.text.clone() });
+                continue;
+            };
+            let resolve = |expression: &Expression| state.resolve(expression).map_err(|error| evaluation_error(error, &line.line, *column));
+            let error = |message: String| line.line.error(*column, message);
+            let constant = |expression: &Expression| match resolve(expression)? {
+                Value::Known(value) => Ok(value),
+                Value::Relocated(_) => Err(error("This value has to be known when assembling, not when linking.".to_string()))
+            };
+            //Values the linker fills in are zero until then.
+            let mut relocations = Vec::new();
+            let mut place = |bytes: &mut Vec<u8>, field: Field, value: Value| -> Result<(), AssemblyError> {
+                match value {
+                    Value::Known(value) => bytes.extend(field.encode(value).map_err(error)?),
+                    Value::Relocated(expression) => {
+                        relocations.push(Relocation { offset: bytes.len() as u32, field, expression, line: line.line.number });
+                        bytes.extend(vec![0; field.size()]);
+                    }
+                }
+                Ok(())
+            };
+
+            let bytes = match statement {
+                Statement::Assign(..) | Statement::AccumulatorWidth(_) | Statement::IndexWidth(_) => Vec::new(),
+                Statement::Org(expression) => {
+                    state.program_counter = constant(expression)?;
+                    Vec::new()
+                }
+                Statement::Byte(data) => {
+                    let mut bytes = Vec::new();
+                    for data in data {
+                        match data {
+                            Data::Value(expression) => place(&mut bytes, Field::Byte, resolve(expression)?)?,
+                            Data::Text(text) => bytes.extend(text.bytes())
+                        }
+                    }
+                    bytes
+                }
+                Statement::Word(values) => {
+                    let mut bytes = Vec::new();
+                    for expression in values {
+                        place(&mut bytes, Field::Word, resolve(expression)?)?;
+                    }
+                    bytes
+                }
+                Statement::Text(text) => text.bytes().collect(),
+                Statement::Binary(bytes) => bytes.clone(),
+                Statement::Reserve(count, fill) => {
+                    let fill = match fill {
+                        Some(fill) => Field::Byte.encode(constant(fill)?).map_err(error)?[0],
+                        None => 0
+                    };
+                    vec![fill; constant(count)? as usize]
+                }
+                Statement::Segment(name) => {
+                    state.switch_segment(name);
+                    Vec::new()
+                }
+                Statement::Import(names, _) => {
+                    output.imports.extend(names.iter().map(|(name, _)| name.clone()));
+                    Vec::new()
+                }
+                Statement::Export(names) => {
+                    for (name, column) in names {
+                        let value = match (state.labels.get(name), state.symbols.get(name)) {
+                            (Some(segment), Some(&offset)) => SymbolValue::Relative { segment: segment.clone(), offset: offset as u32 },
+                            (None, Some(&value)) => SymbolValue::Absolute(value),
+                            _ if state.imports.contains_key(name) => return Err(line.line.error(*column, format!("{} is imported, it can't be exported too.", name))),
+                            _ if state.aliases.contains_key(name) => {
+                                return Err(line.line.error(*column, format!("{} depends on labels or imports, only labels and constants can be exported.", name)));
+                            }
+                            _ => return Err(line.line.error(*column, format!("{} isn't defined.", name)))
+                        };
+                        output.exports.insert(name.clone(), value);
+                    }
+                    Vec::new()
+                }
+                Statement::Instruction { mnemonic, operand } => {
+                    let encoding = encodings[index].expect("Every instruction is encoded in the first pass.");
+                    let fields = self.fields(encoding, operand);
+                    let values = fields.iter().map(|(_, expression)| resolve(expression)).collect::<Result<Vec<_>, _>>()?;
+                    let mut bytes = vec![encoding.opcode];
+                    for ((field, _), value) in fields.into_iter().zip(values) {
+                        place(&mut bytes, field, value)?;
+                    }
+                    self.track_register_widths(mnemonic, operand, &mut state);
+                    bytes
+                }
+            };
+
+            let length = bytes.len() as i64;
+            if state.relocatable {
+                if line.label.is_some() || !matches!(statement, Statement::Segment(_) | Statement::Import(..) | Statement::Export(_)) {
+                    let segment = output.segment(&state.segment);
+                    let offset = segment.bytes.len() as u32;
+                    segment.relocations.extend(relocations.into_iter().map(|relocation| Relocation { offset: relocation.offset + offset, ..relocation }));
+                    segment.bytes.extend(&bytes);
+                }
+            } else {
+                //Only the 65816 goes on into the next bank.
+                let end = if self.variant == Variant::Wdc65816 { 0x1000000 } else { 0x10000 };
+                if state.program_counter < 0 || (length > 0 && state.program_counter >= end) {
+                    return Err(error(format!("{} is outside the address space.", signed_hex(state.program_counter))));
+                }
+                if state.program_counter + length > end {
+                    let byte = end - state.program_counter + 1;
+                    return Err(error(format!("Byte {} of this line would be at ${:X}, past the end of the address space at ${:X}.", byte, end, end - 1)));
+                }
+                for (offset, &byte) in bytes.iter().enumerate() {
+                    output.bytes.push((state.program_counter as u32 + offset as u32, byte));
+                }
+            }
+            output.listing.push(ListingLine { line: line.line.number, address: state.program_counter as u32, bytes, source: line.line.text.clone() });
+            state.program_counter += length;
+        }
+
+        output.symbols = state.symbols;
+        Ok(output)
+    }
+
+    //The addressing modes the operand could mean, in order of preference. Zero page is only picked when the
+    //value is known to fit, so forward references are assembled as absolute addresses.
+    fn choose_encoding(&self, mnemonic: &str, operand: &OperandSyntax, value: Option<i64>, state: &PassState) -> Result<Encoding, String> {
+        use AddressingMode::*;
+        let small = value.is_some_and(|value| (0..=0xFF).contains(&value));
+        let long = value.is_some_and(|value| value > 0xFFFF);
+        let sized = |zero_page: AddressingMode, absolute: AddressingMode, absolute_long: Option<AddressingMode>| -> Vec<AddressingMode> {
+            match (small, long, absolute_long) {
+                (_, true, Some(absolute_long)) => vec![absolute_long],
+                (_, true, None) => vec![],
+                (true, _, _) => [Some(zero_page), Some(absolute), absolute_long].into_iter().flatten().collect(),
+                (false, _, _) => [Some(absolute), absolute_long, Some(zero_page)].into_iter().flatten().collect()
+            }
+        };
+
+        let candidates = match operand {
+            OperandSyntax::None if matches!(mnemonic, "BRK" | "COP" | "WDM") => vec![Implied, Immediate],
+            OperandSyntax::None => vec![Implied, Accumulator, OpcodeOnly],
+            OperandSyntax::Accumulator => vec![Accumulator],
+            OperandSyntax::Immediate(_) => vec![Immediate],
+            OperandSyntax::Direct(..) if self.has_mode(mnemonic, Relative) => vec![Relative],
+            OperandSyntax::Direct(..) if self.has_mode(mnemonic, RelativeLong) => vec![RelativeLong],
+            OperandSyntax::Direct(_, Index::None) => sized(ZeroPage, Absolute, Some(AbsoluteLong)),
+            OperandSyntax::Direct(_, Index::X) => sized(XIndexedZeroPage, XIndexedAbsolute, Some(XIndexedAbsoluteLong)),
+            OperandSyntax::Direct(_, Index::Y) => sized(YIndexedZeroPage, YIndexedAbsolute, None),
+            OperandSyntax::Direct(_, Index::S) => vec![StackRelative],
+            //PEI is written (dp) but has the operand of a direct page instruction.
+            OperandSyntax::Indirect(_) if mnemonic == "PEI" => vec![ZeroPage],
+            OperandSyntax::Indirect(_) => sized(ZeroPageIndirect, AbsoluteIndirect, None),
+            OperandSyntax::IndirectX(_) => sized(XIndexedZeroPageIndirect, XIndexedAbsoluteIndirect, None),
+            OperandSyntax::IndirectY(_) => vec![ZeroPageIndirectYIndexed],
+            OperandSyntax::IndirectLong(_) => sized(ZeroPageIndirectLong, AbsoluteIndirectLong, None),
+            OperandSyntax::IndirectLongY(_) => vec![ZeroPageIndirectLongYIndexed],
+            OperandSyntax::StackIndirectY(_) => vec![StackRelativeIndirectYIndexed],
+            OperandSyntax::Pair(..) => vec![ZeroPageRelative, BlockMove],
+        };
+
+        let (mode, opcode) = candidates.into_iter()
+            .find_map(|mode| self.opcode(mnemonic, mode).map(|opcode| (mode, opcode)))
+            .ok_or_else(|| format!("{} can't be used with this operand.", mnemonic))?;
+
+        let length = match mode {
+            Immediate if self.variant == Variant::Wdc65816 => {
+                let wide = match CPU::<Ram>::get_native_instruction(opcode).map(|instruction| instruction.width) {
+                    Some(OperandWidth::Memory) => state.wide_accumulator,
+                    Some(OperandWidth::Index) => state.wide_index,
+                    _ => false
+                };
+                2 + wide as u8
+            }
+            _ => 1 + mode.operand_length()
+        };
+
+        Ok(Encoding { mode, opcode, length })
+    }
+
+    //The operand's fields in the order they are in memory. Branches store the target's distance from the next
+    //instruction.
+    fn fields(&self, encoding: Encoding, operand: &OperandSyntax) -> Vec<(Field, Expression)> {
+        let from_next = |target: &Expression| Expression::Binary(
+            BinaryOperator::Subtract,
+            Box::new(target.clone()),
+            Box::new(Expression::Binary(BinaryOperator::Add, Box::new(Expression::ProgramCounter), Box::new(Expression::Number(encoding.length as i64))))
+        );
+        let operand_length = encoding.length - 1;
+
+        match (encoding.mode, operand_expressions(operand).as_slice()) {
+            //BRK, COP and WDM on the 65816 take a signature byte, it is zero when left out.
+            (AddressingMode::Immediate, []) => vec![(Field::Byte, Expression::Number(0))],
+            (_, []) => vec![],
+            (AddressingMode::Relative, &[target]) => vec![(Field::Branch, from_next(target))],
+            (AddressingMode::RelativeLong, &[target]) => vec![(Field::LongBranch, from_next(target))],
+            (AddressingMode::ZeroPageRelative, &[address, target]) => vec![(Field::ZeroPage, address.clone()), (Field::Branch, from_next(target))],
+            //Written source bank first, the destination bank comes first in memory.
+            (AddressingMode::BlockMove, &[source, destination]) => vec![(Field::ZeroPage, destination.clone()), (Field::ZeroPage, source.clone())],
+            (AddressingMode::Immediate, &[value]) if operand_length == 2 => vec![(Field::Word, value.clone())],
+            (AddressingMode::Immediate, &[value]) => vec![(Field::Byte, value.clone())],
+            (_, &[value, ..]) => {
+                let field = match operand_length {
+                    1 => Field::ZeroPage,
+                    2 => Field::Absolute,
+                    _ => Field::Long
+                };
+                vec![(field, value.clone())]
+            }
+        }
+    }
+
+    //REP and SEP with a constant change the size of later immediates, like .a16 and friends.
+    fn track_register_widths(&self, mnemonic: &str, operand: &OperandSyntax, state: &mut PassState){
+        if self.variant != Variant::Wdc65816 {
+            return;
+        }
+        let wide = match mnemonic {
+            "REP" => true,
+            "SEP" => false,
+            _ => return
+        };
+        let OperandSyntax::Immediate(expression) = operand else { return };
+        let Ok(bits) = state.evaluate(expression) else { return };
+        if bits & 0x20 != 0 { state.wide_accumulator = wide }
+        if bits & 0x10 != 0 { state.wide_index = wide }
+    }
+}
+
+//Assembles NMOS 6502 source.
+pub fn assemble(source: &str) -> Result<Assembly, AssemblyError> {
+    Assembler::new().assemble(source)
+}
+
+fn read_source(path: &Path) -> Result<Vec<Line>, AssemblyError> {
+    let source = fs::read_to_string(path).map_err(|error| AssemblyError::new(0, 0, error.to_string()).in_file(Some(path)))?;
+    preprocess(&source, Some(path))
+}
+
+fn segment_offset(segment: &str, offset: i64) -> Expression {
+    Expression::Binary(BinaryOperator::Add, Box::new(Expression::Symbol(format!(".{}", segment), 0)), Box::new(Expression::Number(offset)))
+}
+
+//The expression as a number plus multiples of symbols, when it only adds, subtracts and multiplies by numbers.
+fn linear(expression: &Expression) -> Option<(i64, HashMap<String, i64>)> {
+    let scale = |(value, terms): (i64, HashMap<String, i64>), factor: i64| {
+        (value.wrapping_mul(factor), terms.into_iter().map(|(name, term)| (name, term.wrapping_mul(factor))).collect())
+    };
+    match expression {
+        Expression::Number(value) => Some((*value, HashMap::new())),
+        Expression::Symbol(name, _) => Some((0, HashMap::from([(name.clone(), 1)]))),
+        Expression::Unary(UnaryOperator::Negate, operand) => Some(scale(linear(operand)?, -1)),
+        Expression::Binary(operator @ (BinaryOperator::Add | BinaryOperator::Subtract), left, right) => {
+            let (value, mut terms) = linear(left)?;
+            let sign = if *operator == BinaryOperator::Add { 1 } else { -1 };
+            let (right_value, right_terms) = scale(linear(right)?, sign);
+            for (name, term) in right_terms {
+                *terms.entry(name).or_insert(0) += term;
+            }
+            Some((value.wrapping_add(right_value), terms))
+        }
+        Expression::Binary(BinaryOperator::Multiply, left, right) => match (linear(left)?, linear(right)?) {
+            ((factor, terms), other) | (other, (factor, terms)) if terms.is_empty() => Some(scale(other, factor)),
+            _ => None
+        },
+        _ => None
+    }
+}
+
+fn evaluation_error(error: EvaluationError, line: &Line, column: usize) -> AssemblyError {
+    match error {
+        EvaluationError::Undefined { name, column } if name.starts_with(':') => line.error(column, "There is no anonymous label in that direction.".to_string()),
+        EvaluationError::Undefined { name, column } => line.error(column, format!("{} isn't defined.", name)),
+        EvaluationError::DivisionByZero => line.error(column, "Division by zero.".to_string())
+    }
+}
+
+//Gives anonymous labels and @ labels the names they are defined under. A line starting with : defines an
+//anonymous label, named :0, :1 and so on. :+ is the next one, :++ the one after, and :- the last one.
+//@name belongs to the last label without an @, or to the macro expansion it is in.
+fn resolve_labels(mut tokens: Vec<Spanned>, line: &Line, labels: &mut LabelScope) -> Vec<Spanned> {
+    let direction = |tokens: &[Spanned], index: usize| -> Option<(&'static str, usize)> {
+        let Token::Symbol(sign @ ("+" | "-")) = tokens.get(index + 1)?.token else { return None };
+        let count = tokens[index + 1..].iter()
+            .enumerate()
+            .take_while(|(offset, spanned)| spanned.token == Token::Symbol(sign) && spanned.column == tokens[index].column + 1 + offset)
+            .count();
+        (count > 0).then_some((sign, count))
+    };
+
+    if matches!(tokens.first(), Some(Spanned { token: Token::Symbol(":"), .. })) && direction(&tokens, 0).is_none() {
+        let column = tokens[0].column;
+        tokens.insert(0, Spanned { token: Token::Identifier(format!(":{}", labels.anonymous)), column });
+        labels.anonymous += 1;
+    }
+    if let [Spanned { token: Token::Identifier(name), .. }, Spanned { token: Token::Symbol(":"), .. }, ..] = tokens.as_slice() {
+        if !name.starts_with('@') && !name.starts_with(':') && line.local_scope.is_none() {
+            labels.scope = name.clone();
+        }
+    }
+
+    let scope = line.local_scope.as_deref().map_or(labels.scope.clone(), str::to_string);
+    let mut resolved = Vec::with_capacity(tokens.len());
+    let mut index = 0;
+    while index < tokens.len() {
+        let column = tokens[index].column;
+        match (&tokens[index].token, direction(&tokens, index)) {
+            (Token::Symbol(":"), Some((sign, count))) if index > 0 => {
+                let name = match sign {
+                    "+" => format!(":{}", labels.anonymous + count - 1),
+                    _ if count <= labels.anonymous => format!(":{}", labels.anonymous - count),
+                    _ => format!(":-{}", count)
+                };
+                resolved.push(Spanned { token: Token::Identifier(name), column });
+                index += count + 1;
+                continue;
+            }
+            (Token::Identifier(name), _) if name.starts_with('@') => {
+                resolved.push(Spanned { token: Token::Identifier(format!("{}{}", scope, name)), column });
+            }
+            _ => resolved.push(tokens[index].clone())
+        }
+        index += 1;
+    }
+
+    resolved
+}
+
+//.incbin "file" [, offset [, length]], the numbers have to be constants.
+fn read_binary(line: &Line, tokens: &[Spanned], column: usize) -> Result<Vec<u8>, AssemblyError> {
+    let Some(Spanned { token: Token::Str(path), .. }) = tokens.first() else {
+        return Err(line.error(column, ".incbin takes a file name in quotes.".to_string()));
+    };
+    let numbers = match tokens.get(1) {
+        None => Vec::new(),
+        Some(Spanned { token: Token::Symbol(","), .. }) => parse_list(&tokens[2..], line.end_column())
+            .map_err(|(column, message)| line.error(column, message))?
+            .iter()
+            .map(|expression| expression.evaluate(&|_| None, 0).map_err(|error| evaluation_error(error, line, column)))
+            .collect::<Result<Vec<_>, _>>()?,
+        Some(spanned) => return Err(line.error(spanned.column, "Expected , or the end of the line.".to_string()))
+    };
+    let path = line.resolve(path);
+    let bytes = fs::read(&path).map_err(|error| line.error(column, format!("Can't read {}: {}", path.display(), error)))?;
+
+    let (offset, length) = match numbers.as_slice() {
+        [] => (0, bytes.len() as i64),
+        [offset] => (*offset, bytes.len() as i64 - offset),
+        [offset, length] => (*offset, *length),
+        _ => return Err(line.error(column, ".incbin takes a file name, an offset and a length.".to_string()))
+    };
+    if offset < 0 || length < 0 || offset + length > bytes.len() as i64 {
+        return Err(line.error(column, format!("{} is {} bytes long.", path.display(), bytes.len())));
+    }
+
+    Ok(bytes[offset as usize..(offset + length) as usize].to_vec())
+}
+
+fn operand_expressions(operand: &OperandSyntax) -> Vec<&Expression> {
+    match operand {
+        OperandSyntax::None | OperandSyntax::Accumulator => vec![],
+        OperandSyntax::Immediate(expression)
+        | OperandSyntax::Direct(expression, _)
+        | OperandSyntax::Indirect(expression)
+        | OperandSyntax::IndirectX(expression)
+        | OperandSyntax::IndirectY(expression)
+        | OperandSyntax::IndirectLong(expression)
+        | OperandSyntax::IndirectLongY(expression)
+        | OperandSyntax::StackIndirectY(expression) => vec![expression],
+        OperandSyntax::Pair(first, second) => vec![first, second]
+    }
+}
+
+//Comma separated names, for .import and .export.
+fn parse_names(tokens: &[Spanned], end_column: usize) -> Result<Vec<(String, usize)>, (usize, String)> {
+    let mut names = Vec::new();
+    let mut position = 0;
+    loop {
+        match tokens.get(position) {
+            Some(Spanned { token: Token::Identifier(name), column }) => names.push((name.clone(), *column)),
+            Some(spanned) => return Err((spanned.column, "Expected a name.".to_string())),
+            None => return Err((end_column, "Expected a name.".to_string()))
+        }
+        match tokens.get(position + 1) {
+            None => return Ok(names),
+            Some(Spanned { token: Token::Symbol(","), .. }) => position += 2,
+            Some(spanned) => return Err((spanned.column, "Expected , or the end of the line.".to_string()))
+        }
+    }
+}
+
+fn single(mut expressions: Vec<Expression>) -> Result<Expression, String> {
+    match expressions.len() {
+        1 => Ok(expressions.remove(0)),
+        _ => Err("Expected one value.".to_string())
+    }
+}
+
+//Comma separated expressions.
+fn parse_list(tokens: &[Spanned], end_column: usize) -> Result<Vec<Expression>, (usize, String)> {
+    let mut expressions = Vec::new();
+    let mut parser = ExpressionParser::new(tokens, 0, end_column);
+    if tokens.is_empty() {
+        return Err((end_column, "Expected an expression.".to_string()));
+    }
+    loop {
+        expressions.push(parser.parse()?);
+        match tokens.get(parser.position) {
+            None => return Ok(expressions),
+            Some(Spanned { token: Token::Symbol(","), .. }) => parser.position += 1,
+            Some(spanned) => return Err((spanned.column, "Expected , or the end of the line.".to_string()))
+        }
+    }
+}
+
+//Comma separated expressions and strings.
+fn parse_data(tokens: &[Spanned], end_column: usize) -> Result<Vec<Data>, (usize, String)> {
+    let mut data = Vec::new();
+    let mut position = 0;
+    if tokens.is_empty() {
+        return Err((end_column, "Expected a value.".to_string()));
+    }
+    loop {
+        match tokens.get(position) {
+            Some(Spanned { token: Token::Str(text), .. }) => {
+                data.push(Data::Text(text.clone()));
+                position += 1;
+            }
+            _ => {
+                let mut parser = ExpressionParser::new(tokens, position, end_column);
+                data.push(Data::Value(parser.parse()?));
+                position = parser.position;
+            }
+        }
+        match tokens.get(position) {
+            None => return Ok(data),
+            Some(Spanned { token: Token::Symbol(","), .. }) => position += 1,
+            Some(spanned) => return Err((spanned.column, "Expected , or the end of the line.".to_string()))
+        }
+    }
+}
+
+fn is_register(spanned: Option<&Spanned>, register: &str) -> bool {
+    matches!(spanned, Some(Spanned { token: Token::Identifier(name), .. }) if name.eq_ignore_ascii_case(register))
+}
+
+fn is_symbol(spanned: Option<&Spanned>, symbol: &str) -> bool {
+    matches!(spanned, Some(Spanned { token: Token::Symbol(found), .. }) if *found == symbol)
+}
+
+fn parse_operand(tokens: &[Spanned], end_column: usize) -> Result<OperandSyntax, (usize, String)> {
+    let at = |index: usize| tokens.get(index);
+    let expression_at = |position: usize| {
+        let mut parser = ExpressionParser::new(tokens, position, end_column);
+        parser.parse().map(|expression| (expression, parser.position))
+    };
+    let expect_end = |position: usize| match at(position) {
+        None => Ok(()),
+        Some(spanned) => Err((spanned.column, "Expected the end of the line.".to_string()))
+    };
+
+    if tokens.is_empty() {
+        return Ok(OperandSyntax::None);
+    }
+    if tokens.len() == 1 && is_register(at(0), "A") {
+        return Ok(OperandSyntax::Accumulator);
+    }
+    if is_symbol(at(0), "#") {
+        let (expression, end) = expression_at(1)?;
+        expect_end(end)?;
+        return Ok(OperandSyntax::Immediate(expression));
+    }
+    if is_symbol(at(0), "[") {
+        let (expression, end) = expression_at(1)?;
+        if !is_symbol(at(end), "]") {
+            return Err((at(end).map_or(end_column, |spanned| spanned.column), "Expected ].".to_string()));
+        }
+        if at(end + 1).is_none() {
+            return Ok(OperandSyntax::IndirectLong(expression));
+        }
+        if is_symbol(at(end + 1), ",") && is_register(at(end + 2), "Y") {
+            expect_end(end + 3)?;
+            return Ok(OperandSyntax::IndirectLongY(expression));
+        }
+        return Err((at(end + 1).map_or(end_column, |spanned| spanned.column), "Expected ,Y or the end of the line.".to_string()));
+    }
+    //A ( starts an indirect operand when the parentheses are the whole operand, or (expr),Y. Anything else is a
+    //parenthesised expression, like ($10+2)*2.
+    if is_symbol(at(0), "(") {
+        if let Ok((expression, end)) = expression_at(1) {
+            let comma_register = |offset: usize, register: &str| is_symbol(at(end + offset), ",") && is_register(at(end + offset + 1), register);
+            let indirect = if comma_register(0, "X") && is_symbol(at(end + 2), ")") {
+                Some((OperandSyntax::IndirectX(expression), end + 3))
+            } else if comma_register(0, "S") && is_symbol(at(end + 2), ")") && comma_register(3, "Y") {
+                Some((OperandSyntax::StackIndirectY(expression), end + 5))
+            } else if is_symbol(at(end), ")") && comma_register(1, "Y") {
+                Some((OperandSyntax::IndirectY(expression), end + 3))
+            } else if is_symbol(at(end), ")") && at(end + 1).is_none() {
+                Some((OperandSyntax::Indirect(expression), end + 1))
+            } else {
+                None
+            };
+            if let Some((operand, end)) = indirect {
+                expect_end(end)?;
+                return Ok(operand);
+            }
+        }
+    }
+
+    let (expression, end) = expression_at(0)?;
+    if at(end).is_none() {
+        return Ok(OperandSyntax::Direct(expression, Index::None));
+    }
+    if !is_symbol(at(end), ",") {
+        return Err((at(end).map_or(end_column, |spanned| spanned.column), "Expected , or the end of the line.".to_string()));
+    }
+    for (register, index) in [("X", Index::X), ("Y", Index::Y), ("S", Index::S)] {
+        if is_register(at(end + 1), register) && at(end + 2).is_none() {
+            return Ok(OperandSyntax::Direct(expression, index));
+        }
+    }
+    let (second, second_end) = expression_at(end + 1)?;
+    expect_end(second_end)?;
+
+    Ok(OperandSyntax::Pair(expression, second))
+}
@@ -0,0 +1,36 @@
+use std::error::Error;
+use std::fmt;
+use std::path::{Path, PathBuf};
+
+//Where the assembler gave up. Lines and columns count from 1, line 0 is the file as a whole.
+#[derive(Debug, Clone, PartialEq)]
+pub struct AssemblyError {
+    //None for source that didn't come from a file.
+    pub file: Option<PathBuf>,
+    pub line: usize,
+    pub column: usize,
+    pub message: String,
+}
+
+impl AssemblyError {
+    pub fn new(line: usize, column: usize, message: String) -> Self {
+        AssemblyError { file: None, line, column, message }
+    }
+
+    pub fn in_file(mut self, file: Option<&Path>) -> Self {
+        self.file = file.map(Path::to_path_buf);
+        self
+    }
+}
+
+impl fmt::Display for AssemblyError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.file {
+            Some(file) if self.line == 0 => write!(f, "{}: {}", file.display(), self.message),
+            Some(file) => write!(f, "{}:{}:{}: {}", file.display(), self.line, self.column, self.message),
+            None => write!(f, "{}:{}: {}", self.line, self.column, self.message)
+        }
+    }
+}
+
+impl Error for AssemblyError {}
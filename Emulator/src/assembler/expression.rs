@@ -0,0 +1,225 @@
+use crate::assembler::lexer::{Spanned, Token};
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum UnaryOperator {
+    Negate,
+    Complement,
+    Not,
+    LowByte,
+    HighByte,
+    BankByte,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum BinaryOperator {
+    Multiply,
+    Divide,
+    Remainder,
+    Add,
+    Subtract,
+    ShiftLeft,
+    ShiftRight,
+    And,
+    Xor,
+    Or,
+    Equal,
+    NotEqual,
+    Less,
+    LessOrEqual,
+    Greater,
+    GreaterOrEqual,
+    LogicalAnd,
+    LogicalOr,
+}
+
+impl BinaryOperator {
+
+    //Higher binds tighter, like C.
+    fn precedence(self) -> u8 {
+        match self {
+            BinaryOperator::Multiply | BinaryOperator::Divide | BinaryOperator::Remainder => 9,
+            BinaryOperator::Add | BinaryOperator::Subtract => 8,
+            BinaryOperator::ShiftLeft | BinaryOperator::ShiftRight => 7,
+            BinaryOperator::Less | BinaryOperator::LessOrEqual | BinaryOperator::Greater | BinaryOperator::GreaterOrEqual => 6,
+            BinaryOperator::Equal | BinaryOperator::NotEqual => 5,
+            BinaryOperator::And => 4,
+            BinaryOperator::Xor => 3,
+            BinaryOperator::Or => 2,
+            BinaryOperator::LogicalAnd => 1,
+            BinaryOperator::LogicalOr => 0,
+        }
+    }
+
+    fn from_symbol(symbol: &str) -> Option<Self> {
+        Some(match symbol {
+            "*" => BinaryOperator::Multiply,
+            "/" => BinaryOperator::Divide,
+            "%" => BinaryOperator::Remainder,
+            "+" => BinaryOperator::Add,
+            "-" => BinaryOperator::Subtract,
+            "<<" => BinaryOperator::ShiftLeft,
+            ">>" => BinaryOperator::ShiftRight,
+            "&" => BinaryOperator::And,
+            "^" => BinaryOperator::Xor,
+            "|" => BinaryOperator::Or,
+            "==" => BinaryOperator::Equal,
+            "!=" => BinaryOperator::NotEqual,
+            "<" => BinaryOperator::Less,
+            "<=" => BinaryOperator::LessOrEqual,
+            ">" => BinaryOperator::Greater,
+            ">=" => BinaryOperator::GreaterOrEqual,
+            "&&" => BinaryOperator::LogicalAnd,
+            "||" => BinaryOperator::LogicalOr,
+            _ => return None
+        })
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Expression {
+    Number(i64),
+    //A name and the column it was written at.
+    Symbol(String, usize),
+    //*, the address of the statement.
+    ProgramCounter,
+    Unary(UnaryOperator, Box<Expression>),
+    Binary(BinaryOperator, Box<Expression>, Box<Expression>),
+}
+
+//Why an expression has no value. Undefined symbols are expected in the first pass.
+#[derive(Debug, Clone, PartialEq)]
+pub enum EvaluationError {
+    Undefined { name: String, column: usize },
+    DivisionByZero,
+}
+
+impl Expression {
+
+    //lookup gives the value of a symbol, None when it isn't defined (yet).
+    pub fn evaluate<F: Fn(&str) -> Option<i64>>(&self, lookup: &F, program_counter: i64) -> Result<i64, EvaluationError> {
+        Ok(match self {
+            Expression::Number(value) => *value,
+            Expression::Symbol(name, column) => lookup(name).ok_or_else(|| EvaluationError::Undefined { name: name.clone(), column: *column })?,
+            Expression::ProgramCounter => program_counter,
+            Expression::Unary(operator, operand) => {
+                let value = operand.evaluate(lookup, program_counter)?;
+                match operator {
+                    UnaryOperator::Negate => value.wrapping_neg(),
+                    UnaryOperator::Complement => !value,
+                    UnaryOperator::Not => (value == 0) as i64,
+                    UnaryOperator::LowByte => value & 0xFF,
+                    UnaryOperator::HighByte => (value >> 8) & 0xFF,
+                    UnaryOperator::BankByte => (value >> 16) & 0xFF,
+                }
+            }
+            Expression::Binary(operator, left, right) => {
+                let left = left.evaluate(lookup, program_counter)?;
+                let right = right.evaluate(lookup, program_counter)?;
+                match operator {
+                    BinaryOperator::Multiply => left.wrapping_mul(right),
+                    BinaryOperator::Divide => left.checked_div(right).ok_or(EvaluationError::DivisionByZero)?,
+                    BinaryOperator::Remainder => left.checked_rem(right).ok_or(EvaluationError::DivisionByZero)?,
+                    BinaryOperator::Add => left.wrapping_add(right),
+                    BinaryOperator::Subtract => left.wrapping_sub(right),
+                    BinaryOperator::ShiftLeft => left.wrapping_shl(right as u32),
+                    BinaryOperator::ShiftRight => left.wrapping_shr(right as u32),
+                    BinaryOperator::And => left & right,
+                    BinaryOperator::Xor => left ^ right,
+                    BinaryOperator::Or => left | right,
+                    BinaryOperator::Equal => (left == right) as i64,
+                    BinaryOperator::NotEqual => (left != right) as i64,
+                    BinaryOperator::Less => (left < right) as i64,
+                    BinaryOperator::LessOrEqual => (left <= right) as i64,
+                    BinaryOperator::Greater => (left > right) as i64,
+                    BinaryOperator::GreaterOrEqual => (left >= right) as i64,
+                    BinaryOperator::LogicalAnd => (left != 0 && right != 0) as i64,
+                    BinaryOperator::LogicalOr => (left != 0 || right != 0) as i64,
+                }
+            }
+        })
+    }
+}
+
+//Reads an expression from the tokens, position is moved past it. Errors are a column and a message.
+pub struct ExpressionParser<'a> {
+    tokens: &'a [Spanned],
+    pub position: usize,
+    //The column reported when the tokens run out.
+    end_column: usize,
+}
+
+impl<'a> ExpressionParser<'a> {
+
+    pub fn new(tokens: &'a [Spanned], position: usize, end_column: usize) -> Self {
+        ExpressionParser { tokens, position, end_column }
+    }
+
+    fn peek(&self) -> Option<&'a Token> {
+        self.tokens.get(self.position).map(|spanned| &spanned.token)
+    }
+
+    fn column(&self) -> usize {
+        self.tokens.get(self.position).map_or(self.end_column, |spanned| spanned.column)
+    }
+
+    pub fn parse(&mut self) -> Result<Expression, (usize, String)> {
+        self.parse_binary(0)
+    }
+
+    //Precedence climbing, every operator is left associative.
+    fn parse_binary(&mut self, minimum_precedence: u8) -> Result<Expression, (usize, String)> {
+        let mut left = self.parse_unary()?;
+        while let Some(Token::Symbol(symbol)) = self.peek() {
+            let Some(operator) = BinaryOperator::from_symbol(symbol) else { break };
+            if operator.precedence() < minimum_precedence {
+                break;
+            }
+            self.position += 1;
+            let right = self.parse_binary(operator.precedence() + 1)?;
+            left = Expression::Binary(operator, Box::new(left), Box::new(right));
+        }
+
+        Ok(left)
+    }
+
+    fn parse_unary(&mut self) -> Result<Expression, (usize, String)> {
+        let operator = match self.peek() {
+            Some(Token::Symbol("-")) => UnaryOperator::Negate,
+            Some(Token::Symbol("~")) => UnaryOperator::Complement,
+            Some(Token::Symbol("!")) => UnaryOperator::Not,
+            Some(Token::Symbol("<")) => UnaryOperator::LowByte,
+            Some(Token::Symbol(">")) => UnaryOperator::HighByte,
+            Some(Token::Symbol("^")) => UnaryOperator::BankByte,
+            Some(Token::Symbol("+")) => {
+                self.position += 1;
+                return self.parse_unary();
+            }
+            _ => return self.parse_primary()
+        };
+        self.position += 1;
+
+        Ok(Expression::Unary(operator, Box::new(self.parse_unary()?)))
+    }
+
+    fn parse_primary(&mut self) -> Result<Expression, (usize, String)> {
+        let column = self.column();
+        let expression = match self.peek() {
+            Some(Token::Number(value)) => Expression::Number(*value),
+            Some(Token::Identifier(name)) => Expression::Symbol(name.clone(), column),
+            Some(Token::Symbol("*")) => Expression::ProgramCounter,
+            Some(Token::Symbol("(")) => {
+                self.position += 1;
+                let inner = self.parse()?;
+                if self.peek() != Some(&Token::Symbol(")")) {
+                    return Err((self.column(), "Expected ).".to_string()));
+                }
+                inner
+            }
+            Some(_) => return Err((column, "Expected a value.".to_string())),
+            None => return Err((column, "Expected an expression.".to_string()))
+        };
+        self.position += 1;
+
+        Ok(expression)
+    }
+}
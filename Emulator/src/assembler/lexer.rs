@@ -0,0 +1,137 @@
+use crate::assembler::error::AssemblyError;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Token {
+    Identifier(String),
+    //A name starting with a dot, like .org. Stored without the dot, in lower case.
+    Directive(String),
+    Number(i64),
+    Str(String),
+    //Punctuation and operators, like #, (, << or ==.
+    Symbol(&'static str),
+}
+
+//A token and the column it starts at, counted from 1.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Spanned {
+    pub token: Token,
+    pub column: usize,
+}
+
+//Longer symbols first, so << isn't read as two <.
+const SYMBOLS: [&str; 27] = [
+    "<<", ">>", "==", "!=", "<=", ">=", "&&", "||", "::",
+    "#", "(", ")", "[", "]", ",", ":", "=", "+", "-", "*", "/", "%", "&", "|", "^", "~", "!",
+];
+const SINGLE_SYMBOLS: [&str; 2] = ["<", ">"];
+
+fn is_identifier_start(character: char) -> bool {
+    character.is_ascii_alphabetic() || character == '_' || character == '@'
+}
+
+fn is_identifier_part(character: char) -> bool {
+    character.is_ascii_alphanumeric() || character == '_' || character == '@'
+}
+
+//Splits a line into tokens, stopping at a ; comment.
+pub fn tokenize(line: &str, line_number: usize) -> Result<Vec<Spanned>, AssemblyError> {
+    let characters: Vec<char> = line.chars().collect();
+    let mut tokens: Vec<Spanned> = Vec::new();
+    let mut position = 0;
+    let error = |column: usize, message: String| AssemblyError::new(line_number, column + 1, message);
+
+    while position < characters.len() {
+        let character = characters[position];
+        let start = position;
+        if character == ';' {
+            break;
+        }
+        if character.is_whitespace() {
+            position += 1;
+            continue;
+        }
+
+        //% is a binary number where a value can start, and the remainder operator after one.
+        let value_can_start = !matches!(tokens.last(), Some(Spanned { token: Token::Number(_) | Token::Identifier(_) | Token::Symbol(")" | "]"), .. }));
+
+        let token = if character == '$' || (character == '%' && value_can_start && characters.get(position + 1).is_some_and(|c| *c == '0' || *c == '1')) {
+            let radix = if character == '$' { 16 } else { 2 };
+            position += 1;
+            let digits_start = position;
+            while position < characters.len() && characters[position].is_digit(radix) {
+                position += 1;
+            }
+            let digits: String = characters[digits_start..position].iter().collect();
+            Token::Number(i64::from_str_radix(&digits, radix).map_err(|_| error(start, format!("Invalid number {}{}.", character, digits)))?)
+        }
+        else if character.is_ascii_digit() {
+            while position < characters.len() && characters[position].is_ascii_alphanumeric() {
+                position += 1;
+            }
+            let digits: String = characters[start..position].iter().collect();
+            Token::Number(digits.parse().map_err(|_| error(start, format!("Invalid number {}.", digits)))?)
+        }
+        else if is_identifier_start(character) || (character == '.' && characters.get(position + 1).is_some_and(|&c| is_identifier_start(c))) {
+            position += 1;
+            while position < characters.len() && is_identifier_part(characters[position]) {
+                position += 1;
+            }
+            let name: String = characters[start..position].iter().collect();
+            match name.strip_prefix('.') {
+                Some(directive) => Token::Directive(directive.to_ascii_lowercase()),
+                None => Token::Identifier(name)
+            }
+        }
+        else if character == '"' {
+            let (text, end) = read_quoted(&characters, position, '"').ok_or_else(|| error(start, "Unterminated string.".to_string()))?;
+            position = end;
+            Token::Str(text)
+        }
+        else if character == '\'' {
+            let (text, end) = read_quoted(&characters, position, '\'').ok_or_else(|| error(start, "Unterminated character.".to_string()))?;
+            let mut text = text.chars();
+            let (Some(value), None) = (text.next(), text.next()) else {
+                return Err(error(start, "A character constant holds one character.".to_string()));
+            };
+            position = end;
+            Token::Number(value as i64)
+        }
+        else {
+            let rest: String = characters[position..characters.len().min(position + 2)].iter().collect();
+            let symbol = SYMBOLS.iter().chain(SINGLE_SYMBOLS.iter())
+                .find(|symbol| rest.starts_with(**symbol))
+                .ok_or_else(|| error(start, format!("Unexpected character {}.", character)))?;
+            position += symbol.len();
+            Token::Symbol(symbol)
+        };
+
+        tokens.push(Spanned { token, column: start + 1 });
+    }
+
+    Ok(tokens)
+}
+
+//Reads a quoted string from the opening quote, returns the text and the position after the closing quote.
+fn read_quoted(characters: &[char], start: usize, quote: char) -> Option<(String, usize)> {
+    let mut text = String::new();
+    let mut position = start + 1;
+    loop {
+        let character = *characters.get(position)?;
+        position += 1;
+        match character {
+            _ if character == quote => return Some((text, position)),
+            '\\' => {
+                let escaped = *characters.get(position)?;
+                position += 1;
+                text.push(match escaped {
+                    'n' => '\n',
+                    'r' => '\r',
+                    't' => '\t',
+                    '0' => '\0',
+                    other => other
+                });
+            }
+            _ => text.push(character)
+        }
+    }
+}
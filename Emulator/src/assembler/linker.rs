@@ -0,0 +1,398 @@
+use std::collections::{BTreeMap, HashMap};
+use std::error::Error;
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::Path;
+use crate::assembler::assembler::Assembly;
+use crate::assembler::expression::EvaluationError;
+use crate::assembler::object::{ObjectFile, SymbolValue};
+
+#[derive(Debug)]
+pub enum LinkError {
+    InvalidObject(String),
+    //A mistake in the memory configuration, lines count from 1.
+    Config { line: usize, message: String },
+    //Segments, symbols or relocations that don't fit together.
+    Link(String),
+    Io(io::Error),
+}
+
+impl fmt::Display for LinkError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            LinkError::InvalidObject(reason) => write!(f, "{}", reason),
+            LinkError::Config { line, message } => write!(f, "Memory configuration line {}: {}", line, message),
+            LinkError::Link(message) => write!(f, "{}", message),
+            LinkError::Io(error) => write!(f, "{}", error),
+        }
+    }
+}
+
+impl Error for LinkError {}
+
+impl From<io::Error> for LinkError {
+    fn from(error: io::Error) -> Self {
+        LinkError::Io(error)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum SegmentType {
+    //Code and constant data, written to the image.
+    ReadOnly,
+    //Initialised data, written to the image.
+    ReadWrite,
+    //Only reserves space, nothing is written.
+    Bss,
+    //Like Bss, and has to end up in the zero page.
+    ZeroPage,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct MemoryArea {
+    pub name: String,
+    pub start: u32,
+    pub size: u32,
+    //Whether the unused part of the area is written to the image, with fill_value.
+    pub fill: bool,
+    pub fill_value: u8,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct SegmentRule {
+    pub name: String,
+    //The memory area it goes into.
+    pub load: String,
+    pub segment_type: SegmentType,
+    //A fixed address, like $FFFA for the vectors.
+    pub start: Option<u32>,
+    pub align: u32,
+    //Defines __NAME_LOAD__ and __NAME_SIZE__ for the start and size of the segment.
+    pub define: bool,
+}
+
+//Where segments go, in the format of ld65 configurations:
+//
+//MEMORY {
+//    ZP:  start = $0000, size = $0100;
+//    RAM: start = $0200, size = $7E00;
+//    ROM: start = $8000, size = $8000, fill = yes, fillval = $FF;
+//}
+//SEGMENTS {
+//    ZEROPAGE: load = ZP, type = zp;
+//    DATA:     load = RAM, type = rw;
+//    BSS:      load = RAM, type = bss, define = yes;
+//    CODE:     load = ROM, type = ro;
+//    VECTORS:  load = ROM, type = ro, start = $FFFA;
+//}
+//
+//Segments fill their memory area in the order they are listed, # starts a comment.
+#[derive(Debug, Clone, PartialEq)]
+pub struct MemoryConfig {
+    pub memory: Vec<MemoryArea>,
+    pub segments: Vec<SegmentRule>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum ConfigToken {
+    Word(String),
+    Number(u32),
+    Symbol(char),
+}
+
+fn config_error(line: usize, message: String) -> LinkError {
+    LinkError::Config { line, message }
+}
+
+fn tokenize_config(text: &str) -> Result<Vec<(ConfigToken, usize)>, LinkError> {
+    let mut tokens = Vec::new();
+    for (index, line) in text.lines().enumerate() {
+        let number = index + 1;
+        let mut characters = line.split('#').next().unwrap_or("").chars().peekable();
+        while let Some(&character) = characters.peek() {
+            if character.is_whitespace() {
+                characters.next();
+                continue;
+            }
+            let token = if character.is_ascii_alphanumeric() || character == '_' || character == '$' || character == '%' || character == '"' {
+                let mut word = String::new();
+                while let Some(&character) = characters.peek() {
+                    if !(character.is_ascii_alphanumeric() || "_$%\"".contains(character)) {
+                        break;
+                    }
+                    word.push(character);
+                    characters.next();
+                }
+                let number_value = match word.chars().next() {
+                    Some('$') => Some(u32::from_str_radix(&word[1..], 16)),
+                    Some('%') => Some(u32::from_str_radix(&word[1..], 2)),
+                    Some(digit) if digit.is_ascii_digit() => Some(word.parse()),
+                    _ => None
+                };
+                match number_value {
+                    Some(Ok(value)) => ConfigToken::Number(value),
+                    Some(Err(_)) => return Err(config_error(number, format!("Invalid number {}.", word))),
+                    None => ConfigToken::Word(word.trim_matches('"').to_string())
+                }
+            } else if "{}:;,=".contains(character) {
+                characters.next();
+                ConfigToken::Symbol(character)
+            } else {
+                return Err(config_error(number, format!("Unexpected character {}.", character)));
+            };
+            tokens.push((token, number));
+        }
+    }
+
+    Ok(tokens)
+}
+
+impl MemoryConfig {
+
+    pub fn parse(text: &str) -> Result<MemoryConfig, LinkError> {
+        let tokens = tokenize_config(text)?;
+        let mut config = MemoryConfig { memory: Vec::new(), segments: Vec::new() };
+        let mut segment_lines = Vec::new();
+        let mut position = 0;
+        let last_line = tokens.last().map_or(1, |&(_, line)| line);
+        let line_at = |position: usize| tokens.get(position).map_or(last_line, |&(_, line)| line);
+        let expect = |position: usize, symbol: char| match tokens.get(position) {
+            Some((ConfigToken::Symbol(found), _)) if *found == symbol => Ok(position + 1),
+            _ => Err(config_error(line_at(position), format!("Expected {}.", symbol)))
+        };
+
+        while position < tokens.len() {
+            let section = match &tokens[position].0 {
+                ConfigToken::Word(word) if word == "MEMORY" || word == "SEGMENTS" => word.clone(),
+                _ => return Err(config_error(line_at(position), "Expected MEMORY or SEGMENTS.".to_string()))
+            };
+            position = expect(position + 1, '{')?;
+
+            while !matches!(tokens.get(position), Some((ConfigToken::Symbol('}'), _))) {
+                let line = line_at(position);
+                let Some((ConfigToken::Word(name), _)) = tokens.get(position) else {
+                    return Err(config_error(line, "Expected a name or }.".to_string()));
+                };
+                position = expect(position + 1, ':')?;
+
+                let mut attributes = HashMap::new();
+                loop {
+                    let (Some((ConfigToken::Word(key), _)), Some((ConfigToken::Symbol('='), _)), Some((value, _))) =
+                        (tokens.get(position), tokens.get(position + 1), tokens.get(position + 2)) else {
+                        return Err(config_error(line_at(position), "Expected attribute = value.".to_string()));
+                    };
+                    attributes.insert(key.to_ascii_lowercase(), value.clone());
+                    position += 3;
+                    match tokens.get(position) {
+                        Some((ConfigToken::Symbol(','), _)) => position += 1,
+                        Some((ConfigToken::Symbol(';'), _)) => { position += 1; break }
+                        _ => return Err(config_error(line_at(position), "Expected , or ;.".to_string()))
+                    }
+                }
+
+                if section == "MEMORY" {
+                    config.memory.push(memory_area(name, attributes, line)?);
+                } else {
+                    config.segments.push(segment_rule(name, attributes, line)?);
+                    segment_lines.push(line);
+                }
+            }
+            position += 1;
+        }
+
+        for (segment, &line) in config.segments.iter().zip(&segment_lines) {
+            if !config.memory.iter().any(|area| area.name == segment.load) {
+                return Err(config_error(line, format!("Segment {} loads into {}, which isn't in MEMORY.", segment.name, segment.load)));
+            }
+        }
+
+        Ok(config)
+    }
+
+    pub fn from_file<P: AsRef<Path>>(path: P) -> Result<MemoryConfig, LinkError> {
+        MemoryConfig::parse(&fs::read_to_string(path)?)
+    }
+}
+
+fn number(attributes: &mut HashMap<String, ConfigToken>, key: &str, line: usize) -> Result<Option<u32>, LinkError> {
+    match attributes.remove(key) {
+        None => Ok(None),
+        Some(ConfigToken::Number(value)) => Ok(Some(value)),
+        Some(_) => Err(config_error(line, format!("{} takes a number.", key)))
+    }
+}
+
+fn word(attributes: &mut HashMap<String, ConfigToken>, key: &str, line: usize) -> Result<Option<String>, LinkError> {
+    match attributes.remove(key) {
+        None => Ok(None),
+        Some(ConfigToken::Word(value)) => Ok(Some(value)),
+        Some(_) => Err(config_error(line, format!("{} takes a name.", key)))
+    }
+}
+
+fn yes_or_no(attributes: &mut HashMap<String, ConfigToken>, key: &str, line: usize) -> Result<bool, LinkError> {
+    match word(attributes, key, line)?.as_deref() {
+        None | Some("no") => Ok(false),
+        Some("yes") => Ok(true),
+        Some(other) => Err(config_error(line, format!("{} is yes or no, not {}.", key, other)))
+    }
+}
+
+fn no_other_attributes(attributes: HashMap<String, ConfigToken>, name: &str, line: usize) -> Result<(), LinkError> {
+    match attributes.keys().next() {
+        Some(key) => Err(config_error(line, format!("{} has an unknown attribute {}.", name, key))),
+        None => Ok(())
+    }
+}
+
+fn memory_area(name: &str, mut attributes: HashMap<String, ConfigToken>, line: usize) -> Result<MemoryArea, LinkError> {
+    let start = number(&mut attributes, "start", line)?.ok_or_else(|| config_error(line, format!("{} needs a start.", name)))?;
+    let size = number(&mut attributes, "size", line)?.ok_or_else(|| config_error(line, format!("{} needs a size.", name)))?;
+    let fill = yes_or_no(&mut attributes, "fill", line)?;
+    let fill_value = number(&mut attributes, "fillval", line)?.unwrap_or(0);
+    no_other_attributes(attributes, name, line)?;
+    if start as u64 + size as u64 > 0x1000000 {
+        return Err(config_error(line, format!("{} goes past the end of the address space.", name)));
+    }
+    if fill_value > 0xFF {
+        return Err(config_error(line, "fillval has to fit in a byte.".to_string()));
+    }
+
+    Ok(MemoryArea { name: name.to_string(), start, size, fill, fill_value: fill_value as u8 })
+}
+
+fn segment_rule(name: &str, mut attributes: HashMap<String, ConfigToken>, line: usize) -> Result<SegmentRule, LinkError> {
+    let load = word(&mut attributes, "load", line)?.ok_or_else(|| config_error(line, format!("{} needs a load area.", name)))?;
+    let segment_type = match word(&mut attributes, "type", line)?.as_deref() {
+        None | Some("ro") => SegmentType::ReadOnly,
+        Some("rw") => SegmentType::ReadWrite,
+        Some("bss") => SegmentType::Bss,
+        Some("zp") => SegmentType::ZeroPage,
+        Some(other) => return Err(config_error(line, format!("Unknown segment type {}, expected ro, rw, bss or zp.", other)))
+    };
+    let start = number(&mut attributes, "start", line)?;
+    let align = number(&mut attributes, "align", line)?.unwrap_or(1);
+    let define = yes_or_no(&mut attributes, "define", line)?;
+    no_other_attributes(attributes, name, line)?;
+    if align == 0 {
+        return Err(config_error(line, "align has to be at least 1.".to_string()));
+    }
+
+    Ok(SegmentRule { name: name.to_string(), load, segment_type, start, align, define })
+}
+
+//Places the segments of the objects, in the order given, and fills in the relocations. The result loads like
+//an assembled program, with every export and defined symbol in its symbol table.
+pub fn link(config: &MemoryConfig, objects: &[ObjectFile]) -> Result<Assembly, LinkError> {
+    let error = |message: String| Err(LinkError::Link(message));
+
+    for object in objects {
+        if let Some(segment) = object.segments.iter().find(|segment| !config.segments.iter().any(|rule| rule.name == segment.name)) {
+            return error(format!("{} uses segment {}, which isn't in the memory configuration.", object.name, segment.name));
+        }
+    }
+
+    //Where each object's part of each segment starts.
+    let mut placements: HashMap<(usize, &str), u32> = HashMap::new();
+    let mut symbols = BTreeMap::new();
+    for area in &config.memory {
+        let mut address = area.start;
+        for rule in config.segments.iter().filter(|rule| rule.load == area.name) {
+            if let Some(start) = rule.start {
+                if start < address {
+                    return error(format!("Segment {} starts at ${:04X}, but the segments before it end at ${:04X}.", rule.name, start, address));
+                }
+                address = start;
+            }
+            address = address.div_ceil(rule.align) * rule.align;
+            let start = address;
+            for (index, object) in objects.iter().enumerate() {
+                if let Some(segment) = object.segment(&rule.name) {
+                    placements.insert((index, rule.name.as_str()), address);
+                    address += segment.bytes.len() as u32;
+                }
+            }
+            if address > area.start + area.size {
+                return error(format!("Segment {} doesn't fit in {}, it needs {} more bytes.", rule.name, area.name, address - (area.start + area.size)));
+            }
+            if rule.segment_type == SegmentType::ZeroPage && address > 0x100 {
+                return error(format!("Segment {} is zp, but it ends at ${:04X}.", rule.name, address));
+            }
+            if rule.define {
+                symbols.insert(format!("__{}_LOAD__", rule.name), start as i64);
+                symbols.insert(format!("__{}_SIZE__", rule.name), (address - start) as i64);
+            }
+        }
+    }
+
+    for (index, object) in objects.iter().enumerate() {
+        for (name, value) in &object.exports {
+            let value = match value {
+                SymbolValue::Absolute(value) => *value,
+                SymbolValue::Relative { segment, offset } => match placements.get(&(index, segment.as_str())) {
+                    Some(&address) => address as i64 + *offset as i64,
+                    None => return error(format!("{} exports {} from segment {}, which it doesn't have.", object.name, name, segment))
+                }
+            };
+            if symbols.insert(name.clone(), value).is_some() {
+                return error(format!("{} is exported more than once, again by {}.", name, object.name));
+            }
+        }
+    }
+    for object in objects {
+        if let Some(name) = object.imports.iter().find(|name| !symbols.contains_key(*name)) {
+            return error(format!("{} imports {}, but nothing exports it.", object.name, name));
+        }
+    }
+
+    let mut output: Vec<(u32, u8)> = Vec::new();
+    for area in config.memory.iter().filter(|area| area.fill) {
+        output.extend((area.start..area.start + area.size).map(|address| (address, area.fill_value)));
+    }
+    for (index, object) in objects.iter().enumerate() {
+        for segment in &object.segments {
+            let base = placements[&(index, segment.name.as_str())];
+            let rule = config.segments.iter().find(|rule| rule.name == segment.name).unwrap();
+            let mut bytes = segment.bytes.clone();
+
+            let lookup = |name: &str| match name.strip_prefix('.') {
+                Some(segment) => placements.get(&(index, segment)).map(|&address| address as i64),
+                None => symbols.get(name).copied()
+            };
+            for relocation in &segment.relocations {
+                let at = |message: String| LinkError::Link(format!("{}:{}: {}", object.name, relocation.line, message));
+                let value = relocation.expression.evaluate(&lookup, 0).map_err(|evaluation| at(match evaluation {
+                    EvaluationError::Undefined { name, .. } => format!("{} isn't defined.", name),
+                    EvaluationError::DivisionByZero => "Division by zero.".to_string()
+                }))?;
+                let encoded = relocation.field.encode(value).map_err(at)?;
+                let offset = relocation.offset as usize;
+                if offset + encoded.len() > bytes.len() {
+                    return Err(at(format!("The relocation at offset {} is outside segment {}.", offset, segment.name)));
+                }
+                bytes[offset..offset + encoded.len()].copy_from_slice(&encoded);
+            }
+
+            match rule.segment_type {
+                SegmentType::ReadOnly | SegmentType::ReadWrite => {
+                    output.extend(bytes.into_iter().enumerate().map(|(offset, byte)| (base + offset as u32, byte)));
+                }
+                SegmentType::Bss | SegmentType::ZeroPage if bytes.iter().any(|&byte| byte != 0) => {
+                    return error(format!("{} puts data in segment {}, which is {}.", object.name, segment.name,
+                        if rule.segment_type == SegmentType::Bss { "bss" } else { "zp" }));
+                }
+                SegmentType::Bss | SegmentType::ZeroPage => {}
+            }
+        }
+    }
+
+    let origin = output.iter().map(|&(address, _)| address).min().unwrap_or(0);
+    let end = output.iter().map(|&(address, _)| address + 1).max().unwrap_or(0);
+    let mut image = vec![0; (end - origin) as usize];
+    for (address, byte) in output {
+        image[(address - origin) as usize] = byte;
+    }
+
+    Ok(Assembly { origin, image, symbols, listing: Vec::new() })
+}
@@ -0,0 +1,307 @@
+use std::collections::BTreeMap;
+use std::fs;
+use std::io;
+use std::path::Path;
+use crate::assembler::expression::{BinaryOperator, Expression, UnaryOperator};
+use crate::assembler::linker::LinkError;
+use crate::cpu::save_state::{checksum, SaveStateError, StateReader, StateWriter};
+
+const MAGIC: &[u8; 4] = b"R65O";
+pub const OBJECT_VERSION: u16 = 1;
+//How deep an expression read from an object file can nest, so a damaged file stops with an error instead of
+//overflowing the stack.
+const MAXIMUM_EXPRESSION_DEPTH: usize = 256;
+
+//In the order they are stored in object files.
+const UNARY_OPERATORS: [UnaryOperator; 6] = [
+    UnaryOperator::Negate, UnaryOperator::Complement, UnaryOperator::Not, UnaryOperator::LowByte, UnaryOperator::HighByte,
+    UnaryOperator::BankByte,
+];
+const BINARY_OPERATORS: [BinaryOperator; 18] = [
+    BinaryOperator::Multiply, BinaryOperator::Divide, BinaryOperator::Remainder, BinaryOperator::Add, BinaryOperator::Subtract,
+    BinaryOperator::ShiftLeft, BinaryOperator::ShiftRight, BinaryOperator::And, BinaryOperator::Xor, BinaryOperator::Or,
+    BinaryOperator::Equal, BinaryOperator::NotEqual, BinaryOperator::Less, BinaryOperator::LessOrEqual, BinaryOperator::Greater,
+    BinaryOperator::GreaterOrEqual, BinaryOperator::LogicalAnd, BinaryOperator::LogicalOr,
+];
+
+//How a value is stored in the bytes of an instruction or data, and which values fit.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Field {
+    //Data and immediates, -128 to 255.
+    Byte,
+    //Zero page addresses and bank numbers, 0 to 255.
+    ZeroPage,
+    //Data and 16-bit immediates, -32768 to 65535.
+    Word,
+    Absolute,
+    Long,
+    //The value is the offset from the next instruction.
+    Branch,
+    LongBranch,
+}
+
+const FIELDS: [Field; 7] = [Field::Byte, Field::ZeroPage, Field::Word, Field::Absolute, Field::Long, Field::Branch, Field::LongBranch];
+
+impl Field {
+
+    pub fn size(self) -> usize {
+        match self {
+            Field::Byte | Field::ZeroPage | Field::Branch => 1,
+            Field::Word | Field::Absolute | Field::LongBranch => 2,
+            Field::Long => 3,
+        }
+    }
+
+    //Little endian, or why the value doesn't fit.
+    pub fn encode(self, value: i64) -> Result<Vec<u8>, String> {
+        let fits = match self {
+            Field::Byte => (-0x80..=0xFF).contains(&value),
+            Field::ZeroPage => (0..=0xFF).contains(&value),
+            Field::Word => (-0x8000..=0xFFFF).contains(&value),
+            Field::Absolute => (0..=0xFFFF).contains(&value),
+            Field::Long => (0..=0xFFFFFF).contains(&value),
+            Field::Branch => (-0x80..=0x7F).contains(&value),
+            //BRL wraps around the bank, so every offset reaches.
+            Field::LongBranch => true,
+        };
+        if !fits {
+            return Err(match self {
+                Field::Byte => format!("{} doesn't fit in a byte.", value),
+                Field::ZeroPage => format!("{} isn't a zero page address.", signed_hex(value)),
+                Field::Word => format!("{} doesn't fit in a word.", value),
+                Field::Absolute => format!("{} doesn't fit in 16 bits.", signed_hex(value)),
+                Field::Long => format!("{} doesn't fit in 24 bits.", signed_hex(value)),
+                _ => format!("The branch target is {} bytes away, branches reach -128 to 127.", value)
+            });
+        }
+
+        Ok(value.to_le_bytes()[..self.size()].to_vec())
+    }
+}
+
+//$FF, or -$1 for negative values instead of their two's complement.
+pub(crate) fn signed_hex(value: i64) -> String {
+    if value < 0 { format!("-${:X}", value.unsigned_abs()) } else { format!("${:X}", value) }
+}
+
+//A value the assembler couldn't know, filled in by the linker. The expression uses imported symbols and the
+//start of this object's segments, written as the segment name with a dot in front (.CODE).
+#[derive(Debug, Clone, PartialEq)]
+pub struct Relocation {
+    //From the start of the segment.
+    pub offset: u32,
+    pub field: Field,
+    pub expression: Expression,
+    //The source line, for errors while linking.
+    pub line: usize,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ObjectSegment {
+    pub name: String,
+    pub bytes: Vec<u8>,
+    pub relocations: Vec<Relocation>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum SymbolValue {
+    Absolute(i64),
+    //A label, from the start of this object's part of the segment.
+    Relative { segment: String, offset: u32 },
+}
+
+//What the assembler makes of one source file when it is linked with others.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ObjectFile {
+    //Where it came from, for errors while linking.
+    pub name: String,
+    pub segments: Vec<ObjectSegment>,
+    pub exports: BTreeMap<String, SymbolValue>,
+    pub imports: Vec<String>,
+}
+
+impl ObjectFile {
+
+    pub fn segment(&self, name: &str) -> Option<&ObjectSegment> {
+        self.segments.iter().find(|segment| segment.name == name)
+    }
+
+    //The same layout as save states: a header, length prefixed fields and a CRC-32 at the end.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut writer = StateWriter::new();
+        MAGIC.iter().for_each(|&byte| writer.write_u8(byte));
+        writer.write_u16(OBJECT_VERSION);
+        writer.write_bytes(self.name.as_bytes());
+
+        writer.write_u32(self.segments.len() as u32);
+        for segment in &self.segments {
+            writer.write_bytes(segment.name.as_bytes());
+            writer.write_bytes(&segment.bytes);
+            writer.write_u32(segment.relocations.len() as u32);
+            for relocation in &segment.relocations {
+                writer.write_u32(relocation.offset);
+                writer.write_u8(FIELDS.iter().position(|&field| field == relocation.field).unwrap() as u8);
+                writer.write_u32(relocation.line as u32);
+                write_expression(&mut writer, &relocation.expression);
+            }
+        }
+
+        writer.write_u32(self.exports.len() as u32);
+        for (name, value) in &self.exports {
+            writer.write_bytes(name.as_bytes());
+            match value {
+                SymbolValue::Absolute(value) => {
+                    writer.write_u8(0);
+                    write_i64(&mut writer, *value);
+                }
+                SymbolValue::Relative { segment, offset } => {
+                    writer.write_u8(1);
+                    writer.write_bytes(segment.as_bytes());
+                    writer.write_u32(*offset);
+                }
+            }
+        }
+
+        writer.write_u32(self.imports.len() as u32);
+        for name in &self.imports {
+            writer.write_bytes(name.as_bytes());
+        }
+
+        let mut bytes = writer.into_bytes();
+        let crc = checksum(&bytes);
+        bytes.extend_from_slice(&crc.to_le_bytes());
+        bytes
+    }
+
+    pub fn from_bytes(bytes: &[u8]) -> Result<ObjectFile, LinkError> {
+        if bytes.len() < MAGIC.len() + 2 + 4 || &bytes[..MAGIC.len()] != MAGIC {
+            return Err(LinkError::InvalidObject("Not an object file.".to_string()));
+        }
+        let (body, crc) = bytes.split_at(bytes.len() - 4);
+        if checksum(body) != u32::from_le_bytes([crc[0], crc[1], crc[2], crc[3]]) {
+            return Err(LinkError::InvalidObject("The object file is damaged, its checksum doesn't match.".to_string()));
+        }
+
+        let mut reader = StateReader::new(&body[MAGIC.len()..]);
+        let version = reader.read_u16()?;
+        if version != OBJECT_VERSION {
+            return Err(LinkError::InvalidObject(format!("Object file version {} isn't supported, the linker reads version {}.", version, OBJECT_VERSION)));
+        }
+        let name = read_string(&mut reader)?;
+
+        let mut segments = Vec::new();
+        for _ in 0..reader.read_u32()? {
+            let name = read_string(&mut reader)?;
+            let bytes = reader.read_bytes()?.to_vec();
+            let mut relocations = Vec::new();
+            for _ in 0..reader.read_u32()? {
+                let offset = reader.read_u32()?;
+                let field = *FIELDS.get(reader.read_u8()? as usize).ok_or(invalid("an unknown field"))?;
+                let line = reader.read_u32()? as usize;
+                let expression = read_expression(&mut reader, 0)?;
+                relocations.push(Relocation { offset, field, expression, line });
+            }
+            segments.push(ObjectSegment { name, bytes, relocations });
+        }
+
+        let mut exports = BTreeMap::new();
+        for _ in 0..reader.read_u32()? {
+            let name = read_string(&mut reader)?;
+            let value = match reader.read_u8()? {
+                0 => SymbolValue::Absolute(read_i64(&mut reader)?),
+                1 => SymbolValue::Relative { segment: read_string(&mut reader)?, offset: reader.read_u32()? },
+                _ => return Err(invalid("an unknown kind of symbol"))
+            };
+            exports.insert(name, value);
+        }
+
+        let mut imports = Vec::new();
+        for _ in 0..reader.read_u32()? {
+            imports.push(read_string(&mut reader)?);
+        }
+
+        Ok(ObjectFile { name, segments, exports, imports })
+    }
+
+    pub fn write_to_file<P: AsRef<Path>>(&self, path: P) -> io::Result<()> {
+        fs::write(path, self.to_bytes())
+    }
+
+    pub fn read_from_file<P: AsRef<Path>>(path: P) -> Result<ObjectFile, LinkError> {
+        ObjectFile::from_bytes(&fs::read(path)?)
+    }
+}
+
+fn invalid(what: &str) -> LinkError {
+    LinkError::InvalidObject(format!("The object file has {}.", what))
+}
+
+impl From<SaveStateError> for LinkError {
+    fn from(error: SaveStateError) -> Self {
+        match error {
+            SaveStateError::Truncated => LinkError::InvalidObject("The object file ends early.".to_string()),
+            error => LinkError::InvalidObject(error.to_string())
+        }
+    }
+}
+
+fn write_i64(writer: &mut StateWriter, value: i64){
+    writer.write_u32(value as u32);
+    writer.write_u32((value >> 32) as u32);
+}
+
+fn read_i64(reader: &mut StateReader) -> Result<i64, LinkError> {
+    let low = reader.read_u32()? as i64;
+    let high = reader.read_u32()? as i64;
+    Ok(high << 32 | low)
+}
+
+fn read_string(reader: &mut StateReader) -> Result<String, LinkError> {
+    String::from_utf8(reader.read_bytes()?.to_vec()).map_err(|_| invalid("a name that isn't UTF-8"))
+}
+
+fn write_expression(writer: &mut StateWriter, expression: &Expression){
+    match expression {
+        Expression::Number(value) => {
+            writer.write_u8(0);
+            write_i64(writer, *value);
+        }
+        Expression::Symbol(name, column) => {
+            writer.write_u8(1);
+            writer.write_bytes(name.as_bytes());
+            writer.write_u32(*column as u32);
+        }
+        Expression::ProgramCounter => writer.write_u8(2),
+        Expression::Unary(operator, operand) => {
+            writer.write_u8(3);
+            writer.write_u8(UNARY_OPERATORS.iter().position(|candidate| candidate == operator).unwrap() as u8);
+            write_expression(writer, operand);
+        }
+        Expression::Binary(operator, left, right) => {
+            writer.write_u8(4);
+            writer.write_u8(BINARY_OPERATORS.iter().position(|candidate| candidate == operator).unwrap() as u8);
+            write_expression(writer, left);
+            write_expression(writer, right);
+        }
+    }
+}
+
+fn read_expression(reader: &mut StateReader, depth: usize) -> Result<Expression, LinkError> {
+    if depth >= MAXIMUM_EXPRESSION_DEPTH {
+        return Err(invalid("an expression that nests too deep"));
+    }
+    Ok(match reader.read_u8()? {
+        0 => Expression::Number(read_i64(reader)?),
+        1 => Expression::Symbol(read_string(reader)?, reader.read_u32()? as usize),
+        2 => Expression::ProgramCounter,
+        3 => {
+            let operator = *UNARY_OPERATORS.get(reader.read_u8()? as usize).ok_or(invalid("an unknown operator"))?;
+            Expression::Unary(operator, Box::new(read_expression(reader, depth + 1)?))
+        }
+        4 => {
+            let operator = *BINARY_OPERATORS.get(reader.read_u8()? as usize).ok_or(invalid("an unknown operator"))?;
+            Expression::Binary(operator, Box::new(read_expression(reader, depth + 1)?), Box::new(read_expression(reader, depth + 1)?))
+        }
+        _ => return Err(invalid("an unknown kind of expression"))
+    })
+}
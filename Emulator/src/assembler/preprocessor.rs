@@ -0,0 +1,389 @@
+use std::collections::HashMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::rc::Rc;
+use crate::assembler::error::AssemblyError;
+use crate::assembler::expression::{EvaluationError, ExpressionParser};
+use crate::assembler::lexer::{tokenize, Spanned, Token};
+
+//How deep macros, repeats and includes can nest, so a macro that calls itself stops with an error.
+const MAXIMUM_DEPTH: usize = 64;
+//How many lines macros, repeats and includes can expand to, so .repeat $7FFFFFFF stops with an error instead of
+//running out of memory.
+const MAXIMUM_LINES: usize = 1_000_000;
+
+//A line after macros, repeats, includes and conditionals are expanded, with where it came from.
+#[derive(Debug, Clone)]
+pub struct Line {
+    pub text: String,
+    pub file: Option<Rc<PathBuf>>,
+    pub number: usize,
+    //Set in a macro expansion, its @ labels belong to the expansion instead of the last label.
+    pub local_scope: Option<Rc<str>>,
+    //A macro call, kept for its label and the listing. The expansion follows it.
+    pub macro_call: bool,
+}
+
+impl Line {
+
+    pub fn error(&self, column: usize, message: String) -> AssemblyError {
+        AssemblyError::new(self.number, column, message).in_file(self.file.as_deref().map(PathBuf::as_path))
+    }
+
+    pub fn tokenize(&self) -> Result<Vec<Spanned>, AssemblyError> {
+        tokenize(&self.text, self.number).map_err(|error| error.in_file(self.file.as_deref().map(PathBuf::as_path)))
+    }
+
+    //The column past the end of the line, for errors about something missing.
+    pub fn end_column(&self) -> usize {
+        self.text.chars().count() + 1
+    }
+
+    //Paths in .include and .incbin are relative to the file they are written in.
+    pub fn resolve(&self, path: &str) -> PathBuf {
+        match self.file.as_deref().and_then(|file| file.parent()) {
+            Some(directory) => directory.join(path),
+            None => PathBuf::from(path)
+        }
+    }
+}
+
+struct Macro {
+    parameters: Vec<String>,
+    body: Vec<Line>,
+}
+
+//One .if block. active is whether the current branch is assembled, taken whether any branch has been.
+struct Conditional<'a> {
+    line: &'a Line,
+    column: usize,
+    enclosing_active: bool,
+    active: bool,
+    taken: bool,
+    has_else: bool,
+}
+
+//Constants are assignments whose value is known where they are written, which is what .if, .ifdef and
+//.repeat can use.
+struct Preprocessor {
+    macros: HashMap<String, Macro>,
+    constants: HashMap<String, i64>,
+    expansions: usize,
+    //Lines processed so far, counted against MAXIMUM_LINES. Every repeat iteration counts too.
+    lines: usize,
+    output: Vec<Line>,
+}
+
+pub fn preprocess(source: &str, file: Option<&Path>) -> Result<Vec<Line>, AssemblyError> {
+    let mut preprocessor = Preprocessor { macros: HashMap::new(), constants: HashMap::new(), expansions: 0, lines: 0, output: Vec::new() };
+    let lines = split_lines(source, file.map(|file| Rc::new(file.to_path_buf())));
+    preprocessor.process(&lines, None, 0)?;
+
+    Ok(preprocessor.output)
+}
+
+fn split_lines(source: &str, file: Option<Rc<PathBuf>>) -> Vec<Line> {
+    source.lines()
+        .enumerate()
+        .map(|(index, text)| Line { text: text.to_string(), file: file.clone(), number: index + 1, local_scope: None, macro_call: false })
+        .collect()
+}
+
+fn directive(tokens: &[Spanned]) -> Option<(&str, usize)> {
+    match tokens.first() {
+        Some(Spanned { token: Token::Directive(name), column }) => Some((name.as_str(), *column)),
+        _ => None
+    }
+}
+
+impl Preprocessor {
+
+    fn process(&mut self, lines: &[Line], local_scope: Option<Rc<str>>, depth: usize) -> Result<(), AssemblyError> {
+        let mut conditionals: Vec<Conditional> = Vec::new();
+        let mut index = 0;
+
+        while index < lines.len() {
+            let line = &lines[index];
+            index += 1;
+            self.count_line(line, 1)?;
+            let active = conditionals.last().is_none_or(|conditional| conditional.active);
+            //Lines that aren't assembled only matter for the .if structure, so they don't have to tokenize.
+            let tokens = match line.tokenize() {
+                Ok(tokens) => tokens,
+                Err(_) if !active => continue,
+                Err(error) => return Err(error)
+            };
+
+            match directive(&tokens) {
+                Some(("if" | "ifdef" | "ifndef", column)) => {
+                    let condition = active && self.condition(line, &tokens)?;
+                    conditionals.push(Conditional { line, column, enclosing_active: active, active: condition, taken: condition, has_else: false });
+                    continue;
+                }
+                Some((name @ ("elseif" | "else"), column)) => {
+                    let Some(conditional) = conditionals.last_mut() else {
+                        return Err(line.error(column, format!(".{} without .if.", name)));
+                    };
+                    if conditional.has_else {
+                        return Err(line.error(column, format!(".{} after .else.", name)));
+                    }
+                    let condition = conditional.enclosing_active && !conditional.taken && (name == "else" || self.condition(line, &tokens)?);
+                    conditional.active = condition;
+                    conditional.taken |= condition;
+                    conditional.has_else = name == "else";
+                    continue;
+                }
+                Some(("endif", column)) => {
+                    if conditionals.pop().is_none() {
+                        return Err(line.error(column, ".endif without .if.".to_string()));
+                    }
+                    continue;
+                }
+                _ if !active => continue,
+                Some(("macro", column)) => {
+                    let (body, end) = block(lines, index, line, column, &["macro"], &["endmacro", "endm"])?;
+                    if let Some(nested) = body.iter().find(|line| line.tokenize().is_ok_and(|tokens| matches!(directive(&tokens), Some(("macro", _))))) {
+                        return Err(nested.error(1, "Macros can't be defined inside a macro.".to_string()));
+                    }
+                    index = end;
+                    self.define_macro(line, &tokens, body)?;
+                    continue;
+                }
+                Some(("repeat" | "rept", column)) => {
+                    let (body, end) = block(lines, index, line, column, &["repeat", "rept"], &["endrepeat", "endrep", "endr"])?;
+                    index = end;
+                    let (count, variable) = self.repeat_header(line, &tokens)?;
+                    for iteration in 0..count {
+                        self.count_line(line, column)?;
+                        let replacements = variable.iter().map(|name| (name.clone(), iteration.to_string())).collect();
+                        let body = body.iter().map(|line| substitute(line, &replacements)).collect::<Result<Vec<_>, _>>()?;
+                        self.process(&body, local_scope.clone(), nested(depth, line, column)?)?;
+                    }
+                    continue;
+                }
+                Some(("include", column)) => {
+                    let [_, Spanned { token: Token::Str(path), .. }] = tokens.as_slice() else {
+                        return Err(line.error(column, ".include takes a file name in quotes.".to_string()));
+                    };
+                    let path = line.resolve(path);
+                    let source = fs::read_to_string(&path).map_err(|error| line.error(column, format!("Can't read {}: {}", path.display(), error)))?;
+                    self.process(&split_lines(&source, Some(Rc::new(path))), None, nested(depth, line, column)?)?;
+                    continue;
+                }
+                Some((name @ ("endmacro" | "endm" | "endrepeat" | "endrep" | "endr"), column)) => {
+                    return Err(line.error(column, format!(".{} without a block to end.", name)));
+                }
+                _ => {}
+            }
+
+            let label_length = match tokens.as_slice() {
+                [Spanned { token: Token::Identifier(_), .. }, Spanned { token: Token::Symbol(":"), .. }, ..] => 2,
+                _ => 0
+            };
+            if let Some(Spanned { token: Token::Identifier(name), column }) = tokens.get(label_length) {
+                if self.macros.contains_key(name) {
+                    self.output.push(Line { local_scope: local_scope.clone(), macro_call: true, ..line.clone() });
+                    self.expand(line, name, *column, &tokens[label_length + 1..], nested(depth, line, *column)?)?;
+                    continue;
+                }
+            }
+
+            if let [Spanned { token: Token::Identifier(name), .. }, Spanned { token: Token::Symbol("="), .. }, ..] = tokens.as_slice() {
+                let mut parser = ExpressionParser::new(&tokens, 2, line.end_column());
+                let value = parser.parse().ok().and_then(|expression| expression.evaluate(&|name| self.constants.get(name).copied(), 0).ok());
+                if let Some(value) = value {
+                    self.constants.insert(name.clone(), value);
+                }
+            }
+            self.output.push(Line { local_scope: local_scope.clone(), ..line.clone() });
+        }
+
+        match conditionals.first() {
+            Some(conditional) => Err(conditional.line.error(conditional.column, ".if without .endif.".to_string())),
+            None => Ok(())
+        }
+    }
+
+    //.if expression, .ifdef name or .ifndef name.
+    fn condition(&self, line: &Line, tokens: &[Spanned]) -> Result<bool, AssemblyError> {
+        let Some((name, column)) = directive(tokens) else { unreachable!() };
+        if name == "ifdef" || name == "ifndef" {
+            let [_, Spanned { token: Token::Identifier(symbol), .. }] = tokens else {
+                return Err(line.error(column, format!(".{} takes a name.", name)));
+            };
+            let defined = self.constants.contains_key(symbol) || self.macros.contains_key(symbol);
+            return Ok(defined == (name == "ifdef"));
+        }
+
+        Ok(self.constant(line, tokens, 1, column)?.0 != 0)
+    }
+
+    //An expression of constants from position, returns the value and the position after it.
+    fn constant(&self, line: &Line, tokens: &[Spanned], position: usize, column: usize) -> Result<(i64, usize), AssemblyError> {
+        let mut parser = ExpressionParser::new(tokens, position, line.end_column());
+        let expression = parser.parse().map_err(|(column, message)| line.error(column, message))?;
+        let value = expression.evaluate(&|name| self.constants.get(name).copied(), 0).map_err(|error| match error {
+            EvaluationError::Undefined { name, column } => line.error(column, format!("{} has to be a constant defined before this line.", name)),
+            EvaluationError::DivisionByZero => line.error(column, "Division by zero.".to_string())
+        })?;
+
+        Ok((value, parser.position))
+    }
+
+    //.macro name [parameter, ...]
+    fn define_macro(&mut self, line: &Line, tokens: &[Spanned], body: Vec<Line>) -> Result<(), AssemblyError> {
+        let Some(Spanned { token: Token::Identifier(name), column }) = tokens.get(1) else {
+            return Err(line.error(tokens[0].column, ".macro takes a name and its parameters.".to_string()));
+        };
+        if self.macros.contains_key(name) {
+            return Err(line.error(*column, format!("Macro {} is already defined.", name)));
+        }
+        let parameters = names(line, &tokens[2..])?;
+        self.macros.insert(name.clone(), Macro { parameters, body });
+
+        Ok(())
+    }
+
+    //.repeat count [, variable], the variable counts from 0.
+    fn repeat_header(&self, line: &Line, tokens: &[Spanned]) -> Result<(i64, Option<String>), AssemblyError> {
+        let column = tokens[0].column;
+        let (count, end) = self.constant(line, tokens, 1, column)?;
+        let variable = match &tokens[end..] {
+            [] => None,
+            [Spanned { token: Token::Symbol(","), .. }, Spanned { token: Token::Identifier(name), .. }] => Some(name.clone()),
+            [spanned, ..] => return Err(line.error(spanned.column, "Expected , and a variable name or the end of the line.".to_string()))
+        };
+        if count < 0 {
+            return Err(line.error(column, "The repeat count can't be negative.".to_string()));
+        }
+        if count as u64 > MAXIMUM_LINES as u64 {
+            return Err(line.error(column, format!("The repeat count can't be more than {}.", MAXIMUM_LINES)));
+        }
+
+        Ok((count, variable))
+    }
+
+    fn count_line(&mut self, line: &Line, column: usize) -> Result<(), AssemblyError> {
+        self.lines += 1;
+        if self.lines > MAXIMUM_LINES {
+            return Err(line.error(column, format!("Macros, repeats and includes expand to more than {} lines.", MAXIMUM_LINES)));
+        }
+        Ok(())
+    }
+
+    fn expand(&mut self, line: &Line, name: &str, column: usize, argument_tokens: &[Spanned], depth: usize) -> Result<(), AssemblyError> {
+        let arguments = split_arguments(&line.text, argument_tokens);
+        let definition = &self.macros[name];
+        if arguments.len() != definition.parameters.len() {
+            return Err(line.error(column, format!("{} takes {} arguments, not {}.", name, definition.parameters.len(), arguments.len())));
+        }
+        let replacements = definition.parameters.iter().cloned().zip(arguments).collect();
+        let body = definition.body.iter().map(|line| substitute(line, &replacements)).collect::<Result<Vec<_>, _>>()?;
+
+        self.expansions += 1;
+        let scope: Rc<str> = Rc::from(format!("{}#{}", name, self.expansions));
+        self.process(&body, Some(scope), depth)
+    }
+}
+
+fn nested(depth: usize, line: &Line, column: usize) -> Result<usize, AssemblyError> {
+    if depth >= MAXIMUM_DEPTH {
+        return Err(line.error(column, "Macros, repeats and includes nest too deep.".to_string()));
+    }
+    Ok(depth + 1)
+}
+
+//The lines from start up to the directive that ends the block, nested blocks of the same kind included.
+//Returns them and the index after the end.
+fn block(lines: &[Line], start: usize, line: &Line, column: usize, openers: &[&str], closers: &[&str]) -> Result<(Vec<Line>, usize), AssemblyError> {
+    let mut nesting = 0;
+    for (index, candidate) in lines.iter().enumerate().skip(start) {
+        let Ok(tokens) = candidate.tokenize() else { continue };
+        match directive(&tokens) {
+            Some((name, _)) if openers.contains(&name) => nesting += 1,
+            Some((name, _)) if closers.contains(&name) && nesting == 0 => return Ok((lines[start..index].to_vec(), index + 1)),
+            Some((name, _)) if closers.contains(&name) => nesting -= 1,
+            _ => {}
+        }
+    }
+
+    Err(line.error(column, format!(".{} without .{}.", openers[0], closers[0])))
+}
+
+//Comma separated names, like macro parameters.
+fn names(line: &Line, tokens: &[Spanned]) -> Result<Vec<String>, AssemblyError> {
+    let mut names = Vec::new();
+    for (index, spanned) in tokens.iter().enumerate() {
+        match (&spanned.token, index % 2) {
+            (Token::Identifier(name), 0) => names.push(name.clone()),
+            (Token::Symbol(","), 1) => {}
+            _ => return Err(line.error(spanned.column, "Expected a comma separated list of names.".to_string()))
+        }
+    }
+
+    Ok(names)
+}
+
+//The text of each comma separated argument. Commas inside brackets, like (ptr,X), don't split.
+fn split_arguments(text: &str, tokens: &[Spanned]) -> Vec<String> {
+    let characters: Vec<char> = text.chars().collect();
+    let end = code_end(&characters);
+    let Some(first) = tokens.first() else { return Vec::new() };
+    let mut arguments = Vec::new();
+    let mut start = first.column - 1;
+    let mut depth = 0;
+
+    for spanned in tokens {
+        match spanned.token {
+            Token::Symbol("(" | "[") => depth += 1,
+            Token::Symbol(")" | "]") => depth -= 1,
+            Token::Symbol(",") if depth == 0 => {
+                arguments.push(characters[start..spanned.column - 1].iter().collect::<String>().trim().to_string());
+                start = spanned.column;
+            }
+            _ => {}
+        }
+    }
+    arguments.push(characters[start..end].iter().collect::<String>().trim().to_string());
+
+    arguments
+}
+
+//Where the ; comment starts, or the length of the line.
+fn code_end(characters: &[char]) -> usize {
+    let mut quote = None;
+    let mut escaped = false;
+    for (index, &character) in characters.iter().enumerate() {
+        match quote {
+            Some(_) if escaped => escaped = false,
+            Some(_) if character == '\\' => escaped = true,
+            Some(open) if character == open => quote = None,
+            Some(_) => {}
+            None if character == '"' || character == '\'' => quote = Some(character),
+            None if character == ';' => return index,
+            None => {}
+        }
+    }
+
+    characters.len()
+}
+
+//Replaces names with text, like macro parameters with the arguments.
+fn substitute(line: &Line, replacements: &HashMap<String, String>) -> Result<Line, AssemblyError> {
+    if replacements.is_empty() {
+        return Ok(line.clone());
+    }
+    let characters: Vec<char> = line.text.chars().collect();
+    let mut text = String::new();
+    let mut copied = 0;
+    for spanned in line.tokenize()? {
+        let Token::Identifier(name) = &spanned.token else { continue };
+        let Some(replacement) = replacements.get(name) else { continue };
+        text.extend(&characters[copied..spanned.column - 1]);
+        text.push_str(replacement);
+        copied = spanned.column - 1 + name.chars().count();
+    }
+    text.extend(&characters[copied..]);
+
+    Ok(Line { text, ..line.clone() })
+}
@@ -1,5 +1,17 @@
+pub mod bus;
+pub mod cmos;
 pub mod cpu;
+pub mod decimal;
+pub mod devices;
+pub mod execution;
 pub mod memory;
+pub mod rewind;
+pub mod run;
+pub mod save_state;
 pub mod instructions;
+pub mod interrupts;
 pub mod instruction_set;
-pub mod stack;
\ No newline at end of file
+pub mod stack;
+pub mod trace;
+pub mod undocumented;
+pub mod w65816;
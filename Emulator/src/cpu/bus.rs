@@ -0,0 +1,136 @@
+use std::ops::{Deref, DerefMut};
+use crate::cpu::memory::MEMORY_SIZE;
+use crate::cpu::save_state::{SaveStateError, StateReader, StateWriter};
+
+//Everything the CPU reads or writes goes through a bus, so a machine decides what every address means.
+//Buses are 'static so the instruction table for them can be built at compile time.
+pub trait Bus: 'static {
+    fn read(&mut self, address: u16) -> u8 {
+        self.peek(address)
+    }
+
+    fn write(&mut self, address: u16, value: u8);
+
+    //Reads without triggering side effects, for debuggers and dumps.
+    fn peek(&self, address: u16) -> u8;
+
+    //24-bit accesses made by the 65816. A 16-bit bus sees every bank as bank 0.
+    fn read_long(&mut self, address: u32) -> u8 {
+        self.read(address as u16)
+    }
+
+    fn write_long(&mut self, address: u32, value: u8) {
+        self.write(address as u16, value);
+    }
+
+    fn peek_long(&self, address: u32) -> u8 {
+        self.peek(address as u16)
+    }
+
+    //Called with the CPU cycle count after every instruction, or before every cycle when the CPU is cycle accurate.
+    fn tick(&mut self, _cycles: u32) {}
+
+    //Called with the CPU cycle count before every access, so the bus knows which cycle the access happens on
+    //without the CPU being cycle accurate.
+    fn sync(&mut self, _cycles: u32) {}
+
+    //Called on a cold start, for volatile state like device registers. Flat Ram keeps its contents, it can't tell a
+    //loaded ROM image and the vectors from RAM.
+    fn power_on(&mut self) {}
+
+    //Whether anything on the bus is pulling the IRQ or NMI line.
+    fn irq(&self) -> bool {
+        false
+    }
+
+    fn nmi(&self) -> bool {
+        false
+    }
+
+    //Writes whatever the bus needs to restore itself into a save state. Buses without state save nothing. Loading
+    //checks the data before changing anything, an error has to leave the bus as it was.
+    fn save_state(&self, _state: &mut StateWriter) {}
+
+    fn load_state(&mut self, _state: &mut StateReader) -> Result<(), SaveStateError> {
+        Ok(())
+    }
+}
+
+//Flat RAM, every address is read/write. 64 KiB unless more banks are asked for, banks past the end mirror the first ones.
+pub struct Ram {
+    bytes: Vec<u8>
+}
+
+impl Ram {
+
+    pub fn new() -> Self {
+        Ram {
+            bytes: vec![0; MEMORY_SIZE]
+        }
+    }
+
+    pub fn with_banks(banks: usize) -> Self {
+        Ram {
+            bytes: vec![0; MEMORY_SIZE * banks.max(1)]
+        }
+    }
+}
+
+impl Default for Ram {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Bus for Ram {
+
+    fn write(&mut self, address: u16, value: u8) {
+        self.bytes[address as usize] = value;
+    }
+
+    fn peek(&self, address: u16) -> u8 {
+        self.bytes[address as usize]
+    }
+
+    fn read_long(&mut self, address: u32) -> u8 {
+        self.peek_long(address)
+    }
+
+    fn write_long(&mut self, address: u32, value: u8) {
+        let index = address as usize % self.bytes.len();
+        self.bytes[index] = value;
+    }
+
+    fn peek_long(&self, address: u32) -> u8 {
+        self.bytes[address as usize % self.bytes.len()]
+    }
+
+    fn save_state(&self, state: &mut StateWriter) {
+        state.write_bytes(&self.bytes);
+    }
+
+    //The saved RAM has to be the size of this one, banks × 64 KiB.
+    fn load_state(&mut self, state: &mut StateReader) -> Result<(), SaveStateError> {
+        let bytes = state.read_bytes()?;
+        if bytes.len() != self.bytes.len() {
+            return Err(SaveStateError::Invalid(format!("the RAM is {} bytes, expected {}", bytes.len(), self.bytes.len())));
+        }
+        self.bytes.copy_from_slice(bytes);
+        Ok(())
+    }
+}
+
+impl Deref for Ram {
+    type Target = [u8];
+
+    fn deref(&self) -> &Self::Target {
+        &self.bytes
+    }
+}
+
+impl DerefMut for Ram {
+
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.bytes
+    }
+}
@@ -0,0 +1,187 @@
+use crate::cpu::bus::Bus;
+use crate::cpu::cpu::CPU;
+use crate::cpu::instructions::InstructionParameter;
+use crate::helpers::bitwise::{get_bit_at_position, get_msb};
+
+//Instructions the WDC 65C02 adds to the NMOS instruction set.
+impl<B: Bus> CPU<B> {
+
+    //BBR and BBS branch on a bit of a zero page byte, the offset is the last operand byte.
+    pub(crate) fn op_bbr<const BIT: u8>(&mut self, parameter: InstructionParameter) -> Option<u8> {
+        match parameter {
+            InstructionParameter::Byte(value) => {
+                self.branch_on_bit(get_bit_at_position(value, BIT) == 0);
+
+                None
+            }
+            _ => { None }
+        }
+    }
+
+    pub(crate) fn op_bbs<const BIT: u8>(&mut self, parameter: InstructionParameter) -> Option<u8> {
+        match parameter {
+            InstructionParameter::Byte(value) => {
+                self.branch_on_bit(get_bit_at_position(value, BIT) != 0);
+
+                None
+            }
+            _ => { None }
+        }
+    }
+
+    //BIT immediate has no memory operand to copy N and V from, it only sets Z.
+    pub(crate) fn op_bit_immediate(&mut self, parameter: InstructionParameter) -> Option<u8> {
+        match parameter {
+            InstructionParameter::Byte(value) => {
+                self.flags.zero = self.registers.acc & value == 0;
+
+                None
+            }
+            _ => { None }
+        }
+    }
+
+    pub(crate) fn op_bra(&mut self, parameter: InstructionParameter) -> Option<u8> {
+        match parameter {
+            InstructionParameter::Word(offset) => {
+                self.take_branch(offset);
+
+                None
+            }
+            _ => { None }
+        }
+    }
+
+    //NOP $5C reads its operand like any absolute instruction, then keeps the bus busy for four more cycles.
+    pub(crate) fn op_nop_long(&mut self, _: InstructionParameter) -> Option<u8> {
+        for _ in 0..4 {
+            self.dummy_read(self.registers.pc);
+        }
+
+        None
+    }
+
+    pub(crate) fn op_phx(&mut self, parameter: InstructionParameter) -> Option<u8> {
+        match parameter {
+            InstructionParameter::None => {
+                self.push_byte_to_stack(self.registers.xr);
+
+                None
+            }
+            _ => { None }
+        }
+    }
+
+    pub(crate) fn op_phy(&mut self, parameter: InstructionParameter) -> Option<u8> {
+        match parameter {
+            InstructionParameter::None => {
+                self.push_byte_to_stack(self.registers.yr);
+
+                None
+            }
+            _ => { None }
+        }
+    }
+
+    pub(crate) fn op_plx(&mut self, parameter: InstructionParameter) -> Option<u8> {
+        match parameter {
+            InstructionParameter::None => {
+                self.dummy_read_stack();
+                let result = self.pop_byte_from_stack();
+                self.registers.xr = result;
+                self.flags.zero = result == 0;
+                self.flags.negative = get_msb(result) != 0;
+
+                None
+            }
+            _ => { None }
+        }
+    }
+
+    pub(crate) fn op_ply(&mut self, parameter: InstructionParameter) -> Option<u8> {
+        match parameter {
+            InstructionParameter::None => {
+                self.dummy_read_stack();
+                let result = self.pop_byte_from_stack();
+                self.registers.yr = result;
+                self.flags.zero = result == 0;
+                self.flags.negative = get_msb(result) != 0;
+
+                None
+            }
+            _ => { None }
+        }
+    }
+
+    pub(crate) fn op_rmb<const BIT: u8>(&mut self, parameter: InstructionParameter) -> Option<u8> {
+        match parameter {
+            InstructionParameter::Byte(value) => { Some(value & !(1 << BIT)) }
+            _ => { None }
+        }
+    }
+
+    pub(crate) fn op_smb<const BIT: u8>(&mut self, parameter: InstructionParameter) -> Option<u8> {
+        match parameter {
+            InstructionParameter::Byte(value) => { Some(value | (1 << BIT)) }
+            _ => { None }
+        }
+    }
+
+    //Stops the clock, only a reset starts the CPU again. PC stays on the STP like it does on a JAM.
+    pub(crate) fn op_stp(&mut self, _: InstructionParameter) -> Option<u8> {
+        self.dummy_read(self.registers.pc);
+        self.jammed = true;
+        self.registers.pc = self.registers.pc.wrapping_sub(1);
+
+        None
+    }
+
+    pub(crate) fn op_stz(&mut self, parameter: InstructionParameter) -> Option<u8> {
+        match parameter {
+            InstructionParameter::Word(address) => {
+                self.write_byte(address, 0);
+
+                None
+            }
+            _ => { None }
+        }
+    }
+
+    //TRB and TSB set Z like BIT does, then clear or set the accumulator's bits in memory.
+    pub(crate) fn op_trb(&mut self, parameter: InstructionParameter) -> Option<u8> {
+        match parameter {
+            InstructionParameter::Byte(value) => {
+                self.flags.zero = self.registers.acc & value == 0;
+
+                Some(value & !self.registers.acc)
+            }
+            _ => { None }
+        }
+    }
+
+    pub(crate) fn op_tsb(&mut self, parameter: InstructionParameter) -> Option<u8> {
+        match parameter {
+            InstructionParameter::Byte(value) => {
+                self.flags.zero = self.registers.acc & value == 0;
+
+                Some(value | self.registers.acc)
+            }
+            _ => { None }
+        }
+    }
+
+    //Sleeps until IRQ or NMI is asserted. With I set an IRQ only wakes the CPU, it continues after WAI.
+    pub(crate) fn op_wai(&mut self, _: InstructionParameter) -> Option<u8> {
+        self.dummy_read(self.registers.pc);
+        self.waiting = true;
+
+        None
+    }
+
+    fn branch_on_bit(&mut self, condition: bool){
+        let offset = self.get_next_byte();
+        if condition {
+            self.take_branch(offset as i8 as u16);
+        }
+    }
+}
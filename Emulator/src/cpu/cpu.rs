@@ -1,99 +1,227 @@
+use std::collections::HashSet;
+use std::fmt;
 use crate::cpu::instructions::InstructionParameter;
-use crate::helpers::bitwise::{get_bit_at_position, get_msb, is_highest_bit_set, merge_bytes_into_word, split_word_into_bytes};
-
-pub struct CPU{
+use crate::cpu::bus::{Bus, Ram};
+use crate::cpu::interrupts::{Interrupts, IRQ_VECTOR};
+use crate::cpu::rewind::Rewind;
+use crate::cpu::trace::Tracer;
+use crate::cpu::undocumented::UnstableConstants;
+use crate::helpers::bitwise::{get_bit_at_position, get_msb, is_highest_bit_set};
+
+pub struct CPU<B: Bus = Ram>{
+    pub variant: Variant,
     pub registers: Registers,
     pub flags: Flags,
-    pub memory: Vec<u8>,
-    pub cycles: u32
+    pub memory: B,
+    pub cycles: u32,
+    pub(crate) interrupts: Interrupts,
+    pub unstable_constants: UnstableConstants,
+    //Set by the JAM opcodes and STP, the CPU stops until it is reset.
+    pub jammed: bool,
+    //Set by WAI, the CPU sleeps until an interrupt line is asserted.
+    pub waiting: bool,
+    //Ticks the bus before every cycle instead of after every instruction, so devices see each access as it happens.
+    pub cycle_accurate: bool,
+    //Addresses the run methods stop at before executing the instruction there.
+    pub breakpoints: HashSet<u16>,
+    pub(crate) rewind: Option<Rewind>,
+    pub(crate) tracer: Option<Tracer>
+}
+
+//The chip being emulated, it picks the instruction table and the behaviour that differs between them.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum Variant {
+    #[default]
+    Nmos6502,
+    Wdc65C02,
+    Wdc65816
+}
+
+impl Variant {
+
+    //The 65C02 and the 65816 share the CMOS fixes.
+    pub fn is_cmos(&self) -> bool {
+        !matches!(self, Variant::Nmos6502)
+    }
 }
 
+#[derive(Default)]
 pub struct Registers{
     pub acc: u8,
     pub pc: u16,
     pub xr: u8,
     pub yr: u8,
-    pub sp: u8
+    pub sp: u8,
+    //65816 only: the high bytes of A (called B), X, Y and S, the direct page and the data and program banks.
+    pub acc_high: u8,
+    pub xr_high: u8,
+    pub yr_high: u8,
+    pub sp_high: u8,
+    pub direct_page: u16,
+    pub data_bank: u8,
+    pub program_bank: u8
 }
 
-#[derive(Debug, PartialEq)]
+//The P register. B and bit 5 aren't stored, they only exist in the copies pushed to the stack.
+#[derive(Clone, Default, PartialEq)]
 pub struct Flags{
     pub negative: bool,
     pub overflow: bool,
-    pub brk: bool,
     pub decimal: bool,
     pub interrupt: bool,
     pub zero: bool,
-    pub carry: bool
+    pub carry: bool,
+    //65816 only: E, and M and X which make the accumulator and the index registers 8-bit when set.
+    pub emulation: bool,
+    pub memory_select: bool,
+    pub index_select: bool
 }
 
 impl Flags{
 
+    pub const NEGATIVE: u8  = 1 << 7;
+    pub const OVERFLOW: u8  = 1 << 6;
+    pub const UNUSED: u8    = 1 << 5;
+    pub const BREAK: u8     = 1 << 4;
+    pub const DECIMAL: u8   = 1 << 3;
+    pub const INTERRUPT: u8 = 1 << 2;
+    pub const ZERO: u8      = 1 << 1;
+    pub const CARRY: u8     = 1 << 0;
+
+    //Bits 4 and 5 are ignored.
     pub fn from_byte(byte: u8) -> Self{
         Flags{
-            negative:  byte & 1 != 0,
-            overflow:  byte >> 1 & 1 != 0,
-            brk:       byte >> 3 & 1 != 0,
-            decimal:   byte >> 4 & 1 != 0,
-            interrupt: byte >> 5 & 1 != 0,
-            zero:      byte >> 6 & 1 != 0,
-            carry:     byte >> 7 & 1 != 0,
+            negative:  byte & Self::NEGATIVE != 0,
+            overflow:  byte & Self::OVERFLOW != 0,
+            decimal:   byte & Self::DECIMAL != 0,
+            interrupt: byte & Self::INTERRUPT != 0,
+            zero:      byte & Self::ZERO != 0,
+            carry:     byte & Self::CARRY != 0,
+            ..Flags::default()
         }
     }
 
+    //NV-BDIZC with B and bit 5 clear.
     pub fn to_byte(&self) -> u8{
         let mut byte = 0u8;
 
-        if self.negative  { byte |= 1 << 0 }
-        if self.overflow  { byte |= 1 << 1 }
-        if self.brk       { byte |= 1 << 3 }
-        if self.decimal   { byte |= 1 << 4 }
-        if self.interrupt { byte |= 1 << 5 }
-        if self.zero      { byte |= 1 << 6 }
-        if self.carry     { byte |= 1 << 7 }
+        if self.negative  { byte |= Self::NEGATIVE }
+        if self.overflow  { byte |= Self::OVERFLOW }
+        if self.decimal   { byte |= Self::DECIMAL }
+        if self.interrupt { byte |= Self::INTERRUPT }
+        if self.zero      { byte |= Self::ZERO }
+        if self.carry     { byte |= Self::CARRY }
+
+        byte
+    }
+
+    //The status as pushed to the stack. Bit 5 is always set, B is set by BRK and PHP and clear for IRQ and NMI.
+    pub fn to_pushed_byte(&self, brk: bool) -> u8{
+        let mut byte = self.to_byte() | Self::UNUSED;
+        if brk { byte |= Self::BREAK }
 
         byte
     }
 
     pub fn load_from_byte(&mut self, byte: u8){
-        self.negative =  byte & 1 != 0;
-        self.overflow =  byte >> 1 & 1 != 0;
-        self.brk =       byte >> 3 & 1 != 0;
-        self.decimal =   byte >> 4 & 1 != 0;
-        self.interrupt = byte >> 5 & 1 != 0;
-        self.zero =      byte >> 6 & 1 != 0;
-        self.carry =     byte >> 7 & 1 != 0;
+        *self = Flags {
+            emulation: self.emulation,
+            memory_select: self.memory_select,
+            index_select: self.index_select,
+            ..Flags::from_byte(byte)
+        };
+    }
+}
+
+//Set flags in upper case, like nv-bdIzc.
+impl fmt::Display for Flags {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let flag = |set: bool, name: char| if set { name.to_ascii_uppercase() } else { name };
+        write!(f, "{}{}-b{}{}{}{}",
+            flag(self.negative, 'n'),
+            flag(self.overflow, 'v'),
+            flag(self.decimal, 'd'),
+            flag(self.interrupt, 'i'),
+            flag(self.zero, 'z'),
+            flag(self.carry, 'c')
+        )
+    }
+}
+
+impl fmt::Debug for Flags {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(self, f)
     }
 }
 
 impl CPU{
 
     pub fn new() -> Self{
-        Self{
+        CPU::with_bus(Ram::new())
+    }
+
+    pub fn with_variant(variant: Variant) -> Self{
+        CPU::with_bus_and_variant(Ram::new(), variant)
+    }
+}
+
+impl Default for CPU {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<B: Bus> CPU<B>{
+
+    pub fn with_bus(bus: B) -> Self{
+        Self::with_bus_and_variant(bus, Variant::Nmos6502)
+    }
+
+    pub fn with_bus_and_variant(bus: B, variant: Variant) -> Self{
+        let mut cpu = Self{
+            variant,
             registers: Registers {
                 acc: 0,
                 pc: 0,
                 xr: 0,
                 yr: 0,
                 sp: 0xFFu8,
+                ..Registers::default()
             },
             flags: Flags {
                 negative: false,
                 overflow: false,
-                brk: false,
                 decimal: false,
                 interrupt: false,
                 zero: false,
                 carry: false,
+                ..Flags::default()
             },
-            memory: vec![0;32 * 32 * 32 * 2],
-            cycles: 0
+            memory: bus,
+            cycles: 0,
+            interrupts: Interrupts::default(),
+            unstable_constants: UnstableConstants::default(),
+            jammed: false,
+            waiting: false,
+            cycle_accurate: false,
+            breakpoints: HashSet::new(),
+            rewind: None,
+            tracer: None
+        };
+        if variant == Variant::Wdc65816 {
+            cpu.enter_emulation_mode();
         }
+
+        cpu
     }
 
-    pub fn op_adc(&mut self, parameter: InstructionParameter) -> Option<u8> {
+    pub(crate) fn op_adc(&mut self, parameter: InstructionParameter) -> Option<u8> {
         match parameter {
             InstructionParameter::Byte(value) => {
+                if self.flags.decimal {
+                    return Some(self.add_decimal(value));
+                }
+
                 let value = value as u16;
                 let carry = if self.flags.carry { 1 } else { 0 };
                 let sum = (self.registers.acc as u16) + value + carry;
@@ -113,7 +241,7 @@ impl CPU{
         }
     }
 
-    pub fn op_and(&mut self, parameter: InstructionParameter) -> Option<u8> {
+    pub(crate) fn op_and(&mut self, parameter: InstructionParameter) -> Option<u8> {
         match parameter {
             InstructionParameter::Byte(value) => {
                 let and = self.registers.acc & value;
@@ -126,7 +254,7 @@ impl CPU{
         }
     }
 
-    pub fn op_asl(&mut self, parameter: InstructionParameter) -> Option<u8> {
+    pub(crate) fn op_asl(&mut self, parameter: InstructionParameter) -> Option<u8> {
         match parameter {
             InstructionParameter::Byte(value) => {
                 let result = value << 1;
@@ -140,12 +268,11 @@ impl CPU{
         }
     }
 
-    pub fn op_bcc(&mut self, parameter: InstructionParameter) -> Option<u8> {
+    pub(crate) fn op_bcc(&mut self, parameter: InstructionParameter) -> Option<u8> {
         match parameter {
             InstructionParameter::Word(offset) => {
                 if !self.flags.carry {
-                    let new_pc = self.registers.pc.wrapping_add(offset);
-                    self.registers.pc = new_pc;
+                    self.take_branch(offset);
                 }
 
                 None
@@ -154,12 +281,11 @@ impl CPU{
         }
     }
 
-    pub fn op_bcs(&mut self, parameter: InstructionParameter) -> Option<u8> {
+    pub(crate) fn op_bcs(&mut self, parameter: InstructionParameter) -> Option<u8> {
         match parameter {
             InstructionParameter::Word(offset) => {
                 if self.flags.carry {
-                    let new_pc = self.registers.pc.wrapping_add(offset);
-                    self.registers.pc = new_pc;
+                    self.take_branch(offset);
                 }
 
                 None
@@ -168,12 +294,11 @@ impl CPU{
         }
     }
 
-    pub fn op_beq(&mut self, parameter: InstructionParameter) -> Option<u8> {
+    pub(crate) fn op_beq(&mut self, parameter: InstructionParameter) -> Option<u8> {
         match parameter {
             InstructionParameter::Word(offset) => {
                 if self.flags.zero {
-                    let new_pc = self.registers.pc.wrapping_add(offset);
-                    self.registers.pc = new_pc;
+                    self.take_branch(offset);
                 }
 
                 None
@@ -182,7 +307,7 @@ impl CPU{
         }
     }
 
-    pub fn op_bit(&mut self, parameter: InstructionParameter) -> Option<u8> {
+    pub(crate) fn op_bit(&mut self, parameter: InstructionParameter) -> Option<u8> {
         match parameter {
             InstructionParameter::Byte(value) => {
                 let result = self.registers.acc & value;
@@ -197,30 +322,24 @@ impl CPU{
         }
     }
 
-    pub fn op_bmi(&mut self, parameter: InstructionParameter) -> Option<u8> {
+    pub(crate) fn op_bmi(&mut self, parameter: InstructionParameter) -> Option<u8> {
         match parameter {
             InstructionParameter::Word(offset) => {
                 if self.flags.negative {
-                    let new_pc = self.registers.pc.wrapping_add(offset as u16);
-                    self.registers.pc = new_pc;
-                    self.cycles += 1;
+                    self.take_branch(offset);
                 }
 
                 None
-
             }
             _ => { None }
         }
     }
 
-    pub fn op_bne(&mut self, parameter: InstructionParameter) -> Option<u8> {
+    pub(crate) fn op_bne(&mut self, parameter: InstructionParameter) -> Option<u8> {
         match parameter {
             InstructionParameter::Word(offset) => {
                 if !self.flags.zero {
-                    let calculated_offset = offset as i16 as u16; //Truncate to an i8 to correctly get negative numbers.
-                    let new_pc = self.registers.pc.wrapping_add(calculated_offset);
-                    self.registers.pc = new_pc;
-                    self.cycles += 1;
+                    self.take_branch(offset);
                 }
 
                 None
@@ -229,13 +348,11 @@ impl CPU{
         }
     }
 
-    pub fn op_bpl(&mut self, parameter: InstructionParameter) -> Option<u8> {
+    pub(crate) fn op_bpl(&mut self, parameter: InstructionParameter) -> Option<u8> {
         match parameter {
             InstructionParameter::Word(offset) => {
                 if !self.flags.negative {
-                    let new_pc = self.registers.pc.wrapping_add(offset as u16);
-                    self.registers.pc = new_pc;
-                    self.cycles += 1;
+                    self.take_branch(offset);
                 }
 
                 None
@@ -244,22 +361,11 @@ impl CPU{
         }
     }
 
-    pub fn op_brk(&mut self, parameter: InstructionParameter) -> Option<u8> {
+    pub(crate) fn op_brk(&mut self, parameter: InstructionParameter) -> Option<u8> {
         match parameter {
             InstructionParameter::None => {
-                let (high_byte, low_byte) = split_word_into_bytes(self.registers.pc + 2);
-                self.push_byte_to_stack(high_byte);
-                self.push_byte_to_stack(low_byte);
-
-                self.flags.brk = true;
-                let status = self.flags.to_byte();
-                self.push_byte_to_stack(status);
-                self.flags.interrupt = true;
-
-                //Set PC to interrupt vector.
-                let low_byte = self.memory[0xFFFE];
-                let high_byte = self.memory[0xFFFF];
-                self.registers.pc = merge_bytes_into_word(high_byte, low_byte);
+                //BRK skips a padding byte, the handler returns to the address after it.
+                self.enter_interrupt(self.registers.pc.wrapping_add(1), true, IRQ_VECTOR);
 
                 None
             }
@@ -267,12 +373,11 @@ impl CPU{
         }
     }
 
-    pub fn op_bvc(&mut self, parameter: InstructionParameter) -> Option<u8> {
+    pub(crate) fn op_bvc(&mut self, parameter: InstructionParameter) -> Option<u8> {
         match parameter {
             InstructionParameter::Word(offset) => {
                 if !self.flags.overflow {
-                    self.registers.pc = self.registers.pc.wrapping_add(offset as u16);
-                    self.cycles += 1;
+                    self.take_branch(offset);
                 }
 
                 None
@@ -281,12 +386,11 @@ impl CPU{
         }
     }
 
-    pub fn op_bvs(&mut self, parameter: InstructionParameter) -> Option<u8> {
+    pub(crate) fn op_bvs(&mut self, parameter: InstructionParameter) -> Option<u8> {
         match parameter {
             InstructionParameter::Word(offset) => {
                 if self.flags.overflow {
-                    self.registers.pc = self.registers.pc.wrapping_add(offset as u16);
-                    self.cycles += 1;
+                    self.take_branch(offset);
                 }
 
                 None
@@ -295,7 +399,7 @@ impl CPU{
         }
     }
 
-    pub fn op_clc(&mut self, parameter: InstructionParameter) -> Option<u8> {
+    pub(crate) fn op_clc(&mut self, parameter: InstructionParameter) -> Option<u8> {
         match parameter {
             InstructionParameter::None => {
                 self.flags.carry = false;
@@ -306,7 +410,7 @@ impl CPU{
         }
     }
 
-    pub fn op_cld(&mut self, parameter: InstructionParameter) -> Option<u8> {
+    pub(crate) fn op_cld(&mut self, parameter: InstructionParameter) -> Option<u8> {
         match parameter {
             InstructionParameter::None => {
                 self.flags.decimal = false;
@@ -317,9 +421,10 @@ impl CPU{
         }
     }
 
-    pub fn op_cli(&mut self, parameter: InstructionParameter) -> Option<u8> {
+    pub(crate) fn op_cli(&mut self, parameter: InstructionParameter) -> Option<u8> {
         match parameter {
             InstructionParameter::None => {
+                self.delay_interrupt_flag();
                 self.flags.interrupt = false;
 
                 None
@@ -328,7 +433,7 @@ impl CPU{
         }
     }
 
-    pub fn op_clv(&mut self, parameter: InstructionParameter) -> Option<u8> {
+    pub(crate) fn op_clv(&mut self, parameter: InstructionParameter) -> Option<u8> {
         match parameter {
             InstructionParameter::None => {
                 self.flags.overflow = false;
@@ -339,7 +444,7 @@ impl CPU{
         }
     }
 
-    pub fn op_cmp(&mut self, parameter: InstructionParameter) -> Option<u8> {
+    pub(crate) fn op_cmp(&mut self, parameter: InstructionParameter) -> Option<u8> {
         match parameter {
             InstructionParameter::Byte(value) => {
                 let result = self.registers.acc.wrapping_sub(value);
@@ -353,7 +458,7 @@ impl CPU{
         }
     }
 
-    pub fn op_cpx(&mut self, parameter: InstructionParameter) -> Option<u8> {
+    pub(crate) fn op_cpx(&mut self, parameter: InstructionParameter) -> Option<u8> {
         match parameter {
             InstructionParameter::Byte(value) => {
                 let result = self.registers.xr.wrapping_sub(value);
@@ -367,7 +472,7 @@ impl CPU{
         }
     }
 
-    pub fn op_cpy(&mut self, parameter: InstructionParameter) -> Option<u8> {
+    pub(crate) fn op_cpy(&mut self, parameter: InstructionParameter) -> Option<u8> {
         match parameter {
             InstructionParameter::Byte(value) => {
                 let result = self.registers.yr.wrapping_sub(value);
@@ -381,7 +486,7 @@ impl CPU{
         }
     }
 
-    pub fn op_dec(&mut self, parameter: InstructionParameter) -> Option<u8> {
+    pub(crate) fn op_dec(&mut self, parameter: InstructionParameter) -> Option<u8> {
         match parameter {
             InstructionParameter::Byte(value) => {
                 let result = value.wrapping_sub(1);
@@ -394,7 +499,7 @@ impl CPU{
         }
     }
 
-    pub fn op_dex(&mut self, parameter: InstructionParameter) -> Option<u8> {
+    pub(crate) fn op_dex(&mut self, parameter: InstructionParameter) -> Option<u8> {
         match parameter {
             InstructionParameter::None => {
                 let result = self.registers.xr.wrapping_sub(1);
@@ -408,7 +513,7 @@ impl CPU{
         }
     }
 
-    pub fn op_dey(&mut self, parameter: InstructionParameter) -> Option<u8> {
+    pub(crate) fn op_dey(&mut self, parameter: InstructionParameter) -> Option<u8> {
         match parameter {
             InstructionParameter::None => {
                 let result = self.registers.yr.wrapping_sub(1);
@@ -422,7 +527,7 @@ impl CPU{
         }
     }
 
-    pub fn op_eor(&mut self, parameter: InstructionParameter) -> Option<u8> {
+    pub(crate) fn op_eor(&mut self, parameter: InstructionParameter) -> Option<u8> {
         match parameter {
             InstructionParameter::Byte(value) => {
                 let result = self.registers.acc ^ value;
@@ -435,7 +540,7 @@ impl CPU{
         }
     }
 
-    pub fn op_inc(&mut self, parameter: InstructionParameter) -> Option<u8>{
+    pub(crate) fn op_inc(&mut self, parameter: InstructionParameter) -> Option<u8>{
         match parameter {
             InstructionParameter::Byte(value) => {
                 let result = value.wrapping_add(1);
@@ -448,7 +553,7 @@ impl CPU{
         }
     }
 
-    pub fn op_inx(&mut self, parameter: InstructionParameter) -> Option<u8> {
+    pub(crate) fn op_inx(&mut self, parameter: InstructionParameter) -> Option<u8> {
         match parameter {
             InstructionParameter::None => {
                 self.registers.xr = self.registers.xr.wrapping_add(1);
@@ -461,7 +566,7 @@ impl CPU{
         }
     }
 
-    pub fn op_iny(&mut self, parameter: InstructionParameter) -> Option<u8> {
+    pub(crate) fn op_iny(&mut self, parameter: InstructionParameter) -> Option<u8> {
         match parameter {
             InstructionParameter::None => {
                 self.registers.yr = self.registers.yr.wrapping_add(1);
@@ -474,7 +579,7 @@ impl CPU{
         }
     }
 
-    pub fn op_jmp(&mut self, parameter: InstructionParameter) -> Option<u8> {
+    pub(crate) fn op_jmp(&mut self, parameter: InstructionParameter) -> Option<u8> {
         match parameter {
             InstructionParameter::Word(address) => {
                 self.registers.pc = address;
@@ -485,10 +590,12 @@ impl CPU{
         }
     }
 
-    pub fn op_jsr(&mut self, parameter: InstructionParameter) -> Option<u8> {
+    pub(crate) fn op_jsr(&mut self, parameter: InstructionParameter) -> Option<u8> {
         match parameter {
             InstructionParameter::Word(address) => {
-                self.push_word_to_stack(self.registers.pc - 1);
+                //The 6502 fetches the high byte of the address after pushing, the pushed value is the same.
+                self.dummy_read_stack();
+                self.push_word_to_stack(self.registers.pc.wrapping_sub(1));
                 self.registers.pc = address;
 
                 None
@@ -497,7 +604,7 @@ impl CPU{
         }
     }
 
-    pub fn op_lda(&mut self, parameter: InstructionParameter) -> Option<u8> {
+    pub(crate) fn op_lda(&mut self, parameter: InstructionParameter) -> Option<u8> {
         match parameter {
             InstructionParameter::Byte(value) => {
                 self.registers.acc = value;
@@ -510,7 +617,7 @@ impl CPU{
         }
     }
 
-    pub fn op_ldx(&mut self, parameter: InstructionParameter) -> Option<u8> {
+    pub(crate) fn op_ldx(&mut self, parameter: InstructionParameter) -> Option<u8> {
         match parameter {
             InstructionParameter::Byte(value) => {
                 self.registers.xr = value;
@@ -524,7 +631,7 @@ impl CPU{
 
     }
 
-    pub fn op_ldy(&mut self, parameter: InstructionParameter) -> Option<u8> {
+    pub(crate) fn op_ldy(&mut self, parameter: InstructionParameter) -> Option<u8> {
         match parameter {
             InstructionParameter::Byte(value) => {
                 self.registers.yr = value;
@@ -537,7 +644,7 @@ impl CPU{
         }
     }
 
-    pub fn op_lsr(&mut self, parameter: InstructionParameter) -> Option<u8> {
+    pub(crate) fn op_lsr(&mut self, parameter: InstructionParameter) -> Option<u8> {
         match parameter {
             InstructionParameter::Byte(value) => {
                 self.flags.carry = value & 1 != 0;
@@ -552,11 +659,11 @@ impl CPU{
         }
     }
 
-    pub fn op_nop(&mut self, _: InstructionParameter) -> Option<u8>{
+    pub(crate) fn op_nop(&mut self, _: InstructionParameter) -> Option<u8>{
         None
     }
 
-    pub fn op_ora(&mut self, parameter: InstructionParameter) -> Option<u8> {
+    pub(crate) fn op_ora(&mut self, parameter: InstructionParameter) -> Option<u8> {
         match parameter {
             InstructionParameter::Byte(value) => {
                 self.registers.acc |= value;
@@ -569,7 +676,7 @@ impl CPU{
         }
     }
 
-    pub fn op_pha(&mut self, parameter: InstructionParameter) -> Option<u8> {
+    pub(crate) fn op_pha(&mut self, parameter: InstructionParameter) -> Option<u8> {
         match parameter {
             InstructionParameter::None => {
                 self.push_byte_to_stack(self.registers.acc);
@@ -580,11 +687,10 @@ impl CPU{
         }
     }
 
-    pub fn op_php(&mut self, parameter: InstructionParameter) -> Option<u8> {
+    pub(crate) fn op_php(&mut self, parameter: InstructionParameter) -> Option<u8> {
         match parameter {
             InstructionParameter::None => {
-                self.push_byte_to_stack(self.flags.to_byte());
-                self.flags.brk = true;
+                self.push_byte_to_stack(self.flags.to_pushed_byte(true));
 
                 None
             }
@@ -592,14 +698,13 @@ impl CPU{
         }
     }
 
-    pub fn op_pla(&mut self, parameter: InstructionParameter) -> Option<u8> {
+    pub(crate) fn op_pla(&mut self, parameter: InstructionParameter) -> Option<u8> {
         match parameter {
             InstructionParameter::None => {
-                if let Some(result) = self.pop_byte_from_stack(){
-                    self.registers.acc = result;
-                    self.flags.zero = self.registers.acc == 0;
-                    self.flags.negative = get_msb(self.registers.acc) != 0;
-                }
+                self.dummy_read_stack();
+                self.registers.acc = self.pop_byte_from_stack();
+                self.flags.zero = self.registers.acc == 0;
+                self.flags.negative = get_msb(self.registers.acc) != 0;
 
                 None
             }
@@ -607,13 +712,13 @@ impl CPU{
         }
     }
 
-    pub fn op_plp(&mut self, parameter: InstructionParameter) -> Option<u8> {
+    pub(crate) fn op_plp(&mut self, parameter: InstructionParameter) -> Option<u8> {
         match parameter {
             InstructionParameter::None => {
-                if let Some(result) = self.pop_byte_from_stack(){
-                    self.flags.load_from_byte(result);
-                    self.flags.brk = false;
-                }
+                self.delay_interrupt_flag();
+                self.dummy_read_stack();
+                let result = self.pop_byte_from_stack();
+                self.flags.load_from_byte(result);
 
                 None
             }
@@ -621,12 +726,11 @@ impl CPU{
         }
     }
 
-    pub fn op_rol(&mut self, parameter: InstructionParameter) -> Option<u8> {
+    pub(crate) fn op_rol(&mut self, parameter: InstructionParameter) -> Option<u8> {
         match parameter {
             InstructionParameter::Byte(value) => {
+                let result = (value << 1) | self.flags.carry as u8;
                 self.flags.carry = get_msb(value) != 0;
-
-                let result = value.rotate_left(1);
                 self.flags.negative = get_msb(result) != 0;
                 self.flags.zero = result == 0;
 
@@ -636,13 +740,12 @@ impl CPU{
         }
     }
 
-    pub fn op_ror(&mut self, parameter: InstructionParameter) -> Option<u8> {
+    pub(crate) fn op_ror(&mut self, parameter: InstructionParameter) -> Option<u8> {
         match parameter {
             InstructionParameter::Byte(value) => {
+                let result = (value >> 1) | ((self.flags.carry as u8) << 7);
                 self.flags.carry = value & 1 != 0;
-
-                let result = value.rotate_right(1);
-                self.flags.negative = get_msb(value) != 0;
+                self.flags.negative = get_msb(result) != 0;
                 self.flags.zero = result == 0;
 
                 Some(result)
@@ -651,16 +754,13 @@ impl CPU{
         }
     }
 
-    pub fn op_rti(&mut self, parameter: InstructionParameter) -> Option<u8> {
+    pub(crate) fn op_rti(&mut self, parameter: InstructionParameter) -> Option<u8> {
         match parameter {
             InstructionParameter::None => {
-                if let Some(status) = self.pop_byte_from_stack() {
-                    self.flags.load_from_byte(status);
-                }
-
-                if let Some(value) = self.pop_word_from_stack() {
-                    self.registers.pc = value;
-                }
+                self.dummy_read_stack();
+                let status = self.pop_byte_from_stack();
+                self.flags.load_from_byte(status);
+                self.registers.pc = self.pop_word_from_stack();
 
                 None
             }
@@ -668,12 +768,14 @@ impl CPU{
         }
     }
 
-    pub fn op_rts(&mut self, parameter: InstructionParameter) -> Option<u8> {
+    pub(crate) fn op_rts(&mut self, parameter: InstructionParameter) -> Option<u8> {
         match parameter {
             InstructionParameter::None => {
-                if let Some(value) = self.pop_word_from_stack() {
-                    self.registers.pc = value;
-                }
+                //JSR pushes the address of its last byte, so continue one past it.
+                self.dummy_read_stack();
+                let value = self.pop_word_from_stack();
+                self.dummy_read(value);
+                self.registers.pc = value.wrapping_add(1);
 
                 None
             }
@@ -681,21 +783,14 @@ impl CPU{
         }
     }
 
-    pub fn op_sbc(&mut self, parameter: InstructionParameter) -> Option<u8> {
+    pub(crate) fn op_sbc(&mut self, parameter: InstructionParameter) -> Option<u8> {
         match parameter {
             InstructionParameter::Byte(value) => {
-                let acc = self.registers.acc as u16;
-                let value = value as u16;
-                let carry = if self.flags.carry { 1 } else { 0 };
-
-                let result = acc.wrapping_sub(value).wrapping_sub(1 - carry);
-
-                self.flags.carry = acc >= value + (1 - carry);
-                self.flags.zero = (result as u8) == 0;
-                self.flags.negative = get_msb(result as u8) != 0;
-                self.flags.overflow = (((acc ^ result) & (value ^ result)) & 0x80) != 0;
-
-                self.registers.acc = result as u8;
+                self.registers.acc = if self.flags.decimal {
+                    self.subtract_decimal(value)
+                } else {
+                    self.subtract_binary(value)
+                };
 
                 None
             }
@@ -703,7 +798,7 @@ impl CPU{
         }
     }
 
-    pub fn op_sec(&mut self, parameter: InstructionParameter) -> Option<u8> {
+    pub(crate) fn op_sec(&mut self, parameter: InstructionParameter) -> Option<u8> {
         match parameter {
             InstructionParameter::None => {
                 self.flags.carry = true;
@@ -714,7 +809,7 @@ impl CPU{
         }
     }
 
-    pub fn op_sed(&mut self, parameter: InstructionParameter) -> Option<u8> {
+    pub(crate) fn op_sed(&mut self, parameter: InstructionParameter) -> Option<u8> {
         match parameter {
             InstructionParameter::None => {
                 self.flags.decimal = true;
@@ -725,9 +820,10 @@ impl CPU{
         }
     }
 
-    pub fn op_sei(&mut self, parameter: InstructionParameter) -> Option<u8> {
+    pub(crate) fn op_sei(&mut self, parameter: InstructionParameter) -> Option<u8> {
         match parameter {
             InstructionParameter::None => {
+                self.delay_interrupt_flag();
                 self.flags.interrupt = true;
 
                 None
@@ -736,13 +832,10 @@ impl CPU{
         }
     }
 
-    pub fn op_sta(&mut self, parameter: InstructionParameter) -> Option<u8> {
+    pub(crate) fn op_sta(&mut self, parameter: InstructionParameter) -> Option<u8> {
         match parameter {
             InstructionParameter::Word(address) => {
-                if (address as usize) >= self.memory.len() {
-                    panic!("Memory access out of bounds");
-                }
-                self.memory[address as usize] = self.registers.acc;
+                self.write_byte(address, self.registers.acc);
                 self.registers.acc = 0x00;
 
                 None
@@ -751,13 +844,10 @@ impl CPU{
         }
     }
 
-    pub fn op_stx(&mut self, parameter: InstructionParameter) -> Option<u8> {
+    pub(crate) fn op_stx(&mut self, parameter: InstructionParameter) -> Option<u8> {
         match parameter {
             InstructionParameter::Word(address) => {
-                if (address as usize) >= self.memory.len() {
-                    panic!("Memory access out of bounds");
-                }
-                self.memory[address as usize] = self.registers.xr;
+                self.write_byte(address, self.registers.xr);
                 self.registers.xr = 0x00;
 
                 None
@@ -766,13 +856,10 @@ impl CPU{
         }
     }
 
-    pub fn op_sty(&mut self, parameter: InstructionParameter) -> Option<u8> {
+    pub(crate) fn op_sty(&mut self, parameter: InstructionParameter) -> Option<u8> {
         match parameter {
             InstructionParameter::Word(address) => {
-                if (address as usize) >= self.memory.len() {
-                    panic!("Memory access out of bounds");
-                }
-                self.memory[address as usize] = self.registers.yr;
+                self.write_byte(address, self.registers.yr);
                 self.registers.yr = 0x00;
 
                 None
@@ -781,7 +868,7 @@ impl CPU{
         }
     }
 
-    pub fn op_tax(&mut self, parameter: InstructionParameter) -> Option<u8> {
+    pub(crate) fn op_tax(&mut self, parameter: InstructionParameter) -> Option<u8> {
         match parameter {
             InstructionParameter::None => {
                 self.registers.xr = self.registers.acc;
@@ -794,7 +881,7 @@ impl CPU{
         }
     }
 
-    pub fn op_tay(&mut self, parameter: InstructionParameter) -> Option<u8> {
+    pub(crate) fn op_tay(&mut self, parameter: InstructionParameter) -> Option<u8> {
         match parameter {
             InstructionParameter::None => {
                 self.registers.yr = self.registers.acc;
@@ -807,7 +894,7 @@ impl CPU{
         }
     }
 
-    pub fn op_tsx(&mut self, parameter: InstructionParameter) -> Option<u8> {
+    pub(crate) fn op_tsx(&mut self, parameter: InstructionParameter) -> Option<u8> {
         match parameter {
             InstructionParameter::None => {
                 self.registers.xr = self.registers.sp;
@@ -820,7 +907,7 @@ impl CPU{
         }
     }
 
-    pub fn op_txa(&mut self, parameter: InstructionParameter) -> Option<u8> {
+    pub(crate) fn op_txa(&mut self, parameter: InstructionParameter) -> Option<u8> {
         match parameter {
             InstructionParameter::None => {
                 self.registers.acc = self.registers.xr;
@@ -833,7 +920,7 @@ impl CPU{
         }
     }
 
-    pub fn op_txs(&mut self, parameter: InstructionParameter) -> Option<u8> {
+    pub(crate) fn op_txs(&mut self, parameter: InstructionParameter) -> Option<u8> {
         match parameter {
             InstructionParameter::None => {
                 self.registers.sp = self.registers.xr;
@@ -845,7 +932,7 @@ impl CPU{
         }
     }
 
-    pub fn op_tya(&mut self, parameter: InstructionParameter) -> Option<u8> {
+    pub(crate) fn op_tya(&mut self, parameter: InstructionParameter) -> Option<u8> {
         match parameter {
             InstructionParameter::None => {
                 self.registers.acc = self.registers.yr;
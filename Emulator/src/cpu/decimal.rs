@@ -0,0 +1,78 @@
+use crate::cpu::bus::Bus;
+use crate::cpu::cpu::CPU;
+use crate::helpers::bitwise::get_msb;
+
+//NMOS decimal mode, following Bruce Clark's "Decimal Mode" tutorial. Inputs that aren't valid BCD still
+//produce the results the chip does. Z always comes from the binary sum, N and V from the sum before the high digit
+//is adjusted. The 65C02 spends an extra cycle to set N and Z from the adjusted result.
+impl<B: Bus> CPU<B> {
+
+    pub(crate) fn add_decimal(&mut self, value: u8) -> u8 {
+        let acc = self.registers.acc as u16;
+        let value = value as u16;
+        let carry = self.flags.carry as u16;
+
+        let mut low_digit = (acc & 0x0F) + (value & 0x0F) + carry;
+        if low_digit >= 0x0A {
+            low_digit = ((low_digit + 0x06) & 0x0F) + 0x10;
+        }
+        let mut result = (acc & 0xF0) + (value & 0xF0) + low_digit;
+
+        let signed_result = (acc & 0xF0) as u8 as i8 as i16 + (value & 0xF0) as u8 as i8 as i16 + low_digit as i16;
+        self.flags.overflow = !(-128..=127).contains(&signed_result);
+        self.flags.negative = get_msb(result as u8) != 0;
+        self.flags.zero = (acc + value + carry) as u8 == 0;
+
+        if result >= 0xA0 {
+            result += 0x60;
+        }
+        self.flags.carry = result >= 0x100;
+
+        self.finish_decimal(result as u8)
+    }
+
+    //The flags are the same as in binary mode, only the result is adjusted.
+    pub(crate) fn subtract_decimal(&mut self, value: u8) -> u8 {
+        let acc = self.registers.acc as i16;
+        let value = value as i16;
+        let borrow = 1 - self.flags.carry as i16;
+
+        let mut low_digit = (acc & 0x0F) - (value & 0x0F) - borrow;
+        if low_digit < 0 {
+            low_digit = ((low_digit - 0x06) & 0x0F) - 0x10;
+        }
+        let mut result = (acc & 0xF0) - (value & 0xF0) + low_digit;
+        if result < 0 {
+            result -= 0x60;
+        }
+
+        self.subtract_binary(value as u8);
+        self.finish_decimal(result as u8)
+    }
+
+    //Sets the flags of a binary subtraction and returns its result.
+    pub(crate) fn subtract_binary(&mut self, value: u8) -> u8 {
+        let acc = self.registers.acc as u16;
+        let value = value as u16;
+        let borrow = 1 - self.flags.carry as u16;
+
+        let result = acc.wrapping_sub(value).wrapping_sub(borrow);
+
+        self.flags.carry = acc >= value + borrow;
+        self.flags.zero = (result as u8) == 0;
+        self.flags.negative = get_msb(result as u8) != 0;
+        self.flags.overflow = (((acc ^ result) & (acc ^ value)) & 0x80) != 0;
+
+        result as u8
+    }
+
+    fn finish_decimal(&mut self, result: u8) -> u8 {
+        if self.variant.is_cmos() {
+            self.dummy_read(self.registers.pc.wrapping_sub(1));
+            self.flags.zero = result == 0;
+            self.flags.negative = get_msb(result) != 0;
+        }
+
+        result
+    }
+}
@@ -0,0 +1,253 @@
+use std::ops::{Deref, DerefMut, RangeInclusive};
+use crate::cpu::bus::{Bus, Ram};
+use crate::cpu::save_state::{SaveStateError, StateReader, StateWriter};
+
+//A memory-mapped peripheral. Addresses passed in are offsets into the claimed range, after mirroring.
+pub trait Device {
+    //Cycles is the CPU cycle the access happens on.
+    fn read(&mut self, offset: u16, cycles: u32) -> u8;
+
+    fn write(&mut self, offset: u16, value: u8, cycles: u32);
+
+    //Reads without triggering side effects, for debuggers and dumps.
+    fn peek(&self, offset: u16) -> u8;
+
+    //Called with the CPU cycle count after every instruction, or before every cycle when the CPU is cycle accurate.
+    fn tick(&mut self, _cycles: u32) {}
+
+    //Called on a cold start.
+    fn power_on(&mut self) {}
+
+    //Whether the device is pulling the IRQ or NMI line.
+    fn irq(&self) -> bool {
+        false
+    }
+
+    fn nmi(&self) -> bool {
+        false
+    }
+
+    //The device's part of a save state, devices without internal state save nothing.
+    fn save_state(&self, _state: &mut StateWriter) {}
+
+    fn load_state(&mut self, _state: &mut StateReader) -> Result<(), SaveStateError> {
+        Ok(())
+    }
+}
+
+struct MappedDevice {
+    range: RangeInclusive<u16>,
+    mirror_mask: u16,
+    device: Box<dyn Device>
+}
+
+impl MappedDevice {
+
+    fn offset(&self, address: u16) -> u16 {
+        (address - self.range.start()) & self.mirror_mask
+    }
+}
+
+//Dispatches accesses to the devices attached to an address range, everything else falls through to memory.
+pub struct DeviceBus<B: Bus = Ram> {
+    memory: B,
+    devices: Vec<MappedDevice>,
+    cycles: u32
+}
+
+impl DeviceBus {
+
+    pub fn new() -> Self {
+        DeviceBus::with_memory(Ram::new())
+    }
+}
+
+impl Default for DeviceBus {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<B: Bus> DeviceBus<B> {
+
+    pub fn with_memory(memory: B) -> Self {
+        DeviceBus {
+            memory,
+            devices: Vec::new(),
+            cycles: 0
+        }
+    }
+
+    //Claims an address range for a device. The offset within the range is ANDed with the mirror mask,
+    //so a device with 16 registers mapped over $D000-$D3FF uses a mask of $0F.
+    //When ranges overlap, the device attached last wins. Returns the id of the attached device.
+    pub fn attach(&mut self, range: RangeInclusive<u16>, mirror_mask: u16, device: Box<dyn Device>) -> usize {
+        self.devices.push(MappedDevice { range, mirror_mask, device });
+        self.devices.len() - 1
+    }
+
+    pub fn device(&self, id: usize) -> Option<&dyn Device> {
+        self.devices.get(id).map(|mapped| mapped.device.as_ref())
+    }
+
+    pub fn device_mut(&mut self, id: usize) -> Option<&mut (dyn Device + 'static)> {
+        self.devices.get_mut(id).map(|mapped| mapped.device.as_mut())
+    }
+
+    fn find_device(&self, address: u16) -> Option<usize> {
+        self.devices.iter().rposition(|mapped| mapped.range.contains(&address))
+    }
+}
+
+impl<B: Bus> Bus for DeviceBus<B> {
+
+    fn read(&mut self, address: u16) -> u8 {
+        match self.find_device(address) {
+            Some(id) => {
+                let mapped = &mut self.devices[id];
+                let offset = mapped.offset(address);
+                mapped.device.read(offset, self.cycles)
+            }
+            None => self.memory.read(address)
+        }
+    }
+
+    fn write(&mut self, address: u16, value: u8) {
+        match self.find_device(address) {
+            Some(id) => {
+                let mapped = &mut self.devices[id];
+                let offset = mapped.offset(address);
+                mapped.device.write(offset, value, self.cycles);
+            }
+            None => self.memory.write(address, value)
+        }
+    }
+
+    fn peek(&self, address: u16) -> u8 {
+        match self.find_device(address) {
+            Some(id) => {
+                let mapped = &self.devices[id];
+                mapped.device.peek(mapped.offset(address))
+            }
+            None => self.memory.peek(address)
+        }
+    }
+
+    //Devices live in bank 0, the other banks go straight to memory.
+    fn read_long(&mut self, address: u32) -> u8 {
+        match address >> 16 {
+            0 => self.read(address as u16),
+            _ => self.memory.read_long(address)
+        }
+    }
+
+    fn write_long(&mut self, address: u32, value: u8) {
+        match address >> 16 {
+            0 => self.write(address as u16, value),
+            _ => self.memory.write_long(address, value)
+        }
+    }
+
+    fn peek_long(&self, address: u32) -> u8 {
+        match address >> 16 {
+            0 => self.peek(address as u16),
+            _ => self.memory.peek_long(address)
+        }
+    }
+
+    fn tick(&mut self, cycles: u32) {
+        self.cycles = cycles;
+        self.memory.tick(cycles);
+        for mapped in self.devices.iter_mut() {
+            mapped.device.tick(cycles);
+        }
+    }
+
+    fn sync(&mut self, cycles: u32) {
+        self.cycles = cycles;
+        self.memory.sync(cycles);
+    }
+
+    fn power_on(&mut self) {
+        self.memory.power_on();
+        for mapped in self.devices.iter_mut() {
+            mapped.device.power_on();
+        }
+    }
+
+    fn irq(&self) -> bool {
+        self.memory.irq() || self.devices.iter().any(|mapped| mapped.device.irq())
+    }
+
+    fn nmi(&self) -> bool {
+        self.memory.nmi() || self.devices.iter().any(|mapped| mapped.device.nmi())
+    }
+
+    //Every device gets its own block, so it can't read into the next one. The devices themselves aren't saved,
+    //the same devices have to be attached in the same order before loading.
+    fn save_state(&self, state: &mut StateWriter) {
+        state.write_u32(self.cycles);
+
+        let mut memory = StateWriter::new();
+        self.memory.save_state(&mut memory);
+        state.write_bytes(&memory.into_bytes());
+
+        state.write_u32(self.devices.len() as u32);
+        for mapped in self.devices.iter() {
+            let mut device = StateWriter::new();
+            mapped.device.save_state(&mut device);
+            state.write_bytes(&device.into_bytes());
+        }
+    }
+
+    //Everything is read and the device count checked before the memory and the devices load. Only a device knows
+    //whether its data is good, so when one refuses it the memory and the devices loaded before it are put back.
+    fn load_state(&mut self, state: &mut StateReader) -> Result<(), SaveStateError> {
+        let cycles = state.read_u32()?;
+        let memory = state.read_bytes()?;
+        let count = state.read_u32()? as usize;
+        if count != self.devices.len() {
+            return Err(SaveStateError::Invalid(format!("{} devices saved, {} attached", count, self.devices.len())));
+        }
+        let devices = (0..count).map(|_| state.read_bytes()).collect::<Result<Vec<_>, _>>()?;
+
+        let mut backup = StateWriter::new();
+        self.memory.save_state(&mut backup);
+        let memory_backup = backup.into_bytes();
+        let device_backups = self.devices.iter().map(|mapped| {
+            let mut backup = StateWriter::new();
+            mapped.device.save_state(&mut backup);
+            backup.into_bytes()
+        }).collect::<Vec<_>>();
+
+        self.memory.load_state(&mut StateReader::new(memory))?;
+        for (index, device) in devices.into_iter().enumerate() {
+            if let Err(error) = self.devices[index].device.load_state(&mut StateReader::new(device)) {
+                //They saved the backups themselves, loading them back can't fail.
+                let _ = self.memory.load_state(&mut StateReader::new(&memory_backup));
+                for (mapped, backup) in self.devices.iter_mut().zip(&device_backups).take(index + 1) {
+                    let _ = mapped.device.load_state(&mut StateReader::new(backup));
+                }
+                return Err(error);
+            }
+        }
+        self.cycles = cycles;
+
+        Ok(())
+    }
+}
+
+impl<B: Bus> Deref for DeviceBus<B> {
+    type Target = B;
+
+    fn deref(&self) -> &Self::Target {
+        &self.memory
+    }
+}
+
+impl<B: Bus> DerefMut for DeviceBus<B> {
+
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.memory
+    }
+}
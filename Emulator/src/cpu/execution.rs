@@ -0,0 +1,88 @@
+use std::error::Error;
+use std::fmt;
+
+//What a call to step did.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum StepOutcome {
+    //The instruction at pc ran to completion.
+    Executed { pc: u16, opcode: u8 },
+    //An NMI or IRQ handler was entered instead of fetching an instruction.
+    Interrupt,
+    //The reset line is held, or the reset sequence just ran.
+    Reset,
+    //The CPU is jammed or stopped, only a reset gets it going again.
+    Halted,
+    //WAI is waiting for an interrupt line.
+    Waiting,
+}
+
+//Why an instruction couldn't be executed. pc is the address of the opcode.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ExecutionError {
+    UnknownOpcode { pc: u16, opcode: u8 },
+    MissingValue { pc: u16, opcode: u8, address: Option<u16> },
+    MissingAddress { pc: u16, opcode: u8 },
+    UnsupportedAddressingMode { pc: u16, opcode: u8 },
+}
+
+impl ExecutionError {
+    pub fn pc(&self) -> u16 {
+        match *self {
+            ExecutionError::UnknownOpcode { pc, .. }
+            | ExecutionError::MissingValue { pc, .. }
+            | ExecutionError::MissingAddress { pc, .. }
+            | ExecutionError::UnsupportedAddressingMode { pc, .. } => pc
+        }
+    }
+
+    pub fn opcode(&self) -> u8 {
+        match *self {
+            ExecutionError::UnknownOpcode { opcode, .. }
+            | ExecutionError::MissingValue { opcode, .. }
+            | ExecutionError::MissingAddress { opcode, .. }
+            | ExecutionError::UnsupportedAddressingMode { opcode, .. } => opcode
+        }
+    }
+}
+
+impl fmt::Display for ExecutionError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ExecutionError::UnknownOpcode { pc, opcode } => {
+                write!(f, "Unknown opcode {:#04X} at {:#06X}.", opcode, pc)
+            }
+            ExecutionError::MissingValue { pc, opcode, address: Some(address) } => {
+                write!(f, "Opcode {:#04X} at {:#06X} produced no value to store at {:#06X}.", opcode, pc, address)
+            }
+            ExecutionError::MissingValue { pc, opcode, address: None } => {
+                write!(f, "Opcode {:#04X} at {:#06X} produced no value to store.", opcode, pc)
+            }
+            ExecutionError::MissingAddress { pc, opcode } => {
+                write!(f, "Opcode {:#04X} at {:#06X} has no address to store its result at.", opcode, pc)
+            }
+            ExecutionError::UnsupportedAddressingMode { pc, opcode } => {
+                write!(f, "Opcode {:#04X} at {:#06X} uses an addressing mode this core can't execute.", opcode, pc)
+            }
+        }
+    }
+}
+
+impl Error for ExecutionError {}
+
+//An error raised while an instruction runs, before step attaches the pc and opcode it belongs to.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Fault {
+    MissingValue(Option<u16>),
+    MissingAddress,
+    UnsupportedAddressingMode,
+}
+
+impl Fault {
+    pub(crate) fn at(self, pc: u16, opcode: u8) -> ExecutionError {
+        match self {
+            Fault::MissingValue(address) => ExecutionError::MissingValue { pc, opcode, address },
+            Fault::MissingAddress => ExecutionError::MissingAddress { pc, opcode },
+            Fault::UnsupportedAddressingMode => ExecutionError::UnsupportedAddressingMode { pc, opcode },
+        }
+    }
+}
@@ -1,1357 +1,3564 @@
-use std::collections::HashMap;
-use crate::cpu::cpu::CPU;
-use crate::cpu::instructions::{AddressingMode, Instruction};
-use crate::cpu::instructions::ValueOrAddress::{Address, Value};
-
-impl CPU{
-
-    pub fn get_instruction_set(&self) -> HashMap<u8, Instruction>{
-        HashMap::from([
-            //ADC
-            (0x69, Instruction{
-                address_mode: AddressingMode::Immediate,
-                operation: CPU::op_adc,
-                result_handler: CPU::result_into_acc,
-                value_or_address: Value,
-                cycle_increase: 2,
-                cycle_increases_on_page_cross: false
-            }),
-            (0x6D, Instruction{
-                address_mode: AddressingMode::Absolute,
-                operation: CPU::op_adc,
-                result_handler: CPU::result_into_acc,
-                value_or_address: Value,
-                cycle_increase: 4,
-                cycle_increases_on_page_cross: false
-            }),
-            (0x7D, Instruction{
-                address_mode: AddressingMode::XIndexedAbsolute,
-                operation: CPU::op_adc,
-                result_handler: CPU::result_into_acc,
-                value_or_address: Value,
-                cycle_increase: 4,
-                cycle_increases_on_page_cross: true
-            }),
-            (0x79, Instruction{
-                address_mode: AddressingMode::YIndexedAbsolute,
-                operation: CPU::op_adc,
-                result_handler: CPU::result_into_acc,
-                value_or_address: Value,
-                cycle_increase: 4,
-                cycle_increases_on_page_cross: true
-            }),
-            (0x65, Instruction{
-                address_mode: AddressingMode::ZeroPage,
-                operation: CPU::op_adc,
-                result_handler: CPU::result_into_acc,
-                value_or_address: Value,
-                cycle_increase: 3,
-                cycle_increases_on_page_cross: false
-            }),
-            (0x75, Instruction{
-                address_mode: AddressingMode::XIndexedZeroPage,
-                operation: CPU::op_adc,
-                result_handler: CPU::result_into_acc,
-                value_or_address: Value,
-                cycle_increase: 4,
-                cycle_increases_on_page_cross: false
-            }),
-            (0x61, Instruction{
-                address_mode: AddressingMode::XIndexedZeroPageIndirect,
-                operation: CPU::op_adc,
-                result_handler: CPU::result_into_acc,
-                value_or_address: Value,
-                cycle_increase: 6,
-                cycle_increases_on_page_cross: false
-            }),
-            (0x71, Instruction{
-                address_mode: AddressingMode::ZeroPageIndirectYIndexed,
-                operation: CPU::op_adc,
-                result_handler: CPU::result_into_acc,
-                value_or_address: Value,
-                cycle_increase: 5,
-                cycle_increases_on_page_cross: true
-            }),
-
-            //AND
-            (0x29, Instruction{
-                address_mode: AddressingMode::Immediate,
-                operation: CPU::op_and,
-                result_handler: CPU::result_into_acc,
-                value_or_address: Value,
-                cycle_increase: 2,
-                cycle_increases_on_page_cross: false
-            }),
-            (0x2D, Instruction{
-                address_mode: AddressingMode::Absolute,
-                operation: CPU::op_and,
-                result_handler: CPU::result_into_acc,
-                value_or_address: Value,
-                cycle_increase: 4,
-                cycle_increases_on_page_cross: false
-            }),
-            (0x3D, Instruction{
-                address_mode: AddressingMode::XIndexedAbsolute,
-                operation: CPU::op_and,
-                result_handler: CPU::result_into_acc,
-                value_or_address: Value,
-                cycle_increase: 4,
-                cycle_increases_on_page_cross: true
-            }),
-            (0x39, Instruction{
-                address_mode: AddressingMode::YIndexedAbsolute,
-                operation: CPU::op_and,
-                result_handler: CPU::result_into_acc,
-                value_or_address: Value,
-                cycle_increase: 4,
-                cycle_increases_on_page_cross: true
-            }),
-            (0x25, Instruction{
-                address_mode: AddressingMode::ZeroPage,
-                operation: CPU::op_and,
-                result_handler: CPU::result_into_acc,
-                value_or_address: Value,
-                cycle_increase: 3,
-                cycle_increases_on_page_cross: false
-            }),
-            (0x35, Instruction{
-                address_mode: AddressingMode::XIndexedZeroPage,
-                operation: CPU::op_and,
-                result_handler: CPU::result_into_acc,
-                value_or_address: Value,
-                cycle_increase: 4,
-                cycle_increases_on_page_cross: false
-            }),
-            (0x21, Instruction{
-                address_mode: AddressingMode::XIndexedZeroPageIndirect,
-                operation: CPU::op_and,
-                result_handler: CPU::result_into_acc,
-                value_or_address: Value,
-                cycle_increase: 6,
-                cycle_increases_on_page_cross: false
-            }),
-            (0x31, Instruction{
-                address_mode: AddressingMode::ZeroPageIndirectYIndexed,
-                operation: CPU::op_and,
-                result_handler: CPU::result_into_acc,
-                value_or_address: Value,
-                cycle_increase: 5,
-                cycle_increases_on_page_cross: true
-            }),
-
-            //ASL
-            (0x0A, Instruction{
-                address_mode: AddressingMode::Accumulator,
-                operation: CPU::op_asl,
-                result_handler: CPU::result_into_acc,
-                value_or_address: Value,
-                cycle_increase: 2,
-                cycle_increases_on_page_cross: false
-            }),
-            (0x0E, Instruction{
-                address_mode: AddressingMode::Absolute,
-                operation: CPU::op_asl,
-                result_handler: CPU::result_into_memory,
-                value_or_address: Value,
-                cycle_increase: 6,
-                cycle_increases_on_page_cross: false
-            }),
-            (0x1E, Instruction{
-                address_mode: AddressingMode::XIndexedAbsolute,
-                operation: CPU::op_asl,
-                result_handler: CPU::result_into_memory,
-                value_or_address: Value,
-                cycle_increase: 7,
-                cycle_increases_on_page_cross: false
-            }),
-            (0x06, Instruction{
-                address_mode: AddressingMode::ZeroPage,
-                operation: CPU::op_asl,
-                result_handler: CPU::result_into_memory,
-                value_or_address: Value,
-                cycle_increase: 5,
-                cycle_increases_on_page_cross: false
-            }),
-            (0x16, Instruction{
-                address_mode: AddressingMode::XIndexedZeroPage,
-                operation: CPU::op_asl,
-                result_handler: CPU::result_into_memory,
-                value_or_address: Value,
-                cycle_increase: 6,
-                cycle_increases_on_page_cross: false
-            }),
-
-            //BCC
-            (0x90, Instruction{
-                address_mode: AddressingMode::Relative,
-                operation: CPU::op_bcc,
-                result_handler: CPU::no_handler,
-                value_or_address: Value,
-                cycle_increase: 2,
-                cycle_increases_on_page_cross: true
-            }),
-
-            //BCS
-            (0xB0, Instruction{
-                address_mode: AddressingMode::Relative,
-                operation: CPU::op_bcs,
-                result_handler: CPU::no_handler,
-                value_or_address: Value,
-                cycle_increase: 2,
-                cycle_increases_on_page_cross: true
-            }),
-
-            //BEQ
-            (0xF0, Instruction{
-                address_mode: AddressingMode::Relative,
-                operation: CPU::op_beq,
-                result_handler: CPU::no_handler,
-                value_or_address: Value,
-                cycle_increase: 2,
-                cycle_increases_on_page_cross: true
-            }),
-
-            //BIT
-            (0x2C, Instruction{
-                address_mode: AddressingMode::Absolute,
-                operation: CPU::op_bit,
-                result_handler: CPU::no_handler,
-                value_or_address: Value,
-                cycle_increase: 4,
-                cycle_increases_on_page_cross: false
-            }),
-            (0x24, Instruction{
-                address_mode: AddressingMode::ZeroPage,
-                operation: CPU::op_bit,
-                result_handler: CPU::no_handler,
-                value_or_address: Value,
-                cycle_increase: 3,
-                cycle_increases_on_page_cross: false
-            }),
-
-            //BMI
-            (0x30, Instruction{
-                address_mode: AddressingMode::Relative,
-                operation: CPU::op_bmi,
-                result_handler: CPU::no_handler,
-                value_or_address: Value,
-                cycle_increase: 2,
-                cycle_increases_on_page_cross: true
-            }),
-
-            //BNE
-            (0xD0, Instruction{
-                address_mode: AddressingMode::Relative,
-                operation: CPU::op_bne,
-                result_handler: CPU::no_handler,
-                value_or_address: Value,
-                cycle_increase: 2,
-                cycle_increases_on_page_cross: true
-            }),
-
-            //BPL
-            (0x10, Instruction{
-                address_mode: AddressingMode::Relative,
-                operation: CPU::op_bpl,
-                result_handler: CPU::no_handler,
-                value_or_address: Value,
-                cycle_increase: 2,
-                cycle_increases_on_page_cross: true
-            }),
-
-            //BRK
-            (0x00, Instruction{
-                address_mode: AddressingMode::Implied,
-                operation: CPU::op_brk,
-                result_handler: CPU::no_handler,
-                value_or_address: Value,
-                cycle_increase: 7,
-                cycle_increases_on_page_cross: false
-            }),
-
-            //BVC
-            (0x50, Instruction{
-                address_mode: AddressingMode::Relative,
-                operation: CPU::op_bvc,
-                result_handler: CPU::no_handler,
-                value_or_address: Value,
-                cycle_increase: 2,
-                cycle_increases_on_page_cross: true
-            }),
-
-            //BVS
-            (0x70, Instruction{
-                address_mode: AddressingMode::Relative,
-                operation: CPU::op_bvs,
-                result_handler: CPU::no_handler,
-                value_or_address: Value,
-                cycle_increase: 2,
-                cycle_increases_on_page_cross: true
-            }),
-
-            //CLC,
-            (0x18, Instruction{
-                address_mode: AddressingMode::Implied,
-                operation: CPU::op_clc,
-                result_handler: CPU::no_handler,
-                value_or_address: Value,
-                cycle_increase: 2,
-                cycle_increases_on_page_cross: false
-            }),
-
-            //CLD
-            (0xD8, Instruction{
-                address_mode: AddressingMode::Implied,
-                operation: CPU::op_cld,
-                result_handler: CPU::no_handler,
-                value_or_address: Value,
-                cycle_increase: 2,
-                cycle_increases_on_page_cross: false
-            }),
-
-            //CLI,
-            (0x58, Instruction{
-                address_mode: AddressingMode::Implied,
-                operation: CPU::op_cli,
-                result_handler: CPU::no_handler,
-                value_or_address: Value,
-                cycle_increase: 2,
-                cycle_increases_on_page_cross: false
-            }),
-
-            //CLV
-            (0xB8, Instruction{
-                address_mode: AddressingMode::Implied,
-                operation: CPU::op_clv,
-                result_handler: CPU::no_handler,
-                value_or_address: Value,
-                cycle_increase: 2,
-                cycle_increases_on_page_cross: false
-            }),
-
-            //CMP
-            (0xC9, Instruction{
-                address_mode: AddressingMode::Immediate,
-                operation: CPU::op_cmp,
-                result_handler: CPU::no_handler,
-                value_or_address: Value,
-                cycle_increase: 2,
-                cycle_increases_on_page_cross: false
-            }),
-            (0xCD, Instruction{
-                address_mode: AddressingMode::Absolute,
-                operation: CPU::op_cmp,
-                result_handler: CPU::no_handler,
-                value_or_address: Value,
-                cycle_increase: 4,
-                cycle_increases_on_page_cross: false
-            }),
-            (0xDD, Instruction{
-                address_mode: AddressingMode::XIndexedAbsolute,
-                operation: CPU::op_cmp,
-                result_handler: CPU::no_handler,
-                value_or_address: Value,
-                cycle_increase: 4,
-                cycle_increases_on_page_cross: true
-            }),
-            (0xD9, Instruction{
-                address_mode: AddressingMode::YIndexedAbsolute,
-                operation: CPU::op_cmp,
-                result_handler: CPU::no_handler,
-                value_or_address: Value,
-                cycle_increase: 4,
-                cycle_increases_on_page_cross: true
-            }),
-            (0xC5, Instruction{
-                address_mode: AddressingMode::ZeroPage,
-                operation: CPU::op_cmp,
-                result_handler: CPU::no_handler,
-                value_or_address: Value,
-                cycle_increase: 3,
-                cycle_increases_on_page_cross: false
-            }),
-            (0xD5, Instruction{
-                address_mode: AddressingMode::XIndexedZeroPage,
-                operation: CPU::op_cmp,
-                result_handler: CPU::no_handler,
-                value_or_address: Value,
-                cycle_increase: 4,
-                cycle_increases_on_page_cross: false
-            }),
-            (0xC1, Instruction{
-                address_mode: AddressingMode::XIndexedZeroPageIndirect,
-                operation: CPU::op_cmp,
-                result_handler: CPU::no_handler,
-                value_or_address: Value,
-                cycle_increase: 6,
-                cycle_increases_on_page_cross: false
-            }),
-            (0xD1, Instruction{
-                address_mode: AddressingMode::ZeroPageIndirectYIndexed,
-                operation: CPU::op_cmp,
-                result_handler: CPU::no_handler,
-                value_or_address: Value,
-                cycle_increase: 5,
-                cycle_increases_on_page_cross: true
-            }),
-
-            //CPX
-            (0xE0, Instruction{
-                address_mode: AddressingMode::Immediate,
-                operation: CPU::op_cpx,
-                result_handler: CPU::no_handler,
-                value_or_address: Value,
-                cycle_increase: 2,
-                cycle_increases_on_page_cross: false
-            }),
-            (0xEC, Instruction{
-                address_mode: AddressingMode::Absolute,
-                operation: CPU::op_cpx,
-                result_handler: CPU::no_handler,
-                value_or_address: Value,
-                cycle_increase: 4,
-                cycle_increases_on_page_cross: false
-            }),
-            (0xE4, Instruction{
-                address_mode: AddressingMode::ZeroPage,
-                operation: CPU::op_cpx,
-                result_handler: CPU::no_handler,
-                value_or_address: Value,
-                cycle_increase: 3,
-                cycle_increases_on_page_cross: false
-            }),
-
-            //CPY
-            (0xC0, Instruction{
-                address_mode: AddressingMode::Immediate,
-                operation: CPU::op_cpy,
-                result_handler: CPU::no_handler,
-                value_or_address: Value,
-                cycle_increase: 2,
-                cycle_increases_on_page_cross: false
-            }),
-            (0xCC, Instruction{
-                address_mode: AddressingMode::Absolute,
-                operation: CPU::op_cpy,
-                result_handler: CPU::no_handler,
-                value_or_address: Value,
-                cycle_increase: 4,
-                cycle_increases_on_page_cross: false
-            }),
-            (0xC4, Instruction{
-                address_mode: AddressingMode::ZeroPage,
-                operation: CPU::op_cpy,
-                result_handler: CPU::no_handler,
-                value_or_address: Value,
-                cycle_increase: 3,
-                cycle_increases_on_page_cross: false
-            }),
-
-            //DEC
-            (0xCE, Instruction{
-                address_mode: AddressingMode::Absolute,
-                operation: CPU::op_dec,
-                result_handler: CPU::result_into_memory,
-                value_or_address: Value,
-                cycle_increase: 6,
-                cycle_increases_on_page_cross: false
-            }),
-            (0xDE, Instruction{
-                address_mode: AddressingMode::XIndexedAbsolute,
-                operation: CPU::op_dec,
-                result_handler: CPU::result_into_memory,
-                value_or_address: Value,
-                cycle_increase: 7,
-                cycle_increases_on_page_cross: false
-            }),
-            (0xC6, Instruction{
-                address_mode: AddressingMode::ZeroPage,
-                operation: CPU::op_dec,
-                result_handler: CPU::result_into_memory,
-                value_or_address: Value,
-                cycle_increase: 5,
-                cycle_increases_on_page_cross: false
-            }),
-            (0xD6, Instruction{
-                address_mode: AddressingMode::XIndexedZeroPage,
-                operation: CPU::op_dec,
-                result_handler: CPU::result_into_memory,
-                value_or_address: Value,
-                cycle_increase: 6,
-                cycle_increases_on_page_cross: false
-            }),
-
-            //DEX
-            (0xCA, Instruction{
-                address_mode: AddressingMode::Implied,
-                operation: CPU::op_dex,
-                result_handler: CPU::no_handler,
-                value_or_address: Value,
-                cycle_increase: 2,
-                cycle_increases_on_page_cross: false
-            }),
-
-            //DEY
-            (0x88, Instruction{
-                address_mode: AddressingMode::Implied,
-                operation: CPU::op_dey,
-                result_handler: CPU::no_handler,
-                value_or_address: Value,
-                cycle_increase: 2,
-                cycle_increases_on_page_cross: false
-            }),
-
-            //EOR
-            (0x49, Instruction{
-                address_mode: AddressingMode::Immediate,
-                operation: CPU::op_eor,
-                result_handler: CPU::result_into_acc,
-                value_or_address: Value,
-                cycle_increase: 2,
-                cycle_increases_on_page_cross: false
-            }),
-            (0x4D, Instruction{
-                address_mode: AddressingMode::Absolute,
-                operation: CPU::op_eor,
-                result_handler: CPU::result_into_acc,
-                value_or_address: Value,
-                cycle_increase: 4,
-                cycle_increases_on_page_cross: false
-            }),
-            (0x5D, Instruction{
-                address_mode: AddressingMode::XIndexedAbsolute,
-                operation: CPU::op_eor,
-                result_handler: CPU::result_into_acc,
-                value_or_address: Value,
-                cycle_increase: 4,
-                cycle_increases_on_page_cross: true
-            }),
-            (0x59, Instruction{
-                address_mode: AddressingMode::YIndexedAbsolute,
-                operation: CPU::op_eor,
-                result_handler: CPU::result_into_acc,
-                value_or_address: Value,
-                cycle_increase: 4,
-                cycle_increases_on_page_cross: true
-            }),
-            (0x45, Instruction{
-                address_mode: AddressingMode::ZeroPage,
-                operation: CPU::op_eor,
-                result_handler: CPU::result_into_acc,
-                value_or_address: Value,
-                cycle_increase: 3,
-                cycle_increases_on_page_cross: false
-            }),
-            (0x55, Instruction{
-                address_mode: AddressingMode::XIndexedZeroPage,
-                operation: CPU::op_eor,
-                result_handler: CPU::result_into_acc,
-                value_or_address: Value,
-                cycle_increase: 4,
-                cycle_increases_on_page_cross: false
-            }),
-            (0x41, Instruction{
-                address_mode: AddressingMode::XIndexedZeroPageIndirect,
-                operation: CPU::op_eor,
-                result_handler: CPU::result_into_acc,
-                value_or_address: Value,
-                cycle_increase: 6,
-                cycle_increases_on_page_cross: false
-            }),
-            (0x51, Instruction{
-                address_mode: AddressingMode::ZeroPageIndirectYIndexed,
-                operation: CPU::op_eor,
-                result_handler: CPU::result_into_acc,
-                value_or_address: Value,
-                cycle_increase: 5,
-                cycle_increases_on_page_cross: true
-            }),
-
-            //INC
-            (0xEE, Instruction{
-                address_mode: AddressingMode::Absolute,
-                operation: CPU::op_inc,
-                result_handler: CPU::result_into_memory,
-                value_or_address: Value,
-                cycle_increase: 6,
-                cycle_increases_on_page_cross: false
-            }),
-            (0xFE, Instruction{
-                address_mode: AddressingMode::XIndexedAbsolute,
-                operation: CPU::op_inc,
-                result_handler: CPU::result_into_memory,
-                value_or_address: Value,
-                cycle_increase: 7,
-                cycle_increases_on_page_cross: false
-            }),
-            (0xE6, Instruction{
-                address_mode: AddressingMode::ZeroPage,
-                operation: CPU::op_inc,
-                result_handler: CPU::result_into_memory,
-                value_or_address: Value,
-                cycle_increase: 5,
-                cycle_increases_on_page_cross: false
-            }),
-            (0xF6, Instruction{
-                address_mode: AddressingMode::XIndexedZeroPage,
-                operation: CPU::op_inc,
-                result_handler: CPU::result_into_memory,
-                value_or_address: Value,
-                cycle_increase: 6,
-                cycle_increases_on_page_cross: false
-            }),
-
-            //INX
-            (0xE8, Instruction{
-                address_mode: AddressingMode::Implied,
-                operation: CPU::op_inx,
-                result_handler: CPU::no_handler,
-                value_or_address: Value,
-                cycle_increase: 2,
-                cycle_increases_on_page_cross: false
-            }),
-
-            //INY
-            (0xC8, Instruction{
-                address_mode: AddressingMode::Implied,
-                operation: CPU::op_iny,
-                result_handler: CPU::no_handler,
-                value_or_address: Value,
-                cycle_increase: 2,
-                cycle_increases_on_page_cross: false
-            }),
-
-            //JMP
-            (0x4C, Instruction{
-                address_mode: AddressingMode::Absolute,
-                operation: CPU::op_jmp,
-                result_handler: CPU::no_handler,
-                value_or_address: Address,
-                cycle_increase: 3,
-                cycle_increases_on_page_cross: false
-            }),
-            (0x6C, Instruction{
-                address_mode: AddressingMode::AbsoluteIndirect,
-                operation: CPU::op_jmp,
-                result_handler: CPU::no_handler,
-                value_or_address: Address,
-                cycle_increase: 5,
-                cycle_increases_on_page_cross: false
-            }),
-
-            //JSR
-            (0x20, Instruction{
-                address_mode: AddressingMode::Absolute,
-                operation: CPU::op_jsr,
-                result_handler: CPU::no_handler,
-                value_or_address: Address,
-                cycle_increase: 6,
-                cycle_increases_on_page_cross: false
-            }),
-
-            //LDA
-            (0xA9, Instruction{
-                address_mode: AddressingMode::Immediate,
-                operation: CPU::op_lda,
-                result_handler: CPU::no_handler,
-                value_or_address: Value,
-                cycle_increase: 2,
-                cycle_increases_on_page_cross: false
-            }),
-            (0xAD, Instruction{
-                address_mode: AddressingMode::Absolute,
-                operation: CPU::op_lda,
-                result_handler: CPU::no_handler,
-                value_or_address: Value,
-                cycle_increase: 4,
-                cycle_increases_on_page_cross: false
-            }),
-            (0xBD, Instruction{
-                address_mode: AddressingMode::XIndexedAbsolute,
-                operation: CPU::op_lda,
-                result_handler: CPU::no_handler,
-                value_or_address: Value,
-                cycle_increase: 4,
-                cycle_increases_on_page_cross: true
-            }),
-            (0xB9, Instruction{
-                address_mode: AddressingMode::YIndexedAbsolute,
-                operation: CPU::op_lda,
-                result_handler: CPU::no_handler,
-                value_or_address: Value,
-                cycle_increase: 4,
-                cycle_increases_on_page_cross: true
-            }),
-            (0xA5, Instruction{
-                address_mode: AddressingMode::ZeroPage,
-                operation: CPU::op_lda,
-                result_handler: CPU::no_handler,
-                value_or_address: Value,
-                cycle_increase: 3,
-                cycle_increases_on_page_cross: false
-            }),
-            (0xB5, Instruction{
-                address_mode: AddressingMode::XIndexedZeroPage,
-                operation: CPU::op_lda,
-                result_handler: CPU::no_handler,
-                value_or_address: Value,
-                cycle_increase: 4,
-                cycle_increases_on_page_cross: false
-            }),
-            (0xA1, Instruction{
-                address_mode: AddressingMode::XIndexedZeroPageIndirect,
-                operation: CPU::op_lda,
-                result_handler: CPU::no_handler,
-                value_or_address: Value,
-                cycle_increase: 6,
-                cycle_increases_on_page_cross: false
-            }),
-            (0xB1, Instruction{
-                address_mode: AddressingMode::ZeroPageIndirectYIndexed,
-                operation: CPU::op_lda,
-                result_handler: CPU::no_handler,
-                value_or_address: Value,
-                cycle_increase: 5,
-                cycle_increases_on_page_cross: true
-            }),
-
-            //LDX
-            (0xA2, Instruction{
-                address_mode: AddressingMode::Immediate,
-                operation: CPU::op_ldx,
-                result_handler: CPU::no_handler,
-                value_or_address: Value,
-                cycle_increase: 2,
-                cycle_increases_on_page_cross: false
-            }),
-            (0xAE, Instruction{
-                address_mode: AddressingMode::Absolute,
-                operation: CPU::op_ldx,
-                result_handler: CPU::no_handler,
-                value_or_address: Value,
-                cycle_increase: 4,
-                cycle_increases_on_page_cross: false
-            }),
-            (0xBE, Instruction{
-                address_mode: AddressingMode::YIndexedAbsolute,
-                operation: CPU::op_ldx,
-                result_handler: CPU::no_handler,
-                value_or_address: Value,
-                cycle_increase: 4,
-                cycle_increases_on_page_cross: true
-            }),
-            (0xA6, Instruction{
-                address_mode: AddressingMode::ZeroPage,
-                operation: CPU::op_ldx,
-                result_handler: CPU::no_handler,
-                value_or_address: Value,
-                cycle_increase: 3,
-                cycle_increases_on_page_cross: false
-            }),
-            (0xB6, Instruction{
-                address_mode: AddressingMode::YIndexedZeroPage,
-                operation: CPU::op_ldx,
-                result_handler: CPU::no_handler,
-                value_or_address: Value,
-                cycle_increase: 3,
-                cycle_increases_on_page_cross: false
-            }),
-
-            //LDY
-            (0xA0, Instruction{
-                address_mode: AddressingMode::Immediate,
-                operation: CPU::op_ldy,
-                result_handler: CPU::no_handler,
-                value_or_address: Value,
-                cycle_increase: 2,
-                cycle_increases_on_page_cross: false
-            }),
-            (0xAC, Instruction{
-                address_mode: AddressingMode::Absolute,
-                operation: CPU::op_ldy,
-                result_handler: CPU::no_handler,
-                value_or_address: Value,
-                cycle_increase: 4,
-                cycle_increases_on_page_cross: false
-            }),
-            (0xBC, Instruction{
-                address_mode: AddressingMode::XIndexedAbsolute,
-                operation: CPU::op_ldy,
-                result_handler: CPU::no_handler,
-                value_or_address: Value,
-                cycle_increase: 4,
-                cycle_increases_on_page_cross: true
-            }),
-            (0xA4, Instruction{
-                address_mode: AddressingMode::ZeroPage,
-                operation: CPU::op_ldy,
-                result_handler: CPU::no_handler,
-                value_or_address: Value,
-                cycle_increase: 3,
-                cycle_increases_on_page_cross: false
-            }),
-            (0xB4, Instruction{
-                address_mode: AddressingMode::XIndexedZeroPage,
-                operation: CPU::op_ldy,
-                result_handler: CPU::no_handler,
-                value_or_address: Value,
-                cycle_increase: 3,
-                cycle_increases_on_page_cross: false
-            }),
-
-            //LSR
-            (0x4A, Instruction{
-                address_mode: AddressingMode::Accumulator,
-                operation: CPU::op_lsr,
-                result_handler: CPU::no_handler,
-                value_or_address: Value,
-                cycle_increase: 2,
-                cycle_increases_on_page_cross: false
-            }),
-            (0x4E, Instruction{
-                address_mode: AddressingMode::Absolute,
-                operation: CPU::op_lsr,
-                result_handler: CPU::no_handler,
-                value_or_address: Value,
-                cycle_increase: 6,
-                cycle_increases_on_page_cross: false
-            }),
-            (0x5E, Instruction{
-                address_mode: AddressingMode::XIndexedAbsolute,
-                operation: CPU::op_lsr,
-                result_handler: CPU::no_handler,
-                value_or_address: Value,
-                cycle_increase: 7,
-                cycle_increases_on_page_cross: false
-            }),
-            (0x46, Instruction{
-                address_mode: AddressingMode::ZeroPage,
-                operation: CPU::op_lsr,
-                result_handler: CPU::no_handler,
-                value_or_address: Value,
-                cycle_increase: 5,
-                cycle_increases_on_page_cross: false
-            }),
-            (0x56, Instruction{
-                address_mode: AddressingMode::ZeroPage,
-                operation: CPU::op_lsr,
-                result_handler: CPU::no_handler,
-                value_or_address: Value,
-                cycle_increase: 6,
-                cycle_increases_on_page_cross: false
-            }),
-
-            //NOP
-            (0xEA, Instruction{
-                address_mode: AddressingMode::Implied,
-                operation: CPU::op_nop,
-                result_handler: CPU::no_handler,
-                value_or_address: Value,
-                cycle_increase: 2,
-                cycle_increases_on_page_cross: false
-            }),
-
-            //ORA
-            (0x09, Instruction{
-                address_mode: AddressingMode::Immediate,
-                operation: CPU::op_ora,
-                result_handler: CPU::no_handler,
-                value_or_address: Value,
-                cycle_increase: 2,
-                cycle_increases_on_page_cross: false
-            }),
-            (0x0D, Instruction{
-                address_mode: AddressingMode::Absolute,
-                operation: CPU::op_ora,
-                result_handler: CPU::no_handler,
-                value_or_address: Value,
-                cycle_increase: 4,
-                cycle_increases_on_page_cross: false
-            }),
-            (0x1D, Instruction{
-                address_mode: AddressingMode::XIndexedAbsolute,
-                operation: CPU::op_ora,
-                result_handler: CPU::no_handler,
-                value_or_address: Value,
-                cycle_increase: 4,
-                cycle_increases_on_page_cross: true
-            }),
-            (0x19, Instruction{
-                address_mode: AddressingMode::YIndexedAbsolute,
-                operation: CPU::op_ora,
-                result_handler: CPU::no_handler,
-                value_or_address: Value,
-                cycle_increase: 4,
-                cycle_increases_on_page_cross: true
-            }),
-            (0x05, Instruction{
-                address_mode: AddressingMode::ZeroPage,
-                operation: CPU::op_ora,
-                result_handler: CPU::no_handler,
-                value_or_address: Value,
-                cycle_increase: 3,
-                cycle_increases_on_page_cross: false
-            }),
-            (0x15, Instruction{
-                address_mode: AddressingMode::XIndexedZeroPage,
-                operation: CPU::op_ora,
-                result_handler: CPU::no_handler,
-                value_or_address: Value,
-                cycle_increase: 4,
-                cycle_increases_on_page_cross: false
-            }),
-            (0x01, Instruction{
-                address_mode: AddressingMode::XIndexedZeroPageIndirect,
-                operation: CPU::op_ora,
-                result_handler: CPU::no_handler,
-                value_or_address: Value,
-                cycle_increase: 6,
-                cycle_increases_on_page_cross: false
-            }),
-            (0x11, Instruction{
-                address_mode: AddressingMode::ZeroPageIndirectYIndexed,
-                operation: CPU::op_ora,
-                result_handler: CPU::no_handler,
-                value_or_address: Value,
-                cycle_increase: 5,
-                cycle_increases_on_page_cross: true
-            }),
-
-            //PHA
-            (0x48, Instruction{
-                address_mode: AddressingMode::Implied,
-                operation: CPU::op_pha,
-                result_handler: CPU::no_handler,
-                value_or_address: Address,
-                cycle_increase: 3,
-                cycle_increases_on_page_cross: false
-            }),
-
-            //PHP
-            (0x08, Instruction{
-                address_mode: AddressingMode::Implied,
-                operation: CPU::op_php,
-                result_handler: CPU::no_handler,
-                value_or_address: Address,
-                cycle_increase: 3,
-                cycle_increases_on_page_cross: false
-            }),
-
-            //PLA
-            (0x68, Instruction{
-                address_mode: AddressingMode::Implied,
-                operation: CPU::op_pla,
-                result_handler: CPU::no_handler,
-                value_or_address: Address,
-                cycle_increase: 4,
-                cycle_increases_on_page_cross: false
-            }),
-
-            //PLP
-            (0x28, Instruction{
-                address_mode: AddressingMode::Implied,
-                operation: CPU::op_plp,
-                result_handler: CPU::no_handler,
-                value_or_address: Address,
-                cycle_increase: 4,
-                cycle_increases_on_page_cross: false
-            }),
-
-            //ROL
-            (0x2A, Instruction{
-                address_mode: AddressingMode::Accumulator,
-                operation: CPU::op_rol,
-                result_handler: CPU::result_into_acc,
-                value_or_address: Value,
-                cycle_increase: 2,
-                cycle_increases_on_page_cross: false
-            }),
-            (0x2E, Instruction{
-                address_mode: AddressingMode::Absolute,
-                operation: CPU::op_rol,
-                result_handler: CPU::result_into_memory,
-                value_or_address: Value,
-                cycle_increase: 6,
-                cycle_increases_on_page_cross: false
-            }),
-            (0x3E, Instruction{
-                address_mode: AddressingMode::XIndexedAbsolute,
-                operation: CPU::op_rol,
-                result_handler: CPU::result_into_memory,
-                value_or_address: Value,
-                cycle_increase: 7,
-                cycle_increases_on_page_cross: false
-            }),
-            (0x26, Instruction{
-                address_mode: AddressingMode::ZeroPage,
-                operation: CPU::op_rol,
-                result_handler: CPU::result_into_memory,
-                value_or_address: Value,
-                cycle_increase: 5,
-                cycle_increases_on_page_cross: false
-            }),
-            (0x36, Instruction{
-                address_mode: AddressingMode::XIndexedZeroPage,
-                operation: CPU::op_rol,
-                result_handler: CPU::result_into_memory,
-                value_or_address: Value,
-                cycle_increase: 6,
-                cycle_increases_on_page_cross: false
-            }),
-
-            //ROR
-            (0x6A, Instruction{
-                address_mode: AddressingMode::Accumulator,
-                operation: CPU::op_ror,
-                result_handler: CPU::result_into_acc,
-                value_or_address: Value,
-                cycle_increase: 2,
-                cycle_increases_on_page_cross: false
-            }),
-            (0x6E, Instruction{
-                address_mode: AddressingMode::Absolute,
-                operation: CPU::op_ror,
-                result_handler: CPU::result_into_memory,
-                value_or_address: Value,
-                cycle_increase: 6,
-                cycle_increases_on_page_cross: false
-            }),
-            (0x7E, Instruction{
-                address_mode: AddressingMode::XIndexedAbsolute,
-                operation: CPU::op_ror,
-                result_handler: CPU::result_into_memory,
-                value_or_address: Value,
-                cycle_increase: 7,
-                cycle_increases_on_page_cross: false
-            }),
-            (0x66, Instruction{
-                address_mode: AddressingMode::ZeroPage,
-                operation: CPU::op_ror,
-                result_handler: CPU::result_into_memory,
-                value_or_address: Value,
-                cycle_increase: 5,
-                cycle_increases_on_page_cross: false
-            }),
-            (0x76, Instruction{
-                address_mode: AddressingMode::XIndexedZeroPage,
-                operation: CPU::op_ror,
-                result_handler: CPU::result_into_memory,
-                value_or_address: Value,
-                cycle_increase: 6,
-                cycle_increases_on_page_cross: false
-            }),
-
-            //RTI
-            (0x40, Instruction{
-                address_mode: AddressingMode::Implied,
-                operation: CPU::op_rti,
-                result_handler: CPU::no_handler,
-                value_or_address: Value,
-                cycle_increase: 6,
-                cycle_increases_on_page_cross: false
-            }),
-
-            //RTS
-            (0x60, Instruction{
-                address_mode: AddressingMode::Implied,
-                operation: CPU::op_rts,
-                result_handler: CPU::no_handler,
-                value_or_address: Value,
-                cycle_increase: 6,
-                cycle_increases_on_page_cross: false
-            }),
-
-            //SBC
-            (0xE9, Instruction{
-                address_mode: AddressingMode::Immediate,
-                operation: CPU::op_sbc,
-                result_handler: CPU::no_handler,
-                value_or_address: Value,
-                cycle_increase: 2,
-                cycle_increases_on_page_cross: false
-            }),
-            (0xED, Instruction{
-                address_mode: AddressingMode::Absolute,
-                operation: CPU::op_sbc,
-                result_handler: CPU::no_handler,
-                value_or_address: Value,
-                cycle_increase: 4,
-                cycle_increases_on_page_cross: false
-            }),
-            (0xFD, Instruction{
-                address_mode: AddressingMode::XIndexedAbsolute,
-                operation: CPU::op_sbc,
-                result_handler: CPU::no_handler,
-                value_or_address: Value,
-                cycle_increase: 4,
-                cycle_increases_on_page_cross: true
-            }),
-            (0xF9, Instruction{
-                address_mode: AddressingMode::YIndexedAbsolute,
-                operation: CPU::op_sbc,
-                result_handler: CPU::no_handler,
-                value_or_address: Value,
-                cycle_increase: 4,
-                cycle_increases_on_page_cross: true
-            }),
-            (0xE5, Instruction{
-                address_mode: AddressingMode::ZeroPage,
-                operation: CPU::op_sbc,
-                result_handler: CPU::no_handler,
-                value_or_address: Value,
-                cycle_increase: 3,
-                cycle_increases_on_page_cross: false
-            }),
-            (0xF5, Instruction{
-                address_mode: AddressingMode::XIndexedZeroPage,
-                operation: CPU::op_sbc,
-                result_handler: CPU::no_handler,
-                value_or_address: Value,
-                cycle_increase: 4,
-                cycle_increases_on_page_cross: false
-            }),
-            (0xE1, Instruction{
-                address_mode: AddressingMode::XIndexedZeroPageIndirect,
-                operation: CPU::op_sbc,
-                result_handler: CPU::no_handler,
-                value_or_address: Value,
-                cycle_increase: 6,
-                cycle_increases_on_page_cross: false
-            }),
-            (0xF1, Instruction{
-                address_mode: AddressingMode::ZeroPageIndirectYIndexed,
-                operation: CPU::op_sbc,
-                result_handler: CPU::no_handler,
-                value_or_address: Value,
-                cycle_increase: 5,
-                cycle_increases_on_page_cross: true
-            }),
-
-            //SEC
-            (0x38, Instruction{
-                address_mode: AddressingMode::Implied,
-                operation: CPU::op_sec,
-                result_handler: CPU::no_handler,
-                value_or_address: Address,
-                cycle_increase: 2,
-                cycle_increases_on_page_cross: false
-            }),
-
-            //SED
-            (0xF8, Instruction{
-                address_mode: AddressingMode::Implied,
-                operation: CPU::op_sed,
-                result_handler: CPU::no_handler,
-                value_or_address: Address,
-                cycle_increase: 2,
-                cycle_increases_on_page_cross: false
-            }),
-
-            //SEI
-            (0x78, Instruction{
-                address_mode: AddressingMode::Implied,
-                operation: CPU::op_sei,
-                result_handler: CPU::no_handler,
-                value_or_address: Address,
-                cycle_increase: 2,
-                cycle_increases_on_page_cross: false
-            }),
-
-            //STA
-            (0x8D, Instruction{
-                address_mode: AddressingMode::Absolute,
-                operation: CPU::op_sta,
-                result_handler: CPU::no_handler,
-                value_or_address: Address,
-                cycle_increase: 4,
-                cycle_increases_on_page_cross: false
-            }),
-            (0x9D, Instruction{
-                address_mode: AddressingMode::XIndexedAbsolute,
-                operation: CPU::op_sta,
-                result_handler: CPU::no_handler,
-                value_or_address: Address,
-                cycle_increase: 5,
-                cycle_increases_on_page_cross: false
-            }),
-            (0x99, Instruction{
-                address_mode: AddressingMode::YIndexedAbsolute,
-                operation: CPU::op_sta,
-                result_handler: CPU::no_handler,
-                value_or_address: Address,
-                cycle_increase: 5,
-                cycle_increases_on_page_cross: false
-            }),
-            (0x85, Instruction{
-                address_mode: AddressingMode::ZeroPage,
-                operation: CPU::op_sta,
-                result_handler: CPU::no_handler,
-                value_or_address: Address,
-                cycle_increase: 3,
-                cycle_increases_on_page_cross: false
-            }),
-            (0x95, Instruction{
-                address_mode: AddressingMode::XIndexedZeroPage,
-                operation: CPU::op_sta,
-                result_handler: CPU::no_handler,
-                value_or_address: Address,
-                cycle_increase: 4,
-                cycle_increases_on_page_cross: false
-            }),
-            (0x81, Instruction{
-                address_mode: AddressingMode::XIndexedZeroPageIndirect,
-                operation: CPU::op_sta,
-                result_handler: CPU::no_handler,
-                value_or_address: Address,
-                cycle_increase: 6,
-                cycle_increases_on_page_cross: false
-            }),
-            (0x91, Instruction{
-                address_mode: AddressingMode::ZeroPageIndirectYIndexed,
-                operation: CPU::op_sta,
-                result_handler: CPU::no_handler,
-                value_or_address: Address,
-                cycle_increase: 6,
-                cycle_increases_on_page_cross: false
-            }),
-
-            //STX
-            (0x8E, Instruction{
-                address_mode: AddressingMode::Absolute,
-                operation: CPU::op_stx,
-                result_handler: CPU::no_handler,
-                value_or_address: Address,
-                cycle_increase: 4,
-                cycle_increases_on_page_cross: false
-            }),
-            (0x86, Instruction{
-                address_mode: AddressingMode::ZeroPage,
-                operation: CPU::op_stx,
-                result_handler: CPU::no_handler,
-                value_or_address: Address,
-                cycle_increase: 3,
-                cycle_increases_on_page_cross: false
-            }),
-            (0x96, Instruction{
-                address_mode: AddressingMode::YIndexedZeroPage,
-                operation: CPU::op_stx,
-                result_handler: CPU::no_handler,
-                value_or_address: Address,
-                cycle_increase: 4,
-                cycle_increases_on_page_cross: false
-            }),
-
-            //STY
-            (0x8C, Instruction{
-                address_mode: AddressingMode::Absolute,
-                operation: CPU::op_sty,
-                result_handler: CPU::no_handler,
-                value_or_address: Address,
-                cycle_increase: 4,
-                cycle_increases_on_page_cross: false
-            }),
-            (0x84, Instruction{
-                address_mode: AddressingMode::ZeroPage,
-                operation: CPU::op_sty,
-                result_handler: CPU::no_handler,
-                value_or_address: Address,
-                cycle_increase: 3,
-                cycle_increases_on_page_cross: false
-            }),
-            (0x94, Instruction{
-                address_mode: AddressingMode::XIndexedZeroPage,
-                operation: CPU::op_sty,
-                result_handler: CPU::no_handler,
-                value_or_address: Address,
-                cycle_increase: 4,
-                cycle_increases_on_page_cross: false
-            }),
-
-            //TAX
-            (0xAA, Instruction{
-                address_mode: AddressingMode::Implied,
-                operation: CPU::op_tax,
-                result_handler: CPU::no_handler,
-                value_or_address: Address,
-                cycle_increase: 2,
-                cycle_increases_on_page_cross: false
-            }),
-
-            //TAY
-            (0xA8, Instruction{
-                address_mode: AddressingMode::Implied,
-                operation: CPU::op_tay,
-                result_handler: CPU::no_handler,
-                value_or_address: Address,
-                cycle_increase: 2,
-                cycle_increases_on_page_cross: false
-            }),
-
-            //TSX
-            (0xBA, Instruction{
-                address_mode: AddressingMode::Implied,
-                operation: CPU::op_tsx,
-                result_handler: CPU::no_handler,
-                value_or_address: Address,
-                cycle_increase: 2,
-                cycle_increases_on_page_cross: false
-            }),
-
-            //TXA
-            (0x8A, Instruction{
-                address_mode: AddressingMode::Implied,
-                operation: CPU::op_txa,
-                result_handler: CPU::no_handler,
-                value_or_address: Address,
-                cycle_increase: 2,
-                cycle_increases_on_page_cross: false
-            }),
-
-            //TXS
-            (0x9A, Instruction{
-                address_mode: AddressingMode::Implied,
-                operation: CPU::op_txs,
-                result_handler: CPU::no_handler,
-                value_or_address: Address,
-                cycle_increase: 2,
-                cycle_increases_on_page_cross: false
-            }),
-
-            //TYA
-            (0x98, Instruction{
-                address_mode: AddressingMode::Implied,
-                operation: CPU::op_tya,
-                result_handler: CPU::no_handler,
-                value_or_address: Address,
-                cycle_increase: 2,
-                cycle_increases_on_page_cross: false
-            }),
-        ])
+use crate::cpu::bus::Bus;
+use crate::cpu::cpu::{Variant, CPU};
+use crate::cpu::execution::Fault;
+use crate::cpu::instructions::{AddressingMode, Instruction, InstructionSet};
+use crate::cpu::instructions::ValueOrAddress::{Address, ReadModifyWrite, Value};
+
+impl<B: Bus> CPU<B>{
+
+    //Built once at compile time, opcodes without an instruction are None.
+    const INSTRUCTION_SET: InstructionSet<B> = Self::build_instruction_set();
+    const CMOS_INSTRUCTION_SET: InstructionSet<B> = Self::build_cmos_instruction_set();
+    const DOCUMENTED_INSTRUCTION_SET: InstructionSet<B> = Self::build_documented_instruction_set();
+
+    //Looks up the instruction for an opcode, returns None for opcodes that aren't implemented.
+    pub fn get_instruction(variant: Variant, opcode: u8) -> Option<&'static Instruction<B>> {
+        Self::get_instruction_set(variant)[opcode as usize].as_ref()
     }
 
-    fn result_into_acc(&mut self, value: Option<u8>, _: Option<u16>){
-        self.registers.acc = value.unwrap_or_else(|| {
-            panic!("Expected value for loading into ACC.");
-        })
+    //Opcodes the NMOS 6502 only runs by accident of its decoding. Every 65C02 opcode is documented, if only as a NOP.
+    pub fn is_undocumented(variant: Variant, opcode: u8) -> bool {
+        variant == Variant::Nmos6502 && const { &Self::DOCUMENTED_INSTRUCTION_SET }[opcode as usize].is_none()
     }
 
-    fn result_into_memory(&mut self, value: Option<u8>, address: Option<u16>){
-        match address {
-            Some(result_address) => {
-                if (result_address as usize) >= self.memory.len() {
-                    panic!("Memory out of bounds when writing result to memory.")
-                }
-                self.memory[result_address as usize] = value.unwrap_or_else(|| {
-                    panic!("Expected value when writing result to memory.")
-                });
-            }
-            None => {
-                panic!("Expected memory address when writing result to memory.")
-            }
+    pub fn get_instruction_set(variant: Variant) -> &'static InstructionSet<B> {
+        match variant {
+            Variant::Nmos6502 => const { &Self::INSTRUCTION_SET },
+            //The 65816 decodes through its own table in w65816, this is never used for it.
+            Variant::Wdc65C02 | Variant::Wdc65816 => const { &Self::CMOS_INSTRUCTION_SET }
         }
     }
 
-    fn no_handler(&mut self, _: Option<u8>, _: Option<u16>){
-        //Empty
+    const fn build_instruction_set() -> InstructionSet<B> {
+        let mut instructions = Self::build_documented_instruction_set();
+
+        //Undocumented NMOS instructions
+
+        //SLO
+        instructions[0x07] = Some(Instruction{
+            address_mode: AddressingMode::ZeroPage,
+            mnemonic: "SLO",
+            operation: CPU::op_slo,
+            result_handler: CPU::result_into_memory,
+            value_or_address: ReadModifyWrite,
+            cycle_increase: 5,
+            cycle_increases_on_page_cross: false
+        });
+        instructions[0x17] = Some(Instruction{
+            address_mode: AddressingMode::XIndexedZeroPage,
+            mnemonic: "SLO",
+            operation: CPU::op_slo,
+            result_handler: CPU::result_into_memory,
+            value_or_address: ReadModifyWrite,
+            cycle_increase: 6,
+            cycle_increases_on_page_cross: false
+        });
+        instructions[0x0F] = Some(Instruction{
+            address_mode: AddressingMode::Absolute,
+            mnemonic: "SLO",
+            operation: CPU::op_slo,
+            result_handler: CPU::result_into_memory,
+            value_or_address: ReadModifyWrite,
+            cycle_increase: 6,
+            cycle_increases_on_page_cross: false
+        });
+        instructions[0x1F] = Some(Instruction{
+            address_mode: AddressingMode::XIndexedAbsolute,
+            mnemonic: "SLO",
+            operation: CPU::op_slo,
+            result_handler: CPU::result_into_memory,
+            value_or_address: ReadModifyWrite,
+            cycle_increase: 7,
+            cycle_increases_on_page_cross: false
+        });
+        instructions[0x1B] = Some(Instruction{
+            address_mode: AddressingMode::YIndexedAbsolute,
+            mnemonic: "SLO",
+            operation: CPU::op_slo,
+            result_handler: CPU::result_into_memory,
+            value_or_address: ReadModifyWrite,
+            cycle_increase: 7,
+            cycle_increases_on_page_cross: false
+        });
+        instructions[0x03] = Some(Instruction{
+            address_mode: AddressingMode::XIndexedZeroPageIndirect,
+            mnemonic: "SLO",
+            operation: CPU::op_slo,
+            result_handler: CPU::result_into_memory,
+            value_or_address: ReadModifyWrite,
+            cycle_increase: 8,
+            cycle_increases_on_page_cross: false
+        });
+        instructions[0x13] = Some(Instruction{
+            address_mode: AddressingMode::ZeroPageIndirectYIndexed,
+            mnemonic: "SLO",
+            operation: CPU::op_slo,
+            result_handler: CPU::result_into_memory,
+            value_or_address: ReadModifyWrite,
+            cycle_increase: 8,
+            cycle_increases_on_page_cross: false
+        });
+
+        //RLA
+        instructions[0x27] = Some(Instruction{
+            address_mode: AddressingMode::ZeroPage,
+            mnemonic: "RLA",
+            operation: CPU::op_rla,
+            result_handler: CPU::result_into_memory,
+            value_or_address: ReadModifyWrite,
+            cycle_increase: 5,
+            cycle_increases_on_page_cross: false
+        });
+        instructions[0x37] = Some(Instruction{
+            address_mode: AddressingMode::XIndexedZeroPage,
+            mnemonic: "RLA",
+            operation: CPU::op_rla,
+            result_handler: CPU::result_into_memory,
+            value_or_address: ReadModifyWrite,
+            cycle_increase: 6,
+            cycle_increases_on_page_cross: false
+        });
+        instructions[0x2F] = Some(Instruction{
+            address_mode: AddressingMode::Absolute,
+            mnemonic: "RLA",
+            operation: CPU::op_rla,
+            result_handler: CPU::result_into_memory,
+            value_or_address: ReadModifyWrite,
+            cycle_increase: 6,
+            cycle_increases_on_page_cross: false
+        });
+        instructions[0x3F] = Some(Instruction{
+            address_mode: AddressingMode::XIndexedAbsolute,
+            mnemonic: "RLA",
+            operation: CPU::op_rla,
+            result_handler: CPU::result_into_memory,
+            value_or_address: ReadModifyWrite,
+            cycle_increase: 7,
+            cycle_increases_on_page_cross: false
+        });
+        instructions[0x3B] = Some(Instruction{
+            address_mode: AddressingMode::YIndexedAbsolute,
+            mnemonic: "RLA",
+            operation: CPU::op_rla,
+            result_handler: CPU::result_into_memory,
+            value_or_address: ReadModifyWrite,
+            cycle_increase: 7,
+            cycle_increases_on_page_cross: false
+        });
+        instructions[0x23] = Some(Instruction{
+            address_mode: AddressingMode::XIndexedZeroPageIndirect,
+            mnemonic: "RLA",
+            operation: CPU::op_rla,
+            result_handler: CPU::result_into_memory,
+            value_or_address: ReadModifyWrite,
+            cycle_increase: 8,
+            cycle_increases_on_page_cross: false
+        });
+        instructions[0x33] = Some(Instruction{
+            address_mode: AddressingMode::ZeroPageIndirectYIndexed,
+            mnemonic: "RLA",
+            operation: CPU::op_rla,
+            result_handler: CPU::result_into_memory,
+            value_or_address: ReadModifyWrite,
+            cycle_increase: 8,
+            cycle_increases_on_page_cross: false
+        });
+
+        //SRE
+        instructions[0x47] = Some(Instruction{
+            address_mode: AddressingMode::ZeroPage,
+            mnemonic: "SRE",
+            operation: CPU::op_sre,
+            result_handler: CPU::result_into_memory,
+            value_or_address: ReadModifyWrite,
+            cycle_increase: 5,
+            cycle_increases_on_page_cross: false
+        });
+        instructions[0x57] = Some(Instruction{
+            address_mode: AddressingMode::XIndexedZeroPage,
+            mnemonic: "SRE",
+            operation: CPU::op_sre,
+            result_handler: CPU::result_into_memory,
+            value_or_address: ReadModifyWrite,
+            cycle_increase: 6,
+            cycle_increases_on_page_cross: false
+        });
+        instructions[0x4F] = Some(Instruction{
+            address_mode: AddressingMode::Absolute,
+            mnemonic: "SRE",
+            operation: CPU::op_sre,
+            result_handler: CPU::result_into_memory,
+            value_or_address: ReadModifyWrite,
+            cycle_increase: 6,
+            cycle_increases_on_page_cross: false
+        });
+        instructions[0x5F] = Some(Instruction{
+            address_mode: AddressingMode::XIndexedAbsolute,
+            mnemonic: "SRE",
+            operation: CPU::op_sre,
+            result_handler: CPU::result_into_memory,
+            value_or_address: ReadModifyWrite,
+            cycle_increase: 7,
+            cycle_increases_on_page_cross: false
+        });
+        instructions[0x5B] = Some(Instruction{
+            address_mode: AddressingMode::YIndexedAbsolute,
+            mnemonic: "SRE",
+            operation: CPU::op_sre,
+            result_handler: CPU::result_into_memory,
+            value_or_address: ReadModifyWrite,
+            cycle_increase: 7,
+            cycle_increases_on_page_cross: false
+        });
+        instructions[0x43] = Some(Instruction{
+            address_mode: AddressingMode::XIndexedZeroPageIndirect,
+            mnemonic: "SRE",
+            operation: CPU::op_sre,
+            result_handler: CPU::result_into_memory,
+            value_or_address: ReadModifyWrite,
+            cycle_increase: 8,
+            cycle_increases_on_page_cross: false
+        });
+        instructions[0x53] = Some(Instruction{
+            address_mode: AddressingMode::ZeroPageIndirectYIndexed,
+            mnemonic: "SRE",
+            operation: CPU::op_sre,
+            result_handler: CPU::result_into_memory,
+            value_or_address: ReadModifyWrite,
+            cycle_increase: 8,
+            cycle_increases_on_page_cross: false
+        });
+
+        //RRA
+        instructions[0x67] = Some(Instruction{
+            address_mode: AddressingMode::ZeroPage,
+            mnemonic: "RRA",
+            operation: CPU::op_rra,
+            result_handler: CPU::result_into_memory,
+            value_or_address: ReadModifyWrite,
+            cycle_increase: 5,
+            cycle_increases_on_page_cross: false
+        });
+        instructions[0x77] = Some(Instruction{
+            address_mode: AddressingMode::XIndexedZeroPage,
+            mnemonic: "RRA",
+            operation: CPU::op_rra,
+            result_handler: CPU::result_into_memory,
+            value_or_address: ReadModifyWrite,
+            cycle_increase: 6,
+            cycle_increases_on_page_cross: false
+        });
+        instructions[0x6F] = Some(Instruction{
+            address_mode: AddressingMode::Absolute,
+            mnemonic: "RRA",
+            operation: CPU::op_rra,
+            result_handler: CPU::result_into_memory,
+            value_or_address: ReadModifyWrite,
+            cycle_increase: 6,
+            cycle_increases_on_page_cross: false
+        });
+        instructions[0x7F] = Some(Instruction{
+            address_mode: AddressingMode::XIndexedAbsolute,
+            mnemonic: "RRA",
+            operation: CPU::op_rra,
+            result_handler: CPU::result_into_memory,
+            value_or_address: ReadModifyWrite,
+            cycle_increase: 7,
+            cycle_increases_on_page_cross: false
+        });
+        instructions[0x7B] = Some(Instruction{
+            address_mode: AddressingMode::YIndexedAbsolute,
+            mnemonic: "RRA",
+            operation: CPU::op_rra,
+            result_handler: CPU::result_into_memory,
+            value_or_address: ReadModifyWrite,
+            cycle_increase: 7,
+            cycle_increases_on_page_cross: false
+        });
+        instructions[0x63] = Some(Instruction{
+            address_mode: AddressingMode::XIndexedZeroPageIndirect,
+            mnemonic: "RRA",
+            operation: CPU::op_rra,
+            result_handler: CPU::result_into_memory,
+            value_or_address: ReadModifyWrite,
+            cycle_increase: 8,
+            cycle_increases_on_page_cross: false
+        });
+        instructions[0x73] = Some(Instruction{
+            address_mode: AddressingMode::ZeroPageIndirectYIndexed,
+            mnemonic: "RRA",
+            operation: CPU::op_rra,
+            result_handler: CPU::result_into_memory,
+            value_or_address: ReadModifyWrite,
+            cycle_increase: 8,
+            cycle_increases_on_page_cross: false
+        });
+
+        //DCP
+        instructions[0xC7] = Some(Instruction{
+            address_mode: AddressingMode::ZeroPage,
+            mnemonic: "DCP",
+            operation: CPU::op_dcp,
+            result_handler: CPU::result_into_memory,
+            value_or_address: ReadModifyWrite,
+            cycle_increase: 5,
+            cycle_increases_on_page_cross: false
+        });
+        instructions[0xD7] = Some(Instruction{
+            address_mode: AddressingMode::XIndexedZeroPage,
+            mnemonic: "DCP",
+            operation: CPU::op_dcp,
+            result_handler: CPU::result_into_memory,
+            value_or_address: ReadModifyWrite,
+            cycle_increase: 6,
+            cycle_increases_on_page_cross: false
+        });
+        instructions[0xCF] = Some(Instruction{
+            address_mode: AddressingMode::Absolute,
+            mnemonic: "DCP",
+            operation: CPU::op_dcp,
+            result_handler: CPU::result_into_memory,
+            value_or_address: ReadModifyWrite,
+            cycle_increase: 6,
+            cycle_increases_on_page_cross: false
+        });
+        instructions[0xDF] = Some(Instruction{
+            address_mode: AddressingMode::XIndexedAbsolute,
+            mnemonic: "DCP",
+            operation: CPU::op_dcp,
+            result_handler: CPU::result_into_memory,
+            value_or_address: ReadModifyWrite,
+            cycle_increase: 7,
+            cycle_increases_on_page_cross: false
+        });
+        instructions[0xDB] = Some(Instruction{
+            address_mode: AddressingMode::YIndexedAbsolute,
+            mnemonic: "DCP",
+            operation: CPU::op_dcp,
+            result_handler: CPU::result_into_memory,
+            value_or_address: ReadModifyWrite,
+            cycle_increase: 7,
+            cycle_increases_on_page_cross: false
+        });
+        instructions[0xC3] = Some(Instruction{
+            address_mode: AddressingMode::XIndexedZeroPageIndirect,
+            mnemonic: "DCP",
+            operation: CPU::op_dcp,
+            result_handler: CPU::result_into_memory,
+            value_or_address: ReadModifyWrite,
+            cycle_increase: 8,
+            cycle_increases_on_page_cross: false
+        });
+        instructions[0xD3] = Some(Instruction{
+            address_mode: AddressingMode::ZeroPageIndirectYIndexed,
+            mnemonic: "DCP",
+            operation: CPU::op_dcp,
+            result_handler: CPU::result_into_memory,
+            value_or_address: ReadModifyWrite,
+            cycle_increase: 8,
+            cycle_increases_on_page_cross: false
+        });
+
+        //ISC
+        instructions[0xE7] = Some(Instruction{
+            address_mode: AddressingMode::ZeroPage,
+            mnemonic: "ISC",
+            operation: CPU::op_isc,
+            result_handler: CPU::result_into_memory,
+            value_or_address: ReadModifyWrite,
+            cycle_increase: 5,
+            cycle_increases_on_page_cross: false
+        });
+        instructions[0xF7] = Some(Instruction{
+            address_mode: AddressingMode::XIndexedZeroPage,
+            mnemonic: "ISC",
+            operation: CPU::op_isc,
+            result_handler: CPU::result_into_memory,
+            value_or_address: ReadModifyWrite,
+            cycle_increase: 6,
+            cycle_increases_on_page_cross: false
+        });
+        instructions[0xEF] = Some(Instruction{
+            address_mode: AddressingMode::Absolute,
+            mnemonic: "ISC",
+            operation: CPU::op_isc,
+            result_handler: CPU::result_into_memory,
+            value_or_address: ReadModifyWrite,
+            cycle_increase: 6,
+            cycle_increases_on_page_cross: false
+        });
+        instructions[0xFF] = Some(Instruction{
+            address_mode: AddressingMode::XIndexedAbsolute,
+            mnemonic: "ISC",
+            operation: CPU::op_isc,
+            result_handler: CPU::result_into_memory,
+            value_or_address: ReadModifyWrite,
+            cycle_increase: 7,
+            cycle_increases_on_page_cross: false
+        });
+        instructions[0xFB] = Some(Instruction{
+            address_mode: AddressingMode::YIndexedAbsolute,
+            mnemonic: "ISC",
+            operation: CPU::op_isc,
+            result_handler: CPU::result_into_memory,
+            value_or_address: ReadModifyWrite,
+            cycle_increase: 7,
+            cycle_increases_on_page_cross: false
+        });
+        instructions[0xE3] = Some(Instruction{
+            address_mode: AddressingMode::XIndexedZeroPageIndirect,
+            mnemonic: "ISC",
+            operation: CPU::op_isc,
+            result_handler: CPU::result_into_memory,
+            value_or_address: ReadModifyWrite,
+            cycle_increase: 8,
+            cycle_increases_on_page_cross: false
+        });
+        instructions[0xF3] = Some(Instruction{
+            address_mode: AddressingMode::ZeroPageIndirectYIndexed,
+            mnemonic: "ISC",
+            operation: CPU::op_isc,
+            result_handler: CPU::result_into_memory,
+            value_or_address: ReadModifyWrite,
+            cycle_increase: 8,
+            cycle_increases_on_page_cross: false
+        });
+
+        //SAX
+        instructions[0x87] = Some(Instruction{
+            address_mode: AddressingMode::ZeroPage,
+            mnemonic: "SAX",
+            operation: CPU::op_sax,
+            result_handler: CPU::no_handler,
+            value_or_address: Address,
+            cycle_increase: 3,
+            cycle_increases_on_page_cross: false
+        });
+        instructions[0x97] = Some(Instruction{
+            address_mode: AddressingMode::YIndexedZeroPage,
+            mnemonic: "SAX",
+            operation: CPU::op_sax,
+            result_handler: CPU::no_handler,
+            value_or_address: Address,
+            cycle_increase: 4,
+            cycle_increases_on_page_cross: false
+        });
+        instructions[0x8F] = Some(Instruction{
+            address_mode: AddressingMode::Absolute,
+            mnemonic: "SAX",
+            operation: CPU::op_sax,
+            result_handler: CPU::no_handler,
+            value_or_address: Address,
+            cycle_increase: 4,
+            cycle_increases_on_page_cross: false
+        });
+        instructions[0x83] = Some(Instruction{
+            address_mode: AddressingMode::XIndexedZeroPageIndirect,
+            mnemonic: "SAX",
+            operation: CPU::op_sax,
+            result_handler: CPU::no_handler,
+            value_or_address: Address,
+            cycle_increase: 6,
+            cycle_increases_on_page_cross: false
+        });
+
+        //LAX
+        instructions[0xA7] = Some(Instruction{
+            address_mode: AddressingMode::ZeroPage,
+            mnemonic: "LAX",
+            operation: CPU::op_lax,
+            result_handler: CPU::no_handler,
+            value_or_address: Value,
+            cycle_increase: 3,
+            cycle_increases_on_page_cross: false
+        });
+        instructions[0xB7] = Some(Instruction{
+            address_mode: AddressingMode::YIndexedZeroPage,
+            mnemonic: "LAX",
+            operation: CPU::op_lax,
+            result_handler: CPU::no_handler,
+            value_or_address: Value,
+            cycle_increase: 4,
+            cycle_increases_on_page_cross: false
+        });
+        instructions[0xAF] = Some(Instruction{
+            address_mode: AddressingMode::Absolute,
+            mnemonic: "LAX",
+            operation: CPU::op_lax,
+            result_handler: CPU::no_handler,
+            value_or_address: Value,
+            cycle_increase: 4,
+            cycle_increases_on_page_cross: false
+        });
+        instructions[0xBF] = Some(Instruction{
+            address_mode: AddressingMode::YIndexedAbsolute,
+            mnemonic: "LAX",
+            operation: CPU::op_lax,
+            result_handler: CPU::no_handler,
+            value_or_address: Value,
+            cycle_increase: 4,
+            cycle_increases_on_page_cross: true
+        });
+        instructions[0xA3] = Some(Instruction{
+            address_mode: AddressingMode::XIndexedZeroPageIndirect,
+            mnemonic: "LAX",
+            operation: CPU::op_lax,
+            result_handler: CPU::no_handler,
+            value_or_address: Value,
+            cycle_increase: 6,
+            cycle_increases_on_page_cross: false
+        });
+        instructions[0xB3] = Some(Instruction{
+            address_mode: AddressingMode::ZeroPageIndirectYIndexed,
+            mnemonic: "LAX",
+            operation: CPU::op_lax,
+            result_handler: CPU::no_handler,
+            value_or_address: Value,
+            cycle_increase: 5,
+            cycle_increases_on_page_cross: true
+        });
+
+        //LXA
+        instructions[0xAB] = Some(Instruction{
+            address_mode: AddressingMode::Immediate,
+            mnemonic: "LXA",
+            operation: CPU::op_lxa,
+            result_handler: CPU::no_handler,
+            value_or_address: Value,
+            cycle_increase: 2,
+            cycle_increases_on_page_cross: false
+        });
+
+        //ANC
+        instructions[0x0B] = Some(Instruction{
+            address_mode: AddressingMode::Immediate,
+            mnemonic: "ANC",
+            operation: CPU::op_anc,
+            result_handler: CPU::no_handler,
+            value_or_address: Value,
+            cycle_increase: 2,
+            cycle_increases_on_page_cross: false
+        });
+        instructions[0x2B] = Some(Instruction{
+            address_mode: AddressingMode::Immediate,
+            mnemonic: "ANC",
+            operation: CPU::op_anc,
+            result_handler: CPU::no_handler,
+            value_or_address: Value,
+            cycle_increase: 2,
+            cycle_increases_on_page_cross: false
+        });
+
+        //ALR
+        instructions[0x4B] = Some(Instruction{
+            address_mode: AddressingMode::Immediate,
+            mnemonic: "ALR",
+            operation: CPU::op_alr,
+            result_handler: CPU::no_handler,
+            value_or_address: Value,
+            cycle_increase: 2,
+            cycle_increases_on_page_cross: false
+        });
+
+        //ARR
+        instructions[0x6B] = Some(Instruction{
+            address_mode: AddressingMode::Immediate,
+            mnemonic: "ARR",
+            operation: CPU::op_arr,
+            result_handler: CPU::no_handler,
+            value_or_address: Value,
+            cycle_increase: 2,
+            cycle_increases_on_page_cross: false
+        });
+
+        //SBX
+        instructions[0xCB] = Some(Instruction{
+            address_mode: AddressingMode::Immediate,
+            mnemonic: "SBX",
+            operation: CPU::op_sbx,
+            result_handler: CPU::no_handler,
+            value_or_address: Value,
+            cycle_increase: 2,
+            cycle_increases_on_page_cross: false
+        });
+
+        //SBC
+        instructions[0xEB] = Some(Instruction{
+            address_mode: AddressingMode::Immediate,
+            mnemonic: "SBC",
+            operation: CPU::op_sbc,
+            result_handler: CPU::no_handler,
+            value_or_address: Value,
+            cycle_increase: 2,
+            cycle_increases_on_page_cross: false
+        });
+
+        //ANE
+        instructions[0x8B] = Some(Instruction{
+            address_mode: AddressingMode::Immediate,
+            mnemonic: "ANE",
+            operation: CPU::op_ane,
+            result_handler: CPU::no_handler,
+            value_or_address: Value,
+            cycle_increase: 2,
+            cycle_increases_on_page_cross: false
+        });
+
+        //LAS
+        instructions[0xBB] = Some(Instruction{
+            address_mode: AddressingMode::YIndexedAbsolute,
+            mnemonic: "LAS",
+            operation: CPU::op_las,
+            result_handler: CPU::no_handler,
+            value_or_address: Value,
+            cycle_increase: 4,
+            cycle_increases_on_page_cross: true
+        });
+
+        //TAS
+        instructions[0x9B] = Some(Instruction{
+            address_mode: AddressingMode::YIndexedAbsolute,
+            mnemonic: "TAS",
+            operation: CPU::op_tas,
+            result_handler: CPU::no_handler,
+            value_or_address: Address,
+            cycle_increase: 5,
+            cycle_increases_on_page_cross: false
+        });
+
+        //SHY
+        instructions[0x9C] = Some(Instruction{
+            address_mode: AddressingMode::XIndexedAbsolute,
+            mnemonic: "SHY",
+            operation: CPU::op_shy,
+            result_handler: CPU::no_handler,
+            value_or_address: Address,
+            cycle_increase: 5,
+            cycle_increases_on_page_cross: false
+        });
+
+        //SHX
+        instructions[0x9E] = Some(Instruction{
+            address_mode: AddressingMode::YIndexedAbsolute,
+            mnemonic: "SHX",
+            operation: CPU::op_shx,
+            result_handler: CPU::no_handler,
+            value_or_address: Address,
+            cycle_increase: 5,
+            cycle_increases_on_page_cross: false
+        });
+
+        //SHA
+        instructions[0x9F] = Some(Instruction{
+            address_mode: AddressingMode::YIndexedAbsolute,
+            mnemonic: "SHA",
+            operation: CPU::op_sha,
+            result_handler: CPU::no_handler,
+            value_or_address: Address,
+            cycle_increase: 5,
+            cycle_increases_on_page_cross: false
+        });
+        instructions[0x93] = Some(Instruction{
+            address_mode: AddressingMode::ZeroPageIndirectYIndexed,
+            mnemonic: "SHA",
+            operation: CPU::op_sha,
+            result_handler: CPU::no_handler,
+            value_or_address: Address,
+            cycle_increase: 6,
+            cycle_increases_on_page_cross: false
+        });
+
+        //NOP
+        instructions[0x1A] = Some(Instruction{
+            address_mode: AddressingMode::Implied,
+            mnemonic: "NOP",
+            operation: CPU::op_nop,
+            result_handler: CPU::no_handler,
+            value_or_address: Value,
+            cycle_increase: 2,
+            cycle_increases_on_page_cross: false
+        });
+        instructions[0x3A] = Some(Instruction{
+            address_mode: AddressingMode::Implied,
+            mnemonic: "NOP",
+            operation: CPU::op_nop,
+            result_handler: CPU::no_handler,
+            value_or_address: Value,
+            cycle_increase: 2,
+            cycle_increases_on_page_cross: false
+        });
+        instructions[0x5A] = Some(Instruction{
+            address_mode: AddressingMode::Implied,
+            mnemonic: "NOP",
+            operation: CPU::op_nop,
+            result_handler: CPU::no_handler,
+            value_or_address: Value,
+            cycle_increase: 2,
+            cycle_increases_on_page_cross: false
+        });
+        instructions[0x7A] = Some(Instruction{
+            address_mode: AddressingMode::Implied,
+            mnemonic: "NOP",
+            operation: CPU::op_nop,
+            result_handler: CPU::no_handler,
+            value_or_address: Value,
+            cycle_increase: 2,
+            cycle_increases_on_page_cross: false
+        });
+        instructions[0xDA] = Some(Instruction{
+            address_mode: AddressingMode::Implied,
+            mnemonic: "NOP",
+            operation: CPU::op_nop,
+            result_handler: CPU::no_handler,
+            value_or_address: Value,
+            cycle_increase: 2,
+            cycle_increases_on_page_cross: false
+        });
+        instructions[0xFA] = Some(Instruction{
+            address_mode: AddressingMode::Implied,
+            mnemonic: "NOP",
+            operation: CPU::op_nop,
+            result_handler: CPU::no_handler,
+            value_or_address: Value,
+            cycle_increase: 2,
+            cycle_increases_on_page_cross: false
+        });
+        instructions[0x80] = Some(Instruction{
+            address_mode: AddressingMode::Immediate,
+            mnemonic: "NOP",
+            operation: CPU::op_nop,
+            result_handler: CPU::no_handler,
+            value_or_address: Value,
+            cycle_increase: 2,
+            cycle_increases_on_page_cross: false
+        });
+        instructions[0x82] = Some(Instruction{
+            address_mode: AddressingMode::Immediate,
+            mnemonic: "NOP",
+            operation: CPU::op_nop,
+            result_handler: CPU::no_handler,
+            value_or_address: Value,
+            cycle_increase: 2,
+            cycle_increases_on_page_cross: false
+        });
+        instructions[0x89] = Some(Instruction{
+            address_mode: AddressingMode::Immediate,
+            mnemonic: "NOP",
+            operation: CPU::op_nop,
+            result_handler: CPU::no_handler,
+            value_or_address: Value,
+            cycle_increase: 2,
+            cycle_increases_on_page_cross: false
+        });
+        instructions[0xC2] = Some(Instruction{
+            address_mode: AddressingMode::Immediate,
+            mnemonic: "NOP",
+            operation: CPU::op_nop,
+            result_handler: CPU::no_handler,
+            value_or_address: Value,
+            cycle_increase: 2,
+            cycle_increases_on_page_cross: false
+        });
+        instructions[0xE2] = Some(Instruction{
+            address_mode: AddressingMode::Immediate,
+            mnemonic: "NOP",
+            operation: CPU::op_nop,
+            result_handler: CPU::no_handler,
+            value_or_address: Value,
+            cycle_increase: 2,
+            cycle_increases_on_page_cross: false
+        });
+        instructions[0x04] = Some(Instruction{
+            address_mode: AddressingMode::ZeroPage,
+            mnemonic: "NOP",
+            operation: CPU::op_nop,
+            result_handler: CPU::no_handler,
+            value_or_address: Value,
+            cycle_increase: 3,
+            cycle_increases_on_page_cross: false
+        });
+        instructions[0x44] = Some(Instruction{
+            address_mode: AddressingMode::ZeroPage,
+            mnemonic: "NOP",
+            operation: CPU::op_nop,
+            result_handler: CPU::no_handler,
+            value_or_address: Value,
+            cycle_increase: 3,
+            cycle_increases_on_page_cross: false
+        });
+        instructions[0x64] = Some(Instruction{
+            address_mode: AddressingMode::ZeroPage,
+            mnemonic: "NOP",
+            operation: CPU::op_nop,
+            result_handler: CPU::no_handler,
+            value_or_address: Value,
+            cycle_increase: 3,
+            cycle_increases_on_page_cross: false
+        });
+        instructions[0x14] = Some(Instruction{
+            address_mode: AddressingMode::XIndexedZeroPage,
+            mnemonic: "NOP",
+            operation: CPU::op_nop,
+            result_handler: CPU::no_handler,
+            value_or_address: Value,
+            cycle_increase: 4,
+            cycle_increases_on_page_cross: false
+        });
+        instructions[0x34] = Some(Instruction{
+            address_mode: AddressingMode::XIndexedZeroPage,
+            mnemonic: "NOP",
+            operation: CPU::op_nop,
+            result_handler: CPU::no_handler,
+            value_or_address: Value,
+            cycle_increase: 4,
+            cycle_increases_on_page_cross: false
+        });
+        instructions[0x54] = Some(Instruction{
+            address_mode: AddressingMode::XIndexedZeroPage,
+            mnemonic: "NOP",
+            operation: CPU::op_nop,
+            result_handler: CPU::no_handler,
+            value_or_address: Value,
+            cycle_increase: 4,
+            cycle_increases_on_page_cross: false
+        });
+        instructions[0x74] = Some(Instruction{
+            address_mode: AddressingMode::XIndexedZeroPage,
+            mnemonic: "NOP",
+            operation: CPU::op_nop,
+            result_handler: CPU::no_handler,
+            value_or_address: Value,
+            cycle_increase: 4,
+            cycle_increases_on_page_cross: false
+        });
+        instructions[0xD4] = Some(Instruction{
+            address_mode: AddressingMode::XIndexedZeroPage,
+            mnemonic: "NOP",
+            operation: CPU::op_nop,
+            result_handler: CPU::no_handler,
+            value_or_address: Value,
+            cycle_increase: 4,
+            cycle_increases_on_page_cross: false
+        });
+        instructions[0xF4] = Some(Instruction{
+            address_mode: AddressingMode::XIndexedZeroPage,
+            mnemonic: "NOP",
+            operation: CPU::op_nop,
+            result_handler: CPU::no_handler,
+            value_or_address: Value,
+            cycle_increase: 4,
+            cycle_increases_on_page_cross: false
+        });
+        instructions[0x0C] = Some(Instruction{
+            address_mode: AddressingMode::Absolute,
+            mnemonic: "NOP",
+            operation: CPU::op_nop,
+            result_handler: CPU::no_handler,
+            value_or_address: Value,
+            cycle_increase: 4,
+            cycle_increases_on_page_cross: false
+        });
+        instructions[0x1C] = Some(Instruction{
+            address_mode: AddressingMode::XIndexedAbsolute,
+            mnemonic: "NOP",
+            operation: CPU::op_nop,
+            result_handler: CPU::no_handler,
+            value_or_address: Value,
+            cycle_increase: 4,
+            cycle_increases_on_page_cross: true
+        });
+        instructions[0x3C] = Some(Instruction{
+            address_mode: AddressingMode::XIndexedAbsolute,
+            mnemonic: "NOP",
+            operation: CPU::op_nop,
+            result_handler: CPU::no_handler,
+            value_or_address: Value,
+            cycle_increase: 4,
+            cycle_increases_on_page_cross: true
+        });
+        instructions[0x5C] = Some(Instruction{
+            address_mode: AddressingMode::XIndexedAbsolute,
+            mnemonic: "NOP",
+            operation: CPU::op_nop,
+            result_handler: CPU::no_handler,
+            value_or_address: Value,
+            cycle_increase: 4,
+            cycle_increases_on_page_cross: true
+        });
+        instructions[0x7C] = Some(Instruction{
+            address_mode: AddressingMode::XIndexedAbsolute,
+            mnemonic: "NOP",
+            operation: CPU::op_nop,
+            result_handler: CPU::no_handler,
+            value_or_address: Value,
+            cycle_increase: 4,
+            cycle_increases_on_page_cross: true
+        });
+        instructions[0xDC] = Some(Instruction{
+            address_mode: AddressingMode::XIndexedAbsolute,
+            mnemonic: "NOP",
+            operation: CPU::op_nop,
+            result_handler: CPU::no_handler,
+            value_or_address: Value,
+            cycle_increase: 4,
+            cycle_increases_on_page_cross: true
+        });
+        instructions[0xFC] = Some(Instruction{
+            address_mode: AddressingMode::XIndexedAbsolute,
+            mnemonic: "NOP",
+            operation: CPU::op_nop,
+            result_handler: CPU::no_handler,
+            value_or_address: Value,
+            cycle_increase: 4,
+            cycle_increases_on_page_cross: true
+        });
+
+        //JAM
+        instructions[0x02] = Some(Instruction{
+            address_mode: AddressingMode::Implied,
+            mnemonic: "JAM",
+            operation: CPU::op_jam,
+            result_handler: CPU::no_handler,
+            value_or_address: Value,
+            cycle_increase: 2,
+            cycle_increases_on_page_cross: false
+        });
+        instructions[0x12] = Some(Instruction{
+            address_mode: AddressingMode::Implied,
+            mnemonic: "JAM",
+            operation: CPU::op_jam,
+            result_handler: CPU::no_handler,
+            value_or_address: Value,
+            cycle_increase: 2,
+            cycle_increases_on_page_cross: false
+        });
+        instructions[0x22] = Some(Instruction{
+            address_mode: AddressingMode::Implied,
+            mnemonic: "JAM",
+            operation: CPU::op_jam,
+            result_handler: CPU::no_handler,
+            value_or_address: Value,
+            cycle_increase: 2,
+            cycle_increases_on_page_cross: false
+        });
+        instructions[0x32] = Some(Instruction{
+            address_mode: AddressingMode::Implied,
+            mnemonic: "JAM",
+            operation: CPU::op_jam,
+            result_handler: CPU::no_handler,
+            value_or_address: Value,
+            cycle_increase: 2,
+            cycle_increases_on_page_cross: false
+        });
+        instructions[0x42] = Some(Instruction{
+            address_mode: AddressingMode::Implied,
+            mnemonic: "JAM",
+            operation: CPU::op_jam,
+            result_handler: CPU::no_handler,
+            value_or_address: Value,
+            cycle_increase: 2,
+            cycle_increases_on_page_cross: false
+        });
+        instructions[0x52] = Some(Instruction{
+            address_mode: AddressingMode::Implied,
+            mnemonic: "JAM",
+            operation: CPU::op_jam,
+            result_handler: CPU::no_handler,
+            value_or_address: Value,
+            cycle_increase: 2,
+            cycle_increases_on_page_cross: false
+        });
+        instructions[0x62] = Some(Instruction{
+            address_mode: AddressingMode::Implied,
+            mnemonic: "JAM",
+            operation: CPU::op_jam,
+            result_handler: CPU::no_handler,
+            value_or_address: Value,
+            cycle_increase: 2,
+            cycle_increases_on_page_cross: false
+        });
+        instructions[0x72] = Some(Instruction{
+            address_mode: AddressingMode::Implied,
+            mnemonic: "JAM",
+            operation: CPU::op_jam,
+            result_handler: CPU::no_handler,
+            value_or_address: Value,
+            cycle_increase: 2,
+            cycle_increases_on_page_cross: false
+        });
+        instructions[0x92] = Some(Instruction{
+            address_mode: AddressingMode::Implied,
+            mnemonic: "JAM",
+            operation: CPU::op_jam,
+            result_handler: CPU::no_handler,
+            value_or_address: Value,
+            cycle_increase: 2,
+            cycle_increases_on_page_cross: false
+        });
+        instructions[0xB2] = Some(Instruction{
+            address_mode: AddressingMode::Implied,
+            mnemonic: "JAM",
+            operation: CPU::op_jam,
+            result_handler: CPU::no_handler,
+            value_or_address: Value,
+            cycle_increase: 2,
+            cycle_increases_on_page_cross: false
+        });
+        instructions[0xD2] = Some(Instruction{
+            address_mode: AddressingMode::Implied,
+            mnemonic: "JAM",
+            operation: CPU::op_jam,
+            result_handler: CPU::no_handler,
+            value_or_address: Value,
+            cycle_increase: 2,
+            cycle_increases_on_page_cross: false
+        });
+        instructions[0xF2] = Some(Instruction{
+            address_mode: AddressingMode::Implied,
+            mnemonic: "JAM",
+            operation: CPU::op_jam,
+            result_handler: CPU::no_handler,
+            value_or_address: Value,
+            cycle_increase: 2,
+            cycle_increases_on_page_cross: false
+        });
+
+        instructions
+    }
+
+    //The WDC 65C02 fills every opcode the NMOS chip leaves undocumented and fixes a few timings.
+    const fn build_cmos_instruction_set() -> InstructionSet<B> {
+        let mut instructions = Self::build_documented_instruction_set();
+
+        //WDC 65C02 instructions
+
+        //Fixed indirect JMP
+        instructions[0x6C] = Some(Instruction{
+            address_mode: AddressingMode::AbsoluteIndirect,
+            mnemonic: "JMP",
+            operation: CPU::op_jmp,
+            result_handler: CPU::no_handler,
+            value_or_address: Address,
+            cycle_increase: 6,
+            cycle_increases_on_page_cross: false
+        });
+
+        //Shifts and rotates only take the extra cycle when indexing crosses a page
+        instructions[0x1E] = Some(Instruction{
+            address_mode: AddressingMode::XIndexedAbsolute,
+            mnemonic: "ASL",
+            operation: CPU::op_asl,
+            result_handler: CPU::result_into_memory,
+            value_or_address: ReadModifyWrite,
+            cycle_increase: 6,
+            cycle_increases_on_page_cross: true
+        });
+        instructions[0x3E] = Some(Instruction{
+            address_mode: AddressingMode::XIndexedAbsolute,
+            mnemonic: "ROL",
+            operation: CPU::op_rol,
+            result_handler: CPU::result_into_memory,
+            value_or_address: ReadModifyWrite,
+            cycle_increase: 6,
+            cycle_increases_on_page_cross: true
+        });
+        instructions[0x5E] = Some(Instruction{
+            address_mode: AddressingMode::XIndexedAbsolute,
+            mnemonic: "LSR",
+            operation: CPU::op_lsr,
+            result_handler: CPU::result_into_memory,
+            value_or_address: ReadModifyWrite,
+            cycle_increase: 6,
+            cycle_increases_on_page_cross: true
+        });
+        instructions[0x7E] = Some(Instruction{
+            address_mode: AddressingMode::XIndexedAbsolute,
+            mnemonic: "ROR",
+            operation: CPU::op_ror,
+            result_handler: CPU::result_into_memory,
+            value_or_address: ReadModifyWrite,
+            cycle_increase: 6,
+            cycle_increases_on_page_cross: true
+        });
+
+        //(zp) addressing
+        instructions[0x12] = Some(Instruction{
+            address_mode: AddressingMode::ZeroPageIndirect,
+            mnemonic: "ORA",
+            operation: CPU::op_ora,
+            result_handler: CPU::no_handler,
+            value_or_address: Value,
+            cycle_increase: 5,
+            cycle_increases_on_page_cross: false
+        });
+        instructions[0x32] = Some(Instruction{
+            address_mode: AddressingMode::ZeroPageIndirect,
+            mnemonic: "AND",
+            operation: CPU::op_and,
+            result_handler: CPU::result_into_acc,
+            value_or_address: Value,
+            cycle_increase: 5,
+            cycle_increases_on_page_cross: false
+        });
+        instructions[0x52] = Some(Instruction{
+            address_mode: AddressingMode::ZeroPageIndirect,
+            mnemonic: "EOR",
+            operation: CPU::op_eor,
+            result_handler: CPU::result_into_acc,
+            value_or_address: Value,
+            cycle_increase: 5,
+            cycle_increases_on_page_cross: false
+        });
+        instructions[0x72] = Some(Instruction{
+            address_mode: AddressingMode::ZeroPageIndirect,
+            mnemonic: "ADC",
+            operation: CPU::op_adc,
+            result_handler: CPU::result_into_acc,
+            value_or_address: Value,
+            cycle_increase: 5,
+            cycle_increases_on_page_cross: false
+        });
+        instructions[0x92] = Some(Instruction{
+            address_mode: AddressingMode::ZeroPageIndirect,
+            mnemonic: "STA",
+            operation: CPU::op_sta,
+            result_handler: CPU::no_handler,
+            value_or_address: Address,
+            cycle_increase: 5,
+            cycle_increases_on_page_cross: false
+        });
+        instructions[0xB2] = Some(Instruction{
+            address_mode: AddressingMode::ZeroPageIndirect,
+            mnemonic: "LDA",
+            operation: CPU::op_lda,
+            result_handler: CPU::no_handler,
+            value_or_address: Value,
+            cycle_increase: 5,
+            cycle_increases_on_page_cross: false
+        });
+        instructions[0xD2] = Some(Instruction{
+            address_mode: AddressingMode::ZeroPageIndirect,
+            mnemonic: "CMP",
+            operation: CPU::op_cmp,
+            result_handler: CPU::no_handler,
+            value_or_address: Value,
+            cycle_increase: 5,
+            cycle_increases_on_page_cross: false
+        });
+        instructions[0xF2] = Some(Instruction{
+            address_mode: AddressingMode::ZeroPageIndirect,
+            mnemonic: "SBC",
+            operation: CPU::op_sbc,
+            result_handler: CPU::no_handler,
+            value_or_address: Value,
+            cycle_increase: 5,
+            cycle_increases_on_page_cross: false
+        });
+
+        //BIT
+        instructions[0x89] = Some(Instruction{
+            address_mode: AddressingMode::Immediate,
+            mnemonic: "BIT",
+            operation: CPU::op_bit_immediate,
+            result_handler: CPU::no_handler,
+            value_or_address: Value,
+            cycle_increase: 2,
+            cycle_increases_on_page_cross: false
+        });
+        instructions[0x34] = Some(Instruction{
+            address_mode: AddressingMode::XIndexedZeroPage,
+            mnemonic: "BIT",
+            operation: CPU::op_bit,
+            result_handler: CPU::no_handler,
+            value_or_address: Value,
+            cycle_increase: 4,
+            cycle_increases_on_page_cross: false
+        });
+        instructions[0x3C] = Some(Instruction{
+            address_mode: AddressingMode::XIndexedAbsolute,
+            mnemonic: "BIT",
+            operation: CPU::op_bit,
+            result_handler: CPU::no_handler,
+            value_or_address: Value,
+            cycle_increase: 4,
+            cycle_increases_on_page_cross: true
+        });
+
+        //BRA
+        instructions[0x80] = Some(Instruction{
+            address_mode: AddressingMode::Relative,
+            mnemonic: "BRA",
+            operation: CPU::op_bra,
+            result_handler: CPU::no_handler,
+            value_or_address: Value,
+            cycle_increase: 3,
+            cycle_increases_on_page_cross: false
+        });
+
+        //DEC
+        instructions[0x3A] = Some(Instruction{
+            address_mode: AddressingMode::Accumulator,
+            mnemonic: "DEC",
+            operation: CPU::op_dec,
+            result_handler: CPU::result_into_acc,
+            value_or_address: Value,
+            cycle_increase: 2,
+            cycle_increases_on_page_cross: false
+        });
+
+        //INC
+        instructions[0x1A] = Some(Instruction{
+            address_mode: AddressingMode::Accumulator,
+            mnemonic: "INC",
+            operation: CPU::op_inc,
+            result_handler: CPU::result_into_acc,
+            value_or_address: Value,
+            cycle_increase: 2,
+            cycle_increases_on_page_cross: false
+        });
+
+        //JMP
+        instructions[0x7C] = Some(Instruction{
+            address_mode: AddressingMode::XIndexedAbsoluteIndirect,
+            mnemonic: "JMP",
+            operation: CPU::op_jmp,
+            result_handler: CPU::no_handler,
+            value_or_address: Address,
+            cycle_increase: 6,
+            cycle_increases_on_page_cross: false
+        });
+
+        //PHX
+        instructions[0xDA] = Some(Instruction{
+            address_mode: AddressingMode::Implied,
+            mnemonic: "PHX",
+            operation: CPU::op_phx,
+            result_handler: CPU::no_handler,
+            value_or_address: Value,
+            cycle_increase: 3,
+            cycle_increases_on_page_cross: false
+        });
+
+        //PHY
+        instructions[0x5A] = Some(Instruction{
+            address_mode: AddressingMode::Implied,
+            mnemonic: "PHY",
+            operation: CPU::op_phy,
+            result_handler: CPU::no_handler,
+            value_or_address: Value,
+            cycle_increase: 3,
+            cycle_increases_on_page_cross: false
+        });
+
+        //PLX
+        instructions[0xFA] = Some(Instruction{
+            address_mode: AddressingMode::Implied,
+            mnemonic: "PLX",
+            operation: CPU::op_plx,
+            result_handler: CPU::no_handler,
+            value_or_address: Value,
+            cycle_increase: 4,
+            cycle_increases_on_page_cross: false
+        });
+
+        //PLY
+        instructions[0x7A] = Some(Instruction{
+            address_mode: AddressingMode::Implied,
+            mnemonic: "PLY",
+            operation: CPU::op_ply,
+            result_handler: CPU::no_handler,
+            value_or_address: Value,
+            cycle_increase: 4,
+            cycle_increases_on_page_cross: false
+        });
+
+        //STZ
+        instructions[0x64] = Some(Instruction{
+            address_mode: AddressingMode::ZeroPage,
+            mnemonic: "STZ",
+            operation: CPU::op_stz,
+            result_handler: CPU::no_handler,
+            value_or_address: Address,
+            cycle_increase: 3,
+            cycle_increases_on_page_cross: false
+        });
+        instructions[0x74] = Some(Instruction{
+            address_mode: AddressingMode::XIndexedZeroPage,
+            mnemonic: "STZ",
+            operation: CPU::op_stz,
+            result_handler: CPU::no_handler,
+            value_or_address: Address,
+            cycle_increase: 4,
+            cycle_increases_on_page_cross: false
+        });
+        instructions[0x9C] = Some(Instruction{
+            address_mode: AddressingMode::Absolute,
+            mnemonic: "STZ",
+            operation: CPU::op_stz,
+            result_handler: CPU::no_handler,
+            value_or_address: Address,
+            cycle_increase: 4,
+            cycle_increases_on_page_cross: false
+        });
+        instructions[0x9E] = Some(Instruction{
+            address_mode: AddressingMode::XIndexedAbsolute,
+            mnemonic: "STZ",
+            operation: CPU::op_stz,
+            result_handler: CPU::no_handler,
+            value_or_address: Address,
+            cycle_increase: 5,
+            cycle_increases_on_page_cross: false
+        });
+
+        //TRB
+        instructions[0x14] = Some(Instruction{
+            address_mode: AddressingMode::ZeroPage,
+            mnemonic: "TRB",
+            operation: CPU::op_trb,
+            result_handler: CPU::result_into_memory,
+            value_or_address: ReadModifyWrite,
+            cycle_increase: 5,
+            cycle_increases_on_page_cross: false
+        });
+        instructions[0x1C] = Some(Instruction{
+            address_mode: AddressingMode::Absolute,
+            mnemonic: "TRB",
+            operation: CPU::op_trb,
+            result_handler: CPU::result_into_memory,
+            value_or_address: ReadModifyWrite,
+            cycle_increase: 6,
+            cycle_increases_on_page_cross: false
+        });
+
+        //TSB
+        instructions[0x04] = Some(Instruction{
+            address_mode: AddressingMode::ZeroPage,
+            mnemonic: "TSB",
+            operation: CPU::op_tsb,
+            result_handler: CPU::result_into_memory,
+            value_or_address: ReadModifyWrite,
+            cycle_increase: 5,
+            cycle_increases_on_page_cross: false
+        });
+        instructions[0x0C] = Some(Instruction{
+            address_mode: AddressingMode::Absolute,
+            mnemonic: "TSB",
+            operation: CPU::op_tsb,
+            result_handler: CPU::result_into_memory,
+            value_or_address: ReadModifyWrite,
+            cycle_increase: 6,
+            cycle_increases_on_page_cross: false
+        });
+
+        //RMB
+        instructions[0x07] = Some(Instruction{
+            address_mode: AddressingMode::ZeroPage,
+            mnemonic: "RMB0",
+            operation: CPU::op_rmb::<0>,
+            result_handler: CPU::result_into_memory,
+            value_or_address: ReadModifyWrite,
+            cycle_increase: 5,
+            cycle_increases_on_page_cross: false
+        });
+        instructions[0x17] = Some(Instruction{
+            address_mode: AddressingMode::ZeroPage,
+            mnemonic: "RMB1",
+            operation: CPU::op_rmb::<1>,
+            result_handler: CPU::result_into_memory,
+            value_or_address: ReadModifyWrite,
+            cycle_increase: 5,
+            cycle_increases_on_page_cross: false
+        });
+        instructions[0x27] = Some(Instruction{
+            address_mode: AddressingMode::ZeroPage,
+            mnemonic: "RMB2",
+            operation: CPU::op_rmb::<2>,
+            result_handler: CPU::result_into_memory,
+            value_or_address: ReadModifyWrite,
+            cycle_increase: 5,
+            cycle_increases_on_page_cross: false
+        });
+        instructions[0x37] = Some(Instruction{
+            address_mode: AddressingMode::ZeroPage,
+            mnemonic: "RMB3",
+            operation: CPU::op_rmb::<3>,
+            result_handler: CPU::result_into_memory,
+            value_or_address: ReadModifyWrite,
+            cycle_increase: 5,
+            cycle_increases_on_page_cross: false
+        });
+        instructions[0x47] = Some(Instruction{
+            address_mode: AddressingMode::ZeroPage,
+            mnemonic: "RMB4",
+            operation: CPU::op_rmb::<4>,
+            result_handler: CPU::result_into_memory,
+            value_or_address: ReadModifyWrite,
+            cycle_increase: 5,
+            cycle_increases_on_page_cross: false
+        });
+        instructions[0x57] = Some(Instruction{
+            address_mode: AddressingMode::ZeroPage,
+            mnemonic: "RMB5",
+            operation: CPU::op_rmb::<5>,
+            result_handler: CPU::result_into_memory,
+            value_or_address: ReadModifyWrite,
+            cycle_increase: 5,
+            cycle_increases_on_page_cross: false
+        });
+        instructions[0x67] = Some(Instruction{
+            address_mode: AddressingMode::ZeroPage,
+            mnemonic: "RMB6",
+            operation: CPU::op_rmb::<6>,
+            result_handler: CPU::result_into_memory,
+            value_or_address: ReadModifyWrite,
+            cycle_increase: 5,
+            cycle_increases_on_page_cross: false
+        });
+        instructions[0x77] = Some(Instruction{
+            address_mode: AddressingMode::ZeroPage,
+            mnemonic: "RMB7",
+            operation: CPU::op_rmb::<7>,
+            result_handler: CPU::result_into_memory,
+            value_or_address: ReadModifyWrite,
+            cycle_increase: 5,
+            cycle_increases_on_page_cross: false
+        });
+
+        //SMB
+        instructions[0x87] = Some(Instruction{
+            address_mode: AddressingMode::ZeroPage,
+            mnemonic: "SMB0",
+            operation: CPU::op_smb::<0>,
+            result_handler: CPU::result_into_memory,
+            value_or_address: ReadModifyWrite,
+            cycle_increase: 5,
+            cycle_increases_on_page_cross: false
+        });
+        instructions[0x97] = Some(Instruction{
+            address_mode: AddressingMode::ZeroPage,
+            mnemonic: "SMB1",
+            operation: CPU::op_smb::<1>,
+            result_handler: CPU::result_into_memory,
+            value_or_address: ReadModifyWrite,
+            cycle_increase: 5,
+            cycle_increases_on_page_cross: false
+        });
+        instructions[0xA7] = Some(Instruction{
+            address_mode: AddressingMode::ZeroPage,
+            mnemonic: "SMB2",
+            operation: CPU::op_smb::<2>,
+            result_handler: CPU::result_into_memory,
+            value_or_address: ReadModifyWrite,
+            cycle_increase: 5,
+            cycle_increases_on_page_cross: false
+        });
+        instructions[0xB7] = Some(Instruction{
+            address_mode: AddressingMode::ZeroPage,
+            mnemonic: "SMB3",
+            operation: CPU::op_smb::<3>,
+            result_handler: CPU::result_into_memory,
+            value_or_address: ReadModifyWrite,
+            cycle_increase: 5,
+            cycle_increases_on_page_cross: false
+        });
+        instructions[0xC7] = Some(Instruction{
+            address_mode: AddressingMode::ZeroPage,
+            mnemonic: "SMB4",
+            operation: CPU::op_smb::<4>,
+            result_handler: CPU::result_into_memory,
+            value_or_address: ReadModifyWrite,
+            cycle_increase: 5,
+            cycle_increases_on_page_cross: false
+        });
+        instructions[0xD7] = Some(Instruction{
+            address_mode: AddressingMode::ZeroPage,
+            mnemonic: "SMB5",
+            operation: CPU::op_smb::<5>,
+            result_handler: CPU::result_into_memory,
+            value_or_address: ReadModifyWrite,
+            cycle_increase: 5,
+            cycle_increases_on_page_cross: false
+        });
+        instructions[0xE7] = Some(Instruction{
+            address_mode: AddressingMode::ZeroPage,
+            mnemonic: "SMB6",
+            operation: CPU::op_smb::<6>,
+            result_handler: CPU::result_into_memory,
+            value_or_address: ReadModifyWrite,
+            cycle_increase: 5,
+            cycle_increases_on_page_cross: false
+        });
+        instructions[0xF7] = Some(Instruction{
+            address_mode: AddressingMode::ZeroPage,
+            mnemonic: "SMB7",
+            operation: CPU::op_smb::<7>,
+            result_handler: CPU::result_into_memory,
+            value_or_address: ReadModifyWrite,
+            cycle_increase: 5,
+            cycle_increases_on_page_cross: false
+        });
+
+        //BBR
+        instructions[0x0F] = Some(Instruction{
+            address_mode: AddressingMode::ZeroPageRelative,
+            mnemonic: "BBR0",
+            operation: CPU::op_bbr::<0>,
+            result_handler: CPU::no_handler,
+            value_or_address: Value,
+            cycle_increase: 5,
+            cycle_increases_on_page_cross: false
+        });
+        instructions[0x1F] = Some(Instruction{
+            address_mode: AddressingMode::ZeroPageRelative,
+            mnemonic: "BBR1",
+            operation: CPU::op_bbr::<1>,
+            result_handler: CPU::no_handler,
+            value_or_address: Value,
+            cycle_increase: 5,
+            cycle_increases_on_page_cross: false
+        });
+        instructions[0x2F] = Some(Instruction{
+            address_mode: AddressingMode::ZeroPageRelative,
+            mnemonic: "BBR2",
+            operation: CPU::op_bbr::<2>,
+            result_handler: CPU::no_handler,
+            value_or_address: Value,
+            cycle_increase: 5,
+            cycle_increases_on_page_cross: false
+        });
+        instructions[0x3F] = Some(Instruction{
+            address_mode: AddressingMode::ZeroPageRelative,
+            mnemonic: "BBR3",
+            operation: CPU::op_bbr::<3>,
+            result_handler: CPU::no_handler,
+            value_or_address: Value,
+            cycle_increase: 5,
+            cycle_increases_on_page_cross: false
+        });
+        instructions[0x4F] = Some(Instruction{
+            address_mode: AddressingMode::ZeroPageRelative,
+            mnemonic: "BBR4",
+            operation: CPU::op_bbr::<4>,
+            result_handler: CPU::no_handler,
+            value_or_address: Value,
+            cycle_increase: 5,
+            cycle_increases_on_page_cross: false
+        });
+        instructions[0x5F] = Some(Instruction{
+            address_mode: AddressingMode::ZeroPageRelative,
+            mnemonic: "BBR5",
+            operation: CPU::op_bbr::<5>,
+            result_handler: CPU::no_handler,
+            value_or_address: Value,
+            cycle_increase: 5,
+            cycle_increases_on_page_cross: false
+        });
+        instructions[0x6F] = Some(Instruction{
+            address_mode: AddressingMode::ZeroPageRelative,
+            mnemonic: "BBR6",
+            operation: CPU::op_bbr::<6>,
+            result_handler: CPU::no_handler,
+            value_or_address: Value,
+            cycle_increase: 5,
+            cycle_increases_on_page_cross: false
+        });
+        instructions[0x7F] = Some(Instruction{
+            address_mode: AddressingMode::ZeroPageRelative,
+            mnemonic: "BBR7",
+            operation: CPU::op_bbr::<7>,
+            result_handler: CPU::no_handler,
+            value_or_address: Value,
+            cycle_increase: 5,
+            cycle_increases_on_page_cross: false
+        });
+
+        //BBS
+        instructions[0x8F] = Some(Instruction{
+            address_mode: AddressingMode::ZeroPageRelative,
+            mnemonic: "BBS0",
+            operation: CPU::op_bbs::<0>,
+            result_handler: CPU::no_handler,
+            value_or_address: Value,
+            cycle_increase: 5,
+            cycle_increases_on_page_cross: false
+        });
+        instructions[0x9F] = Some(Instruction{
+            address_mode: AddressingMode::ZeroPageRelative,
+            mnemonic: "BBS1",
+            operation: CPU::op_bbs::<1>,
+            result_handler: CPU::no_handler,
+            value_or_address: Value,
+            cycle_increase: 5,
+            cycle_increases_on_page_cross: false
+        });
+        instructions[0xAF] = Some(Instruction{
+            address_mode: AddressingMode::ZeroPageRelative,
+            mnemonic: "BBS2",
+            operation: CPU::op_bbs::<2>,
+            result_handler: CPU::no_handler,
+            value_or_address: Value,
+            cycle_increase: 5,
+            cycle_increases_on_page_cross: false
+        });
+        instructions[0xBF] = Some(Instruction{
+            address_mode: AddressingMode::ZeroPageRelative,
+            mnemonic: "BBS3",
+            operation: CPU::op_bbs::<3>,
+            result_handler: CPU::no_handler,
+            value_or_address: Value,
+            cycle_increase: 5,
+            cycle_increases_on_page_cross: false
+        });
+        instructions[0xCF] = Some(Instruction{
+            address_mode: AddressingMode::ZeroPageRelative,
+            mnemonic: "BBS4",
+            operation: CPU::op_bbs::<4>,
+            result_handler: CPU::no_handler,
+            value_or_address: Value,
+            cycle_increase: 5,
+            cycle_increases_on_page_cross: false
+        });
+        instructions[0xDF] = Some(Instruction{
+            address_mode: AddressingMode::ZeroPageRelative,
+            mnemonic: "BBS5",
+            operation: CPU::op_bbs::<5>,
+            result_handler: CPU::no_handler,
+            value_or_address: Value,
+            cycle_increase: 5,
+            cycle_increases_on_page_cross: false
+        });
+        instructions[0xEF] = Some(Instruction{
+            address_mode: AddressingMode::ZeroPageRelative,
+            mnemonic: "BBS6",
+            operation: CPU::op_bbs::<6>,
+            result_handler: CPU::no_handler,
+            value_or_address: Value,
+            cycle_increase: 5,
+            cycle_increases_on_page_cross: false
+        });
+        instructions[0xFF] = Some(Instruction{
+            address_mode: AddressingMode::ZeroPageRelative,
+            mnemonic: "BBS7",
+            operation: CPU::op_bbs::<7>,
+            result_handler: CPU::no_handler,
+            value_or_address: Value,
+            cycle_increase: 5,
+            cycle_increases_on_page_cross: false
+        });
+
+        //WAI
+        instructions[0xCB] = Some(Instruction{
+            address_mode: AddressingMode::Implied,
+            mnemonic: "WAI",
+            operation: CPU::op_wai,
+            result_handler: CPU::no_handler,
+            value_or_address: Value,
+            cycle_increase: 3,
+            cycle_increases_on_page_cross: false
+        });
+
+        //STP
+        instructions[0xDB] = Some(Instruction{
+            address_mode: AddressingMode::Implied,
+            mnemonic: "STP",
+            operation: CPU::op_stp,
+            result_handler: CPU::no_handler,
+            value_or_address: Value,
+            cycle_increase: 3,
+            cycle_increases_on_page_cross: false
+        });
+
+        //The remaining opcodes are NOPs of different lengths
+        instructions[0x02] = Some(Instruction{
+            address_mode: AddressingMode::Immediate,
+            mnemonic: "NOP",
+            operation: CPU::op_nop,
+            result_handler: CPU::no_handler,
+            value_or_address: Value,
+            cycle_increase: 2,
+            cycle_increases_on_page_cross: false
+        });
+        instructions[0x22] = Some(Instruction{
+            address_mode: AddressingMode::Immediate,
+            mnemonic: "NOP",
+            operation: CPU::op_nop,
+            result_handler: CPU::no_handler,
+            value_or_address: Value,
+            cycle_increase: 2,
+            cycle_increases_on_page_cross: false
+        });
+        instructions[0x42] = Some(Instruction{
+            address_mode: AddressingMode::Immediate,
+            mnemonic: "NOP",
+            operation: CPU::op_nop,
+            result_handler: CPU::no_handler,
+            value_or_address: Value,
+            cycle_increase: 2,
+            cycle_increases_on_page_cross: false
+        });
+        instructions[0x62] = Some(Instruction{
+            address_mode: AddressingMode::Immediate,
+            mnemonic: "NOP",
+            operation: CPU::op_nop,
+            result_handler: CPU::no_handler,
+            value_or_address: Value,
+            cycle_increase: 2,
+            cycle_increases_on_page_cross: false
+        });
+        instructions[0x82] = Some(Instruction{
+            address_mode: AddressingMode::Immediate,
+            mnemonic: "NOP",
+            operation: CPU::op_nop,
+            result_handler: CPU::no_handler,
+            value_or_address: Value,
+            cycle_increase: 2,
+            cycle_increases_on_page_cross: false
+        });
+        instructions[0xC2] = Some(Instruction{
+            address_mode: AddressingMode::Immediate,
+            mnemonic: "NOP",
+            operation: CPU::op_nop,
+            result_handler: CPU::no_handler,
+            value_or_address: Value,
+            cycle_increase: 2,
+            cycle_increases_on_page_cross: false
+        });
+        instructions[0xE2] = Some(Instruction{
+            address_mode: AddressingMode::Immediate,
+            mnemonic: "NOP",
+            operation: CPU::op_nop,
+            result_handler: CPU::no_handler,
+            value_or_address: Value,
+            cycle_increase: 2,
+            cycle_increases_on_page_cross: false
+        });
+        instructions[0x44] = Some(Instruction{
+            address_mode: AddressingMode::ZeroPage,
+            mnemonic: "NOP",
+            operation: CPU::op_nop,
+            result_handler: CPU::no_handler,
+            value_or_address: Value,
+            cycle_increase: 3,
+            cycle_increases_on_page_cross: false
+        });
+        instructions[0x54] = Some(Instruction{
+            address_mode: AddressingMode::XIndexedZeroPage,
+            mnemonic: "NOP",
+            operation: CPU::op_nop,
+            result_handler: CPU::no_handler,
+            value_or_address: Value,
+            cycle_increase: 4,
+            cycle_increases_on_page_cross: false
+        });
+        instructions[0xD4] = Some(Instruction{
+            address_mode: AddressingMode::XIndexedZeroPage,
+            mnemonic: "NOP",
+            operation: CPU::op_nop,
+            result_handler: CPU::no_handler,
+            value_or_address: Value,
+            cycle_increase: 4,
+            cycle_increases_on_page_cross: false
+        });
+        instructions[0xF4] = Some(Instruction{
+            address_mode: AddressingMode::XIndexedZeroPage,
+            mnemonic: "NOP",
+            operation: CPU::op_nop,
+            result_handler: CPU::no_handler,
+            value_or_address: Value,
+            cycle_increase: 4,
+            cycle_increases_on_page_cross: false
+        });
+        instructions[0x5C] = Some(Instruction{
+            address_mode: AddressingMode::Absolute,
+            mnemonic: "NOP",
+            operation: CPU::op_nop_long,
+            result_handler: CPU::no_handler,
+            value_or_address: Value,
+            cycle_increase: 8,
+            cycle_increases_on_page_cross: false
+        });
+        instructions[0xDC] = Some(Instruction{
+            address_mode: AddressingMode::Absolute,
+            mnemonic: "NOP",
+            operation: CPU::op_nop,
+            result_handler: CPU::no_handler,
+            value_or_address: Value,
+            cycle_increase: 4,
+            cycle_increases_on_page_cross: false
+        });
+        instructions[0xFC] = Some(Instruction{
+            address_mode: AddressingMode::Absolute,
+            mnemonic: "NOP",
+            operation: CPU::op_nop,
+            result_handler: CPU::no_handler,
+            value_or_address: Value,
+            cycle_increase: 4,
+            cycle_increases_on_page_cross: false
+        });
+        instructions[0x03] = Some(Instruction{
+            address_mode: AddressingMode::OpcodeOnly,
+            mnemonic: "NOP",
+            operation: CPU::op_nop,
+            result_handler: CPU::no_handler,
+            value_or_address: Value,
+            cycle_increase: 1,
+            cycle_increases_on_page_cross: false
+        });
+        instructions[0x0B] = Some(Instruction{
+            address_mode: AddressingMode::OpcodeOnly,
+            mnemonic: "NOP",
+            operation: CPU::op_nop,
+            result_handler: CPU::no_handler,
+            value_or_address: Value,
+            cycle_increase: 1,
+            cycle_increases_on_page_cross: false
+        });
+        instructions[0x13] = Some(Instruction{
+            address_mode: AddressingMode::OpcodeOnly,
+            mnemonic: "NOP",
+            operation: CPU::op_nop,
+            result_handler: CPU::no_handler,
+            value_or_address: Value,
+            cycle_increase: 1,
+            cycle_increases_on_page_cross: false
+        });
+        instructions[0x1B] = Some(Instruction{
+            address_mode: AddressingMode::OpcodeOnly,
+            mnemonic: "NOP",
+            operation: CPU::op_nop,
+            result_handler: CPU::no_handler,
+            value_or_address: Value,
+            cycle_increase: 1,
+            cycle_increases_on_page_cross: false
+        });
+        instructions[0x23] = Some(Instruction{
+            address_mode: AddressingMode::OpcodeOnly,
+            mnemonic: "NOP",
+            operation: CPU::op_nop,
+            result_handler: CPU::no_handler,
+            value_or_address: Value,
+            cycle_increase: 1,
+            cycle_increases_on_page_cross: false
+        });
+        instructions[0x2B] = Some(Instruction{
+            address_mode: AddressingMode::OpcodeOnly,
+            mnemonic: "NOP",
+            operation: CPU::op_nop,
+            result_handler: CPU::no_handler,
+            value_or_address: Value,
+            cycle_increase: 1,
+            cycle_increases_on_page_cross: false
+        });
+        instructions[0x33] = Some(Instruction{
+            address_mode: AddressingMode::OpcodeOnly,
+            mnemonic: "NOP",
+            operation: CPU::op_nop,
+            result_handler: CPU::no_handler,
+            value_or_address: Value,
+            cycle_increase: 1,
+            cycle_increases_on_page_cross: false
+        });
+        instructions[0x3B] = Some(Instruction{
+            address_mode: AddressingMode::OpcodeOnly,
+            mnemonic: "NOP",
+            operation: CPU::op_nop,
+            result_handler: CPU::no_handler,
+            value_or_address: Value,
+            cycle_increase: 1,
+            cycle_increases_on_page_cross: false
+        });
+        instructions[0x43] = Some(Instruction{
+            address_mode: AddressingMode::OpcodeOnly,
+            mnemonic: "NOP",
+            operation: CPU::op_nop,
+            result_handler: CPU::no_handler,
+            value_or_address: Value,
+            cycle_increase: 1,
+            cycle_increases_on_page_cross: false
+        });
+        instructions[0x4B] = Some(Instruction{
+            address_mode: AddressingMode::OpcodeOnly,
+            mnemonic: "NOP",
+            operation: CPU::op_nop,
+            result_handler: CPU::no_handler,
+            value_or_address: Value,
+            cycle_increase: 1,
+            cycle_increases_on_page_cross: false
+        });
+        instructions[0x53] = Some(Instruction{
+            address_mode: AddressingMode::OpcodeOnly,
+            mnemonic: "NOP",
+            operation: CPU::op_nop,
+            result_handler: CPU::no_handler,
+            value_or_address: Value,
+            cycle_increase: 1,
+            cycle_increases_on_page_cross: false
+        });
+        instructions[0x5B] = Some(Instruction{
+            address_mode: AddressingMode::OpcodeOnly,
+            mnemonic: "NOP",
+            operation: CPU::op_nop,
+            result_handler: CPU::no_handler,
+            value_or_address: Value,
+            cycle_increase: 1,
+            cycle_increases_on_page_cross: false
+        });
+        instructions[0x63] = Some(Instruction{
+            address_mode: AddressingMode::OpcodeOnly,
+            mnemonic: "NOP",
+            operation: CPU::op_nop,
+            result_handler: CPU::no_handler,
+            value_or_address: Value,
+            cycle_increase: 1,
+            cycle_increases_on_page_cross: false
+        });
+        instructions[0x6B] = Some(Instruction{
+            address_mode: AddressingMode::OpcodeOnly,
+            mnemonic: "NOP",
+            operation: CPU::op_nop,
+            result_handler: CPU::no_handler,
+            value_or_address: Value,
+            cycle_increase: 1,
+            cycle_increases_on_page_cross: false
+        });
+        instructions[0x73] = Some(Instruction{
+            address_mode: AddressingMode::OpcodeOnly,
+            mnemonic: "NOP",
+            operation: CPU::op_nop,
+            result_handler: CPU::no_handler,
+            value_or_address: Value,
+            cycle_increase: 1,
+            cycle_increases_on_page_cross: false
+        });
+        instructions[0x7B] = Some(Instruction{
+            address_mode: AddressingMode::OpcodeOnly,
+            mnemonic: "NOP",
+            operation: CPU::op_nop,
+            result_handler: CPU::no_handler,
+            value_or_address: Value,
+            cycle_increase: 1,
+            cycle_increases_on_page_cross: false
+        });
+        instructions[0x83] = Some(Instruction{
+            address_mode: AddressingMode::OpcodeOnly,
+            mnemonic: "NOP",
+            operation: CPU::op_nop,
+            result_handler: CPU::no_handler,
+            value_or_address: Value,
+            cycle_increase: 1,
+            cycle_increases_on_page_cross: false
+        });
+        instructions[0x8B] = Some(Instruction{
+            address_mode: AddressingMode::OpcodeOnly,
+            mnemonic: "NOP",
+            operation: CPU::op_nop,
+            result_handler: CPU::no_handler,
+            value_or_address: Value,
+            cycle_increase: 1,
+            cycle_increases_on_page_cross: false
+        });
+        instructions[0x93] = Some(Instruction{
+            address_mode: AddressingMode::OpcodeOnly,
+            mnemonic: "NOP",
+            operation: CPU::op_nop,
+            result_handler: CPU::no_handler,
+            value_or_address: Value,
+            cycle_increase: 1,
+            cycle_increases_on_page_cross: false
+        });
+        instructions[0x9B] = Some(Instruction{
+            address_mode: AddressingMode::OpcodeOnly,
+            mnemonic: "NOP",
+            operation: CPU::op_nop,
+            result_handler: CPU::no_handler,
+            value_or_address: Value,
+            cycle_increase: 1,
+            cycle_increases_on_page_cross: false
+        });
+        instructions[0xA3] = Some(Instruction{
+            address_mode: AddressingMode::OpcodeOnly,
+            mnemonic: "NOP",
+            operation: CPU::op_nop,
+            result_handler: CPU::no_handler,
+            value_or_address: Value,
+            cycle_increase: 1,
+            cycle_increases_on_page_cross: false
+        });
+        instructions[0xAB] = Some(Instruction{
+            address_mode: AddressingMode::OpcodeOnly,
+            mnemonic: "NOP",
+            operation: CPU::op_nop,
+            result_handler: CPU::no_handler,
+            value_or_address: Value,
+            cycle_increase: 1,
+            cycle_increases_on_page_cross: false
+        });
+        instructions[0xB3] = Some(Instruction{
+            address_mode: AddressingMode::OpcodeOnly,
+            mnemonic: "NOP",
+            operation: CPU::op_nop,
+            result_handler: CPU::no_handler,
+            value_or_address: Value,
+            cycle_increase: 1,
+            cycle_increases_on_page_cross: false
+        });
+        instructions[0xBB] = Some(Instruction{
+            address_mode: AddressingMode::OpcodeOnly,
+            mnemonic: "NOP",
+            operation: CPU::op_nop,
+            result_handler: CPU::no_handler,
+            value_or_address: Value,
+            cycle_increase: 1,
+            cycle_increases_on_page_cross: false
+        });
+        instructions[0xC3] = Some(Instruction{
+            address_mode: AddressingMode::OpcodeOnly,
+            mnemonic: "NOP",
+            operation: CPU::op_nop,
+            result_handler: CPU::no_handler,
+            value_or_address: Value,
+            cycle_increase: 1,
+            cycle_increases_on_page_cross: false
+        });
+        instructions[0xD3] = Some(Instruction{
+            address_mode: AddressingMode::OpcodeOnly,
+            mnemonic: "NOP",
+            operation: CPU::op_nop,
+            result_handler: CPU::no_handler,
+            value_or_address: Value,
+            cycle_increase: 1,
+            cycle_increases_on_page_cross: false
+        });
+        instructions[0xE3] = Some(Instruction{
+            address_mode: AddressingMode::OpcodeOnly,
+            mnemonic: "NOP",
+            operation: CPU::op_nop,
+            result_handler: CPU::no_handler,
+            value_or_address: Value,
+            cycle_increase: 1,
+            cycle_increases_on_page_cross: false
+        });
+        instructions[0xEB] = Some(Instruction{
+            address_mode: AddressingMode::OpcodeOnly,
+            mnemonic: "NOP",
+            operation: CPU::op_nop,
+            result_handler: CPU::no_handler,
+            value_or_address: Value,
+            cycle_increase: 1,
+            cycle_increases_on_page_cross: false
+        });
+        instructions[0xF3] = Some(Instruction{
+            address_mode: AddressingMode::OpcodeOnly,
+            mnemonic: "NOP",
+            operation: CPU::op_nop,
+            result_handler: CPU::no_handler,
+            value_or_address: Value,
+            cycle_increase: 1,
+            cycle_increases_on_page_cross: false
+        });
+        instructions[0xFB] = Some(Instruction{
+            address_mode: AddressingMode::OpcodeOnly,
+            mnemonic: "NOP",
+            operation: CPU::op_nop,
+            result_handler: CPU::no_handler,
+            value_or_address: Value,
+            cycle_increase: 1,
+            cycle_increases_on_page_cross: false
+        });
+
+        instructions
+    }
+
+    const fn build_documented_instruction_set() -> InstructionSet<B> {
+        let mut instructions = [const { None }; 256];
+
+        //ADC
+        instructions[0x69] = Some(Instruction{
+            address_mode: AddressingMode::Immediate,
+            mnemonic: "ADC",
+            operation: CPU::op_adc,
+            result_handler: CPU::result_into_acc,
+            value_or_address: Value,
+            cycle_increase: 2,
+            cycle_increases_on_page_cross: false
+        });
+        instructions[0x6D] = Some(Instruction{
+            address_mode: AddressingMode::Absolute,
+            mnemonic: "ADC",
+            operation: CPU::op_adc,
+            result_handler: CPU::result_into_acc,
+            value_or_address: Value,
+            cycle_increase: 4,
+            cycle_increases_on_page_cross: false
+        });
+        instructions[0x7D] = Some(Instruction{
+            address_mode: AddressingMode::XIndexedAbsolute,
+            mnemonic: "ADC",
+            operation: CPU::op_adc,
+            result_handler: CPU::result_into_acc,
+            value_or_address: Value,
+            cycle_increase: 4,
+            cycle_increases_on_page_cross: true
+        });
+        instructions[0x79] = Some(Instruction{
+            address_mode: AddressingMode::YIndexedAbsolute,
+            mnemonic: "ADC",
+            operation: CPU::op_adc,
+            result_handler: CPU::result_into_acc,
+            value_or_address: Value,
+            cycle_increase: 4,
+            cycle_increases_on_page_cross: true
+        });
+        instructions[0x65] = Some(Instruction{
+            address_mode: AddressingMode::ZeroPage,
+            mnemonic: "ADC",
+            operation: CPU::op_adc,
+            result_handler: CPU::result_into_acc,
+            value_or_address: Value,
+            cycle_increase: 3,
+            cycle_increases_on_page_cross: false
+        });
+        instructions[0x75] = Some(Instruction{
+            address_mode: AddressingMode::XIndexedZeroPage,
+            mnemonic: "ADC",
+            operation: CPU::op_adc,
+            result_handler: CPU::result_into_acc,
+            value_or_address: Value,
+            cycle_increase: 4,
+            cycle_increases_on_page_cross: false
+        });
+        instructions[0x61] = Some(Instruction{
+            address_mode: AddressingMode::XIndexedZeroPageIndirect,
+            mnemonic: "ADC",
+            operation: CPU::op_adc,
+            result_handler: CPU::result_into_acc,
+            value_or_address: Value,
+            cycle_increase: 6,
+            cycle_increases_on_page_cross: false
+        });
+        instructions[0x71] = Some(Instruction{
+            address_mode: AddressingMode::ZeroPageIndirectYIndexed,
+            mnemonic: "ADC",
+            operation: CPU::op_adc,
+            result_handler: CPU::result_into_acc,
+            value_or_address: Value,
+            cycle_increase: 5,
+            cycle_increases_on_page_cross: true
+        });
+
+        //AND
+        instructions[0x29] = Some(Instruction{
+            address_mode: AddressingMode::Immediate,
+            mnemonic: "AND",
+            operation: CPU::op_and,
+            result_handler: CPU::result_into_acc,
+            value_or_address: Value,
+            cycle_increase: 2,
+            cycle_increases_on_page_cross: false
+        });
+        instructions[0x2D] = Some(Instruction{
+            address_mode: AddressingMode::Absolute,
+            mnemonic: "AND",
+            operation: CPU::op_and,
+            result_handler: CPU::result_into_acc,
+            value_or_address: Value,
+            cycle_increase: 4,
+            cycle_increases_on_page_cross: false
+        });
+        instructions[0x3D] = Some(Instruction{
+            address_mode: AddressingMode::XIndexedAbsolute,
+            mnemonic: "AND",
+            operation: CPU::op_and,
+            result_handler: CPU::result_into_acc,
+            value_or_address: Value,
+            cycle_increase: 4,
+            cycle_increases_on_page_cross: true
+        });
+        instructions[0x39] = Some(Instruction{
+            address_mode: AddressingMode::YIndexedAbsolute,
+            mnemonic: "AND",
+            operation: CPU::op_and,
+            result_handler: CPU::result_into_acc,
+            value_or_address: Value,
+            cycle_increase: 4,
+            cycle_increases_on_page_cross: true
+        });
+        instructions[0x25] = Some(Instruction{
+            address_mode: AddressingMode::ZeroPage,
+            mnemonic: "AND",
+            operation: CPU::op_and,
+            result_handler: CPU::result_into_acc,
+            value_or_address: Value,
+            cycle_increase: 3,
+            cycle_increases_on_page_cross: false
+        });
+        instructions[0x35] = Some(Instruction{
+            address_mode: AddressingMode::XIndexedZeroPage,
+            mnemonic: "AND",
+            operation: CPU::op_and,
+            result_handler: CPU::result_into_acc,
+            value_or_address: Value,
+            cycle_increase: 4,
+            cycle_increases_on_page_cross: false
+        });
+        instructions[0x21] = Some(Instruction{
+            address_mode: AddressingMode::XIndexedZeroPageIndirect,
+            mnemonic: "AND",
+            operation: CPU::op_and,
+            result_handler: CPU::result_into_acc,
+            value_or_address: Value,
+            cycle_increase: 6,
+            cycle_increases_on_page_cross: false
+        });
+        instructions[0x31] = Some(Instruction{
+            address_mode: AddressingMode::ZeroPageIndirectYIndexed,
+            mnemonic: "AND",
+            operation: CPU::op_and,
+            result_handler: CPU::result_into_acc,
+            value_or_address: Value,
+            cycle_increase: 5,
+            cycle_increases_on_page_cross: true
+        });
+
+        //ASL
+        instructions[0x0A] = Some(Instruction{
+            address_mode: AddressingMode::Accumulator,
+            mnemonic: "ASL",
+            operation: CPU::op_asl,
+            result_handler: CPU::result_into_acc,
+            value_or_address: Value,
+            cycle_increase: 2,
+            cycle_increases_on_page_cross: false
+        });
+        instructions[0x0E] = Some(Instruction{
+            address_mode: AddressingMode::Absolute,
+            mnemonic: "ASL",
+            operation: CPU::op_asl,
+            result_handler: CPU::result_into_memory,
+            value_or_address: ReadModifyWrite,
+            cycle_increase: 6,
+            cycle_increases_on_page_cross: false
+        });
+        instructions[0x1E] = Some(Instruction{
+            address_mode: AddressingMode::XIndexedAbsolute,
+            mnemonic: "ASL",
+            operation: CPU::op_asl,
+            result_handler: CPU::result_into_memory,
+            value_or_address: ReadModifyWrite,
+            cycle_increase: 7,
+            cycle_increases_on_page_cross: false
+        });
+        instructions[0x06] = Some(Instruction{
+            address_mode: AddressingMode::ZeroPage,
+            mnemonic: "ASL",
+            operation: CPU::op_asl,
+            result_handler: CPU::result_into_memory,
+            value_or_address: ReadModifyWrite,
+            cycle_increase: 5,
+            cycle_increases_on_page_cross: false
+        });
+        instructions[0x16] = Some(Instruction{
+            address_mode: AddressingMode::XIndexedZeroPage,
+            mnemonic: "ASL",
+            operation: CPU::op_asl,
+            result_handler: CPU::result_into_memory,
+            value_or_address: ReadModifyWrite,
+            cycle_increase: 6,
+            cycle_increases_on_page_cross: false
+        });
+
+        //BCC
+        instructions[0x90] = Some(Instruction{
+            address_mode: AddressingMode::Relative,
+            mnemonic: "BCC",
+            operation: CPU::op_bcc,
+            result_handler: CPU::no_handler,
+            value_or_address: Value,
+            cycle_increase: 2,
+            cycle_increases_on_page_cross: true
+        });
+
+        //BCS
+        instructions[0xB0] = Some(Instruction{
+            address_mode: AddressingMode::Relative,
+            mnemonic: "BCS",
+            operation: CPU::op_bcs,
+            result_handler: CPU::no_handler,
+            value_or_address: Value,
+            cycle_increase: 2,
+            cycle_increases_on_page_cross: true
+        });
+
+        //BEQ
+        instructions[0xF0] = Some(Instruction{
+            address_mode: AddressingMode::Relative,
+            mnemonic: "BEQ",
+            operation: CPU::op_beq,
+            result_handler: CPU::no_handler,
+            value_or_address: Value,
+            cycle_increase: 2,
+            cycle_increases_on_page_cross: true
+        });
+
+        //BIT
+        instructions[0x2C] = Some(Instruction{
+            address_mode: AddressingMode::Absolute,
+            mnemonic: "BIT",
+            operation: CPU::op_bit,
+            result_handler: CPU::no_handler,
+            value_or_address: Value,
+            cycle_increase: 4,
+            cycle_increases_on_page_cross: false
+        });
+        instructions[0x24] = Some(Instruction{
+            address_mode: AddressingMode::ZeroPage,
+            mnemonic: "BIT",
+            operation: CPU::op_bit,
+            result_handler: CPU::no_handler,
+            value_or_address: Value,
+            cycle_increase: 3,
+            cycle_increases_on_page_cross: false
+        });
+
+        //BMI
+        instructions[0x30] = Some(Instruction{
+            address_mode: AddressingMode::Relative,
+            mnemonic: "BMI",
+            operation: CPU::op_bmi,
+            result_handler: CPU::no_handler,
+            value_or_address: Value,
+            cycle_increase: 2,
+            cycle_increases_on_page_cross: true
+        });
+
+        //BNE
+        instructions[0xD0] = Some(Instruction{
+            address_mode: AddressingMode::Relative,
+            mnemonic: "BNE",
+            operation: CPU::op_bne,
+            result_handler: CPU::no_handler,
+            value_or_address: Value,
+            cycle_increase: 2,
+            cycle_increases_on_page_cross: true
+        });
+
+        //BPL
+        instructions[0x10] = Some(Instruction{
+            address_mode: AddressingMode::Relative,
+            mnemonic: "BPL",
+            operation: CPU::op_bpl,
+            result_handler: CPU::no_handler,
+            value_or_address: Value,
+            cycle_increase: 2,
+            cycle_increases_on_page_cross: true
+        });
+
+        //BRK
+        instructions[0x00] = Some(Instruction{
+            address_mode: AddressingMode::Implied,
+            mnemonic: "BRK",
+            operation: CPU::op_brk,
+            result_handler: CPU::no_handler,
+            value_or_address: Value,
+            cycle_increase: 7,
+            cycle_increases_on_page_cross: false
+        });
+
+        //BVC
+        instructions[0x50] = Some(Instruction{
+            address_mode: AddressingMode::Relative,
+            mnemonic: "BVC",
+            operation: CPU::op_bvc,
+            result_handler: CPU::no_handler,
+            value_or_address: Value,
+            cycle_increase: 2,
+            cycle_increases_on_page_cross: true
+        });
+
+        //BVS
+        instructions[0x70] = Some(Instruction{
+            address_mode: AddressingMode::Relative,
+            mnemonic: "BVS",
+            operation: CPU::op_bvs,
+            result_handler: CPU::no_handler,
+            value_or_address: Value,
+            cycle_increase: 2,
+            cycle_increases_on_page_cross: true
+        });
+
+        //CLC,
+        instructions[0x18] = Some(Instruction{
+            address_mode: AddressingMode::Implied,
+            mnemonic: "CLC",
+            operation: CPU::op_clc,
+            result_handler: CPU::no_handler,
+            value_or_address: Value,
+            cycle_increase: 2,
+            cycle_increases_on_page_cross: false
+        });
+
+        //CLD
+        instructions[0xD8] = Some(Instruction{
+            address_mode: AddressingMode::Implied,
+            mnemonic: "CLD",
+            operation: CPU::op_cld,
+            result_handler: CPU::no_handler,
+            value_or_address: Value,
+            cycle_increase: 2,
+            cycle_increases_on_page_cross: false
+        });
+
+        //CLI,
+        instructions[0x58] = Some(Instruction{
+            address_mode: AddressingMode::Implied,
+            mnemonic: "CLI",
+            operation: CPU::op_cli,
+            result_handler: CPU::no_handler,
+            value_or_address: Value,
+            cycle_increase: 2,
+            cycle_increases_on_page_cross: false
+        });
+
+        //CLV
+        instructions[0xB8] = Some(Instruction{
+            address_mode: AddressingMode::Implied,
+            mnemonic: "CLV",
+            operation: CPU::op_clv,
+            result_handler: CPU::no_handler,
+            value_or_address: Value,
+            cycle_increase: 2,
+            cycle_increases_on_page_cross: false
+        });
+
+        //CMP
+        instructions[0xC9] = Some(Instruction{
+            address_mode: AddressingMode::Immediate,
+            mnemonic: "CMP",
+            operation: CPU::op_cmp,
+            result_handler: CPU::no_handler,
+            value_or_address: Value,
+            cycle_increase: 2,
+            cycle_increases_on_page_cross: false
+        });
+        instructions[0xCD] = Some(Instruction{
+            address_mode: AddressingMode::Absolute,
+            mnemonic: "CMP",
+            operation: CPU::op_cmp,
+            result_handler: CPU::no_handler,
+            value_or_address: Value,
+            cycle_increase: 4,
+            cycle_increases_on_page_cross: false
+        });
+        instructions[0xDD] = Some(Instruction{
+            address_mode: AddressingMode::XIndexedAbsolute,
+            mnemonic: "CMP",
+            operation: CPU::op_cmp,
+            result_handler: CPU::no_handler,
+            value_or_address: Value,
+            cycle_increase: 4,
+            cycle_increases_on_page_cross: true
+        });
+        instructions[0xD9] = Some(Instruction{
+            address_mode: AddressingMode::YIndexedAbsolute,
+            mnemonic: "CMP",
+            operation: CPU::op_cmp,
+            result_handler: CPU::no_handler,
+            value_or_address: Value,
+            cycle_increase: 4,
+            cycle_increases_on_page_cross: true
+        });
+        instructions[0xC5] = Some(Instruction{
+            address_mode: AddressingMode::ZeroPage,
+            mnemonic: "CMP",
+            operation: CPU::op_cmp,
+            result_handler: CPU::no_handler,
+            value_or_address: Value,
+            cycle_increase: 3,
+            cycle_increases_on_page_cross: false
+        });
+        instructions[0xD5] = Some(Instruction{
+            address_mode: AddressingMode::XIndexedZeroPage,
+            mnemonic: "CMP",
+            operation: CPU::op_cmp,
+            result_handler: CPU::no_handler,
+            value_or_address: Value,
+            cycle_increase: 4,
+            cycle_increases_on_page_cross: false
+        });
+        instructions[0xC1] = Some(Instruction{
+            address_mode: AddressingMode::XIndexedZeroPageIndirect,
+            mnemonic: "CMP",
+            operation: CPU::op_cmp,
+            result_handler: CPU::no_handler,
+            value_or_address: Value,
+            cycle_increase: 6,
+            cycle_increases_on_page_cross: false
+        });
+        instructions[0xD1] = Some(Instruction{
+            address_mode: AddressingMode::ZeroPageIndirectYIndexed,
+            mnemonic: "CMP",
+            operation: CPU::op_cmp,
+            result_handler: CPU::no_handler,
+            value_or_address: Value,
+            cycle_increase: 5,
+            cycle_increases_on_page_cross: true
+        });
+
+        //CPX
+        instructions[0xE0] = Some(Instruction{
+            address_mode: AddressingMode::Immediate,
+            mnemonic: "CPX",
+            operation: CPU::op_cpx,
+            result_handler: CPU::no_handler,
+            value_or_address: Value,
+            cycle_increase: 2,
+            cycle_increases_on_page_cross: false
+        });
+        instructions[0xEC] = Some(Instruction{
+            address_mode: AddressingMode::Absolute,
+            mnemonic: "CPX",
+            operation: CPU::op_cpx,
+            result_handler: CPU::no_handler,
+            value_or_address: Value,
+            cycle_increase: 4,
+            cycle_increases_on_page_cross: false
+        });
+        instructions[0xE4] = Some(Instruction{
+            address_mode: AddressingMode::ZeroPage,
+            mnemonic: "CPX",
+            operation: CPU::op_cpx,
+            result_handler: CPU::no_handler,
+            value_or_address: Value,
+            cycle_increase: 3,
+            cycle_increases_on_page_cross: false
+        });
+
+        //CPY
+        instructions[0xC0] = Some(Instruction{
+            address_mode: AddressingMode::Immediate,
+            mnemonic: "CPY",
+            operation: CPU::op_cpy,
+            result_handler: CPU::no_handler,
+            value_or_address: Value,
+            cycle_increase: 2,
+            cycle_increases_on_page_cross: false
+        });
+        instructions[0xCC] = Some(Instruction{
+            address_mode: AddressingMode::Absolute,
+            mnemonic: "CPY",
+            operation: CPU::op_cpy,
+            result_handler: CPU::no_handler,
+            value_or_address: Value,
+            cycle_increase: 4,
+            cycle_increases_on_page_cross: false
+        });
+        instructions[0xC4] = Some(Instruction{
+            address_mode: AddressingMode::ZeroPage,
+            mnemonic: "CPY",
+            operation: CPU::op_cpy,
+            result_handler: CPU::no_handler,
+            value_or_address: Value,
+            cycle_increase: 3,
+            cycle_increases_on_page_cross: false
+        });
+
+        //DEC
+        instructions[0xCE] = Some(Instruction{
+            address_mode: AddressingMode::Absolute,
+            mnemonic: "DEC",
+            operation: CPU::op_dec,
+            result_handler: CPU::result_into_memory,
+            value_or_address: ReadModifyWrite,
+            cycle_increase: 6,
+            cycle_increases_on_page_cross: false
+        });
+        instructions[0xDE] = Some(Instruction{
+            address_mode: AddressingMode::XIndexedAbsolute,
+            mnemonic: "DEC",
+            operation: CPU::op_dec,
+            result_handler: CPU::result_into_memory,
+            value_or_address: ReadModifyWrite,
+            cycle_increase: 7,
+            cycle_increases_on_page_cross: false
+        });
+        instructions[0xC6] = Some(Instruction{
+            address_mode: AddressingMode::ZeroPage,
+            mnemonic: "DEC",
+            operation: CPU::op_dec,
+            result_handler: CPU::result_into_memory,
+            value_or_address: ReadModifyWrite,
+            cycle_increase: 5,
+            cycle_increases_on_page_cross: false
+        });
+        instructions[0xD6] = Some(Instruction{
+            address_mode: AddressingMode::XIndexedZeroPage,
+            mnemonic: "DEC",
+            operation: CPU::op_dec,
+            result_handler: CPU::result_into_memory,
+            value_or_address: ReadModifyWrite,
+            cycle_increase: 6,
+            cycle_increases_on_page_cross: false
+        });
+
+        //DEX
+        instructions[0xCA] = Some(Instruction{
+            address_mode: AddressingMode::Implied,
+            mnemonic: "DEX",
+            operation: CPU::op_dex,
+            result_handler: CPU::no_handler,
+            value_or_address: Value,
+            cycle_increase: 2,
+            cycle_increases_on_page_cross: false
+        });
+
+        //DEY
+        instructions[0x88] = Some(Instruction{
+            address_mode: AddressingMode::Implied,
+            mnemonic: "DEY",
+            operation: CPU::op_dey,
+            result_handler: CPU::no_handler,
+            value_or_address: Value,
+            cycle_increase: 2,
+            cycle_increases_on_page_cross: false
+        });
+
+        //EOR
+        instructions[0x49] = Some(Instruction{
+            address_mode: AddressingMode::Immediate,
+            mnemonic: "EOR",
+            operation: CPU::op_eor,
+            result_handler: CPU::result_into_acc,
+            value_or_address: Value,
+            cycle_increase: 2,
+            cycle_increases_on_page_cross: false
+        });
+        instructions[0x4D] = Some(Instruction{
+            address_mode: AddressingMode::Absolute,
+            mnemonic: "EOR",
+            operation: CPU::op_eor,
+            result_handler: CPU::result_into_acc,
+            value_or_address: Value,
+            cycle_increase: 4,
+            cycle_increases_on_page_cross: false
+        });
+        instructions[0x5D] = Some(Instruction{
+            address_mode: AddressingMode::XIndexedAbsolute,
+            mnemonic: "EOR",
+            operation: CPU::op_eor,
+            result_handler: CPU::result_into_acc,
+            value_or_address: Value,
+            cycle_increase: 4,
+            cycle_increases_on_page_cross: true
+        });
+        instructions[0x59] = Some(Instruction{
+            address_mode: AddressingMode::YIndexedAbsolute,
+            mnemonic: "EOR",
+            operation: CPU::op_eor,
+            result_handler: CPU::result_into_acc,
+            value_or_address: Value,
+            cycle_increase: 4,
+            cycle_increases_on_page_cross: true
+        });
+        instructions[0x45] = Some(Instruction{
+            address_mode: AddressingMode::ZeroPage,
+            mnemonic: "EOR",
+            operation: CPU::op_eor,
+            result_handler: CPU::result_into_acc,
+            value_or_address: Value,
+            cycle_increase: 3,
+            cycle_increases_on_page_cross: false
+        });
+        instructions[0x55] = Some(Instruction{
+            address_mode: AddressingMode::XIndexedZeroPage,
+            mnemonic: "EOR",
+            operation: CPU::op_eor,
+            result_handler: CPU::result_into_acc,
+            value_or_address: Value,
+            cycle_increase: 4,
+            cycle_increases_on_page_cross: false
+        });
+        instructions[0x41] = Some(Instruction{
+            address_mode: AddressingMode::XIndexedZeroPageIndirect,
+            mnemonic: "EOR",
+            operation: CPU::op_eor,
+            result_handler: CPU::result_into_acc,
+            value_or_address: Value,
+            cycle_increase: 6,
+            cycle_increases_on_page_cross: false
+        });
+        instructions[0x51] = Some(Instruction{
+            address_mode: AddressingMode::ZeroPageIndirectYIndexed,
+            mnemonic: "EOR",
+            operation: CPU::op_eor,
+            result_handler: CPU::result_into_acc,
+            value_or_address: Value,
+            cycle_increase: 5,
+            cycle_increases_on_page_cross: true
+        });
+
+        //INC
+        instructions[0xEE] = Some(Instruction{
+            address_mode: AddressingMode::Absolute,
+            mnemonic: "INC",
+            operation: CPU::op_inc,
+            result_handler: CPU::result_into_memory,
+            value_or_address: ReadModifyWrite,
+            cycle_increase: 6,
+            cycle_increases_on_page_cross: false
+        });
+        instructions[0xFE] = Some(Instruction{
+            address_mode: AddressingMode::XIndexedAbsolute,
+            mnemonic: "INC",
+            operation: CPU::op_inc,
+            result_handler: CPU::result_into_memory,
+            value_or_address: ReadModifyWrite,
+            cycle_increase: 7,
+            cycle_increases_on_page_cross: false
+        });
+        instructions[0xE6] = Some(Instruction{
+            address_mode: AddressingMode::ZeroPage,
+            mnemonic: "INC",
+            operation: CPU::op_inc,
+            result_handler: CPU::result_into_memory,
+            value_or_address: ReadModifyWrite,
+            cycle_increase: 5,
+            cycle_increases_on_page_cross: false
+        });
+        instructions[0xF6] = Some(Instruction{
+            address_mode: AddressingMode::XIndexedZeroPage,
+            mnemonic: "INC",
+            operation: CPU::op_inc,
+            result_handler: CPU::result_into_memory,
+            value_or_address: ReadModifyWrite,
+            cycle_increase: 6,
+            cycle_increases_on_page_cross: false
+        });
+
+        //INX
+        instructions[0xE8] = Some(Instruction{
+            address_mode: AddressingMode::Implied,
+            mnemonic: "INX",
+            operation: CPU::op_inx,
+            result_handler: CPU::no_handler,
+            value_or_address: Value,
+            cycle_increase: 2,
+            cycle_increases_on_page_cross: false
+        });
+
+        //INY
+        instructions[0xC8] = Some(Instruction{
+            address_mode: AddressingMode::Implied,
+            mnemonic: "INY",
+            operation: CPU::op_iny,
+            result_handler: CPU::no_handler,
+            value_or_address: Value,
+            cycle_increase: 2,
+            cycle_increases_on_page_cross: false
+        });
+
+        //JMP
+        instructions[0x4C] = Some(Instruction{
+            address_mode: AddressingMode::Absolute,
+            mnemonic: "JMP",
+            operation: CPU::op_jmp,
+            result_handler: CPU::no_handler,
+            value_or_address: Address,
+            cycle_increase: 3,
+            cycle_increases_on_page_cross: false
+        });
+        instructions[0x6C] = Some(Instruction{
+            address_mode: AddressingMode::AbsoluteIndirect,
+            mnemonic: "JMP",
+            operation: CPU::op_jmp,
+            result_handler: CPU::no_handler,
+            value_or_address: Address,
+            cycle_increase: 5,
+            cycle_increases_on_page_cross: false
+        });
+
+        //JSR
+        instructions[0x20] = Some(Instruction{
+            address_mode: AddressingMode::Absolute,
+            mnemonic: "JSR",
+            operation: CPU::op_jsr,
+            result_handler: CPU::no_handler,
+            value_or_address: Address,
+            cycle_increase: 6,
+            cycle_increases_on_page_cross: false
+        });
+
+        //LDA
+        instructions[0xA9] = Some(Instruction{
+            address_mode: AddressingMode::Immediate,
+            mnemonic: "LDA",
+            operation: CPU::op_lda,
+            result_handler: CPU::no_handler,
+            value_or_address: Value,
+            cycle_increase: 2,
+            cycle_increases_on_page_cross: false
+        });
+        instructions[0xAD] = Some(Instruction{
+            address_mode: AddressingMode::Absolute,
+            mnemonic: "LDA",
+            operation: CPU::op_lda,
+            result_handler: CPU::no_handler,
+            value_or_address: Value,
+            cycle_increase: 4,
+            cycle_increases_on_page_cross: false
+        });
+        instructions[0xBD] = Some(Instruction{
+            address_mode: AddressingMode::XIndexedAbsolute,
+            mnemonic: "LDA",
+            operation: CPU::op_lda,
+            result_handler: CPU::no_handler,
+            value_or_address: Value,
+            cycle_increase: 4,
+            cycle_increases_on_page_cross: true
+        });
+        instructions[0xB9] = Some(Instruction{
+            address_mode: AddressingMode::YIndexedAbsolute,
+            mnemonic: "LDA",
+            operation: CPU::op_lda,
+            result_handler: CPU::no_handler,
+            value_or_address: Value,
+            cycle_increase: 4,
+            cycle_increases_on_page_cross: true
+        });
+        instructions[0xA5] = Some(Instruction{
+            address_mode: AddressingMode::ZeroPage,
+            mnemonic: "LDA",
+            operation: CPU::op_lda,
+            result_handler: CPU::no_handler,
+            value_or_address: Value,
+            cycle_increase: 3,
+            cycle_increases_on_page_cross: false
+        });
+        instructions[0xB5] = Some(Instruction{
+            address_mode: AddressingMode::XIndexedZeroPage,
+            mnemonic: "LDA",
+            operation: CPU::op_lda,
+            result_handler: CPU::no_handler,
+            value_or_address: Value,
+            cycle_increase: 4,
+            cycle_increases_on_page_cross: false
+        });
+        instructions[0xA1] = Some(Instruction{
+            address_mode: AddressingMode::XIndexedZeroPageIndirect,
+            mnemonic: "LDA",
+            operation: CPU::op_lda,
+            result_handler: CPU::no_handler,
+            value_or_address: Value,
+            cycle_increase: 6,
+            cycle_increases_on_page_cross: false
+        });
+        instructions[0xB1] = Some(Instruction{
+            address_mode: AddressingMode::ZeroPageIndirectYIndexed,
+            mnemonic: "LDA",
+            operation: CPU::op_lda,
+            result_handler: CPU::no_handler,
+            value_or_address: Value,
+            cycle_increase: 5,
+            cycle_increases_on_page_cross: true
+        });
+
+        //LDX
+        instructions[0xA2] = Some(Instruction{
+            address_mode: AddressingMode::Immediate,
+            mnemonic: "LDX",
+            operation: CPU::op_ldx,
+            result_handler: CPU::no_handler,
+            value_or_address: Value,
+            cycle_increase: 2,
+            cycle_increases_on_page_cross: false
+        });
+        instructions[0xAE] = Some(Instruction{
+            address_mode: AddressingMode::Absolute,
+            mnemonic: "LDX",
+            operation: CPU::op_ldx,
+            result_handler: CPU::no_handler,
+            value_or_address: Value,
+            cycle_increase: 4,
+            cycle_increases_on_page_cross: false
+        });
+        instructions[0xBE] = Some(Instruction{
+            address_mode: AddressingMode::YIndexedAbsolute,
+            mnemonic: "LDX",
+            operation: CPU::op_ldx,
+            result_handler: CPU::no_handler,
+            value_or_address: Value,
+            cycle_increase: 4,
+            cycle_increases_on_page_cross: true
+        });
+        instructions[0xA6] = Some(Instruction{
+            address_mode: AddressingMode::ZeroPage,
+            mnemonic: "LDX",
+            operation: CPU::op_ldx,
+            result_handler: CPU::no_handler,
+            value_or_address: Value,
+            cycle_increase: 3,
+            cycle_increases_on_page_cross: false
+        });
+        instructions[0xB6] = Some(Instruction{
+            address_mode: AddressingMode::YIndexedZeroPage,
+            mnemonic: "LDX",
+            operation: CPU::op_ldx,
+            result_handler: CPU::no_handler,
+            value_or_address: Value,
+            cycle_increase: 4,
+            cycle_increases_on_page_cross: false
+        });
+
+        //LDY
+        instructions[0xA0] = Some(Instruction{
+            address_mode: AddressingMode::Immediate,
+            mnemonic: "LDY",
+            operation: CPU::op_ldy,
+            result_handler: CPU::no_handler,
+            value_or_address: Value,
+            cycle_increase: 2,
+            cycle_increases_on_page_cross: false
+        });
+        instructions[0xAC] = Some(Instruction{
+            address_mode: AddressingMode::Absolute,
+            mnemonic: "LDY",
+            operation: CPU::op_ldy,
+            result_handler: CPU::no_handler,
+            value_or_address: Value,
+            cycle_increase: 4,
+            cycle_increases_on_page_cross: false
+        });
+        instructions[0xBC] = Some(Instruction{
+            address_mode: AddressingMode::XIndexedAbsolute,
+            mnemonic: "LDY",
+            operation: CPU::op_ldy,
+            result_handler: CPU::no_handler,
+            value_or_address: Value,
+            cycle_increase: 4,
+            cycle_increases_on_page_cross: true
+        });
+        instructions[0xA4] = Some(Instruction{
+            address_mode: AddressingMode::ZeroPage,
+            mnemonic: "LDY",
+            operation: CPU::op_ldy,
+            result_handler: CPU::no_handler,
+            value_or_address: Value,
+            cycle_increase: 3,
+            cycle_increases_on_page_cross: false
+        });
+        instructions[0xB4] = Some(Instruction{
+            address_mode: AddressingMode::XIndexedZeroPage,
+            mnemonic: "LDY",
+            operation: CPU::op_ldy,
+            result_handler: CPU::no_handler,
+            value_or_address: Value,
+            cycle_increase: 4,
+            cycle_increases_on_page_cross: false
+        });
+
+        //LSR
+        instructions[0x4A] = Some(Instruction{
+            address_mode: AddressingMode::Accumulator,
+            mnemonic: "LSR",
+            operation: CPU::op_lsr,
+            result_handler: CPU::result_into_acc,
+            value_or_address: Value,
+            cycle_increase: 2,
+            cycle_increases_on_page_cross: false
+        });
+        instructions[0x4E] = Some(Instruction{
+            address_mode: AddressingMode::Absolute,
+            mnemonic: "LSR",
+            operation: CPU::op_lsr,
+            result_handler: CPU::result_into_memory,
+            value_or_address: ReadModifyWrite,
+            cycle_increase: 6,
+            cycle_increases_on_page_cross: false
+        });
+        instructions[0x5E] = Some(Instruction{
+            address_mode: AddressingMode::XIndexedAbsolute,
+            mnemonic: "LSR",
+            operation: CPU::op_lsr,
+            result_handler: CPU::result_into_memory,
+            value_or_address: ReadModifyWrite,
+            cycle_increase: 7,
+            cycle_increases_on_page_cross: false
+        });
+        instructions[0x46] = Some(Instruction{
+            address_mode: AddressingMode::ZeroPage,
+            mnemonic: "LSR",
+            operation: CPU::op_lsr,
+            result_handler: CPU::result_into_memory,
+            value_or_address: ReadModifyWrite,
+            cycle_increase: 5,
+            cycle_increases_on_page_cross: false
+        });
+        instructions[0x56] = Some(Instruction{
+            address_mode: AddressingMode::XIndexedZeroPage,
+            mnemonic: "LSR",
+            operation: CPU::op_lsr,
+            result_handler: CPU::result_into_memory,
+            value_or_address: ReadModifyWrite,
+            cycle_increase: 6,
+            cycle_increases_on_page_cross: false
+        });
+
+        //NOP
+        instructions[0xEA] = Some(Instruction{
+            address_mode: AddressingMode::Implied,
+            mnemonic: "NOP",
+            operation: CPU::op_nop,
+            result_handler: CPU::no_handler,
+            value_or_address: Value,
+            cycle_increase: 2,
+            cycle_increases_on_page_cross: false
+        });
+
+        //ORA
+        instructions[0x09] = Some(Instruction{
+            address_mode: AddressingMode::Immediate,
+            mnemonic: "ORA",
+            operation: CPU::op_ora,
+            result_handler: CPU::no_handler,
+            value_or_address: Value,
+            cycle_increase: 2,
+            cycle_increases_on_page_cross: false
+        });
+        instructions[0x0D] = Some(Instruction{
+            address_mode: AddressingMode::Absolute,
+            mnemonic: "ORA",
+            operation: CPU::op_ora,
+            result_handler: CPU::no_handler,
+            value_or_address: Value,
+            cycle_increase: 4,
+            cycle_increases_on_page_cross: false
+        });
+        instructions[0x1D] = Some(Instruction{
+            address_mode: AddressingMode::XIndexedAbsolute,
+            mnemonic: "ORA",
+            operation: CPU::op_ora,
+            result_handler: CPU::no_handler,
+            value_or_address: Value,
+            cycle_increase: 4,
+            cycle_increases_on_page_cross: true
+        });
+        instructions[0x19] = Some(Instruction{
+            address_mode: AddressingMode::YIndexedAbsolute,
+            mnemonic: "ORA",
+            operation: CPU::op_ora,
+            result_handler: CPU::no_handler,
+            value_or_address: Value,
+            cycle_increase: 4,
+            cycle_increases_on_page_cross: true
+        });
+        instructions[0x05] = Some(Instruction{
+            address_mode: AddressingMode::ZeroPage,
+            mnemonic: "ORA",
+            operation: CPU::op_ora,
+            result_handler: CPU::no_handler,
+            value_or_address: Value,
+            cycle_increase: 3,
+            cycle_increases_on_page_cross: false
+        });
+        instructions[0x15] = Some(Instruction{
+            address_mode: AddressingMode::XIndexedZeroPage,
+            mnemonic: "ORA",
+            operation: CPU::op_ora,
+            result_handler: CPU::no_handler,
+            value_or_address: Value,
+            cycle_increase: 4,
+            cycle_increases_on_page_cross: false
+        });
+        instructions[0x01] = Some(Instruction{
+            address_mode: AddressingMode::XIndexedZeroPageIndirect,
+            mnemonic: "ORA",
+            operation: CPU::op_ora,
+            result_handler: CPU::no_handler,
+            value_or_address: Value,
+            cycle_increase: 6,
+            cycle_increases_on_page_cross: false
+        });
+        instructions[0x11] = Some(Instruction{
+            address_mode: AddressingMode::ZeroPageIndirectYIndexed,
+            mnemonic: "ORA",
+            operation: CPU::op_ora,
+            result_handler: CPU::no_handler,
+            value_or_address: Value,
+            cycle_increase: 5,
+            cycle_increases_on_page_cross: true
+        });
+
+        //PHA
+        instructions[0x48] = Some(Instruction{
+            address_mode: AddressingMode::Implied,
+            mnemonic: "PHA",
+            operation: CPU::op_pha,
+            result_handler: CPU::no_handler,
+            value_or_address: Address,
+            cycle_increase: 3,
+            cycle_increases_on_page_cross: false
+        });
+
+        //PHP
+        instructions[0x08] = Some(Instruction{
+            address_mode: AddressingMode::Implied,
+            mnemonic: "PHP",
+            operation: CPU::op_php,
+            result_handler: CPU::no_handler,
+            value_or_address: Address,
+            cycle_increase: 3,
+            cycle_increases_on_page_cross: false
+        });
+
+        //PLA
+        instructions[0x68] = Some(Instruction{
+            address_mode: AddressingMode::Implied,
+            mnemonic: "PLA",
+            operation: CPU::op_pla,
+            result_handler: CPU::no_handler,
+            value_or_address: Address,
+            cycle_increase: 4,
+            cycle_increases_on_page_cross: false
+        });
+
+        //PLP
+        instructions[0x28] = Some(Instruction{
+            address_mode: AddressingMode::Implied,
+            mnemonic: "PLP",
+            operation: CPU::op_plp,
+            result_handler: CPU::no_handler,
+            value_or_address: Address,
+            cycle_increase: 4,
+            cycle_increases_on_page_cross: false
+        });
+
+        //ROL
+        instructions[0x2A] = Some(Instruction{
+            address_mode: AddressingMode::Accumulator,
+            mnemonic: "ROL",
+            operation: CPU::op_rol,
+            result_handler: CPU::result_into_acc,
+            value_or_address: Value,
+            cycle_increase: 2,
+            cycle_increases_on_page_cross: false
+        });
+        instructions[0x2E] = Some(Instruction{
+            address_mode: AddressingMode::Absolute,
+            mnemonic: "ROL",
+            operation: CPU::op_rol,
+            result_handler: CPU::result_into_memory,
+            value_or_address: ReadModifyWrite,
+            cycle_increase: 6,
+            cycle_increases_on_page_cross: false
+        });
+        instructions[0x3E] = Some(Instruction{
+            address_mode: AddressingMode::XIndexedAbsolute,
+            mnemonic: "ROL",
+            operation: CPU::op_rol,
+            result_handler: CPU::result_into_memory,
+            value_or_address: ReadModifyWrite,
+            cycle_increase: 7,
+            cycle_increases_on_page_cross: false
+        });
+        instructions[0x26] = Some(Instruction{
+            address_mode: AddressingMode::ZeroPage,
+            mnemonic: "ROL",
+            operation: CPU::op_rol,
+            result_handler: CPU::result_into_memory,
+            value_or_address: ReadModifyWrite,
+            cycle_increase: 5,
+            cycle_increases_on_page_cross: false
+        });
+        instructions[0x36] = Some(Instruction{
+            address_mode: AddressingMode::XIndexedZeroPage,
+            mnemonic: "ROL",
+            operation: CPU::op_rol,
+            result_handler: CPU::result_into_memory,
+            value_or_address: ReadModifyWrite,
+            cycle_increase: 6,
+            cycle_increases_on_page_cross: false
+        });
+
+        //ROR
+        instructions[0x6A] = Some(Instruction{
+            address_mode: AddressingMode::Accumulator,
+            mnemonic: "ROR",
+            operation: CPU::op_ror,
+            result_handler: CPU::result_into_acc,
+            value_or_address: Value,
+            cycle_increase: 2,
+            cycle_increases_on_page_cross: false
+        });
+        instructions[0x6E] = Some(Instruction{
+            address_mode: AddressingMode::Absolute,
+            mnemonic: "ROR",
+            operation: CPU::op_ror,
+            result_handler: CPU::result_into_memory,
+            value_or_address: ReadModifyWrite,
+            cycle_increase: 6,
+            cycle_increases_on_page_cross: false
+        });
+        instructions[0x7E] = Some(Instruction{
+            address_mode: AddressingMode::XIndexedAbsolute,
+            mnemonic: "ROR",
+            operation: CPU::op_ror,
+            result_handler: CPU::result_into_memory,
+            value_or_address: ReadModifyWrite,
+            cycle_increase: 7,
+            cycle_increases_on_page_cross: false
+        });
+        instructions[0x66] = Some(Instruction{
+            address_mode: AddressingMode::ZeroPage,
+            mnemonic: "ROR",
+            operation: CPU::op_ror,
+            result_handler: CPU::result_into_memory,
+            value_or_address: ReadModifyWrite,
+            cycle_increase: 5,
+            cycle_increases_on_page_cross: false
+        });
+        instructions[0x76] = Some(Instruction{
+            address_mode: AddressingMode::XIndexedZeroPage,
+            mnemonic: "ROR",
+            operation: CPU::op_ror,
+            result_handler: CPU::result_into_memory,
+            value_or_address: ReadModifyWrite,
+            cycle_increase: 6,
+            cycle_increases_on_page_cross: false
+        });
+
+        //RTI
+        instructions[0x40] = Some(Instruction{
+            address_mode: AddressingMode::Implied,
+            mnemonic: "RTI",
+            operation: CPU::op_rti,
+            result_handler: CPU::no_handler,
+            value_or_address: Value,
+            cycle_increase: 6,
+            cycle_increases_on_page_cross: false
+        });
+
+        //RTS
+        instructions[0x60] = Some(Instruction{
+            address_mode: AddressingMode::Implied,
+            mnemonic: "RTS",
+            operation: CPU::op_rts,
+            result_handler: CPU::no_handler,
+            value_or_address: Value,
+            cycle_increase: 6,
+            cycle_increases_on_page_cross: false
+        });
+
+        //SBC
+        instructions[0xE9] = Some(Instruction{
+            address_mode: AddressingMode::Immediate,
+            mnemonic: "SBC",
+            operation: CPU::op_sbc,
+            result_handler: CPU::no_handler,
+            value_or_address: Value,
+            cycle_increase: 2,
+            cycle_increases_on_page_cross: false
+        });
+        instructions[0xED] = Some(Instruction{
+            address_mode: AddressingMode::Absolute,
+            mnemonic: "SBC",
+            operation: CPU::op_sbc,
+            result_handler: CPU::no_handler,
+            value_or_address: Value,
+            cycle_increase: 4,
+            cycle_increases_on_page_cross: false
+        });
+        instructions[0xFD] = Some(Instruction{
+            address_mode: AddressingMode::XIndexedAbsolute,
+            mnemonic: "SBC",
+            operation: CPU::op_sbc,
+            result_handler: CPU::no_handler,
+            value_or_address: Value,
+            cycle_increase: 4,
+            cycle_increases_on_page_cross: true
+        });
+        instructions[0xF9] = Some(Instruction{
+            address_mode: AddressingMode::YIndexedAbsolute,
+            mnemonic: "SBC",
+            operation: CPU::op_sbc,
+            result_handler: CPU::no_handler,
+            value_or_address: Value,
+            cycle_increase: 4,
+            cycle_increases_on_page_cross: true
+        });
+        instructions[0xE5] = Some(Instruction{
+            address_mode: AddressingMode::ZeroPage,
+            mnemonic: "SBC",
+            operation: CPU::op_sbc,
+            result_handler: CPU::no_handler,
+            value_or_address: Value,
+            cycle_increase: 3,
+            cycle_increases_on_page_cross: false
+        });
+        instructions[0xF5] = Some(Instruction{
+            address_mode: AddressingMode::XIndexedZeroPage,
+            mnemonic: "SBC",
+            operation: CPU::op_sbc,
+            result_handler: CPU::no_handler,
+            value_or_address: Value,
+            cycle_increase: 4,
+            cycle_increases_on_page_cross: false
+        });
+        instructions[0xE1] = Some(Instruction{
+            address_mode: AddressingMode::XIndexedZeroPageIndirect,
+            mnemonic: "SBC",
+            operation: CPU::op_sbc,
+            result_handler: CPU::no_handler,
+            value_or_address: Value,
+            cycle_increase: 6,
+            cycle_increases_on_page_cross: false
+        });
+        instructions[0xF1] = Some(Instruction{
+            address_mode: AddressingMode::ZeroPageIndirectYIndexed,
+            mnemonic: "SBC",
+            operation: CPU::op_sbc,
+            result_handler: CPU::no_handler,
+            value_or_address: Value,
+            cycle_increase: 5,
+            cycle_increases_on_page_cross: true
+        });
+
+        //SEC
+        instructions[0x38] = Some(Instruction{
+            address_mode: AddressingMode::Implied,
+            mnemonic: "SEC",
+            operation: CPU::op_sec,
+            result_handler: CPU::no_handler,
+            value_or_address: Address,
+            cycle_increase: 2,
+            cycle_increases_on_page_cross: false
+        });
+
+        //SED
+        instructions[0xF8] = Some(Instruction{
+            address_mode: AddressingMode::Implied,
+            mnemonic: "SED",
+            operation: CPU::op_sed,
+            result_handler: CPU::no_handler,
+            value_or_address: Address,
+            cycle_increase: 2,
+            cycle_increases_on_page_cross: false
+        });
+
+        //SEI
+        instructions[0x78] = Some(Instruction{
+            address_mode: AddressingMode::Implied,
+            mnemonic: "SEI",
+            operation: CPU::op_sei,
+            result_handler: CPU::no_handler,
+            value_or_address: Address,
+            cycle_increase: 2,
+            cycle_increases_on_page_cross: false
+        });
+
+        //STA
+        instructions[0x8D] = Some(Instruction{
+            address_mode: AddressingMode::Absolute,
+            mnemonic: "STA",
+            operation: CPU::op_sta,
+            result_handler: CPU::no_handler,
+            value_or_address: Address,
+            cycle_increase: 4,
+            cycle_increases_on_page_cross: false
+        });
+        instructions[0x9D] = Some(Instruction{
+            address_mode: AddressingMode::XIndexedAbsolute,
+            mnemonic: "STA",
+            operation: CPU::op_sta,
+            result_handler: CPU::no_handler,
+            value_or_address: Address,
+            cycle_increase: 5,
+            cycle_increases_on_page_cross: false
+        });
+        instructions[0x99] = Some(Instruction{
+            address_mode: AddressingMode::YIndexedAbsolute,
+            mnemonic: "STA",
+            operation: CPU::op_sta,
+            result_handler: CPU::no_handler,
+            value_or_address: Address,
+            cycle_increase: 5,
+            cycle_increases_on_page_cross: false
+        });
+        instructions[0x85] = Some(Instruction{
+            address_mode: AddressingMode::ZeroPage,
+            mnemonic: "STA",
+            operation: CPU::op_sta,
+            result_handler: CPU::no_handler,
+            value_or_address: Address,
+            cycle_increase: 3,
+            cycle_increases_on_page_cross: false
+        });
+        instructions[0x95] = Some(Instruction{
+            address_mode: AddressingMode::XIndexedZeroPage,
+            mnemonic: "STA",
+            operation: CPU::op_sta,
+            result_handler: CPU::no_handler,
+            value_or_address: Address,
+            cycle_increase: 4,
+            cycle_increases_on_page_cross: false
+        });
+        instructions[0x81] = Some(Instruction{
+            address_mode: AddressingMode::XIndexedZeroPageIndirect,
+            mnemonic: "STA",
+            operation: CPU::op_sta,
+            result_handler: CPU::no_handler,
+            value_or_address: Address,
+            cycle_increase: 6,
+            cycle_increases_on_page_cross: false
+        });
+        instructions[0x91] = Some(Instruction{
+            address_mode: AddressingMode::ZeroPageIndirectYIndexed,
+            mnemonic: "STA",
+            operation: CPU::op_sta,
+            result_handler: CPU::no_handler,
+            value_or_address: Address,
+            cycle_increase: 6,
+            cycle_increases_on_page_cross: false
+        });
+
+        //STX
+        instructions[0x8E] = Some(Instruction{
+            address_mode: AddressingMode::Absolute,
+            mnemonic: "STX",
+            operation: CPU::op_stx,
+            result_handler: CPU::no_handler,
+            value_or_address: Address,
+            cycle_increase: 4,
+            cycle_increases_on_page_cross: false
+        });
+        instructions[0x86] = Some(Instruction{
+            address_mode: AddressingMode::ZeroPage,
+            mnemonic: "STX",
+            operation: CPU::op_stx,
+            result_handler: CPU::no_handler,
+            value_or_address: Address,
+            cycle_increase: 3,
+            cycle_increases_on_page_cross: false
+        });
+        instructions[0x96] = Some(Instruction{
+            address_mode: AddressingMode::YIndexedZeroPage,
+            mnemonic: "STX",
+            operation: CPU::op_stx,
+            result_handler: CPU::no_handler,
+            value_or_address: Address,
+            cycle_increase: 4,
+            cycle_increases_on_page_cross: false
+        });
+
+        //STY
+        instructions[0x8C] = Some(Instruction{
+            address_mode: AddressingMode::Absolute,
+            mnemonic: "STY",
+            operation: CPU::op_sty,
+            result_handler: CPU::no_handler,
+            value_or_address: Address,
+            cycle_increase: 4,
+            cycle_increases_on_page_cross: false
+        });
+        instructions[0x84] = Some(Instruction{
+            address_mode: AddressingMode::ZeroPage,
+            mnemonic: "STY",
+            operation: CPU::op_sty,
+            result_handler: CPU::no_handler,
+            value_or_address: Address,
+            cycle_increase: 3,
+            cycle_increases_on_page_cross: false
+        });
+        instructions[0x94] = Some(Instruction{
+            address_mode: AddressingMode::XIndexedZeroPage,
+            mnemonic: "STY",
+            operation: CPU::op_sty,
+            result_handler: CPU::no_handler,
+            value_or_address: Address,
+            cycle_increase: 4,
+            cycle_increases_on_page_cross: false
+        });
+
+        //TAX
+        instructions[0xAA] = Some(Instruction{
+            address_mode: AddressingMode::Implied,
+            mnemonic: "TAX",
+            operation: CPU::op_tax,
+            result_handler: CPU::no_handler,
+            value_or_address: Address,
+            cycle_increase: 2,
+            cycle_increases_on_page_cross: false
+        });
+
+        //TAY
+        instructions[0xA8] = Some(Instruction{
+            address_mode: AddressingMode::Implied,
+            mnemonic: "TAY",
+            operation: CPU::op_tay,
+            result_handler: CPU::no_handler,
+            value_or_address: Address,
+            cycle_increase: 2,
+            cycle_increases_on_page_cross: false
+        });
+
+        //TSX
+        instructions[0xBA] = Some(Instruction{
+            address_mode: AddressingMode::Implied,
+            mnemonic: "TSX",
+            operation: CPU::op_tsx,
+            result_handler: CPU::no_handler,
+            value_or_address: Address,
+            cycle_increase: 2,
+            cycle_increases_on_page_cross: false
+        });
+
+        //TXA
+        instructions[0x8A] = Some(Instruction{
+            address_mode: AddressingMode::Implied,
+            mnemonic: "TXA",
+            operation: CPU::op_txa,
+            result_handler: CPU::no_handler,
+            value_or_address: Address,
+            cycle_increase: 2,
+            cycle_increases_on_page_cross: false
+        });
+
+        //TXS
+        instructions[0x9A] = Some(Instruction{
+            address_mode: AddressingMode::Implied,
+            mnemonic: "TXS",
+            operation: CPU::op_txs,
+            result_handler: CPU::no_handler,
+            value_or_address: Address,
+            cycle_increase: 2,
+            cycle_increases_on_page_cross: false
+        });
+
+        //TYA
+        instructions[0x98] = Some(Instruction{
+            address_mode: AddressingMode::Implied,
+            mnemonic: "TYA",
+            operation: CPU::op_tya,
+            result_handler: CPU::no_handler,
+            value_or_address: Address,
+            cycle_increase: 2,
+            cycle_increases_on_page_cross: false
+        });
+
+        instructions
+    }
+
+    fn result_into_acc(&mut self, value: Option<u8>, _: Option<u16>) -> Result<(), Fault> {
+        self.registers.acc = value.ok_or(Fault::MissingValue(None))?;
+        Ok(())
+    }
+
+    fn result_into_memory(&mut self, value: Option<u8>, address: Option<u16>) -> Result<(), Fault> {
+        let address = address.ok_or(Fault::MissingAddress)?;
+        let value = value.ok_or(Fault::MissingValue(Some(address)))?;
+        self.write_byte(address, value);
+        Ok(())
+    }
+
+    fn no_handler(&mut self, _: Option<u8>, _: Option<u16>) -> Result<(), Fault> {
+        Ok(())
     }
-}
\ No newline at end of file
+}
@@ -1,4 +1,6 @@
-use crate::cpu::cpu::CPU;
+use crate::cpu::bus::{Bus, Ram};
+use crate::cpu::cpu::{Variant, CPU};
+use crate::cpu::execution::{ExecutionError, Fault, StepOutcome};
 use crate::helpers::addressing::page_crossed;
 
 pub enum InstructionParameter {
@@ -9,13 +11,15 @@ pub enum InstructionParameter {
 
 pub enum ValueOrAddress{
     Value,
-    Address
+    Address,
+    ReadModifyWrite
 }
 
-type InstructionFn = fn(&mut CPU, val: InstructionParameter) -> Option<u8>;
+type InstructionFn<B> = fn(&mut CPU<B>, val: InstructionParameter) -> Option<u8>;
 
-type ResultHandlerFn = fn(&mut CPU, val: Option<u8>, address: Option<u16>);
+type ResultHandlerFn<B> = fn(&mut CPU<B>, val: Option<u8>, address: Option<u16>) -> Result<(), Fault>;
 
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
 pub enum AddressingMode {
     Implied,
     Immediate,
@@ -30,155 +34,259 @@ pub enum AddressingMode {
     XIndexedZeroPageIndirect,
     ZeroPageIndirectYIndexed,
     Relative,
+    //65C02 only
+    OpcodeOnly,
+    ZeroPageIndirect,
+    XIndexedAbsoluteIndirect,
+    ZeroPageRelative,
+    //65816 only, the zero page modes are relative to the direct page register there
+    AbsoluteLong,
+    XIndexedAbsoluteLong,
+    AbsoluteIndirectLong,
+    ZeroPageIndirectLong,
+    ZeroPageIndirectLongYIndexed,
+    StackRelative,
+    StackRelativeIndirectYIndexed,
+    RelativeLong,
+    BlockMove,
 }
 
-pub struct Instruction {
+impl AddressingMode {
+
+    //Bytes after the opcode. A 65816 immediate is one byte here, two when the register it goes to is 16-bit.
+    pub fn operand_length(&self) -> u8 {
+        match self {
+            AddressingMode::Implied | AddressingMode::Accumulator | AddressingMode::OpcodeOnly => 0,
+            AddressingMode::Immediate
+            | AddressingMode::ZeroPage
+            | AddressingMode::XIndexedZeroPage
+            | AddressingMode::YIndexedZeroPage
+            | AddressingMode::XIndexedZeroPageIndirect
+            | AddressingMode::ZeroPageIndirectYIndexed
+            | AddressingMode::Relative
+            | AddressingMode::ZeroPageIndirect
+            | AddressingMode::ZeroPageIndirectLong
+            | AddressingMode::ZeroPageIndirectLongYIndexed
+            | AddressingMode::StackRelative
+            | AddressingMode::StackRelativeIndirectYIndexed => 1,
+            AddressingMode::Absolute
+            | AddressingMode::AbsoluteIndirect
+            | AddressingMode::XIndexedAbsolute
+            | AddressingMode::YIndexedAbsolute
+            | AddressingMode::XIndexedAbsoluteIndirect
+            | AddressingMode::ZeroPageRelative
+            | AddressingMode::AbsoluteIndirectLong
+            | AddressingMode::RelativeLong
+            | AddressingMode::BlockMove => 2,
+            AddressingMode::AbsoluteLong | AddressingMode::XIndexedAbsoluteLong => 3,
+        }
+    }
+}
+
+pub type InstructionSet<B> = [Option<Instruction<B>>; 256];
+
+pub struct Instruction<B: Bus = Ram> {
     pub address_mode: AddressingMode,
-    pub operation: InstructionFn,
-    pub result_handler: ResultHandlerFn,
+    pub mnemonic: &'static str,
+    pub operation: InstructionFn<B>,
+    pub result_handler: ResultHandlerFn<B>,
     pub value_or_address: ValueOrAddress,
     pub cycle_increase: u32,
     pub cycle_increases_on_page_cross: bool
 }
 
-impl CPU {
+impl<B: Bus> CPU<B> {
 
-    pub fn get_next_byte(&mut self, bytes: &mut &[u8]) -> u8 {
-        if bytes.is_empty() {
-            panic!("Bytes array is empty.");
-        }
-
-        let byte = *bytes.get(self.registers.pc as usize).unwrap_or_else(|| {
-            panic!("Program end reached!")
-        });
-        self.registers.pc += 1;
+    pub(crate) fn get_next_byte(&mut self) -> u8 {
+        let byte = self.read_byte(self.registers.pc);
+        self.registers.pc = self.registers.pc.wrapping_add(1);
 
         byte
     }
 
-    pub fn get_next_word(&mut self, bytes: &mut &[u8]) -> u16 {
-        let low_byte = self.get_next_byte(bytes) as u16;
-        let high_byte = self.get_next_byte(bytes) as u16;
+    pub(crate) fn get_next_word(&mut self) -> u16 {
+        let low_byte = self.get_next_byte() as u16;
+        let high_byte = self.get_next_byte() as u16;
         (high_byte << 8) | low_byte
     }
 
-    pub fn execute_instruction(&mut self, instruction: &Instruction, mut bytes: &[u8]) {
+    //Takes a pending interrupt, or fetches and executes the next instruction.
+    pub fn step(&mut self) -> Result<StepOutcome, ExecutionError> {
+        self.record_rewind_step();
+        if let Some(outcome) = self.poll_interrupts() {
+            return Ok(outcome);
+        }
+        if self.tracer.is_some() {
+            self.trace_instruction();
+        }
+        if self.variant == Variant::Wdc65816 {
+            return self.execute_native_instruction();
+        }
+
+        let pc = self.registers.pc;
+        let opcode = self.get_next_byte();
+        let instruction = Self::get_instruction(self.variant, opcode)
+            .ok_or(ExecutionError::UnknownOpcode { pc, opcode })?;
+        self.execute_instruction(instruction).map_err(|fault| fault.at(pc, opcode))?;
+
+        Ok(StepOutcome::Executed { pc, opcode })
+    }
+
+    //Every instruction performs its bus accesses in the order the 6502 does, including the dummy reads and writes.
+    //Each access takes one cycle, so the cycle count follows from the accesses.
+    pub(crate) fn execute_instruction(&mut self, instruction: &Instruction<B>) -> Result<(), Fault> {
         match instruction.address_mode {
             AddressingMode::Implied => {
+                self.dummy_read(self.registers.pc);
                 let result = (instruction.operation)(self, InstructionParameter::None);
-                (instruction.result_handler)(self, result, None);
-
-                self.cycles += instruction.cycle_increase
+                (instruction.result_handler)(self, result, None)?;
             }
             AddressingMode::Immediate => {
-                let value = self.get_next_byte(&mut bytes);
+                let value = self.get_next_byte();
                 let result = (instruction.operation)(self, InstructionParameter::Byte(value));
-                (instruction.result_handler)(self, result, None);
-
-                self.cycles += instruction.cycle_increase
+                (instruction.result_handler)(self, result, None)?;
             }
             AddressingMode::Accumulator => {
+                self.dummy_read(self.registers.pc);
                 let value = self.registers.acc;
                 let result = (instruction.operation)(self, InstructionParameter::Byte(value));
-                (instruction.result_handler)(self, result, None);
-
-                self.cycles += instruction.cycle_increase
+                (instruction.result_handler)(self, result, None)?;
             }
             AddressingMode::Absolute => {
-                let address = self.get_next_word(&mut bytes);
-                match instruction.value_or_address {
-                    ValueOrAddress::Value => {
-                        let stored = self.index_absolute(address);
-                        let result = (instruction.operation)(self, InstructionParameter::Byte(stored));
-                        (instruction.result_handler)(self, result, Some(address));
-                    }
-                    ValueOrAddress::Address => {
-                        let result = (instruction.operation)(self, InstructionParameter::Word(address));
-                        (instruction.result_handler)(self, result, Some(address));
-                    }
-                }
-
-                self.cycles += instruction.cycle_increase
+                let address = self.get_next_word();
+                self.handle_instruction(instruction, address)?;
             }
             AddressingMode::AbsoluteIndirect => {
-                let address = self.get_next_word(&mut bytes);
-                let (stored, final_address) = self.index_absolute_indirect(address);
-
-                self.handle_instruction(instruction, final_address, stored);
-                self.cycles += instruction.cycle_increase
+                let address = self.get_next_word();
+                let final_address = self.index_absolute_indirect(address);
+                self.handle_instruction(instruction, final_address)?;
             }
             AddressingMode::XIndexedAbsolute => {
-                let address = self.get_next_word(&mut bytes);
-                let (stored, page_crossed, final_address) = self.index_absolute_indexed(address, self.registers.xr);
-                self.handle_instruction(instruction, final_address, stored);
-                self.cycles += instruction.cycle_increase + ((instruction.cycle_increases_on_page_cross && page_crossed) as u32);
+                let address = self.get_next_word();
+                let (final_address, page_crossed) = self.index_absolute_indexed(address, self.registers.xr);
+                self.handle_indexed_instruction(instruction, final_address, page_crossed)?;
             }
             AddressingMode::YIndexedAbsolute => {
-                let address = self.get_next_word(&mut bytes);
-                let (stored, page_crossed, final_address) = self.index_absolute_indexed(address, self.registers.yr);
-                self.handle_instruction(instruction, final_address, stored);
-                self.cycles += instruction.cycle_increase + ((instruction.cycle_increases_on_page_cross && page_crossed) as u32);
+                let address = self.get_next_word();
+                let (final_address, page_crossed) = self.index_absolute_indexed(address, self.registers.yr);
+                self.handle_indexed_instruction(instruction, final_address, page_crossed)?;
             }
             AddressingMode::ZeroPage => {
-                let address = self.get_next_byte(&mut bytes);
-                match instruction.value_or_address {
-                    ValueOrAddress::Value => {
-                        let stored = self.index_zero_page(address);
-                        let result = (instruction.operation)(self, InstructionParameter::Byte(stored));
-                        (instruction.result_handler)(self, result, Some(address as u16));
-                    }
-                    ValueOrAddress::Address => {
-                        let result = (instruction.operation)(self, InstructionParameter::Word(address as u16));
-                        (instruction.result_handler)(self, result, Some(address as u16));
-                    }
-                }
-
-                self.cycles += instruction.cycle_increase;
+                let address = self.get_next_byte();
+                self.handle_instruction(instruction, address as u16)?;
             }
             AddressingMode::XIndexedZeroPage => {
-                let address = self.get_next_byte(&mut bytes);
-                let (stored, final_address) = self.index_zero_page_indexed(address, self.registers.xr);
-                self.handle_instruction(instruction, final_address, stored);
-                self.cycles += instruction.cycle_increase;
+                let address = self.get_next_byte();
+                self.dummy_read(address as u16);
+                let final_address = self.index_zero_page_indexed(address, self.registers.xr);
+                self.handle_instruction(instruction, final_address)?;
             }
             AddressingMode::YIndexedZeroPage => {
-                let address = self.get_next_byte(&mut bytes);
-                let (stored, final_address) = self.index_zero_page_indexed(address, self.registers.yr);
-                self.handle_instruction(instruction, final_address, stored);
-                self.cycles += instruction.cycle_increase;
+                let address = self.get_next_byte();
+                self.dummy_read(address as u16);
+                let final_address = self.index_zero_page_indexed(address, self.registers.yr);
+                self.handle_instruction(instruction, final_address)?;
             }
             AddressingMode::XIndexedZeroPageIndirect => {
-                let address = self.get_next_byte(&mut bytes);
-                let (stored, final_address) = self.index_zero_paged_indexed_indirect(address, self.registers.xr);
-                self.handle_instruction(instruction, final_address, stored);
-                self.cycles += instruction.cycle_increase;
+                let address = self.get_next_byte();
+                self.dummy_read(address as u16);
+                let final_address = self.index_zero_paged_indexed_indirect(address, self.registers.xr);
+                self.handle_instruction(instruction, final_address)?;
             }
             AddressingMode::ZeroPageIndirectYIndexed => {
-                let address = self.get_next_byte(&mut bytes);
-                let (stored, page_crossed, final_address) = self.index_zero_paged_indirect_indexed(address, self.registers.yr);
-                self.handle_instruction(instruction, final_address, stored);
-                self.cycles += instruction.cycle_increase + ((instruction.cycle_increases_on_page_cross && page_crossed) as u32);
+                let address = self.get_next_byte();
+                let (final_address, page_crossed) = self.index_zero_paged_indirect_indexed(address, self.registers.yr);
+                self.handle_indexed_instruction(instruction, final_address, page_crossed)?;
             }
             AddressingMode::Relative => {
-                let value = self.get_next_word(&mut bytes);
-                (instruction.operation)(self, InstructionParameter::Word(value));
-                (instruction.result_handler)(self, None, None);
-
-                self.cycles += instruction.cycle_increase + ((instruction.cycle_increases_on_page_cross && page_crossed(self.registers.pc, value as u16)) as u32);
+                //The offset is signed, sign extending it lets the branch add it to PC.
+                let offset = self.get_next_byte() as i8 as u16;
+                (instruction.operation)(self, InstructionParameter::Word(offset));
+                (instruction.result_handler)(self, None, None)?;
+            }
+            AddressingMode::OpcodeOnly => {
+                let result = (instruction.operation)(self, InstructionParameter::None);
+                (instruction.result_handler)(self, result, None)?;
+            }
+            AddressingMode::ZeroPageIndirect => {
+                let address = self.get_next_byte();
+                let final_address = self.index_zero_page_indirect(address);
+                self.handle_instruction(instruction, final_address)?;
+            }
+            AddressingMode::XIndexedAbsoluteIndirect => {
+                let address = self.get_next_word();
+                self.dummy_read(self.registers.pc.wrapping_sub(1));
+                let final_address = self.index_absolute_indexed_indirect(address, self.registers.xr);
+                self.handle_instruction(instruction, final_address)?;
+            }
+            AddressingMode::ZeroPageRelative => {
+                //BBR and BBS test a zero page byte, the operation fetches the branch offset itself.
+                let address = self.get_next_byte() as u16;
+                let value = self.read_byte(address);
+                self.dummy_read(address);
+                (instruction.operation)(self, InstructionParameter::Byte(value));
+                (instruction.result_handler)(self, None, None)?;
             }
+            _ => return Err(Fault::UnsupportedAddressingMode)
         }
+
+        if !self.cycle_accurate {
+            self.memory.tick(self.cycles);
+        }
+
+        Ok(())
     }
 
-    pub fn handle_instruction(&mut self, instruction: &Instruction, address: u16, value: u8){
+    pub(crate) fn handle_instruction(&mut self, instruction: &Instruction<B>, address: u16) -> Result<(), Fault> {
         match instruction.value_or_address {
             ValueOrAddress::Value => {
+                let value = self.read_byte(address);
                 let result = (instruction.operation)(self, InstructionParameter::Byte(value));
-                (instruction.result_handler)(self, result, Some(address));
+                (instruction.result_handler)(self, result, Some(address))
             }
             ValueOrAddress::Address => {
                 let result = (instruction.operation)(self, InstructionParameter::Word(address));
-                (instruction.result_handler)(self, result, Some(address));
+                (instruction.result_handler)(self, result, Some(address))
+            }
+            ValueOrAddress::ReadModifyWrite => {
+                //The 6502 writes the unmodified value back while it computes the result, the 65C02 reads it again.
+                let value = self.read_byte(address);
+                match self.variant {
+                    Variant::Nmos6502 => self.write_byte(address, value),
+                    _ => self.dummy_read(address)
+                }
+                let result = (instruction.operation)(self, InstructionParameter::Byte(value));
+                (instruction.result_handler)(self, result, Some(address))
             }
         }
     }
-}
\ No newline at end of file
+
+    //The indexed address is formed before the carry into the high byte, the 6502 reads that address first.
+    //Instructions that only take the extra cycle on a page cross skip it otherwise, the others always spend it.
+    //The 65C02 reads the last operand byte again instead of the uncorrected address when the page was crossed.
+    fn handle_indexed_instruction(&mut self, instruction: &Instruction<B>, address: u16, page_crossed: bool) -> Result<(), Fault> {
+        if page_crossed || !instruction.cycle_increases_on_page_cross {
+            let dummy_address = if page_crossed && self.variant.is_cmos() {
+                self.registers.pc.wrapping_sub(1)
+            } else {
+                address.wrapping_sub((page_crossed as u16) << 8)
+            };
+            self.dummy_read(dummy_address);
+        }
+
+        self.handle_instruction(instruction, address)
+    }
+
+    //A taken branch spends a cycle reading the next opcode, and another one when PC moves to a different page.
+    pub(crate) fn take_branch(&mut self, offset: u16){
+        let old_pc = self.registers.pc;
+        let new_pc = old_pc.wrapping_add(offset);
+
+        self.dummy_read(old_pc);
+        if page_crossed(old_pc, new_pc) {
+            self.dummy_read((old_pc & 0xFF00) | (new_pc & 0x00FF));
+        }
+
+        self.registers.pc = new_pc;
+    }
+}
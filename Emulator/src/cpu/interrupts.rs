@@ -0,0 +1,262 @@
+use crate::cpu::bus::Bus;
+use crate::cpu::cpu::{CPU, Flags, Registers, Variant};
+use crate::cpu::execution::StepOutcome;
+use crate::cpu::save_state::{SaveStateError, StateReader, StateWriter};
+
+pub const NMI_VECTOR: u16 = 0xFFFA;
+pub const RESET_VECTOR: u16 = 0xFFFC;
+pub const IRQ_VECTOR: u16 = 0xFFFE;
+//65816 only, in emulation mode. Native mode has its own vectors below these.
+pub const COP_VECTOR: u16 = 0xFFF4;
+
+//The IRQ, NMI and RESET inputs. IRQ and NMI count as asserted when either the host sets them here or the bus reports them.
+#[derive(Default)]
+pub struct Interrupts {
+    pub irq: bool,
+    pub nmi: bool,
+    reset: bool,
+    reset_pending: bool,
+    nmi_level: bool,
+    nmi_pending: bool,
+    //CLI, SEI and PLP change the I flag after interrupts were polled, so the old value applies to one more instruction.
+    delayed_interrupt_flag: Option<bool>,
+    //The first instruction of a handler always runs before the next interrupt is taken.
+    handler_entered: bool
+}
+
+impl Interrupts {
+
+    pub(crate) fn save_state(&self, state: &mut StateWriter){
+        state.write_bool(self.irq);
+        state.write_bool(self.nmi);
+        state.write_bool(self.reset);
+        state.write_bool(self.reset_pending);
+        state.write_bool(self.nmi_level);
+        state.write_bool(self.nmi_pending);
+        state.write_u8(match self.delayed_interrupt_flag {
+            None => 0,
+            Some(false) => 1,
+            Some(true) => 2
+        });
+        state.write_bool(self.handler_entered);
+    }
+
+    pub(crate) fn load_state(state: &mut StateReader) -> Result<Self, SaveStateError> {
+        Ok(Interrupts {
+            irq: state.read_bool()?,
+            nmi: state.read_bool()?,
+            reset: state.read_bool()?,
+            reset_pending: state.read_bool()?,
+            nmi_level: state.read_bool()?,
+            nmi_pending: state.read_bool()?,
+            delayed_interrupt_flag: match state.read_u8()? {
+                0 => None,
+                flag => Some(flag == 2)
+            },
+            handler_entered: state.read_bool()?
+        })
+    }
+}
+
+impl<B: Bus> CPU<B> {
+
+    pub fn set_irq_line(&mut self, asserted: bool){
+        self.interrupts.irq = asserted;
+    }
+
+    //While RESET is held the CPU only spends cycles, releasing it runs the reset sequence.
+    pub fn set_reset_line(&mut self, asserted: bool){
+        self.interrupts.reset = asserted;
+        self.interrupts.reset_pending |= asserted;
+    }
+
+    pub fn set_nmi_line(&mut self, asserted: bool){
+        self.interrupts.nmi = asserted;
+        self.sample_nmi();
+    }
+
+    //NMI is edge triggered, it only becomes pending when the line goes from released to asserted.
+    pub(crate) fn sample_nmi(&mut self){
+        let level = self.interrupts.nmi || self.memory.nmi();
+        if level && !self.interrupts.nmi_level {
+            self.interrupts.nmi_pending = true;
+        }
+        self.interrupts.nmi_level = level;
+    }
+
+    pub(crate) fn irq_asserted(&self) -> bool {
+        self.interrupts.irq || self.memory.irq()
+    }
+
+    //Remembers the I flag from before CLI, SEI or PLP changed it, for the next interrupt poll.
+    pub(crate) fn delay_interrupt_flag(&mut self){
+        self.interrupts.delayed_interrupt_flag = Some(self.flags.interrupt);
+    }
+
+    //Called between instructions. Enters the NMI or IRQ handler when one is due, returns what the CPU did
+    //instead of executing an instruction.
+    pub(crate) fn poll_interrupts(&mut self) -> Option<StepOutcome> {
+        //The clock keeps running while RESET is held, so every step spends a cycle.
+        if self.interrupts.reset {
+            self.idle_cycle();
+            if !self.cycle_accurate {
+                self.memory.tick(self.cycles);
+            }
+            return Some(StepOutcome::Reset);
+        }
+        if self.interrupts.reset_pending {
+            self.reset();
+            return Some(StepOutcome::Reset);
+        }
+        //A jammed CPU ignores everything but reset.
+        if self.jammed {
+            return Some(StepOutcome::Halted);
+        }
+
+        let interrupt_flag = self.interrupts.delayed_interrupt_flag.take().unwrap_or(self.flags.interrupt);
+        if self.interrupts.handler_entered {
+            self.interrupts.handler_entered = false;
+            return None;
+        }
+
+        self.sample_nmi();
+        if self.waiting {
+            //WAI wakes on an asserted line even when I is set, the IRQ is then ignored.
+            if !self.interrupts.nmi_pending && !self.irq_asserted() {
+                self.dummy_read(self.registers.pc);
+                if !self.cycle_accurate {
+                    self.memory.tick(self.cycles);
+                }
+                return Some(StepOutcome::Waiting);
+            }
+            self.waiting = false;
+        }
+        if self.interrupts.nmi_pending {
+            self.hardware_interrupt(NMI_VECTOR);
+            Some(StepOutcome::Interrupt)
+        }
+        else if self.irq_asserted() && !interrupt_flag {
+            self.hardware_interrupt(IRQ_VECTOR);
+            Some(StepOutcome::Interrupt)
+        }
+        else {
+            None
+        }
+    }
+
+    //IRQ and NMI replace the opcode fetch with two reads of PC, then run the same sequence as BRK.
+    fn hardware_interrupt(&mut self, vector: u16){
+        self.dummy_read(self.registers.pc);
+        self.dummy_read(self.registers.pc);
+        self.enter_interrupt(self.registers.pc, false, vector);
+    }
+
+    //Pushes PC and the status, sets I and jumps through the vector. An NMI that arrives before the vector is
+    //fetched takes over the sequence, the handler then starts at the NMI vector with B still pushed as given.
+    pub(crate) fn enter_interrupt(&mut self, return_address: u16, brk: bool, vector: u16){
+        if self.variant == Variant::Wdc65816 {
+            self.push_native_interrupt_frame(return_address, brk);
+        } else {
+            self.push_word_to_stack(return_address);
+            let status = self.flags.to_pushed_byte(brk);
+            self.push_byte_to_stack(status);
+        }
+        self.flags.interrupt = true;
+        if self.variant.is_cmos() {
+            self.flags.decimal = false;
+        }
+
+        self.sample_nmi();
+        let vector = if self.interrupts.nmi_pending { NMI_VECTOR } else { vector };
+        if vector == NMI_VECTOR {
+            self.interrupts.nmi_pending = false;
+        }
+
+        if self.variant == Variant::Wdc65816 {
+            let vector = self.native_interrupt_vector(vector, brk);
+            self.registers.program_bank = 0;
+            self.registers.pc = self.read_word(vector);
+        } else {
+            self.registers.pc = self.read_word(vector);
+        }
+        self.interrupts.handler_entered = true;
+    }
+
+    //In native mode the 65816 also pushes the program bank, and P in its NVMXDIZC layout.
+    fn push_native_interrupt_frame(&mut self, return_address: u16, brk: bool){
+        if !self.flags.emulation {
+            self.push_native_byte(self.registers.program_bank);
+        }
+        self.push_native_word(return_address);
+        let status = self.flags.to_native_byte(brk);
+        self.push_native_byte(status);
+    }
+
+    //Native mode gives BRK a vector of its own instead of sharing the IRQ one.
+    fn native_interrupt_vector(&self, vector: u16, brk: bool) -> u16 {
+        if self.flags.emulation {
+            return vector;
+        }
+
+        match vector {
+            COP_VECTOR => 0xFFE4,
+            NMI_VECTOR => 0xFFEA,
+            IRQ_VECTOR if brk => 0xFFE6,
+            IRQ_VECTOR => 0xFFEE,
+            _ => vector
+        }
+    }
+
+    //Cold start: clears the registers and lets the bus power on, then runs the reset sequence through the vector.
+    //Loaded memory is kept.
+    //The stack pointer powers up as $00, so the reset sequence leaves it at $FD.
+    pub fn power_on(&mut self){
+        self.memory.power_on();
+        self.registers = Registers {
+            acc: 0,
+            pc: 0,
+            xr: 0,
+            yr: 0,
+            sp: 0,
+            ..Registers::default()
+        };
+        self.flags = Flags::default();
+        self.cycles = 0;
+
+        self.reset();
+    }
+
+    //Warm start: memory and A, X and Y are kept. Runs the 7 cycle sequence of an interrupt with the stack writes
+    //turned into reads, so SP still moves down by three, then sets I and jumps through the reset vector.
+    pub fn reset(&mut self){
+        self.interrupts = Interrupts {
+            irq: self.interrupts.irq,
+            nmi: self.interrupts.nmi,
+            reset: self.interrupts.reset,
+            nmi_level: self.interrupts.nmi_level,
+            ..Interrupts::default()
+        };
+        self.jammed = false;
+        self.waiting = false;
+        if self.variant == Variant::Wdc65816 {
+            self.enter_emulation_mode();
+            self.registers.direct_page = 0;
+            self.registers.data_bank = 0;
+            self.registers.program_bank = 0;
+        }
+
+        self.dummy_read(self.registers.pc);
+        self.dummy_read(self.registers.pc);
+        for _ in 0..3 {
+            self.dummy_read_stack();
+            self.registers.sp = self.registers.sp.wrapping_sub(1);
+        }
+        self.flags.interrupt = true;
+        if self.variant.is_cmos() {
+            self.flags.decimal = false;
+        }
+
+        self.registers.pc = self.read_word(RESET_VECTOR);
+        self.interrupts.handler_entered = true;
+    }
+}
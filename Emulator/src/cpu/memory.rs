@@ -1,71 +1,129 @@
-use crate::cpu::cpu::CPU;
+use crate::cpu::bus::Bus;
+use crate::cpu::cpu::{Variant, CPU};
 use crate::helpers::addressing::page_crossed;
-use crate::helpers::bitwise::{merge_bytes_into_word, merge_bytes_into_word_u16};
+use crate::helpers::bitwise::merge_bytes_into_word;
 
-impl CPU {
+//Size of the full 16-bit address space. Code, data, the stack and the vectors all live in it.
+pub const MEMORY_SIZE: usize = 0x10000;
 
-    pub fn index_zero_page(&self, index: u8) -> u8{
-        *self.memory.get(index as usize).unwrap_or_else(|| {
-            panic!("Memory index out of bounds!")
-        })
+impl<B: Bus> CPU<B> {
+
+    //Copies the program into memory starting at origin, wrapping around at the end of the address space.
+    //The program counter is left untouched, set registers.pc to choose where execution starts.
+    pub fn load_program(&mut self, bytes: &[u8], origin: u16){
+        for (offset, &byte) in bytes.iter().enumerate() {
+            let address = origin.wrapping_add(offset as u16);
+            self.memory.write(address, byte);
+        }
     }
 
-    pub fn index_zero_page_indexed(&self, index: u8, offset: u8) -> (u8, u16){
-        let address = index.wrapping_add(offset);
-        (*self.memory.get(address as usize).unwrap_or_else(|| {
-            panic!("Memory index out of bounds!")
-        }), address as u16)
+    //Reads and writes through these take a bus cycle each.
+    pub(crate) fn read_byte(&mut self, address: u16) -> u8 {
+        self.begin_cycle();
+        self.memory.read(address)
     }
 
-    pub fn index_absolute(&self, index: u16) -> u8{
-        *self.memory.get(index as usize).unwrap_or_else(|| {
-            panic!("Memory index out of bounds!")
-        })
+    pub(crate) fn write_byte(&mut self, address: u16, value: u8){
+        self.begin_cycle();
+        if self.rewind.is_some() {
+            self.record_rewind_write(address as u32, value);
+        }
+        self.memory.write(address, value);
     }
 
-    pub fn index_absolute_indirect(&self, index: u16) -> (u8, u16){
-        let low_byte = *self.memory.get(index as usize).unwrap_or_else(|| {
-            panic!("Memory out of bounds")
-        });
-        let high_byte = *self.memory.get(index.wrapping_add(1) as usize).unwrap_or_else(|| {
-            panic!("Memory out of bounds")
-        });
-        let final_address = merge_bytes_into_word(high_byte, low_byte);
+    //The 6502 reads the bus on every cycle, even when it has no use for the value.
+    pub(crate) fn dummy_read(&mut self, address: u16){
+        self.read_byte(address);
+    }
 
-        (*self.memory.get(final_address as usize).unwrap_or_else(|| {
-            panic!("Memory out of bounds")
-        }), final_address)
+    //24-bit accesses for the 65816, they cost a cycle like any other access.
+    pub(crate) fn read_long_byte(&mut self, address: u32) -> u8 {
+        self.begin_cycle();
+        self.memory.read_long(address & 0xFFFFFF)
     }
 
-    pub fn index_absolute_indexed(&self, index: u16, offset: u8) -> (u8, bool, u16){
-        let address = index.wrapping_add(offset as u16);
-        let fetched = *self.memory.get(address as usize).unwrap_or_else(|| {
-            panic!("Memory index out of bounds!")
-        });
+    pub(crate) fn write_long_byte(&mut self, address: u32, value: u8){
+        self.begin_cycle();
+        if self.rewind.is_some() {
+            self.record_rewind_write(address & 0xFFFFFF, value);
+        }
+        self.memory.write_long(address & 0xFFFFFF, value);
+    }
 
-        (fetched, page_crossed(index, address), address)
+    //A cycle the CPU spends internally without using the bus.
+    pub(crate) fn idle_cycle(&mut self){
+        self.begin_cycle();
     }
 
-    pub fn index_zero_paged_indexed_indirect(&self, index: u8, offset: u8) -> (u8, u16) {
-        let zero_page_address = index.wrapping_add(offset);
-        let low_byte = self.memory[zero_page_address as usize];
-        let high_byte = self.memory[zero_page_address.wrapping_add(1) as usize];
-        let final_address = merge_bytes_into_word(high_byte, low_byte);
+    fn begin_cycle(&mut self){
+        if self.cycle_accurate {
+            self.memory.tick(self.cycles);
+            self.sample_nmi();
+        }
+        self.memory.sync(self.cycles);
+        self.cycles += 1;
+    }
+
+    pub(crate) fn read_word(&mut self, address: u16) -> u16 {
+        let low_byte = self.read_byte(address);
+        let high_byte = self.read_byte(address.wrapping_add(1));
+        merge_bytes_into_word(high_byte, low_byte)
+    }
+
+    pub(crate) fn index_zero_page_indexed(&self, index: u8, offset: u8) -> u16 {
+        index.wrapping_add(offset) as u16
+    }
+
+    //The NMOS 6502 doesn't carry into the high byte of the pointer, JMP ($10FF) reads $10FF and $1000.
+    //The 65C02 fixes this at the cost of a cycle.
+    pub(crate) fn index_absolute_indirect(&mut self, index: u16) -> u16 {
+        match self.variant {
+            Variant::Nmos6502 => {
+                let low_byte = self.read_byte(index);
+                let high_byte = self.read_byte((index & 0xFF00) | (index.wrapping_add(1) & 0x00FF));
+                merge_bytes_into_word(high_byte, low_byte)
+            }
+            Variant::Wdc65C02 | Variant::Wdc65816 => {
+                self.dummy_read(self.registers.pc.wrapping_sub(1));
+                self.read_word(index)
+            }
+        }
+    }
+
+    pub(crate) fn index_absolute_indexed_indirect(&mut self, index: u16, offset: u8) -> u16 {
+        self.read_word(index.wrapping_add(offset as u16))
+    }
 
-        (self.memory[final_address as usize], final_address)
+    pub(crate) fn index_zero_page_indirect(&mut self, index: u8) -> u16 {
+        self.read_zero_page_word(index)
+    }
+
+    pub(crate) fn index_absolute_indexed(&self, index: u16, offset: u8) -> (u16, bool) {
+        let address = index.wrapping_add(offset as u16);
+        (address, page_crossed(index, address))
     }
 
-    pub fn index_zero_paged_indirect_indexed(&self, index: u8, offset: u8) -> (u8, bool, u16) {
-        let low_byte = self.memory[index as usize] as u16;
-        let high_byte = self.memory[index.wrapping_add(1) as usize] as u16;
-        let effective_address = merge_bytes_into_word_u16(high_byte, low_byte);
+    pub(crate) fn index_zero_paged_indexed_indirect(&mut self, index: u8, offset: u8) -> u16 {
+        let zero_page_address = index.wrapping_add(offset);
+        self.read_zero_page_word(zero_page_address)
+    }
+
+    pub(crate) fn index_zero_paged_indirect_indexed(&mut self, index: u8, offset: u8) -> (u16, bool) {
+        let effective_address = self.read_zero_page_word(index);
         let final_address = effective_address.wrapping_add(offset as u16);
 
-        (self.memory[final_address as usize], page_crossed(effective_address, final_address), final_address)
+        (final_address, page_crossed(effective_address, final_address))
+    }
+
+    //Pointers in the zero page wrap around within the zero page.
+    fn read_zero_page_word(&mut self, address: u8) -> u16 {
+        let low_byte = self.read_byte(address as u16);
+        let high_byte = self.read_byte(address.wrapping_add(1) as u16);
+        merge_bytes_into_word(high_byte, low_byte)
     }
 
     pub fn set_interrupt_vector(&mut self, high_byte: u8, low_byte: u8){
-        self.memory[0xFFFE] = low_byte;
-        self.memory[0xFFFF] = high_byte;
+        self.memory.write(0xFFFE, low_byte);
+        self.memory.write(0xFFFF, high_byte);
     }
-}
\ No newline at end of file
+}
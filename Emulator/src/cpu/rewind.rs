@@ -0,0 +1,219 @@
+use std::collections::VecDeque;
+use std::error::Error;
+use std::fmt;
+use crate::cpu::bus::Bus;
+use crate::cpu::cpu::CPU;
+use crate::cpu::execution::ExecutionError;
+use crate::cpu::save_state::SaveStateError;
+
+//A write made by an instruction. step counts the calls to step since rewinding was enabled.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct WriteRecord {
+    pub step: u64,
+    pub cycle: u32,
+    pub address: u32,
+    pub old_value: u8,
+    pub new_value: u8
+}
+
+struct Snapshot {
+    step: u64,
+    state: Vec<u8>
+}
+
+//Save states taken every few steps, and the writes and cycle counts in between. Going back restores the
+//snapshot before the target and replays the steps up to it, so the bus has to behave the same on every run.
+//Memory changed by the host between steps isn't recorded.
+pub struct Rewind {
+    interval: u64,
+    capacity: usize,
+    snapshots: VecDeque<Snapshot>,
+    journal: VecDeque<WriteRecord>,
+    //The cycle count at the start of every step since the oldest snapshot.
+    step_cycles: VecDeque<u32>,
+    first_step: u64,
+    steps: u64
+}
+
+#[derive(Debug)]
+pub enum RewindError {
+    Disabled,
+    //Going back that far needs history that was already dropped, available is how far back it goes.
+    OutOfHistory { available: u64 },
+    Execution(ExecutionError),
+    State(SaveStateError),
+}
+
+impl fmt::Display for RewindError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RewindError::Disabled => write!(f, "Rewinding isn't enabled."),
+            RewindError::OutOfHistory { available } => write!(f, "Only {} steps of history are available.", available),
+            RewindError::Execution(error) => write!(f, "Replay failed: {}", error),
+            RewindError::State(error) => write!(f, "Restoring a snapshot failed: {}", error),
+        }
+    }
+}
+
+impl Error for RewindError {}
+
+impl From<ExecutionError> for RewindError {
+    fn from(error: ExecutionError) -> Self {
+        RewindError::Execution(error)
+    }
+}
+
+impl From<SaveStateError> for RewindError {
+    fn from(error: SaveStateError) -> Self {
+        RewindError::State(error)
+    }
+}
+
+impl Rewind {
+
+    //Steps that can be undone.
+    pub fn available_steps(&self) -> u64 {
+        self.steps - self.first_step
+    }
+
+    //The recorded writes, oldest first.
+    pub fn journal(&self) -> impl Iterator<Item = &WriteRecord> {
+        self.journal.iter()
+    }
+
+    fn record_step(&mut self, snapshot: Option<Vec<u8>>, cycles: u32){
+        if let Some(state) = snapshot {
+            self.snapshots.push_back(Snapshot { step: self.steps, state });
+            if self.snapshots.len() > self.capacity {
+                self.snapshots.pop_front();
+                self.drop_history_before(self.snapshots[0].step);
+            }
+        }
+        self.step_cycles.push_back(cycles);
+        self.steps += 1;
+    }
+
+    fn drop_history_before(&mut self, step: u64){
+        self.step_cycles.drain(..(step - self.first_step) as usize);
+        while self.journal.front().is_some_and(|record| record.step < step) {
+            self.journal.pop_front();
+        }
+        self.first_step = step;
+    }
+
+    //Forgets everything from the given step on and returns the snapshot to replay from.
+    fn truncate_to(&mut self, step: u64) -> Snapshot {
+        let index = self.snapshots.iter().rposition(|snapshot| snapshot.step <= step).unwrap_or(0);
+        self.snapshots.truncate(index + 1);
+        let snapshot = self.snapshots.pop_back().expect("The oldest snapshot is never dropped.");
+
+        self.step_cycles.truncate((snapshot.step - self.first_step) as usize);
+        while self.journal.back().is_some_and(|record| record.step >= snapshot.step) {
+            self.journal.pop_back();
+        }
+        self.steps = snapshot.step;
+        snapshot
+    }
+}
+
+impl<B: Bus> CPU<B> {
+
+    //Records a snapshot every snapshot_interval steps and keeps the last snapshots of them, starting now.
+    pub fn enable_rewind(&mut self, snapshot_interval: u64, snapshots: usize){
+        self.rewind = Some(Rewind {
+            interval: snapshot_interval.max(1),
+            capacity: snapshots.max(1),
+            snapshots: VecDeque::new(),
+            journal: VecDeque::new(),
+            step_cycles: VecDeque::new(),
+            first_step: 0,
+            steps: 0
+        });
+    }
+
+    pub fn disable_rewind(&mut self){
+        self.rewind = None;
+    }
+
+    pub fn rewind(&self) -> Option<&Rewind> {
+        self.rewind.as_ref()
+    }
+
+    //Goes back the given number of steps.
+    pub fn step_back(&mut self, steps: u64) -> Result<(), RewindError> {
+        let rewind = self.rewind.as_ref().ok_or(RewindError::Disabled)?;
+        let available = rewind.available_steps();
+        if steps > available {
+            return Err(RewindError::OutOfHistory { available });
+        }
+
+        self.rewind_to(rewind.steps - steps)
+    }
+
+    //Goes back to the start of the instruction that was running the given number of cycles ago.
+    pub fn step_back_cycles(&mut self, cycles: u32) -> Result<(), RewindError> {
+        let rewind = self.rewind.as_ref().ok_or(RewindError::Disabled)?;
+        if cycles == 0 {
+            return Ok(());
+        }
+        let target = self.cycles.checked_sub(cycles);
+        let index = target.and_then(|target| rewind.step_cycles.iter().rposition(|&start| start <= target));
+        match index {
+            Some(index) => self.rewind_to(rewind.first_step + index as u64),
+            None => Err(RewindError::OutOfHistory { available: rewind.available_steps() })
+        }
+    }
+
+    //Goes back to just before the last recorded write to the address, and returns that write.
+    //Returns None and stays put when the address wasn't written.
+    pub fn reverse_continue_to_write(&mut self, address: u32) -> Result<Option<WriteRecord>, RewindError> {
+        let rewind = self.rewind.as_ref().ok_or(RewindError::Disabled)?;
+        let Some(&record) = rewind.journal.iter().rev().find(|record| record.address == address) else {
+            return Ok(None);
+        };
+
+        self.rewind_to(record.step)?;
+        Ok(Some(record))
+    }
+
+    fn rewind_to(&mut self, step: u64) -> Result<(), RewindError> {
+        let Some(rewind) = self.rewind.as_mut() else { return Err(RewindError::Disabled) };
+        if step == rewind.steps {
+            return Ok(());
+        }
+
+        let snapshot = rewind.truncate_to(step);
+        self.load_state(&snapshot.state)?;
+        //The replayed steps were already traced when they first ran.
+        let tracer = self.tracer.take();
+        let mut replayed = Ok(());
+        while self.rewind.as_ref().is_some_and(|rewind| rewind.steps < step) {
+            if let Err(error) = self.step() {
+                replayed = Err(error.into());
+                break;
+            }
+        }
+        self.tracer = tracer;
+
+        replayed
+    }
+
+    //Called at the start of every step.
+    pub(crate) fn record_rewind_step(&mut self){
+        let Some(rewind) = self.rewind.as_ref() else { return };
+        let snapshot = (rewind.steps % rewind.interval == 0).then(|| self.save_state());
+        let cycles = self.cycles;
+        if let Some(rewind) = self.rewind.as_mut() {
+            rewind.record_step(snapshot, cycles);
+        }
+    }
+
+    //Called before every write the CPU makes.
+    pub(crate) fn record_rewind_write(&mut self, address: u32, value: u8){
+        let old_value = self.memory.peek_long(address);
+        let cycle = self.cycles;
+        if let Some(rewind) = self.rewind.as_mut() {
+            rewind.journal.push_back(WriteRecord { step: rewind.steps.saturating_sub(1), cycle, address, old_value, new_value: value });
+        }
+    }
+}
@@ -0,0 +1,116 @@
+use crate::cpu::bus::Bus;
+use crate::cpu::cpu::{Variant, CPU};
+use crate::cpu::execution::{ExecutionError, StepOutcome};
+
+const BRK: u8 = 0x00;
+const RTS: u8 = 0x60;
+//65816 only.
+const RTL: u8 = 0x6B;
+const MVP: u8 = 0x44;
+const MVN: u8 = 0x54;
+
+//Why a run returned. pc is the address of the instruction that caused the stop.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum StopReason {
+    //run_cycles spent its budget.
+    CycleBudget,
+    //run_instructions took its number of steps.
+    InstructionBudget,
+    //The condition given to run_until returned true.
+    Condition,
+    //A BRK was executed, the CPU is at the start of the handler.
+    Brk { pc: u16 },
+    //A JAM opcode or STP halted the CPU, PC stays on it.
+    Jammed { pc: u16 },
+    //The next instruction is on a breakpoint.
+    Breakpoint { pc: u16 },
+    //The instruction jumped or branched to itself, it would run forever.
+    SelfJump { pc: u16 },
+    //RTS or RTL popped the stack above where it was when the run started.
+    ReturnFromTopFrame { pc: u16 },
+}
+
+impl<B: Bus> CPU<B> {
+
+    //Runs until at least the given number of cycles have passed.
+    pub fn run_cycles(&mut self, cycles: u32) -> Result<StopReason, ExecutionError> {
+        let start = self.cycles;
+        self.run(|cpu, _| (cpu.cycles.wrapping_sub(start) >= cycles).then_some(StopReason::CycleBudget))
+    }
+
+    //Runs the given number of steps. Entering an interrupt handler or a cycle spent waiting counts as one.
+    pub fn run_instructions(&mut self, count: usize) -> Result<StopReason, ExecutionError> {
+        self.run(|_, steps| (steps >= count).then_some(StopReason::InstructionBudget))
+    }
+
+    //Runs until the condition holds, it is checked before every step.
+    pub fn run_until<F: FnMut(&Self) -> bool>(&mut self, mut condition: F) -> Result<StopReason, ExecutionError> {
+        self.run(|cpu, _| condition(cpu).then_some(StopReason::Condition))
+    }
+
+    pub fn add_breakpoint(&mut self, address: u16){
+        self.breakpoints.insert(address);
+    }
+
+    pub fn remove_breakpoint(&mut self, address: u16){
+        self.breakpoints.remove(&address);
+    }
+
+    //A breakpoint on the instruction the run starts at doesn't stop it, so a stopped run can be resumed.
+    fn run<F: FnMut(&Self, usize) -> Option<StopReason>>(&mut self, mut limit: F) -> Result<StopReason, ExecutionError> {
+        let top_of_stack = self.get_s();
+        let mut steps = 0;
+
+        loop {
+            if let Some(reason) = limit(self, steps) {
+                return Ok(reason);
+            }
+            let pc = self.registers.pc;
+            if steps > 0 && self.breakpoints.contains(&pc) {
+                return Ok(StopReason::Breakpoint { pc });
+            }
+
+            let outcome = self.step()?;
+            steps += 1;
+            match outcome {
+                StepOutcome::Executed { pc, opcode } => {
+                    if let Some(reason) = self.stop_after(pc, opcode, top_of_stack) {
+                        return Ok(reason);
+                    }
+                }
+                StepOutcome::Halted => return Ok(StopReason::Jammed { pc }),
+                _ => {}
+            }
+        }
+    }
+
+    fn stop_after(&self, pc: u16, opcode: u8, top_of_stack: u16) -> Option<StopReason> {
+        let native = self.variant == Variant::Wdc65816;
+        if self.jammed {
+            Some(StopReason::Jammed { pc })
+        }
+        else if opcode == BRK {
+            Some(StopReason::Brk { pc })
+        }
+        else if (opcode == RTS || (native && opcode == RTL)) && self.stack_above(top_of_stack) {
+            Some(StopReason::ReturnFromTopFrame { pc })
+        }
+        //The block moves repeat themselves until they are done.
+        else if self.registers.pc == pc && !(native && (opcode == MVP || opcode == MVN)) {
+            Some(StopReason::SelfJump { pc })
+        }
+        else {
+            None
+        }
+    }
+
+    //The stack wraps inside page 1 unless the 65816 is in native mode.
+    fn stack_above(&self, top_of_stack: u16) -> bool {
+        let distance = self.get_s().wrapping_sub(top_of_stack);
+        if self.flags.emulation || self.variant != Variant::Wdc65816 {
+            distance as i8 > 0
+        } else {
+            distance as i16 > 0
+        }
+    }
+}
@@ -0,0 +1,337 @@
+use std::error::Error;
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::Path;
+use crate::cpu::bus::Bus;
+use crate::cpu::cpu::{CPU, Flags, Registers, Variant};
+use crate::cpu::interrupts::Interrupts;
+use crate::cpu::undocumented::UnstableConstants;
+
+//A save state is the magic, a version and a list of tagged sections, followed by a CRC-32 of everything before it.
+//Each section is its tag, its length and its data. Readers skip sections they don't know, ignore data after the
+//fields they know at the end of a section and keep the current value for sections that are missing. New sections
+//and fields at the end of a section don't change the version, it only goes up when existing fields change.
+const MAGIC: &[u8; 4] = b"R65S";
+pub const SAVE_STATE_VERSION: u16 = 1;
+
+const CPU_SECTION: &[u8; 4] = b"CPU ";
+const INTERRUPT_SECTION: &[u8; 4] = b"INTR";
+const BUS_SECTION: &[u8; 4] = b"BUS ";
+
+#[derive(Debug)]
+pub enum SaveStateError {
+    BadMagic,
+    //The file was written by a newer version that changed the layout of existing sections.
+    UnsupportedVersion(u16),
+    ChecksumMismatch { expected: u32, actual: u32 },
+    Truncated,
+    Invalid(String),
+    Io(io::Error),
+}
+
+impl fmt::Display for SaveStateError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SaveStateError::BadMagic => write!(f, "Not a save state."),
+            SaveStateError::UnsupportedVersion(version) => {
+                write!(f, "Save state version {} is newer than the supported version {}.", version, SAVE_STATE_VERSION)
+            }
+            SaveStateError::ChecksumMismatch { expected, actual } => {
+                write!(f, "Save state checksum is {:#010X}, expected {:#010X}.", actual, expected)
+            }
+            SaveStateError::Truncated => write!(f, "Save state ends early."),
+            SaveStateError::Invalid(reason) => write!(f, "Invalid save state: {}", reason),
+            SaveStateError::Io(error) => write!(f, "{}", error),
+        }
+    }
+}
+
+impl Error for SaveStateError {}
+
+impl From<io::Error> for SaveStateError {
+    fn from(error: io::Error) -> Self {
+        SaveStateError::Io(error)
+    }
+}
+
+//Little endian values appended to a buffer.
+#[derive(Default)]
+pub struct StateWriter {
+    bytes: Vec<u8>
+}
+
+impl StateWriter {
+
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn write_u8(&mut self, value: u8){
+        self.bytes.push(value);
+    }
+
+    pub fn write_bool(&mut self, value: bool){
+        self.write_u8(value as u8);
+    }
+
+    pub fn write_u16(&mut self, value: u16){
+        self.bytes.extend_from_slice(&value.to_le_bytes());
+    }
+
+    pub fn write_u32(&mut self, value: u32){
+        self.bytes.extend_from_slice(&value.to_le_bytes());
+    }
+
+    //Length prefixed, read back with read_bytes.
+    pub fn write_bytes(&mut self, bytes: &[u8]){
+        self.write_u32(bytes.len() as u32);
+        self.bytes.extend_from_slice(bytes);
+    }
+
+    pub fn into_bytes(self) -> Vec<u8> {
+        self.bytes
+    }
+}
+
+pub struct StateReader<'a> {
+    bytes: &'a [u8],
+    position: usize
+}
+
+impl<'a> StateReader<'a> {
+
+    pub fn new(bytes: &'a [u8]) -> Self {
+        StateReader { bytes, position: 0 }
+    }
+
+    //Whether anything is left, fields added in later versions are only read when it is.
+    pub fn has_remaining(&self) -> bool {
+        self.position < self.bytes.len()
+    }
+
+    fn take(&mut self, count: usize) -> Result<&'a [u8], SaveStateError> {
+        let end = self.position.checked_add(count).filter(|&end| end <= self.bytes.len()).ok_or(SaveStateError::Truncated)?;
+        let bytes = &self.bytes[self.position..end];
+        self.position = end;
+        Ok(bytes)
+    }
+
+    pub fn read_u8(&mut self) -> Result<u8, SaveStateError> {
+        Ok(self.take(1)?[0])
+    }
+
+    pub fn read_bool(&mut self) -> Result<bool, SaveStateError> {
+        Ok(self.read_u8()? != 0)
+    }
+
+    pub fn read_u16(&mut self) -> Result<u16, SaveStateError> {
+        let bytes = self.take(2)?;
+        Ok(u16::from_le_bytes([bytes[0], bytes[1]]))
+    }
+
+    pub fn read_u32(&mut self) -> Result<u32, SaveStateError> {
+        let bytes = self.take(4)?;
+        Ok(u32::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]))
+    }
+
+    pub fn read_bytes(&mut self) -> Result<&'a [u8], SaveStateError> {
+        let length = self.read_u32()? as usize;
+        self.take(length)
+    }
+}
+
+//CRC-32 as used by zip and PNG.
+pub(crate) fn checksum(bytes: &[u8]) -> u32 {
+    let mut crc = 0xFFFFFFFFu32;
+    for &byte in bytes {
+        crc ^= byte as u32;
+        for _ in 0..8 {
+            let mask = (crc & 1).wrapping_neg();
+            crc = (crc >> 1) ^ (0xEDB88320 & mask);
+        }
+    }
+
+    !crc
+}
+
+fn write_section(writer: &mut StateWriter, tag: &[u8; 4], section: StateWriter){
+    writer.bytes.extend_from_slice(tag);
+    writer.write_bytes(&section.into_bytes());
+}
+
+//The CPU section read into its own values, so nothing changes until the whole file is known to load.
+struct CpuState {
+    variant: Variant,
+    cycles: u32,
+    jammed: bool,
+    waiting: bool,
+    cycle_accurate: bool,
+    registers: Registers,
+    flags: Flags,
+    unstable_constants: UnstableConstants
+}
+
+impl<B: Bus> CPU<B> {
+
+    //Snapshots the registers, flags, cycle count, interrupt lines and everything the bus saves.
+    pub fn save_state(&self) -> Vec<u8> {
+        let mut writer = StateWriter::new();
+        writer.bytes.extend_from_slice(MAGIC);
+        writer.write_u16(SAVE_STATE_VERSION);
+
+        let mut cpu = StateWriter::new();
+        self.save_cpu_state(&mut cpu);
+        write_section(&mut writer, CPU_SECTION, cpu);
+
+        let mut interrupts = StateWriter::new();
+        self.interrupts.save_state(&mut interrupts);
+        write_section(&mut writer, INTERRUPT_SECTION, interrupts);
+
+        let mut bus = StateWriter::new();
+        self.memory.save_state(&mut bus);
+        write_section(&mut writer, BUS_SECTION, bus);
+
+        let crc = checksum(&writer.bytes);
+        writer.write_u32(crc);
+        writer.into_bytes()
+    }
+
+    //The header, checksum and every section are checked before anything is restored, so a state that doesn't
+    //load leaves the CPU as it was. The bus section is loaded last and has to check its data before using it.
+    pub fn load_state(&mut self, bytes: &[u8]) -> Result<(), SaveStateError> {
+        if bytes.len() < MAGIC.len() + 2 + 4 {
+            return Err(SaveStateError::Truncated);
+        }
+        if &bytes[..MAGIC.len()] != MAGIC {
+            return Err(SaveStateError::BadMagic);
+        }
+        let (body, crc) = bytes.split_at(bytes.len() - 4);
+        let expected = u32::from_le_bytes([crc[0], crc[1], crc[2], crc[3]]);
+        let actual = checksum(body);
+        if expected != actual {
+            return Err(SaveStateError::ChecksumMismatch { expected, actual });
+        }
+
+        let mut reader = StateReader::new(&body[MAGIC.len()..]);
+        let version = reader.read_u16()?;
+        if version > SAVE_STATE_VERSION {
+            return Err(SaveStateError::UnsupportedVersion(version));
+        }
+
+        let mut sections = Vec::new();
+        while reader.has_remaining() {
+            let tag = reader.take(4)?;
+            sections.push((tag, reader.read_bytes()?));
+        }
+        let section = |tag: &[u8; 4]| sections.iter().rev().find(|(name, _)| name == tag).map(|&(_, bytes)| StateReader::new(bytes));
+
+        let cpu = section(CPU_SECTION).map(|mut section| load_cpu_state(&mut section)).transpose()?;
+        let interrupts = section(INTERRUPT_SECTION).map(|mut section| Interrupts::load_state(&mut section)).transpose()?;
+        if let Some(mut section) = section(BUS_SECTION) {
+            self.memory.load_state(&mut section)?;
+        }
+
+        if let Some(cpu) = cpu {
+            self.variant = cpu.variant;
+            self.cycles = cpu.cycles;
+            self.jammed = cpu.jammed;
+            self.waiting = cpu.waiting;
+            self.cycle_accurate = cpu.cycle_accurate;
+            self.registers = cpu.registers;
+            self.flags = cpu.flags;
+            self.unstable_constants = cpu.unstable_constants;
+        }
+        if let Some(interrupts) = interrupts {
+            self.interrupts = interrupts;
+        }
+
+        Ok(())
+    }
+
+    pub fn save_state_to_file<P: AsRef<Path>>(&self, path: P) -> io::Result<()> {
+        fs::write(path, self.save_state())
+    }
+
+    pub fn load_state_from_file<P: AsRef<Path>>(&mut self, path: P) -> Result<(), SaveStateError> {
+        let bytes = fs::read(path)?;
+        self.load_state(&bytes)
+    }
+
+    fn save_cpu_state(&self, writer: &mut StateWriter){
+        writer.write_u8(match self.variant {
+            Variant::Nmos6502 => 0,
+            Variant::Wdc65C02 => 1,
+            Variant::Wdc65816 => 2
+        });
+        writer.write_u32(self.cycles);
+        writer.write_bool(self.jammed);
+        writer.write_bool(self.waiting);
+        writer.write_bool(self.cycle_accurate);
+
+        let registers = &self.registers;
+        writer.write_u8(registers.acc);
+        writer.write_u16(registers.pc);
+        writer.write_u8(registers.xr);
+        writer.write_u8(registers.yr);
+        writer.write_u8(registers.sp);
+        writer.write_u8(registers.acc_high);
+        writer.write_u8(registers.xr_high);
+        writer.write_u8(registers.yr_high);
+        writer.write_u8(registers.sp_high);
+        writer.write_u16(registers.direct_page);
+        writer.write_u8(registers.data_bank);
+        writer.write_u8(registers.program_bank);
+
+        writer.write_u8(self.flags.to_byte());
+        writer.write_bool(self.flags.emulation);
+        writer.write_bool(self.flags.memory_select);
+        writer.write_bool(self.flags.index_select);
+
+        writer.write_u8(self.unstable_constants.ane);
+        writer.write_u8(self.unstable_constants.lxa);
+    }
+}
+
+fn load_cpu_state(reader: &mut StateReader) -> Result<CpuState, SaveStateError> {
+    let variant = match reader.read_u8()? {
+        0 => Variant::Nmos6502,
+        1 => Variant::Wdc65C02,
+        2 => Variant::Wdc65816,
+        variant => return Err(SaveStateError::Invalid(format!("unknown variant {}", variant)))
+    };
+    let cycles = reader.read_u32()?;
+    let jammed = reader.read_bool()?;
+    let waiting = reader.read_bool()?;
+    let cycle_accurate = reader.read_bool()?;
+
+    let registers = Registers {
+        acc: reader.read_u8()?,
+        pc: reader.read_u16()?,
+        xr: reader.read_u8()?,
+        yr: reader.read_u8()?,
+        sp: reader.read_u8()?,
+        acc_high: reader.read_u8()?,
+        xr_high: reader.read_u8()?,
+        yr_high: reader.read_u8()?,
+        sp_high: reader.read_u8()?,
+        direct_page: reader.read_u16()?,
+        data_bank: reader.read_u8()?,
+        program_bank: reader.read_u8()?
+    };
+
+    let status = reader.read_u8()?;
+    let flags = Flags {
+        emulation: reader.read_bool()?,
+        memory_select: reader.read_bool()?,
+        index_select: reader.read_bool()?,
+        ..Flags::from_byte(status)
+    };
+
+    let unstable_constants = UnstableConstants {
+        ane: reader.read_u8()?,
+        lxa: reader.read_u8()?
+    };
+
+    Ok(CpuState { variant, cycles, jammed, waiting, cycle_accurate, registers, flags, unstable_constants })
+}
@@ -1,33 +1,38 @@
+use crate::cpu::bus::Bus;
 use crate::cpu::cpu::CPU;
 use crate::helpers::bitwise::{merge_bytes_into_word, split_word_into_bytes};
 
-impl CPU{
-    pub fn convert_address_to_stack(address: u8) -> usize {
-        0x100 + (address as u16) as usize
+impl<B: Bus> CPU<B>{
+    pub(crate) fn convert_address_to_stack(address: u8) -> u16 {
+        0x100 + address as u16
     }
 
-    pub fn push_byte_to_stack(&mut self, value: u8){
-        let address = CPU::convert_address_to_stack(self.registers.sp);
-        self.memory[address] = value;
+    pub(crate) fn push_byte_to_stack(&mut self, value: u8){
+        let address = Self::convert_address_to_stack(self.registers.sp);
+        self.write_byte(address, value);
         self.registers.sp = self.registers.sp.wrapping_sub(1);
     }
 
-    pub fn push_word_to_stack(&mut self, value: u16){
+    pub(crate) fn push_word_to_stack(&mut self, value: u16){
         let (high_byte, low_byte) = split_word_into_bytes(value);
         self.push_byte_to_stack(high_byte);
         self.push_byte_to_stack(low_byte);
     }
 
-    pub fn pop_byte_from_stack(&mut self) -> Option<u8> {
+    pub(crate) fn pop_byte_from_stack(&mut self) -> u8 {
         self.registers.sp = self.registers.sp.wrapping_add(1);
-        let value = self.memory[CPU::convert_address_to_stack(self.registers.sp)];
-        Some(value)
+        self.read_byte(Self::convert_address_to_stack(self.registers.sp))
     }
 
-    pub fn pop_word_from_stack(&mut self) -> Option<u16>{
-        let low_byte = self.pop_byte_from_stack().unwrap();
-        let high_byte = self.pop_byte_from_stack().unwrap();
+    //Pulls spend a cycle reading the stack before the stack pointer is incremented.
+    pub(crate) fn dummy_read_stack(&mut self){
+        self.dummy_read(Self::convert_address_to_stack(self.registers.sp));
+    }
+
+    pub(crate) fn pop_word_from_stack(&mut self) -> u16 {
+        let low_byte = self.pop_byte_from_stack();
+        let high_byte = self.pop_byte_from_stack();
 
-        Some(merge_bytes_into_word(high_byte, low_byte))
+        merge_bytes_into_word(high_byte, low_byte)
     }
-}
\ No newline at end of file
+}
@@ -0,0 +1,291 @@
+use std::fs::File;
+use std::io::{self, BufWriter, Write};
+use std::path::Path;
+use crate::cpu::bus::Bus;
+use crate::cpu::cpu::{Flags, Variant, CPU};
+use crate::cpu::instructions::AddressingMode;
+use crate::disassembler::Disassembler;
+
+//How every traced instruction is written.
+#[derive(Debug, Clone, PartialEq)]
+pub enum TraceFormat {
+    //The nestest.log layout without the PPU column:
+    //C000  4C F5 C5  JMP $C5F5                       A:00 X:00 Y:00 P:24 SP:FD CYC:7
+    Nestest,
+    //Any text, {pc} {bytes} {mnemonic} {operand} {resolved} {disassembly} {a} {x} {y} {p} {flags} {sp} and {cycles}
+    //are replaced by their values.
+    Template(String),
+}
+
+//The CPU before an instruction runs. Everything is peeked, tracing doesn't touch the bus.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TraceLine {
+    pub pc: u32,
+    pub bytes: Vec<u8>,
+    pub mnemonic: &'static str,
+    pub undocumented: bool,
+    //The operand as it is written in assembly, like $0300,X.
+    pub operand: String,
+    //Where the operand points and what is there, like @ 0300 = 00. Empty for the 65816 and instructions without one.
+    pub resolved: String,
+    pub acc: u16,
+    pub xr: u16,
+    pub yr: u16,
+    pub sp: u16,
+    //P as PHP would push it, without B.
+    pub status: u8,
+    pub flags: Flags,
+    pub cycles: u32,
+    //Registers that are 16-bit on the 65816 are printed with four digits.
+    pub wide_accumulator: bool,
+    pub wide_index: bool,
+    pub wide_stack: bool,
+}
+
+impl TraceLine {
+
+    //The mnemonic, operand and resolved value, like LDA $0300,X @ 0300 = 00.
+    pub fn disassembly(&self) -> String {
+        [self.mnemonic, &self.operand, &self.resolved].iter()
+            .filter(|part| !part.is_empty())
+            .copied()
+            .collect::<Vec<_>>()
+            .join(" ")
+    }
+
+    pub fn format(&self, format: &TraceFormat) -> String {
+        match format {
+            TraceFormat::Nestest => self.format_nestest(),
+            TraceFormat::Template(template) => self.format_template(template)
+        }
+    }
+
+    fn format_nestest(&self) -> String {
+        format!("{:04X}  {:<8} {}{:<32}A:{} X:{} Y:{} P:{:02X} SP:{} CYC:{}",
+            self.pc,
+            self.bytes_text(),
+            if self.undocumented { '*' } else { ' ' },
+            self.disassembly(),
+            hex(self.acc, self.wide_accumulator),
+            hex(self.xr, self.wide_index),
+            hex(self.yr, self.wide_index),
+            self.status,
+            hex(self.sp, self.wide_stack),
+            self.cycles
+        )
+    }
+
+    //Unknown placeholders are left as they are.
+    fn format_template(&self, template: &str) -> String {
+        let mut line = String::with_capacity(template.len() * 2);
+        let mut rest = template;
+        while let Some(start) = rest.find('{') {
+            line.push_str(&rest[..start]);
+            rest = &rest[start..];
+            let Some(end) = rest.find('}') else { break };
+            match self.placeholder(&rest[1..end]) {
+                Some(value) => line.push_str(&value),
+                None => line.push_str(&rest[..=end])
+            }
+            rest = &rest[end + 1..];
+        }
+        line.push_str(rest);
+
+        line
+    }
+
+    fn placeholder(&self, name: &str) -> Option<String> {
+        Some(match name {
+            "pc" if self.pc > 0xFFFF => format!("{:06X}", self.pc),
+            "pc" => format!("{:04X}", self.pc),
+            "bytes" => self.bytes_text(),
+            "mnemonic" => self.mnemonic.to_string(),
+            "operand" => self.operand.clone(),
+            "resolved" => self.resolved.clone(),
+            "disassembly" => self.disassembly(),
+            "a" => hex(self.acc, self.wide_accumulator),
+            "x" => hex(self.xr, self.wide_index),
+            "y" => hex(self.yr, self.wide_index),
+            "p" => format!("{:02X}", self.status),
+            "flags" => self.flags.to_string(),
+            "sp" => hex(self.sp, self.wide_stack),
+            "cycles" => self.cycles.to_string(),
+            _ => return None
+        })
+    }
+
+    fn bytes_text(&self) -> String {
+        self.bytes.iter().map(|byte| format!("{:02X}", byte)).collect::<Vec<_>>().join(" ")
+    }
+}
+
+fn hex(value: u16, wide: bool) -> String {
+    if wide { format!("{:04X}", value) } else { format!("{:02X}", value as u8) }
+}
+
+enum TraceOutput {
+    Writer(Box<dyn Write>),
+    Callback(Box<dyn FnMut(&str)>),
+}
+
+pub(crate) struct Tracer {
+    format: TraceFormat,
+    output: TraceOutput,
+    //The first write that failed, tracing to the writer stops there.
+    error: Option<io::Error>
+}
+
+impl Tracer {
+
+    fn write(&mut self, line: &TraceLine){
+        let text = line.format(&self.format);
+        match &mut self.output {
+            TraceOutput::Writer(writer) => {
+                if self.error.is_none() {
+                    self.error = writeln!(writer, "{}", text).err();
+                }
+            }
+            TraceOutput::Callback(callback) => callback(&text)
+        }
+    }
+}
+
+impl<B: Bus> CPU<B> {
+
+    //Writes a line for every instruction to the file, replacing it.
+    pub fn trace_to_file<P: AsRef<Path>>(&mut self, path: P, format: TraceFormat) -> io::Result<()> {
+        let file = File::create(path)?;
+        self.trace_to_writer(BufWriter::new(file), format);
+        Ok(())
+    }
+
+    pub fn trace_to_writer<W: Write + 'static>(&mut self, writer: W, format: TraceFormat){
+        self.start_trace(format, TraceOutput::Writer(Box::new(writer)));
+    }
+
+    //Calls back with every line, without the newline.
+    pub fn trace_with<F: FnMut(&str) + 'static>(&mut self, format: TraceFormat, callback: F){
+        self.start_trace(format, TraceOutput::Callback(Box::new(callback)));
+    }
+
+    //Flushes the writer and returns the first error writing to it.
+    pub fn stop_trace(&mut self) -> io::Result<()> {
+        let Some(tracer) = self.tracer.take() else { return Ok(()) };
+        if let Some(error) = tracer.error {
+            return Err(error);
+        }
+        match tracer.output {
+            TraceOutput::Writer(mut writer) => writer.flush(),
+            TraceOutput::Callback(_) => Ok(())
+        }
+    }
+
+    pub fn is_tracing(&self) -> bool {
+        self.tracer.is_some()
+    }
+
+    fn start_trace(&mut self, format: TraceFormat, output: TraceOutput){
+        //Errors from a trace that is replaced have nowhere to go.
+        let _ = self.stop_trace();
+        self.tracer = Some(Tracer { format, output, error: None });
+    }
+
+    //Called by step before the instruction is fetched, only while tracing.
+    pub(crate) fn trace_instruction(&mut self){
+        let line = self.trace_line();
+        if let Some(tracer) = self.tracer.as_mut() {
+            tracer.write(&line);
+        }
+    }
+
+    //Describes the instruction at PC and the registers before it runs.
+    pub fn trace_line(&self) -> TraceLine {
+        let native = self.variant == Variant::Wdc65816;
+        let pc = if native { self.get_program_address() } else { self.registers.pc as u32 };
+        //Operand bytes wrap inside the program bank like the fetches do.
+        let bytes: Vec<u8> = (0..4u16)
+            .map(|offset| self.memory.peek_long((pc & 0xFF0000) | (pc as u16).wrapping_add(offset) as u32))
+            .collect();
+        let instruction = Disassembler::new(&bytes, pc)
+            .with_variant(self.variant)
+            .with_register_widths(native && self.accumulator_is_16bit(), native && self.index_is_16bit())
+            .next()
+            .expect("There is always an opcode byte.");
+        let resolved = match instruction.address_mode {
+            Some(mode) if !native => self.resolve_operand_text(mode, instruction.mnemonic, &instruction.bytes[1..]),
+            _ => String::new()
+        };
+
+        TraceLine {
+            pc,
+            undocumented: Self::is_undocumented(self.variant, bytes[0]),
+            bytes: instruction.bytes,
+            mnemonic: instruction.mnemonic,
+            operand: instruction.operand,
+            resolved,
+            acc: if native { self.get_c() } else { self.registers.acc as u16 },
+            xr: if native { self.get_x() } else { self.registers.xr as u16 },
+            yr: if native { self.get_y() } else { self.registers.yr as u16 },
+            sp: self.get_s(),
+            status: if native { self.flags.to_native_byte(false) } else { self.flags.to_pushed_byte(false) },
+            flags: self.flags.clone(),
+            cycles: self.cycles,
+            wide_accumulator: native && self.accumulator_is_16bit(),
+            wide_index: native && self.index_is_16bit(),
+            wide_stack: native && !self.flags.emulation
+        }
+    }
+
+    //The effective address and the value there, the way nestest.log shows them.
+    fn resolve_operand_text(&self, mode: AddressingMode, mnemonic: &str, operand: &[u8]) -> String {
+        let peek = |address: u16| self.memory.peek(address);
+        let peek_word = |low: u16, high: u16| (peek(high) as u16) << 8 | peek(low) as u16;
+        let zero_page_word = |address: u8| peek_word(address as u16, address.wrapping_add(1) as u16);
+        let byte = operand.first().copied().unwrap_or(0);
+        let word = (operand.get(1).copied().unwrap_or(0) as u16) << 8 | byte as u16;
+        let (xr, yr) = (self.registers.xr, self.registers.yr);
+
+        match mode {
+            AddressingMode::ZeroPage => format!("= {:02X}", peek(byte as u16)),
+            AddressingMode::Absolute if mnemonic == "JMP" || mnemonic == "JSR" => String::new(),
+            AddressingMode::Absolute => format!("= {:02X}", peek(word)),
+            AddressingMode::XIndexedZeroPage | AddressingMode::YIndexedZeroPage => {
+                let index = if mode == AddressingMode::XIndexedZeroPage { xr } else { yr };
+                let address = byte.wrapping_add(index);
+                format!("@ {:02X} = {:02X}", address, peek(address as u16))
+            }
+            AddressingMode::XIndexedAbsolute | AddressingMode::YIndexedAbsolute => {
+                let index = if mode == AddressingMode::XIndexedAbsolute { xr } else { yr };
+                let address = word.wrapping_add(index as u16);
+                format!("@ {:04X} = {:02X}", address, peek(address))
+            }
+            AddressingMode::AbsoluteIndirect => {
+                //The NMOS 6502 doesn't carry into the high byte of the pointer.
+                let high = match self.variant {
+                    Variant::Nmos6502 => (word & 0xFF00) | (word.wrapping_add(1) & 0x00FF),
+                    _ => word.wrapping_add(1)
+                };
+                format!("= {:04X}", peek_word(word, high))
+            }
+            AddressingMode::XIndexedAbsoluteIndirect => {
+                let pointer = word.wrapping_add(xr as u16);
+                format!("= {:04X}", peek_word(pointer, pointer.wrapping_add(1)))
+            }
+            AddressingMode::XIndexedZeroPageIndirect => {
+                let pointer = byte.wrapping_add(xr);
+                let address = zero_page_word(pointer);
+                format!("@ {:02X} = {:04X} = {:02X}", pointer, address, peek(address))
+            }
+            AddressingMode::ZeroPageIndirectYIndexed => {
+                let base = zero_page_word(byte);
+                let address = base.wrapping_add(yr as u16);
+                format!("= {:04X} @ {:04X} = {:02X}", base, address, peek(address))
+            }
+            AddressingMode::ZeroPageIndirect => {
+                let address = zero_page_word(byte);
+                format!("= {:04X} = {:02X}", address, peek(address))
+            }
+            _ => String::new()
+        }
+    }
+}
@@ -0,0 +1,291 @@
+use crate::cpu::bus::Bus;
+use crate::cpu::cpu::CPU;
+use crate::cpu::instructions::InstructionParameter;
+use crate::helpers::bitwise::get_msb;
+
+//ANE and LXA OR the accumulator with a value that differs between chips, and even with temperature.
+pub struct UnstableConstants {
+    pub ane: u8,
+    pub lxa: u8
+}
+
+impl Default for UnstableConstants {
+    fn default() -> Self {
+        UnstableConstants {
+            ane: 0xEE,
+            lxa: 0xEE
+        }
+    }
+}
+
+//The opcodes the NMOS 6502 doesn't document. Most combine a read-modify-write with an ALU operation.
+impl<B: Bus> CPU<B> {
+
+    pub(crate) fn op_alr(&mut self, parameter: InstructionParameter) -> Option<u8> {
+        match parameter {
+            InstructionParameter::Byte(value) => {
+                let and = self.registers.acc & value;
+                self.flags.carry = and & 1 != 0;
+                self.registers.acc = and >> 1;
+                self.flags.zero = self.registers.acc == 0;
+                self.flags.negative = false;
+
+                None
+            }
+            _ => { None }
+        }
+    }
+
+    pub(crate) fn op_anc(&mut self, parameter: InstructionParameter) -> Option<u8> {
+        match parameter {
+            InstructionParameter::Byte(value) => {
+                self.registers.acc &= value;
+                self.flags.zero = self.registers.acc == 0;
+                self.flags.negative = get_msb(self.registers.acc) != 0;
+                self.flags.carry = self.flags.negative;
+
+                None
+            }
+            _ => { None }
+        }
+    }
+
+    pub(crate) fn op_ane(&mut self, parameter: InstructionParameter) -> Option<u8> {
+        match parameter {
+            InstructionParameter::Byte(value) => {
+                self.registers.acc = (self.registers.acc | self.unstable_constants.ane) & self.registers.xr & value;
+                self.flags.zero = self.registers.acc == 0;
+                self.flags.negative = get_msb(self.registers.acc) != 0;
+
+                None
+            }
+            _ => { None }
+        }
+    }
+
+    //AND followed by ROR A, with C and V taken from bits 6 and 5. In decimal mode both digits get a BCD fixup.
+    pub(crate) fn op_arr(&mut self, parameter: InstructionParameter) -> Option<u8> {
+        match parameter {
+            InstructionParameter::Byte(value) => {
+                let and = self.registers.acc & value;
+                let mut result = (and >> 1) | ((self.flags.carry as u8) << 7);
+
+                if self.flags.decimal {
+                    self.flags.negative = self.flags.carry;
+                    self.flags.zero = result == 0;
+                    self.flags.overflow = (result ^ and) & 0x40 != 0;
+
+                    if (and & 0x0F) + (and & 0x01) > 0x05 {
+                        result = (result & 0xF0) | (result.wrapping_add(0x06) & 0x0F);
+                    }
+                    self.flags.carry = (and as u16 & 0xF0) + (and as u16 & 0x10) > 0x50;
+                    if self.flags.carry {
+                        result = result.wrapping_add(0x60);
+                    }
+                } else {
+                    self.flags.zero = result == 0;
+                    self.flags.negative = get_msb(result) != 0;
+                    self.flags.carry = result & 0x40 != 0;
+                    self.flags.overflow = ((result >> 6) ^ (result >> 5)) & 1 != 0;
+                }
+
+                self.registers.acc = result;
+
+                None
+            }
+            _ => { None }
+        }
+    }
+
+    pub(crate) fn op_dcp(&mut self, parameter: InstructionParameter) -> Option<u8> {
+        match parameter {
+            InstructionParameter::Byte(value) => {
+                let result = value.wrapping_sub(1);
+                self.op_cmp(InstructionParameter::Byte(result));
+
+                Some(result)
+            }
+            _ => { None }
+        }
+    }
+
+    pub(crate) fn op_isc(&mut self, parameter: InstructionParameter) -> Option<u8> {
+        match parameter {
+            InstructionParameter::Byte(value) => {
+                let result = value.wrapping_add(1);
+                self.op_sbc(InstructionParameter::Byte(result));
+
+                Some(result)
+            }
+            _ => { None }
+        }
+    }
+
+    //Locks up the CPU, only a reset brings it back. PC stays on the JAM, so it shows where the CPU stopped.
+    pub(crate) fn op_jam(&mut self, _: InstructionParameter) -> Option<u8> {
+        self.jammed = true;
+        self.registers.pc = self.registers.pc.wrapping_sub(1);
+
+        None
+    }
+
+    pub(crate) fn op_las(&mut self, parameter: InstructionParameter) -> Option<u8> {
+        match parameter {
+            InstructionParameter::Byte(value) => {
+                let result = value & self.registers.sp;
+                self.registers.acc = result;
+                self.registers.xr = result;
+                self.registers.sp = result;
+                self.flags.zero = result == 0;
+                self.flags.negative = get_msb(result) != 0;
+
+                None
+            }
+            _ => { None }
+        }
+    }
+
+    pub(crate) fn op_lax(&mut self, parameter: InstructionParameter) -> Option<u8> {
+        match parameter {
+            InstructionParameter::Byte(value) => {
+                self.registers.acc = value;
+                self.registers.xr = value;
+                self.flags.zero = value == 0;
+                self.flags.negative = get_msb(value) != 0;
+
+                None
+            }
+            _ => { None }
+        }
+    }
+
+    pub(crate) fn op_lxa(&mut self, parameter: InstructionParameter) -> Option<u8> {
+        match parameter {
+            InstructionParameter::Byte(value) => {
+                let result = (self.registers.acc | self.unstable_constants.lxa) & value;
+                self.op_lax(InstructionParameter::Byte(result))
+            }
+            _ => { None }
+        }
+    }
+
+    pub(crate) fn op_rla(&mut self, parameter: InstructionParameter) -> Option<u8> {
+        let result = self.op_rol(parameter)?;
+        if let Some(and) = self.op_and(InstructionParameter::Byte(result)) {
+            self.registers.acc = and;
+        }
+
+        Some(result)
+    }
+
+    pub(crate) fn op_rra(&mut self, parameter: InstructionParameter) -> Option<u8> {
+        let result = self.op_ror(parameter)?;
+        if let Some(sum) = self.op_adc(InstructionParameter::Byte(result)) {
+            self.registers.acc = sum;
+        }
+
+        Some(result)
+    }
+
+    pub(crate) fn op_sax(&mut self, parameter: InstructionParameter) -> Option<u8> {
+        match parameter {
+            InstructionParameter::Word(address) => {
+                self.write_byte(address, self.registers.acc & self.registers.xr);
+
+                None
+            }
+            _ => { None }
+        }
+    }
+
+    pub(crate) fn op_sbx(&mut self, parameter: InstructionParameter) -> Option<u8> {
+        match parameter {
+            InstructionParameter::Byte(value) => {
+                let and = self.registers.acc & self.registers.xr;
+                self.registers.xr = and.wrapping_sub(value);
+                self.flags.carry = and >= value;
+                self.flags.zero = self.registers.xr == 0;
+                self.flags.negative = get_msb(self.registers.xr) != 0;
+
+                None
+            }
+            _ => { None }
+        }
+    }
+
+    pub(crate) fn op_sha(&mut self, parameter: InstructionParameter) -> Option<u8> {
+        match parameter {
+            InstructionParameter::Word(address) => {
+                self.store_and_high_byte(address, self.registers.yr, self.registers.acc & self.registers.xr);
+
+                None
+            }
+            _ => { None }
+        }
+    }
+
+    pub(crate) fn op_shx(&mut self, parameter: InstructionParameter) -> Option<u8> {
+        match parameter {
+            InstructionParameter::Word(address) => {
+                self.store_and_high_byte(address, self.registers.yr, self.registers.xr);
+
+                None
+            }
+            _ => { None }
+        }
+    }
+
+    pub(crate) fn op_shy(&mut self, parameter: InstructionParameter) -> Option<u8> {
+        match parameter {
+            InstructionParameter::Word(address) => {
+                self.store_and_high_byte(address, self.registers.xr, self.registers.yr);
+
+                None
+            }
+            _ => { None }
+        }
+    }
+
+    pub(crate) fn op_slo(&mut self, parameter: InstructionParameter) -> Option<u8> {
+        let result = self.op_asl(parameter)?;
+        self.op_ora(InstructionParameter::Byte(result));
+
+        Some(result)
+    }
+
+    pub(crate) fn op_sre(&mut self, parameter: InstructionParameter) -> Option<u8> {
+        let result = self.op_lsr(parameter)?;
+        if let Some(eor) = self.op_eor(InstructionParameter::Byte(result)) {
+            self.registers.acc = eor;
+        }
+
+        Some(result)
+    }
+
+    pub(crate) fn op_tas(&mut self, parameter: InstructionParameter) -> Option<u8> {
+        match parameter {
+            InstructionParameter::Word(address) => {
+                self.registers.sp = self.registers.acc & self.registers.xr;
+                self.store_and_high_byte(address, self.registers.yr, self.registers.sp);
+
+                None
+            }
+            _ => { None }
+        }
+    }
+
+    //SHA, SHX, SHY and TAS store the value ANDed with the high byte of the base address plus one.
+    //When indexing crosses a page that same value replaces the high byte of the target address.
+    fn store_and_high_byte(&mut self, address: u16, index: u8, value: u8){
+        let base_address = address.wrapping_sub(index as u16);
+        let high_byte = ((base_address >> 8) as u8).wrapping_add(1);
+        let result = value & high_byte;
+
+        let address = if (base_address ^ address) & 0xFF00 != 0 {
+            ((result as u16) << 8) | (address & 0x00FF)
+        } else {
+            address
+        };
+        self.write_byte(address, result);
+    }
+}
@@ -0,0 +1,4 @@
+pub mod instruction_set;
+pub mod instructions;
+pub mod operations;
+pub mod registers;
@@ -0,0 +1,2260 @@
+use crate::cpu::bus::Bus;
+use crate::cpu::cpu::CPU;
+use crate::cpu::instructions::AddressingMode;
+use crate::cpu::w65816::instructions::{NativeInstruction, NativeInstructionSet};
+use crate::cpu::w65816::instructions::OperandWidth::{Byte, Index, Memory};
+use crate::cpu::w65816::operations;
+
+impl<B: Bus> CPU<B>{
+
+    //Every opcode is defined on the 65816.
+    const NATIVE_INSTRUCTION_SET: NativeInstructionSet<B> = Self::build_native_instruction_set();
+
+    pub fn get_native_instruction(opcode: u8) -> Option<&'static NativeInstruction<B>> {
+        Self::get_native_instruction_set()[opcode as usize].as_ref()
+    }
+
+    pub fn get_native_instruction_set() -> &'static NativeInstructionSet<B> {
+        const { &Self::NATIVE_INSTRUCTION_SET }
+    }
+
+    const fn build_native_instruction_set() -> NativeInstructionSet<B> {
+        let mut instructions = [const { None }; 256];
+
+        //ADC
+        instructions[0x61] = Some(NativeInstruction{
+            address_mode: AddressingMode::XIndexedZeroPageIndirect,
+            mnemonic: "ADC",
+            operation: operations::adc,
+            width: Memory,
+            cycle_increase: 6,
+            cycle_increases_on_page_cross: false
+        });
+        instructions[0x63] = Some(NativeInstruction{
+            address_mode: AddressingMode::StackRelative,
+            mnemonic: "ADC",
+            operation: operations::adc,
+            width: Memory,
+            cycle_increase: 4,
+            cycle_increases_on_page_cross: false
+        });
+        instructions[0x65] = Some(NativeInstruction{
+            address_mode: AddressingMode::ZeroPage,
+            mnemonic: "ADC",
+            operation: operations::adc,
+            width: Memory,
+            cycle_increase: 3,
+            cycle_increases_on_page_cross: false
+        });
+        instructions[0x67] = Some(NativeInstruction{
+            address_mode: AddressingMode::ZeroPageIndirectLong,
+            mnemonic: "ADC",
+            operation: operations::adc,
+            width: Memory,
+            cycle_increase: 6,
+            cycle_increases_on_page_cross: false
+        });
+        instructions[0x69] = Some(NativeInstruction{
+            address_mode: AddressingMode::Immediate,
+            mnemonic: "ADC",
+            operation: operations::adc,
+            width: Memory,
+            cycle_increase: 2,
+            cycle_increases_on_page_cross: false
+        });
+        instructions[0x6D] = Some(NativeInstruction{
+            address_mode: AddressingMode::Absolute,
+            mnemonic: "ADC",
+            operation: operations::adc,
+            width: Memory,
+            cycle_increase: 4,
+            cycle_increases_on_page_cross: false
+        });
+        instructions[0x6F] = Some(NativeInstruction{
+            address_mode: AddressingMode::AbsoluteLong,
+            mnemonic: "ADC",
+            operation: operations::adc,
+            width: Memory,
+            cycle_increase: 5,
+            cycle_increases_on_page_cross: false
+        });
+        instructions[0x71] = Some(NativeInstruction{
+            address_mode: AddressingMode::ZeroPageIndirectYIndexed,
+            mnemonic: "ADC",
+            operation: operations::adc,
+            width: Memory,
+            cycle_increase: 5,
+            cycle_increases_on_page_cross: true
+        });
+        instructions[0x72] = Some(NativeInstruction{
+            address_mode: AddressingMode::ZeroPageIndirect,
+            mnemonic: "ADC",
+            operation: operations::adc,
+            width: Memory,
+            cycle_increase: 5,
+            cycle_increases_on_page_cross: false
+        });
+        instructions[0x73] = Some(NativeInstruction{
+            address_mode: AddressingMode::StackRelativeIndirectYIndexed,
+            mnemonic: "ADC",
+            operation: operations::adc,
+            width: Memory,
+            cycle_increase: 7,
+            cycle_increases_on_page_cross: false
+        });
+        instructions[0x75] = Some(NativeInstruction{
+            address_mode: AddressingMode::XIndexedZeroPage,
+            mnemonic: "ADC",
+            operation: operations::adc,
+            width: Memory,
+            cycle_increase: 4,
+            cycle_increases_on_page_cross: false
+        });
+        instructions[0x77] = Some(NativeInstruction{
+            address_mode: AddressingMode::ZeroPageIndirectLongYIndexed,
+            mnemonic: "ADC",
+            operation: operations::adc,
+            width: Memory,
+            cycle_increase: 6,
+            cycle_increases_on_page_cross: false
+        });
+        instructions[0x79] = Some(NativeInstruction{
+            address_mode: AddressingMode::YIndexedAbsolute,
+            mnemonic: "ADC",
+            operation: operations::adc,
+            width: Memory,
+            cycle_increase: 4,
+            cycle_increases_on_page_cross: true
+        });
+        instructions[0x7D] = Some(NativeInstruction{
+            address_mode: AddressingMode::XIndexedAbsolute,
+            mnemonic: "ADC",
+            operation: operations::adc,
+            width: Memory,
+            cycle_increase: 4,
+            cycle_increases_on_page_cross: true
+        });
+        instructions[0x7F] = Some(NativeInstruction{
+            address_mode: AddressingMode::XIndexedAbsoluteLong,
+            mnemonic: "ADC",
+            operation: operations::adc,
+            width: Memory,
+            cycle_increase: 5,
+            cycle_increases_on_page_cross: false
+        });
+
+        //AND
+        instructions[0x21] = Some(NativeInstruction{
+            address_mode: AddressingMode::XIndexedZeroPageIndirect,
+            mnemonic: "AND",
+            operation: operations::and,
+            width: Memory,
+            cycle_increase: 6,
+            cycle_increases_on_page_cross: false
+        });
+        instructions[0x23] = Some(NativeInstruction{
+            address_mode: AddressingMode::StackRelative,
+            mnemonic: "AND",
+            operation: operations::and,
+            width: Memory,
+            cycle_increase: 4,
+            cycle_increases_on_page_cross: false
+        });
+        instructions[0x25] = Some(NativeInstruction{
+            address_mode: AddressingMode::ZeroPage,
+            mnemonic: "AND",
+            operation: operations::and,
+            width: Memory,
+            cycle_increase: 3,
+            cycle_increases_on_page_cross: false
+        });
+        instructions[0x27] = Some(NativeInstruction{
+            address_mode: AddressingMode::ZeroPageIndirectLong,
+            mnemonic: "AND",
+            operation: operations::and,
+            width: Memory,
+            cycle_increase: 6,
+            cycle_increases_on_page_cross: false
+        });
+        instructions[0x29] = Some(NativeInstruction{
+            address_mode: AddressingMode::Immediate,
+            mnemonic: "AND",
+            operation: operations::and,
+            width: Memory,
+            cycle_increase: 2,
+            cycle_increases_on_page_cross: false
+        });
+        instructions[0x2D] = Some(NativeInstruction{
+            address_mode: AddressingMode::Absolute,
+            mnemonic: "AND",
+            operation: operations::and,
+            width: Memory,
+            cycle_increase: 4,
+            cycle_increases_on_page_cross: false
+        });
+        instructions[0x2F] = Some(NativeInstruction{
+            address_mode: AddressingMode::AbsoluteLong,
+            mnemonic: "AND",
+            operation: operations::and,
+            width: Memory,
+            cycle_increase: 5,
+            cycle_increases_on_page_cross: false
+        });
+        instructions[0x31] = Some(NativeInstruction{
+            address_mode: AddressingMode::ZeroPageIndirectYIndexed,
+            mnemonic: "AND",
+            operation: operations::and,
+            width: Memory,
+            cycle_increase: 5,
+            cycle_increases_on_page_cross: true
+        });
+        instructions[0x32] = Some(NativeInstruction{
+            address_mode: AddressingMode::ZeroPageIndirect,
+            mnemonic: "AND",
+            operation: operations::and,
+            width: Memory,
+            cycle_increase: 5,
+            cycle_increases_on_page_cross: false
+        });
+        instructions[0x33] = Some(NativeInstruction{
+            address_mode: AddressingMode::StackRelativeIndirectYIndexed,
+            mnemonic: "AND",
+            operation: operations::and,
+            width: Memory,
+            cycle_increase: 7,
+            cycle_increases_on_page_cross: false
+        });
+        instructions[0x35] = Some(NativeInstruction{
+            address_mode: AddressingMode::XIndexedZeroPage,
+            mnemonic: "AND",
+            operation: operations::and,
+            width: Memory,
+            cycle_increase: 4,
+            cycle_increases_on_page_cross: false
+        });
+        instructions[0x37] = Some(NativeInstruction{
+            address_mode: AddressingMode::ZeroPageIndirectLongYIndexed,
+            mnemonic: "AND",
+            operation: operations::and,
+            width: Memory,
+            cycle_increase: 6,
+            cycle_increases_on_page_cross: false
+        });
+        instructions[0x39] = Some(NativeInstruction{
+            address_mode: AddressingMode::YIndexedAbsolute,
+            mnemonic: "AND",
+            operation: operations::and,
+            width: Memory,
+            cycle_increase: 4,
+            cycle_increases_on_page_cross: true
+        });
+        instructions[0x3D] = Some(NativeInstruction{
+            address_mode: AddressingMode::XIndexedAbsolute,
+            mnemonic: "AND",
+            operation: operations::and,
+            width: Memory,
+            cycle_increase: 4,
+            cycle_increases_on_page_cross: true
+        });
+        instructions[0x3F] = Some(NativeInstruction{
+            address_mode: AddressingMode::XIndexedAbsoluteLong,
+            mnemonic: "AND",
+            operation: operations::and,
+            width: Memory,
+            cycle_increase: 5,
+            cycle_increases_on_page_cross: false
+        });
+
+        //ASL
+        instructions[0x06] = Some(NativeInstruction{
+            address_mode: AddressingMode::ZeroPage,
+            mnemonic: "ASL",
+            operation: operations::asl,
+            width: Memory,
+            cycle_increase: 5,
+            cycle_increases_on_page_cross: false
+        });
+        instructions[0x0A] = Some(NativeInstruction{
+            address_mode: AddressingMode::Accumulator,
+            mnemonic: "ASL",
+            operation: operations::asl,
+            width: Memory,
+            cycle_increase: 2,
+            cycle_increases_on_page_cross: false
+        });
+        instructions[0x0E] = Some(NativeInstruction{
+            address_mode: AddressingMode::Absolute,
+            mnemonic: "ASL",
+            operation: operations::asl,
+            width: Memory,
+            cycle_increase: 6,
+            cycle_increases_on_page_cross: false
+        });
+        instructions[0x16] = Some(NativeInstruction{
+            address_mode: AddressingMode::XIndexedZeroPage,
+            mnemonic: "ASL",
+            operation: operations::asl,
+            width: Memory,
+            cycle_increase: 6,
+            cycle_increases_on_page_cross: false
+        });
+        instructions[0x1E] = Some(NativeInstruction{
+            address_mode: AddressingMode::XIndexedAbsolute,
+            mnemonic: "ASL",
+            operation: operations::asl,
+            width: Memory,
+            cycle_increase: 7,
+            cycle_increases_on_page_cross: false
+        });
+
+        //BCC
+        instructions[0x90] = Some(NativeInstruction{
+            address_mode: AddressingMode::Relative,
+            mnemonic: "BCC",
+            operation: operations::bcc,
+            width: Byte,
+            cycle_increase: 2,
+            cycle_increases_on_page_cross: false
+        });
+
+        //BCS
+        instructions[0xB0] = Some(NativeInstruction{
+            address_mode: AddressingMode::Relative,
+            mnemonic: "BCS",
+            operation: operations::bcs,
+            width: Byte,
+            cycle_increase: 2,
+            cycle_increases_on_page_cross: false
+        });
+
+        //BEQ
+        instructions[0xF0] = Some(NativeInstruction{
+            address_mode: AddressingMode::Relative,
+            mnemonic: "BEQ",
+            operation: operations::beq,
+            width: Byte,
+            cycle_increase: 2,
+            cycle_increases_on_page_cross: false
+        });
+
+        //BIT
+        instructions[0x24] = Some(NativeInstruction{
+            address_mode: AddressingMode::ZeroPage,
+            mnemonic: "BIT",
+            operation: operations::bit,
+            width: Memory,
+            cycle_increase: 3,
+            cycle_increases_on_page_cross: false
+        });
+        instructions[0x2C] = Some(NativeInstruction{
+            address_mode: AddressingMode::Absolute,
+            mnemonic: "BIT",
+            operation: operations::bit,
+            width: Memory,
+            cycle_increase: 4,
+            cycle_increases_on_page_cross: false
+        });
+        instructions[0x34] = Some(NativeInstruction{
+            address_mode: AddressingMode::XIndexedZeroPage,
+            mnemonic: "BIT",
+            operation: operations::bit,
+            width: Memory,
+            cycle_increase: 4,
+            cycle_increases_on_page_cross: false
+        });
+        instructions[0x3C] = Some(NativeInstruction{
+            address_mode: AddressingMode::XIndexedAbsolute,
+            mnemonic: "BIT",
+            operation: operations::bit,
+            width: Memory,
+            cycle_increase: 4,
+            cycle_increases_on_page_cross: true
+        });
+        instructions[0x89] = Some(NativeInstruction{
+            address_mode: AddressingMode::Immediate,
+            mnemonic: "BIT",
+            operation: operations::bit,
+            width: Memory,
+            cycle_increase: 2,
+            cycle_increases_on_page_cross: false
+        });
+
+        //BMI
+        instructions[0x30] = Some(NativeInstruction{
+            address_mode: AddressingMode::Relative,
+            mnemonic: "BMI",
+            operation: operations::bmi,
+            width: Byte,
+            cycle_increase: 2,
+            cycle_increases_on_page_cross: false
+        });
+
+        //BNE
+        instructions[0xD0] = Some(NativeInstruction{
+            address_mode: AddressingMode::Relative,
+            mnemonic: "BNE",
+            operation: operations::bne,
+            width: Byte,
+            cycle_increase: 2,
+            cycle_increases_on_page_cross: false
+        });
+
+        //BPL
+        instructions[0x10] = Some(NativeInstruction{
+            address_mode: AddressingMode::Relative,
+            mnemonic: "BPL",
+            operation: operations::bpl,
+            width: Byte,
+            cycle_increase: 2,
+            cycle_increases_on_page_cross: false
+        });
+
+        //BRA
+        instructions[0x80] = Some(NativeInstruction{
+            address_mode: AddressingMode::Relative,
+            mnemonic: "BRA",
+            operation: operations::bra,
+            width: Byte,
+            cycle_increase: 2,
+            cycle_increases_on_page_cross: false
+        });
+
+        //BRK
+        instructions[0x00] = Some(NativeInstruction{
+            address_mode: AddressingMode::Immediate,
+            mnemonic: "BRK",
+            operation: operations::brk,
+            width: Byte,
+            cycle_increase: 7,
+            cycle_increases_on_page_cross: false
+        });
+
+        //BRL
+        instructions[0x82] = Some(NativeInstruction{
+            address_mode: AddressingMode::RelativeLong,
+            mnemonic: "BRL",
+            operation: operations::brl,
+            width: Byte,
+            cycle_increase: 4,
+            cycle_increases_on_page_cross: false
+        });
+
+        //BVC
+        instructions[0x50] = Some(NativeInstruction{
+            address_mode: AddressingMode::Relative,
+            mnemonic: "BVC",
+            operation: operations::bvc,
+            width: Byte,
+            cycle_increase: 2,
+            cycle_increases_on_page_cross: false
+        });
+
+        //BVS
+        instructions[0x70] = Some(NativeInstruction{
+            address_mode: AddressingMode::Relative,
+            mnemonic: "BVS",
+            operation: operations::bvs,
+            width: Byte,
+            cycle_increase: 2,
+            cycle_increases_on_page_cross: false
+        });
+
+        //CLC
+        instructions[0x18] = Some(NativeInstruction{
+            address_mode: AddressingMode::Implied,
+            mnemonic: "CLC",
+            operation: operations::clc,
+            width: Byte,
+            cycle_increase: 2,
+            cycle_increases_on_page_cross: false
+        });
+
+        //CLD
+        instructions[0xD8] = Some(NativeInstruction{
+            address_mode: AddressingMode::Implied,
+            mnemonic: "CLD",
+            operation: operations::cld,
+            width: Byte,
+            cycle_increase: 2,
+            cycle_increases_on_page_cross: false
+        });
+
+        //CLI
+        instructions[0x58] = Some(NativeInstruction{
+            address_mode: AddressingMode::Implied,
+            mnemonic: "CLI",
+            operation: operations::cli,
+            width: Byte,
+            cycle_increase: 2,
+            cycle_increases_on_page_cross: false
+        });
+
+        //CLV
+        instructions[0xB8] = Some(NativeInstruction{
+            address_mode: AddressingMode::Implied,
+            mnemonic: "CLV",
+            operation: operations::clv,
+            width: Byte,
+            cycle_increase: 2,
+            cycle_increases_on_page_cross: false
+        });
+
+        //CMP
+        instructions[0xC1] = Some(NativeInstruction{
+            address_mode: AddressingMode::XIndexedZeroPageIndirect,
+            mnemonic: "CMP",
+            operation: operations::cmp,
+            width: Memory,
+            cycle_increase: 6,
+            cycle_increases_on_page_cross: false
+        });
+        instructions[0xC3] = Some(NativeInstruction{
+            address_mode: AddressingMode::StackRelative,
+            mnemonic: "CMP",
+            operation: operations::cmp,
+            width: Memory,
+            cycle_increase: 4,
+            cycle_increases_on_page_cross: false
+        });
+        instructions[0xC5] = Some(NativeInstruction{
+            address_mode: AddressingMode::ZeroPage,
+            mnemonic: "CMP",
+            operation: operations::cmp,
+            width: Memory,
+            cycle_increase: 3,
+            cycle_increases_on_page_cross: false
+        });
+        instructions[0xC7] = Some(NativeInstruction{
+            address_mode: AddressingMode::ZeroPageIndirectLong,
+            mnemonic: "CMP",
+            operation: operations::cmp,
+            width: Memory,
+            cycle_increase: 6,
+            cycle_increases_on_page_cross: false
+        });
+        instructions[0xC9] = Some(NativeInstruction{
+            address_mode: AddressingMode::Immediate,
+            mnemonic: "CMP",
+            operation: operations::cmp,
+            width: Memory,
+            cycle_increase: 2,
+            cycle_increases_on_page_cross: false
+        });
+        instructions[0xCD] = Some(NativeInstruction{
+            address_mode: AddressingMode::Absolute,
+            mnemonic: "CMP",
+            operation: operations::cmp,
+            width: Memory,
+            cycle_increase: 4,
+            cycle_increases_on_page_cross: false
+        });
+        instructions[0xCF] = Some(NativeInstruction{
+            address_mode: AddressingMode::AbsoluteLong,
+            mnemonic: "CMP",
+            operation: operations::cmp,
+            width: Memory,
+            cycle_increase: 5,
+            cycle_increases_on_page_cross: false
+        });
+        instructions[0xD1] = Some(NativeInstruction{
+            address_mode: AddressingMode::ZeroPageIndirectYIndexed,
+            mnemonic: "CMP",
+            operation: operations::cmp,
+            width: Memory,
+            cycle_increase: 5,
+            cycle_increases_on_page_cross: true
+        });
+        instructions[0xD2] = Some(NativeInstruction{
+            address_mode: AddressingMode::ZeroPageIndirect,
+            mnemonic: "CMP",
+            operation: operations::cmp,
+            width: Memory,
+            cycle_increase: 5,
+            cycle_increases_on_page_cross: false
+        });
+        instructions[0xD3] = Some(NativeInstruction{
+            address_mode: AddressingMode::StackRelativeIndirectYIndexed,
+            mnemonic: "CMP",
+            operation: operations::cmp,
+            width: Memory,
+            cycle_increase: 7,
+            cycle_increases_on_page_cross: false
+        });
+        instructions[0xD5] = Some(NativeInstruction{
+            address_mode: AddressingMode::XIndexedZeroPage,
+            mnemonic: "CMP",
+            operation: operations::cmp,
+            width: Memory,
+            cycle_increase: 4,
+            cycle_increases_on_page_cross: false
+        });
+        instructions[0xD7] = Some(NativeInstruction{
+            address_mode: AddressingMode::ZeroPageIndirectLongYIndexed,
+            mnemonic: "CMP",
+            operation: operations::cmp,
+            width: Memory,
+            cycle_increase: 6,
+            cycle_increases_on_page_cross: false
+        });
+        instructions[0xD9] = Some(NativeInstruction{
+            address_mode: AddressingMode::YIndexedAbsolute,
+            mnemonic: "CMP",
+            operation: operations::cmp,
+            width: Memory,
+            cycle_increase: 4,
+            cycle_increases_on_page_cross: true
+        });
+        instructions[0xDD] = Some(NativeInstruction{
+            address_mode: AddressingMode::XIndexedAbsolute,
+            mnemonic: "CMP",
+            operation: operations::cmp,
+            width: Memory,
+            cycle_increase: 4,
+            cycle_increases_on_page_cross: true
+        });
+        instructions[0xDF] = Some(NativeInstruction{
+            address_mode: AddressingMode::XIndexedAbsoluteLong,
+            mnemonic: "CMP",
+            operation: operations::cmp,
+            width: Memory,
+            cycle_increase: 5,
+            cycle_increases_on_page_cross: false
+        });
+
+        //COP
+        instructions[0x02] = Some(NativeInstruction{
+            address_mode: AddressingMode::Immediate,
+            mnemonic: "COP",
+            operation: operations::cop,
+            width: Byte,
+            cycle_increase: 7,
+            cycle_increases_on_page_cross: false
+        });
+
+        //CPX
+        instructions[0xE0] = Some(NativeInstruction{
+            address_mode: AddressingMode::Immediate,
+            mnemonic: "CPX",
+            operation: operations::cpx,
+            width: Index,
+            cycle_increase: 2,
+            cycle_increases_on_page_cross: false
+        });
+        instructions[0xE4] = Some(NativeInstruction{
+            address_mode: AddressingMode::ZeroPage,
+            mnemonic: "CPX",
+            operation: operations::cpx,
+            width: Index,
+            cycle_increase: 3,
+            cycle_increases_on_page_cross: false
+        });
+        instructions[0xEC] = Some(NativeInstruction{
+            address_mode: AddressingMode::Absolute,
+            mnemonic: "CPX",
+            operation: operations::cpx,
+            width: Index,
+            cycle_increase: 4,
+            cycle_increases_on_page_cross: false
+        });
+
+        //CPY
+        instructions[0xC0] = Some(NativeInstruction{
+            address_mode: AddressingMode::Immediate,
+            mnemonic: "CPY",
+            operation: operations::cpy,
+            width: Index,
+            cycle_increase: 2,
+            cycle_increases_on_page_cross: false
+        });
+        instructions[0xC4] = Some(NativeInstruction{
+            address_mode: AddressingMode::ZeroPage,
+            mnemonic: "CPY",
+            operation: operations::cpy,
+            width: Index,
+            cycle_increase: 3,
+            cycle_increases_on_page_cross: false
+        });
+        instructions[0xCC] = Some(NativeInstruction{
+            address_mode: AddressingMode::Absolute,
+            mnemonic: "CPY",
+            operation: operations::cpy,
+            width: Index,
+            cycle_increase: 4,
+            cycle_increases_on_page_cross: false
+        });
+
+        //DEC
+        instructions[0x3A] = Some(NativeInstruction{
+            address_mode: AddressingMode::Accumulator,
+            mnemonic: "DEC",
+            operation: operations::dec,
+            width: Memory,
+            cycle_increase: 2,
+            cycle_increases_on_page_cross: false
+        });
+        instructions[0xC6] = Some(NativeInstruction{
+            address_mode: AddressingMode::ZeroPage,
+            mnemonic: "DEC",
+            operation: operations::dec,
+            width: Memory,
+            cycle_increase: 5,
+            cycle_increases_on_page_cross: false
+        });
+        instructions[0xCE] = Some(NativeInstruction{
+            address_mode: AddressingMode::Absolute,
+            mnemonic: "DEC",
+            operation: operations::dec,
+            width: Memory,
+            cycle_increase: 6,
+            cycle_increases_on_page_cross: false
+        });
+        instructions[0xD6] = Some(NativeInstruction{
+            address_mode: AddressingMode::XIndexedZeroPage,
+            mnemonic: "DEC",
+            operation: operations::dec,
+            width: Memory,
+            cycle_increase: 6,
+            cycle_increases_on_page_cross: false
+        });
+        instructions[0xDE] = Some(NativeInstruction{
+            address_mode: AddressingMode::XIndexedAbsolute,
+            mnemonic: "DEC",
+            operation: operations::dec,
+            width: Memory,
+            cycle_increase: 7,
+            cycle_increases_on_page_cross: false
+        });
+
+        //DEX
+        instructions[0xCA] = Some(NativeInstruction{
+            address_mode: AddressingMode::Implied,
+            mnemonic: "DEX",
+            operation: operations::dex,
+            width: Index,
+            cycle_increase: 2,
+            cycle_increases_on_page_cross: false
+        });
+
+        //DEY
+        instructions[0x88] = Some(NativeInstruction{
+            address_mode: AddressingMode::Implied,
+            mnemonic: "DEY",
+            operation: operations::dey,
+            width: Index,
+            cycle_increase: 2,
+            cycle_increases_on_page_cross: false
+        });
+
+        //EOR
+        instructions[0x41] = Some(NativeInstruction{
+            address_mode: AddressingMode::XIndexedZeroPageIndirect,
+            mnemonic: "EOR",
+            operation: operations::eor,
+            width: Memory,
+            cycle_increase: 6,
+            cycle_increases_on_page_cross: false
+        });
+        instructions[0x43] = Some(NativeInstruction{
+            address_mode: AddressingMode::StackRelative,
+            mnemonic: "EOR",
+            operation: operations::eor,
+            width: Memory,
+            cycle_increase: 4,
+            cycle_increases_on_page_cross: false
+        });
+        instructions[0x45] = Some(NativeInstruction{
+            address_mode: AddressingMode::ZeroPage,
+            mnemonic: "EOR",
+            operation: operations::eor,
+            width: Memory,
+            cycle_increase: 3,
+            cycle_increases_on_page_cross: false
+        });
+        instructions[0x47] = Some(NativeInstruction{
+            address_mode: AddressingMode::ZeroPageIndirectLong,
+            mnemonic: "EOR",
+            operation: operations::eor,
+            width: Memory,
+            cycle_increase: 6,
+            cycle_increases_on_page_cross: false
+        });
+        instructions[0x49] = Some(NativeInstruction{
+            address_mode: AddressingMode::Immediate,
+            mnemonic: "EOR",
+            operation: operations::eor,
+            width: Memory,
+            cycle_increase: 2,
+            cycle_increases_on_page_cross: false
+        });
+        instructions[0x4D] = Some(NativeInstruction{
+            address_mode: AddressingMode::Absolute,
+            mnemonic: "EOR",
+            operation: operations::eor,
+            width: Memory,
+            cycle_increase: 4,
+            cycle_increases_on_page_cross: false
+        });
+        instructions[0x4F] = Some(NativeInstruction{
+            address_mode: AddressingMode::AbsoluteLong,
+            mnemonic: "EOR",
+            operation: operations::eor,
+            width: Memory,
+            cycle_increase: 5,
+            cycle_increases_on_page_cross: false
+        });
+        instructions[0x51] = Some(NativeInstruction{
+            address_mode: AddressingMode::ZeroPageIndirectYIndexed,
+            mnemonic: "EOR",
+            operation: operations::eor,
+            width: Memory,
+            cycle_increase: 5,
+            cycle_increases_on_page_cross: true
+        });
+        instructions[0x52] = Some(NativeInstruction{
+            address_mode: AddressingMode::ZeroPageIndirect,
+            mnemonic: "EOR",
+            operation: operations::eor,
+            width: Memory,
+            cycle_increase: 5,
+            cycle_increases_on_page_cross: false
+        });
+        instructions[0x53] = Some(NativeInstruction{
+            address_mode: AddressingMode::StackRelativeIndirectYIndexed,
+            mnemonic: "EOR",
+            operation: operations::eor,
+            width: Memory,
+            cycle_increase: 7,
+            cycle_increases_on_page_cross: false
+        });
+        instructions[0x55] = Some(NativeInstruction{
+            address_mode: AddressingMode::XIndexedZeroPage,
+            mnemonic: "EOR",
+            operation: operations::eor,
+            width: Memory,
+            cycle_increase: 4,
+            cycle_increases_on_page_cross: false
+        });
+        instructions[0x57] = Some(NativeInstruction{
+            address_mode: AddressingMode::ZeroPageIndirectLongYIndexed,
+            mnemonic: "EOR",
+            operation: operations::eor,
+            width: Memory,
+            cycle_increase: 6,
+            cycle_increases_on_page_cross: false
+        });
+        instructions[0x59] = Some(NativeInstruction{
+            address_mode: AddressingMode::YIndexedAbsolute,
+            mnemonic: "EOR",
+            operation: operations::eor,
+            width: Memory,
+            cycle_increase: 4,
+            cycle_increases_on_page_cross: true
+        });
+        instructions[0x5D] = Some(NativeInstruction{
+            address_mode: AddressingMode::XIndexedAbsolute,
+            mnemonic: "EOR",
+            operation: operations::eor,
+            width: Memory,
+            cycle_increase: 4,
+            cycle_increases_on_page_cross: true
+        });
+        instructions[0x5F] = Some(NativeInstruction{
+            address_mode: AddressingMode::XIndexedAbsoluteLong,
+            mnemonic: "EOR",
+            operation: operations::eor,
+            width: Memory,
+            cycle_increase: 5,
+            cycle_increases_on_page_cross: false
+        });
+
+        //INC
+        instructions[0x1A] = Some(NativeInstruction{
+            address_mode: AddressingMode::Accumulator,
+            mnemonic: "INC",
+            operation: operations::inc,
+            width: Memory,
+            cycle_increase: 2,
+            cycle_increases_on_page_cross: false
+        });
+        instructions[0xE6] = Some(NativeInstruction{
+            address_mode: AddressingMode::ZeroPage,
+            mnemonic: "INC",
+            operation: operations::inc,
+            width: Memory,
+            cycle_increase: 5,
+            cycle_increases_on_page_cross: false
+        });
+        instructions[0xEE] = Some(NativeInstruction{
+            address_mode: AddressingMode::Absolute,
+            mnemonic: "INC",
+            operation: operations::inc,
+            width: Memory,
+            cycle_increase: 6,
+            cycle_increases_on_page_cross: false
+        });
+        instructions[0xF6] = Some(NativeInstruction{
+            address_mode: AddressingMode::XIndexedZeroPage,
+            mnemonic: "INC",
+            operation: operations::inc,
+            width: Memory,
+            cycle_increase: 6,
+            cycle_increases_on_page_cross: false
+        });
+        instructions[0xFE] = Some(NativeInstruction{
+            address_mode: AddressingMode::XIndexedAbsolute,
+            mnemonic: "INC",
+            operation: operations::inc,
+            width: Memory,
+            cycle_increase: 7,
+            cycle_increases_on_page_cross: false
+        });
+
+        //INX
+        instructions[0xE8] = Some(NativeInstruction{
+            address_mode: AddressingMode::Implied,
+            mnemonic: "INX",
+            operation: operations::inx,
+            width: Index,
+            cycle_increase: 2,
+            cycle_increases_on_page_cross: false
+        });
+
+        //INY
+        instructions[0xC8] = Some(NativeInstruction{
+            address_mode: AddressingMode::Implied,
+            mnemonic: "INY",
+            operation: operations::iny,
+            width: Index,
+            cycle_increase: 2,
+            cycle_increases_on_page_cross: false
+        });
+
+        //JML
+        instructions[0x5C] = Some(NativeInstruction{
+            address_mode: AddressingMode::AbsoluteLong,
+            mnemonic: "JML",
+            operation: operations::jml,
+            width: Byte,
+            cycle_increase: 4,
+            cycle_increases_on_page_cross: false
+        });
+        instructions[0xDC] = Some(NativeInstruction{
+            address_mode: AddressingMode::AbsoluteIndirectLong,
+            mnemonic: "JML",
+            operation: operations::jml,
+            width: Byte,
+            cycle_increase: 6,
+            cycle_increases_on_page_cross: false
+        });
+
+        //JMP
+        instructions[0x4C] = Some(NativeInstruction{
+            address_mode: AddressingMode::Absolute,
+            mnemonic: "JMP",
+            operation: operations::jmp,
+            width: Byte,
+            cycle_increase: 3,
+            cycle_increases_on_page_cross: false
+        });
+        instructions[0x6C] = Some(NativeInstruction{
+            address_mode: AddressingMode::AbsoluteIndirect,
+            mnemonic: "JMP",
+            operation: operations::jmp,
+            width: Byte,
+            cycle_increase: 5,
+            cycle_increases_on_page_cross: false
+        });
+        instructions[0x7C] = Some(NativeInstruction{
+            address_mode: AddressingMode::XIndexedAbsoluteIndirect,
+            mnemonic: "JMP",
+            operation: operations::jmp,
+            width: Byte,
+            cycle_increase: 6,
+            cycle_increases_on_page_cross: false
+        });
+
+        //JSL
+        instructions[0x22] = Some(NativeInstruction{
+            address_mode: AddressingMode::AbsoluteLong,
+            mnemonic: "JSL",
+            operation: operations::jsl,
+            width: Byte,
+            cycle_increase: 8,
+            cycle_increases_on_page_cross: false
+        });
+
+        //JSR
+        instructions[0x20] = Some(NativeInstruction{
+            address_mode: AddressingMode::Absolute,
+            mnemonic: "JSR",
+            operation: operations::jsr,
+            width: Byte,
+            cycle_increase: 6,
+            cycle_increases_on_page_cross: false
+        });
+
+        //JSR (abs,X)
+        instructions[0xFC] = Some(NativeInstruction{
+            address_mode: AddressingMode::XIndexedAbsoluteIndirect,
+            mnemonic: "JSR",
+            operation: operations::jsr_indexed_indirect,
+            width: Byte,
+            cycle_increase: 8,
+            cycle_increases_on_page_cross: false
+        });
+
+        //LDA
+        instructions[0xA1] = Some(NativeInstruction{
+            address_mode: AddressingMode::XIndexedZeroPageIndirect,
+            mnemonic: "LDA",
+            operation: operations::lda,
+            width: Memory,
+            cycle_increase: 6,
+            cycle_increases_on_page_cross: false
+        });
+        instructions[0xA3] = Some(NativeInstruction{
+            address_mode: AddressingMode::StackRelative,
+            mnemonic: "LDA",
+            operation: operations::lda,
+            width: Memory,
+            cycle_increase: 4,
+            cycle_increases_on_page_cross: false
+        });
+        instructions[0xA5] = Some(NativeInstruction{
+            address_mode: AddressingMode::ZeroPage,
+            mnemonic: "LDA",
+            operation: operations::lda,
+            width: Memory,
+            cycle_increase: 3,
+            cycle_increases_on_page_cross: false
+        });
+        instructions[0xA7] = Some(NativeInstruction{
+            address_mode: AddressingMode::ZeroPageIndirectLong,
+            mnemonic: "LDA",
+            operation: operations::lda,
+            width: Memory,
+            cycle_increase: 6,
+            cycle_increases_on_page_cross: false
+        });
+        instructions[0xA9] = Some(NativeInstruction{
+            address_mode: AddressingMode::Immediate,
+            mnemonic: "LDA",
+            operation: operations::lda,
+            width: Memory,
+            cycle_increase: 2,
+            cycle_increases_on_page_cross: false
+        });
+        instructions[0xAD] = Some(NativeInstruction{
+            address_mode: AddressingMode::Absolute,
+            mnemonic: "LDA",
+            operation: operations::lda,
+            width: Memory,
+            cycle_increase: 4,
+            cycle_increases_on_page_cross: false
+        });
+        instructions[0xAF] = Some(NativeInstruction{
+            address_mode: AddressingMode::AbsoluteLong,
+            mnemonic: "LDA",
+            operation: operations::lda,
+            width: Memory,
+            cycle_increase: 5,
+            cycle_increases_on_page_cross: false
+        });
+        instructions[0xB1] = Some(NativeInstruction{
+            address_mode: AddressingMode::ZeroPageIndirectYIndexed,
+            mnemonic: "LDA",
+            operation: operations::lda,
+            width: Memory,
+            cycle_increase: 5,
+            cycle_increases_on_page_cross: true
+        });
+        instructions[0xB2] = Some(NativeInstruction{
+            address_mode: AddressingMode::ZeroPageIndirect,
+            mnemonic: "LDA",
+            operation: operations::lda,
+            width: Memory,
+            cycle_increase: 5,
+            cycle_increases_on_page_cross: false
+        });
+        instructions[0xB3] = Some(NativeInstruction{
+            address_mode: AddressingMode::StackRelativeIndirectYIndexed,
+            mnemonic: "LDA",
+            operation: operations::lda,
+            width: Memory,
+            cycle_increase: 7,
+            cycle_increases_on_page_cross: false
+        });
+        instructions[0xB5] = Some(NativeInstruction{
+            address_mode: AddressingMode::XIndexedZeroPage,
+            mnemonic: "LDA",
+            operation: operations::lda,
+            width: Memory,
+            cycle_increase: 4,
+            cycle_increases_on_page_cross: false
+        });
+        instructions[0xB7] = Some(NativeInstruction{
+            address_mode: AddressingMode::ZeroPageIndirectLongYIndexed,
+            mnemonic: "LDA",
+            operation: operations::lda,
+            width: Memory,
+            cycle_increase: 6,
+            cycle_increases_on_page_cross: false
+        });
+        instructions[0xB9] = Some(NativeInstruction{
+            address_mode: AddressingMode::YIndexedAbsolute,
+            mnemonic: "LDA",
+            operation: operations::lda,
+            width: Memory,
+            cycle_increase: 4,
+            cycle_increases_on_page_cross: true
+        });
+        instructions[0xBD] = Some(NativeInstruction{
+            address_mode: AddressingMode::XIndexedAbsolute,
+            mnemonic: "LDA",
+            operation: operations::lda,
+            width: Memory,
+            cycle_increase: 4,
+            cycle_increases_on_page_cross: true
+        });
+        instructions[0xBF] = Some(NativeInstruction{
+            address_mode: AddressingMode::XIndexedAbsoluteLong,
+            mnemonic: "LDA",
+            operation: operations::lda,
+            width: Memory,
+            cycle_increase: 5,
+            cycle_increases_on_page_cross: false
+        });
+
+        //LDX
+        instructions[0xA2] = Some(NativeInstruction{
+            address_mode: AddressingMode::Immediate,
+            mnemonic: "LDX",
+            operation: operations::ldx,
+            width: Index,
+            cycle_increase: 2,
+            cycle_increases_on_page_cross: false
+        });
+        instructions[0xA6] = Some(NativeInstruction{
+            address_mode: AddressingMode::ZeroPage,
+            mnemonic: "LDX",
+            operation: operations::ldx,
+            width: Index,
+            cycle_increase: 3,
+            cycle_increases_on_page_cross: false
+        });
+        instructions[0xAE] = Some(NativeInstruction{
+            address_mode: AddressingMode::Absolute,
+            mnemonic: "LDX",
+            operation: operations::ldx,
+            width: Index,
+            cycle_increase: 4,
+            cycle_increases_on_page_cross: false
+        });
+        instructions[0xB6] = Some(NativeInstruction{
+            address_mode: AddressingMode::YIndexedZeroPage,
+            mnemonic: "LDX",
+            operation: operations::ldx,
+            width: Index,
+            cycle_increase: 4,
+            cycle_increases_on_page_cross: false
+        });
+        instructions[0xBE] = Some(NativeInstruction{
+            address_mode: AddressingMode::YIndexedAbsolute,
+            mnemonic: "LDX",
+            operation: operations::ldx,
+            width: Index,
+            cycle_increase: 4,
+            cycle_increases_on_page_cross: true
+        });
+
+        //LDY
+        instructions[0xA0] = Some(NativeInstruction{
+            address_mode: AddressingMode::Immediate,
+            mnemonic: "LDY",
+            operation: operations::ldy,
+            width: Index,
+            cycle_increase: 2,
+            cycle_increases_on_page_cross: false
+        });
+        instructions[0xA4] = Some(NativeInstruction{
+            address_mode: AddressingMode::ZeroPage,
+            mnemonic: "LDY",
+            operation: operations::ldy,
+            width: Index,
+            cycle_increase: 3,
+            cycle_increases_on_page_cross: false
+        });
+        instructions[0xAC] = Some(NativeInstruction{
+            address_mode: AddressingMode::Absolute,
+            mnemonic: "LDY",
+            operation: operations::ldy,
+            width: Index,
+            cycle_increase: 4,
+            cycle_increases_on_page_cross: false
+        });
+        instructions[0xB4] = Some(NativeInstruction{
+            address_mode: AddressingMode::XIndexedZeroPage,
+            mnemonic: "LDY",
+            operation: operations::ldy,
+            width: Index,
+            cycle_increase: 4,
+            cycle_increases_on_page_cross: false
+        });
+        instructions[0xBC] = Some(NativeInstruction{
+            address_mode: AddressingMode::XIndexedAbsolute,
+            mnemonic: "LDY",
+            operation: operations::ldy,
+            width: Index,
+            cycle_increase: 4,
+            cycle_increases_on_page_cross: true
+        });
+
+        //LSR
+        instructions[0x46] = Some(NativeInstruction{
+            address_mode: AddressingMode::ZeroPage,
+            mnemonic: "LSR",
+            operation: operations::lsr,
+            width: Memory,
+            cycle_increase: 5,
+            cycle_increases_on_page_cross: false
+        });
+        instructions[0x4A] = Some(NativeInstruction{
+            address_mode: AddressingMode::Accumulator,
+            mnemonic: "LSR",
+            operation: operations::lsr,
+            width: Memory,
+            cycle_increase: 2,
+            cycle_increases_on_page_cross: false
+        });
+        instructions[0x4E] = Some(NativeInstruction{
+            address_mode: AddressingMode::Absolute,
+            mnemonic: "LSR",
+            operation: operations::lsr,
+            width: Memory,
+            cycle_increase: 6,
+            cycle_increases_on_page_cross: false
+        });
+        instructions[0x56] = Some(NativeInstruction{
+            address_mode: AddressingMode::XIndexedZeroPage,
+            mnemonic: "LSR",
+            operation: operations::lsr,
+            width: Memory,
+            cycle_increase: 6,
+            cycle_increases_on_page_cross: false
+        });
+        instructions[0x5E] = Some(NativeInstruction{
+            address_mode: AddressingMode::XIndexedAbsolute,
+            mnemonic: "LSR",
+            operation: operations::lsr,
+            width: Memory,
+            cycle_increase: 7,
+            cycle_increases_on_page_cross: false
+        });
+
+        //MVN
+        instructions[0x54] = Some(NativeInstruction{
+            address_mode: AddressingMode::BlockMove,
+            mnemonic: "MVN",
+            operation: operations::mvn,
+            width: Byte,
+            cycle_increase: 7,
+            cycle_increases_on_page_cross: false
+        });
+
+        //MVP
+        instructions[0x44] = Some(NativeInstruction{
+            address_mode: AddressingMode::BlockMove,
+            mnemonic: "MVP",
+            operation: operations::mvp,
+            width: Byte,
+            cycle_increase: 7,
+            cycle_increases_on_page_cross: false
+        });
+
+        //NOP
+        instructions[0xEA] = Some(NativeInstruction{
+            address_mode: AddressingMode::Implied,
+            mnemonic: "NOP",
+            operation: operations::nop,
+            width: Byte,
+            cycle_increase: 2,
+            cycle_increases_on_page_cross: false
+        });
+
+        //ORA
+        instructions[0x01] = Some(NativeInstruction{
+            address_mode: AddressingMode::XIndexedZeroPageIndirect,
+            mnemonic: "ORA",
+            operation: operations::ora,
+            width: Memory,
+            cycle_increase: 6,
+            cycle_increases_on_page_cross: false
+        });
+        instructions[0x03] = Some(NativeInstruction{
+            address_mode: AddressingMode::StackRelative,
+            mnemonic: "ORA",
+            operation: operations::ora,
+            width: Memory,
+            cycle_increase: 4,
+            cycle_increases_on_page_cross: false
+        });
+        instructions[0x05] = Some(NativeInstruction{
+            address_mode: AddressingMode::ZeroPage,
+            mnemonic: "ORA",
+            operation: operations::ora,
+            width: Memory,
+            cycle_increase: 3,
+            cycle_increases_on_page_cross: false
+        });
+        instructions[0x07] = Some(NativeInstruction{
+            address_mode: AddressingMode::ZeroPageIndirectLong,
+            mnemonic: "ORA",
+            operation: operations::ora,
+            width: Memory,
+            cycle_increase: 6,
+            cycle_increases_on_page_cross: false
+        });
+        instructions[0x09] = Some(NativeInstruction{
+            address_mode: AddressingMode::Immediate,
+            mnemonic: "ORA",
+            operation: operations::ora,
+            width: Memory,
+            cycle_increase: 2,
+            cycle_increases_on_page_cross: false
+        });
+        instructions[0x0D] = Some(NativeInstruction{
+            address_mode: AddressingMode::Absolute,
+            mnemonic: "ORA",
+            operation: operations::ora,
+            width: Memory,
+            cycle_increase: 4,
+            cycle_increases_on_page_cross: false
+        });
+        instructions[0x0F] = Some(NativeInstruction{
+            address_mode: AddressingMode::AbsoluteLong,
+            mnemonic: "ORA",
+            operation: operations::ora,
+            width: Memory,
+            cycle_increase: 5,
+            cycle_increases_on_page_cross: false
+        });
+        instructions[0x11] = Some(NativeInstruction{
+            address_mode: AddressingMode::ZeroPageIndirectYIndexed,
+            mnemonic: "ORA",
+            operation: operations::ora,
+            width: Memory,
+            cycle_increase: 5,
+            cycle_increases_on_page_cross: true
+        });
+        instructions[0x12] = Some(NativeInstruction{
+            address_mode: AddressingMode::ZeroPageIndirect,
+            mnemonic: "ORA",
+            operation: operations::ora,
+            width: Memory,
+            cycle_increase: 5,
+            cycle_increases_on_page_cross: false
+        });
+        instructions[0x13] = Some(NativeInstruction{
+            address_mode: AddressingMode::StackRelativeIndirectYIndexed,
+            mnemonic: "ORA",
+            operation: operations::ora,
+            width: Memory,
+            cycle_increase: 7,
+            cycle_increases_on_page_cross: false
+        });
+        instructions[0x15] = Some(NativeInstruction{
+            address_mode: AddressingMode::XIndexedZeroPage,
+            mnemonic: "ORA",
+            operation: operations::ora,
+            width: Memory,
+            cycle_increase: 4,
+            cycle_increases_on_page_cross: false
+        });
+        instructions[0x17] = Some(NativeInstruction{
+            address_mode: AddressingMode::ZeroPageIndirectLongYIndexed,
+            mnemonic: "ORA",
+            operation: operations::ora,
+            width: Memory,
+            cycle_increase: 6,
+            cycle_increases_on_page_cross: false
+        });
+        instructions[0x19] = Some(NativeInstruction{
+            address_mode: AddressingMode::YIndexedAbsolute,
+            mnemonic: "ORA",
+            operation: operations::ora,
+            width: Memory,
+            cycle_increase: 4,
+            cycle_increases_on_page_cross: true
+        });
+        instructions[0x1D] = Some(NativeInstruction{
+            address_mode: AddressingMode::XIndexedAbsolute,
+            mnemonic: "ORA",
+            operation: operations::ora,
+            width: Memory,
+            cycle_increase: 4,
+            cycle_increases_on_page_cross: true
+        });
+        instructions[0x1F] = Some(NativeInstruction{
+            address_mode: AddressingMode::XIndexedAbsoluteLong,
+            mnemonic: "ORA",
+            operation: operations::ora,
+            width: Memory,
+            cycle_increase: 5,
+            cycle_increases_on_page_cross: false
+        });
+
+        //PEA
+        instructions[0xF4] = Some(NativeInstruction{
+            address_mode: AddressingMode::Absolute,
+            mnemonic: "PEA",
+            operation: operations::pea,
+            width: Byte,
+            cycle_increase: 5,
+            cycle_increases_on_page_cross: false
+        });
+
+        //PEI
+        instructions[0xD4] = Some(NativeInstruction{
+            address_mode: AddressingMode::ZeroPage,
+            mnemonic: "PEI",
+            operation: operations::pei,
+            width: Byte,
+            cycle_increase: 6,
+            cycle_increases_on_page_cross: false
+        });
+
+        //PER
+        instructions[0x62] = Some(NativeInstruction{
+            address_mode: AddressingMode::RelativeLong,
+            mnemonic: "PER",
+            operation: operations::per,
+            width: Byte,
+            cycle_increase: 6,
+            cycle_increases_on_page_cross: false
+        });
+
+        //PHA
+        instructions[0x48] = Some(NativeInstruction{
+            address_mode: AddressingMode::Implied,
+            mnemonic: "PHA",
+            operation: operations::pha,
+            width: Memory,
+            cycle_increase: 3,
+            cycle_increases_on_page_cross: false
+        });
+
+        //PHB
+        instructions[0x8B] = Some(NativeInstruction{
+            address_mode: AddressingMode::Implied,
+            mnemonic: "PHB",
+            operation: operations::phb,
+            width: Byte,
+            cycle_increase: 3,
+            cycle_increases_on_page_cross: false
+        });
+
+        //PHD
+        instructions[0x0B] = Some(NativeInstruction{
+            address_mode: AddressingMode::Implied,
+            mnemonic: "PHD",
+            operation: operations::phd,
+            width: Byte,
+            cycle_increase: 4,
+            cycle_increases_on_page_cross: false
+        });
+
+        //PHK
+        instructions[0x4B] = Some(NativeInstruction{
+            address_mode: AddressingMode::Implied,
+            mnemonic: "PHK",
+            operation: operations::phk,
+            width: Byte,
+            cycle_increase: 3,
+            cycle_increases_on_page_cross: false
+        });
+
+        //PHP
+        instructions[0x08] = Some(NativeInstruction{
+            address_mode: AddressingMode::Implied,
+            mnemonic: "PHP",
+            operation: operations::php,
+            width: Byte,
+            cycle_increase: 3,
+            cycle_increases_on_page_cross: false
+        });
+
+        //PHX
+        instructions[0xDA] = Some(NativeInstruction{
+            address_mode: AddressingMode::Implied,
+            mnemonic: "PHX",
+            operation: operations::phx,
+            width: Index,
+            cycle_increase: 3,
+            cycle_increases_on_page_cross: false
+        });
+
+        //PHY
+        instructions[0x5A] = Some(NativeInstruction{
+            address_mode: AddressingMode::Implied,
+            mnemonic: "PHY",
+            operation: operations::phy,
+            width: Index,
+            cycle_increase: 3,
+            cycle_increases_on_page_cross: false
+        });
+
+        //PLA
+        instructions[0x68] = Some(NativeInstruction{
+            address_mode: AddressingMode::Implied,
+            mnemonic: "PLA",
+            operation: operations::pla,
+            width: Memory,
+            cycle_increase: 4,
+            cycle_increases_on_page_cross: false
+        });
+
+        //PLB
+        instructions[0xAB] = Some(NativeInstruction{
+            address_mode: AddressingMode::Implied,
+            mnemonic: "PLB",
+            operation: operations::plb,
+            width: Byte,
+            cycle_increase: 4,
+            cycle_increases_on_page_cross: false
+        });
+
+        //PLD
+        instructions[0x2B] = Some(NativeInstruction{
+            address_mode: AddressingMode::Implied,
+            mnemonic: "PLD",
+            operation: operations::pld,
+            width: Byte,
+            cycle_increase: 5,
+            cycle_increases_on_page_cross: false
+        });
+
+        //PLP
+        instructions[0x28] = Some(NativeInstruction{
+            address_mode: AddressingMode::Implied,
+            mnemonic: "PLP",
+            operation: operations::plp,
+            width: Byte,
+            cycle_increase: 4,
+            cycle_increases_on_page_cross: false
+        });
+
+        //PLX
+        instructions[0xFA] = Some(NativeInstruction{
+            address_mode: AddressingMode::Implied,
+            mnemonic: "PLX",
+            operation: operations::plx,
+            width: Index,
+            cycle_increase: 4,
+            cycle_increases_on_page_cross: false
+        });
+
+        //PLY
+        instructions[0x7A] = Some(NativeInstruction{
+            address_mode: AddressingMode::Implied,
+            mnemonic: "PLY",
+            operation: operations::ply,
+            width: Index,
+            cycle_increase: 4,
+            cycle_increases_on_page_cross: false
+        });
+
+        //REP
+        instructions[0xC2] = Some(NativeInstruction{
+            address_mode: AddressingMode::Immediate,
+            mnemonic: "REP",
+            operation: operations::rep,
+            width: Byte,
+            cycle_increase: 3,
+            cycle_increases_on_page_cross: false
+        });
+
+        //ROL
+        instructions[0x26] = Some(NativeInstruction{
+            address_mode: AddressingMode::ZeroPage,
+            mnemonic: "ROL",
+            operation: operations::rol,
+            width: Memory,
+            cycle_increase: 5,
+            cycle_increases_on_page_cross: false
+        });
+        instructions[0x2A] = Some(NativeInstruction{
+            address_mode: AddressingMode::Accumulator,
+            mnemonic: "ROL",
+            operation: operations::rol,
+            width: Memory,
+            cycle_increase: 2,
+            cycle_increases_on_page_cross: false
+        });
+        instructions[0x2E] = Some(NativeInstruction{
+            address_mode: AddressingMode::Absolute,
+            mnemonic: "ROL",
+            operation: operations::rol,
+            width: Memory,
+            cycle_increase: 6,
+            cycle_increases_on_page_cross: false
+        });
+        instructions[0x36] = Some(NativeInstruction{
+            address_mode: AddressingMode::XIndexedZeroPage,
+            mnemonic: "ROL",
+            operation: operations::rol,
+            width: Memory,
+            cycle_increase: 6,
+            cycle_increases_on_page_cross: false
+        });
+        instructions[0x3E] = Some(NativeInstruction{
+            address_mode: AddressingMode::XIndexedAbsolute,
+            mnemonic: "ROL",
+            operation: operations::rol,
+            width: Memory,
+            cycle_increase: 7,
+            cycle_increases_on_page_cross: false
+        });
+
+        //ROR
+        instructions[0x66] = Some(NativeInstruction{
+            address_mode: AddressingMode::ZeroPage,
+            mnemonic: "ROR",
+            operation: operations::ror,
+            width: Memory,
+            cycle_increase: 5,
+            cycle_increases_on_page_cross: false
+        });
+        instructions[0x6A] = Some(NativeInstruction{
+            address_mode: AddressingMode::Accumulator,
+            mnemonic: "ROR",
+            operation: operations::ror,
+            width: Memory,
+            cycle_increase: 2,
+            cycle_increases_on_page_cross: false
+        });
+        instructions[0x6E] = Some(NativeInstruction{
+            address_mode: AddressingMode::Absolute,
+            mnemonic: "ROR",
+            operation: operations::ror,
+            width: Memory,
+            cycle_increase: 6,
+            cycle_increases_on_page_cross: false
+        });
+        instructions[0x76] = Some(NativeInstruction{
+            address_mode: AddressingMode::XIndexedZeroPage,
+            mnemonic: "ROR",
+            operation: operations::ror,
+            width: Memory,
+            cycle_increase: 6,
+            cycle_increases_on_page_cross: false
+        });
+        instructions[0x7E] = Some(NativeInstruction{
+            address_mode: AddressingMode::XIndexedAbsolute,
+            mnemonic: "ROR",
+            operation: operations::ror,
+            width: Memory,
+            cycle_increase: 7,
+            cycle_increases_on_page_cross: false
+        });
+
+        //RTI
+        instructions[0x40] = Some(NativeInstruction{
+            address_mode: AddressingMode::Implied,
+            mnemonic: "RTI",
+            operation: operations::rti,
+            width: Byte,
+            cycle_increase: 6,
+            cycle_increases_on_page_cross: false
+        });
+
+        //RTL
+        instructions[0x6B] = Some(NativeInstruction{
+            address_mode: AddressingMode::Implied,
+            mnemonic: "RTL",
+            operation: operations::rtl,
+            width: Byte,
+            cycle_increase: 6,
+            cycle_increases_on_page_cross: false
+        });
+
+        //RTS
+        instructions[0x60] = Some(NativeInstruction{
+            address_mode: AddressingMode::Implied,
+            mnemonic: "RTS",
+            operation: operations::rts,
+            width: Byte,
+            cycle_increase: 6,
+            cycle_increases_on_page_cross: false
+        });
+
+        //SBC
+        instructions[0xE1] = Some(NativeInstruction{
+            address_mode: AddressingMode::XIndexedZeroPageIndirect,
+            mnemonic: "SBC",
+            operation: operations::sbc,
+            width: Memory,
+            cycle_increase: 6,
+            cycle_increases_on_page_cross: false
+        });
+        instructions[0xE3] = Some(NativeInstruction{
+            address_mode: AddressingMode::StackRelative,
+            mnemonic: "SBC",
+            operation: operations::sbc,
+            width: Memory,
+            cycle_increase: 4,
+            cycle_increases_on_page_cross: false
+        });
+        instructions[0xE5] = Some(NativeInstruction{
+            address_mode: AddressingMode::ZeroPage,
+            mnemonic: "SBC",
+            operation: operations::sbc,
+            width: Memory,
+            cycle_increase: 3,
+            cycle_increases_on_page_cross: false
+        });
+        instructions[0xE7] = Some(NativeInstruction{
+            address_mode: AddressingMode::ZeroPageIndirectLong,
+            mnemonic: "SBC",
+            operation: operations::sbc,
+            width: Memory,
+            cycle_increase: 6,
+            cycle_increases_on_page_cross: false
+        });
+        instructions[0xE9] = Some(NativeInstruction{
+            address_mode: AddressingMode::Immediate,
+            mnemonic: "SBC",
+            operation: operations::sbc,
+            width: Memory,
+            cycle_increase: 2,
+            cycle_increases_on_page_cross: false
+        });
+        instructions[0xED] = Some(NativeInstruction{
+            address_mode: AddressingMode::Absolute,
+            mnemonic: "SBC",
+            operation: operations::sbc,
+            width: Memory,
+            cycle_increase: 4,
+            cycle_increases_on_page_cross: false
+        });
+        instructions[0xEF] = Some(NativeInstruction{
+            address_mode: AddressingMode::AbsoluteLong,
+            mnemonic: "SBC",
+            operation: operations::sbc,
+            width: Memory,
+            cycle_increase: 5,
+            cycle_increases_on_page_cross: false
+        });
+        instructions[0xF1] = Some(NativeInstruction{
+            address_mode: AddressingMode::ZeroPageIndirectYIndexed,
+            mnemonic: "SBC",
+            operation: operations::sbc,
+            width: Memory,
+            cycle_increase: 5,
+            cycle_increases_on_page_cross: true
+        });
+        instructions[0xF2] = Some(NativeInstruction{
+            address_mode: AddressingMode::ZeroPageIndirect,
+            mnemonic: "SBC",
+            operation: operations::sbc,
+            width: Memory,
+            cycle_increase: 5,
+            cycle_increases_on_page_cross: false
+        });
+        instructions[0xF3] = Some(NativeInstruction{
+            address_mode: AddressingMode::StackRelativeIndirectYIndexed,
+            mnemonic: "SBC",
+            operation: operations::sbc,
+            width: Memory,
+            cycle_increase: 7,
+            cycle_increases_on_page_cross: false
+        });
+        instructions[0xF5] = Some(NativeInstruction{
+            address_mode: AddressingMode::XIndexedZeroPage,
+            mnemonic: "SBC",
+            operation: operations::sbc,
+            width: Memory,
+            cycle_increase: 4,
+            cycle_increases_on_page_cross: false
+        });
+        instructions[0xF7] = Some(NativeInstruction{
+            address_mode: AddressingMode::ZeroPageIndirectLongYIndexed,
+            mnemonic: "SBC",
+            operation: operations::sbc,
+            width: Memory,
+            cycle_increase: 6,
+            cycle_increases_on_page_cross: false
+        });
+        instructions[0xF9] = Some(NativeInstruction{
+            address_mode: AddressingMode::YIndexedAbsolute,
+            mnemonic: "SBC",
+            operation: operations::sbc,
+            width: Memory,
+            cycle_increase: 4,
+            cycle_increases_on_page_cross: true
+        });
+        instructions[0xFD] = Some(NativeInstruction{
+            address_mode: AddressingMode::XIndexedAbsolute,
+            mnemonic: "SBC",
+            operation: operations::sbc,
+            width: Memory,
+            cycle_increase: 4,
+            cycle_increases_on_page_cross: true
+        });
+        instructions[0xFF] = Some(NativeInstruction{
+            address_mode: AddressingMode::XIndexedAbsoluteLong,
+            mnemonic: "SBC",
+            operation: operations::sbc,
+            width: Memory,
+            cycle_increase: 5,
+            cycle_increases_on_page_cross: false
+        });
+
+        //SEC
+        instructions[0x38] = Some(NativeInstruction{
+            address_mode: AddressingMode::Implied,
+            mnemonic: "SEC",
+            operation: operations::sec,
+            width: Byte,
+            cycle_increase: 2,
+            cycle_increases_on_page_cross: false
+        });
+
+        //SED
+        instructions[0xF8] = Some(NativeInstruction{
+            address_mode: AddressingMode::Implied,
+            mnemonic: "SED",
+            operation: operations::sed,
+            width: Byte,
+            cycle_increase: 2,
+            cycle_increases_on_page_cross: false
+        });
+
+        //SEI
+        instructions[0x78] = Some(NativeInstruction{
+            address_mode: AddressingMode::Implied,
+            mnemonic: "SEI",
+            operation: operations::sei,
+            width: Byte,
+            cycle_increase: 2,
+            cycle_increases_on_page_cross: false
+        });
+
+        //SEP
+        instructions[0xE2] = Some(NativeInstruction{
+            address_mode: AddressingMode::Immediate,
+            mnemonic: "SEP",
+            operation: operations::sep,
+            width: Byte,
+            cycle_increase: 3,
+            cycle_increases_on_page_cross: false
+        });
+
+        //STA
+        instructions[0x81] = Some(NativeInstruction{
+            address_mode: AddressingMode::XIndexedZeroPageIndirect,
+            mnemonic: "STA",
+            operation: operations::sta,
+            width: Memory,
+            cycle_increase: 6,
+            cycle_increases_on_page_cross: false
+        });
+        instructions[0x83] = Some(NativeInstruction{
+            address_mode: AddressingMode::StackRelative,
+            mnemonic: "STA",
+            operation: operations::sta,
+            width: Memory,
+            cycle_increase: 4,
+            cycle_increases_on_page_cross: false
+        });
+        instructions[0x85] = Some(NativeInstruction{
+            address_mode: AddressingMode::ZeroPage,
+            mnemonic: "STA",
+            operation: operations::sta,
+            width: Memory,
+            cycle_increase: 3,
+            cycle_increases_on_page_cross: false
+        });
+        instructions[0x87] = Some(NativeInstruction{
+            address_mode: AddressingMode::ZeroPageIndirectLong,
+            mnemonic: "STA",
+            operation: operations::sta,
+            width: Memory,
+            cycle_increase: 6,
+            cycle_increases_on_page_cross: false
+        });
+        instructions[0x8D] = Some(NativeInstruction{
+            address_mode: AddressingMode::Absolute,
+            mnemonic: "STA",
+            operation: operations::sta,
+            width: Memory,
+            cycle_increase: 4,
+            cycle_increases_on_page_cross: false
+        });
+        instructions[0x8F] = Some(NativeInstruction{
+            address_mode: AddressingMode::AbsoluteLong,
+            mnemonic: "STA",
+            operation: operations::sta,
+            width: Memory,
+            cycle_increase: 5,
+            cycle_increases_on_page_cross: false
+        });
+        instructions[0x91] = Some(NativeInstruction{
+            address_mode: AddressingMode::ZeroPageIndirectYIndexed,
+            mnemonic: "STA",
+            operation: operations::sta,
+            width: Memory,
+            cycle_increase: 6,
+            cycle_increases_on_page_cross: false
+        });
+        instructions[0x92] = Some(NativeInstruction{
+            address_mode: AddressingMode::ZeroPageIndirect,
+            mnemonic: "STA",
+            operation: operations::sta,
+            width: Memory,
+            cycle_increase: 5,
+            cycle_increases_on_page_cross: false
+        });
+        instructions[0x93] = Some(NativeInstruction{
+            address_mode: AddressingMode::StackRelativeIndirectYIndexed,
+            mnemonic: "STA",
+            operation: operations::sta,
+            width: Memory,
+            cycle_increase: 7,
+            cycle_increases_on_page_cross: false
+        });
+        instructions[0x95] = Some(NativeInstruction{
+            address_mode: AddressingMode::XIndexedZeroPage,
+            mnemonic: "STA",
+            operation: operations::sta,
+            width: Memory,
+            cycle_increase: 4,
+            cycle_increases_on_page_cross: false
+        });
+        instructions[0x97] = Some(NativeInstruction{
+            address_mode: AddressingMode::ZeroPageIndirectLongYIndexed,
+            mnemonic: "STA",
+            operation: operations::sta,
+            width: Memory,
+            cycle_increase: 6,
+            cycle_increases_on_page_cross: false
+        });
+        instructions[0x99] = Some(NativeInstruction{
+            address_mode: AddressingMode::YIndexedAbsolute,
+            mnemonic: "STA",
+            operation: operations::sta,
+            width: Memory,
+            cycle_increase: 5,
+            cycle_increases_on_page_cross: false
+        });
+        instructions[0x9D] = Some(NativeInstruction{
+            address_mode: AddressingMode::XIndexedAbsolute,
+            mnemonic: "STA",
+            operation: operations::sta,
+            width: Memory,
+            cycle_increase: 5,
+            cycle_increases_on_page_cross: false
+        });
+        instructions[0x9F] = Some(NativeInstruction{
+            address_mode: AddressingMode::XIndexedAbsoluteLong,
+            mnemonic: "STA",
+            operation: operations::sta,
+            width: Memory,
+            cycle_increase: 5,
+            cycle_increases_on_page_cross: false
+        });
+
+        //STP
+        instructions[0xDB] = Some(NativeInstruction{
+            address_mode: AddressingMode::Implied,
+            mnemonic: "STP",
+            operation: operations::stp,
+            width: Byte,
+            cycle_increase: 3,
+            cycle_increases_on_page_cross: false
+        });
+
+        //STX
+        instructions[0x86] = Some(NativeInstruction{
+            address_mode: AddressingMode::ZeroPage,
+            mnemonic: "STX",
+            operation: operations::stx,
+            width: Index,
+            cycle_increase: 3,
+            cycle_increases_on_page_cross: false
+        });
+        instructions[0x8E] = Some(NativeInstruction{
+            address_mode: AddressingMode::Absolute,
+            mnemonic: "STX",
+            operation: operations::stx,
+            width: Index,
+            cycle_increase: 4,
+            cycle_increases_on_page_cross: false
+        });
+        instructions[0x96] = Some(NativeInstruction{
+            address_mode: AddressingMode::YIndexedZeroPage,
+            mnemonic: "STX",
+            operation: operations::stx,
+            width: Index,
+            cycle_increase: 4,
+            cycle_increases_on_page_cross: false
+        });
+
+        //STY
+        instructions[0x84] = Some(NativeInstruction{
+            address_mode: AddressingMode::ZeroPage,
+            mnemonic: "STY",
+            operation: operations::sty,
+            width: Index,
+            cycle_increase: 3,
+            cycle_increases_on_page_cross: false
+        });
+        instructions[0x8C] = Some(NativeInstruction{
+            address_mode: AddressingMode::Absolute,
+            mnemonic: "STY",
+            operation: operations::sty,
+            width: Index,
+            cycle_increase: 4,
+            cycle_increases_on_page_cross: false
+        });
+        instructions[0x94] = Some(NativeInstruction{
+            address_mode: AddressingMode::XIndexedZeroPage,
+            mnemonic: "STY",
+            operation: operations::sty,
+            width: Index,
+            cycle_increase: 4,
+            cycle_increases_on_page_cross: false
+        });
+
+        //STZ
+        instructions[0x64] = Some(NativeInstruction{
+            address_mode: AddressingMode::ZeroPage,
+            mnemonic: "STZ",
+            operation: operations::stz,
+            width: Memory,
+            cycle_increase: 3,
+            cycle_increases_on_page_cross: false
+        });
+        instructions[0x74] = Some(NativeInstruction{
+            address_mode: AddressingMode::XIndexedZeroPage,
+            mnemonic: "STZ",
+            operation: operations::stz,
+            width: Memory,
+            cycle_increase: 4,
+            cycle_increases_on_page_cross: false
+        });
+        instructions[0x9C] = Some(NativeInstruction{
+            address_mode: AddressingMode::Absolute,
+            mnemonic: "STZ",
+            operation: operations::stz,
+            width: Memory,
+            cycle_increase: 4,
+            cycle_increases_on_page_cross: false
+        });
+        instructions[0x9E] = Some(NativeInstruction{
+            address_mode: AddressingMode::XIndexedAbsolute,
+            mnemonic: "STZ",
+            operation: operations::stz,
+            width: Memory,
+            cycle_increase: 5,
+            cycle_increases_on_page_cross: false
+        });
+
+        //TAX
+        instructions[0xAA] = Some(NativeInstruction{
+            address_mode: AddressingMode::Implied,
+            mnemonic: "TAX",
+            operation: operations::tax,
+            width: Index,
+            cycle_increase: 2,
+            cycle_increases_on_page_cross: false
+        });
+
+        //TAY
+        instructions[0xA8] = Some(NativeInstruction{
+            address_mode: AddressingMode::Implied,
+            mnemonic: "TAY",
+            operation: operations::tay,
+            width: Index,
+            cycle_increase: 2,
+            cycle_increases_on_page_cross: false
+        });
+
+        //TCD
+        instructions[0x5B] = Some(NativeInstruction{
+            address_mode: AddressingMode::Implied,
+            mnemonic: "TCD",
+            operation: operations::tcd,
+            width: Byte,
+            cycle_increase: 2,
+            cycle_increases_on_page_cross: false
+        });
+
+        //TCS
+        instructions[0x1B] = Some(NativeInstruction{
+            address_mode: AddressingMode::Implied,
+            mnemonic: "TCS",
+            operation: operations::tcs,
+            width: Byte,
+            cycle_increase: 2,
+            cycle_increases_on_page_cross: false
+        });
+
+        //TDC
+        instructions[0x7B] = Some(NativeInstruction{
+            address_mode: AddressingMode::Implied,
+            mnemonic: "TDC",
+            operation: operations::tdc,
+            width: Byte,
+            cycle_increase: 2,
+            cycle_increases_on_page_cross: false
+        });
+
+        //TRB
+        instructions[0x14] = Some(NativeInstruction{
+            address_mode: AddressingMode::ZeroPage,
+            mnemonic: "TRB",
+            operation: operations::trb,
+            width: Memory,
+            cycle_increase: 5,
+            cycle_increases_on_page_cross: false
+        });
+        instructions[0x1C] = Some(NativeInstruction{
+            address_mode: AddressingMode::Absolute,
+            mnemonic: "TRB",
+            operation: operations::trb,
+            width: Memory,
+            cycle_increase: 6,
+            cycle_increases_on_page_cross: false
+        });
+
+        //TSB
+        instructions[0x04] = Some(NativeInstruction{
+            address_mode: AddressingMode::ZeroPage,
+            mnemonic: "TSB",
+            operation: operations::tsb,
+            width: Memory,
+            cycle_increase: 5,
+            cycle_increases_on_page_cross: false
+        });
+        instructions[0x0C] = Some(NativeInstruction{
+            address_mode: AddressingMode::Absolute,
+            mnemonic: "TSB",
+            operation: operations::tsb,
+            width: Memory,
+            cycle_increase: 6,
+            cycle_increases_on_page_cross: false
+        });
+
+        //TSC
+        instructions[0x3B] = Some(NativeInstruction{
+            address_mode: AddressingMode::Implied,
+            mnemonic: "TSC",
+            operation: operations::tsc,
+            width: Byte,
+            cycle_increase: 2,
+            cycle_increases_on_page_cross: false
+        });
+
+        //TSX
+        instructions[0xBA] = Some(NativeInstruction{
+            address_mode: AddressingMode::Implied,
+            mnemonic: "TSX",
+            operation: operations::tsx,
+            width: Index,
+            cycle_increase: 2,
+            cycle_increases_on_page_cross: false
+        });
+
+        //TXA
+        instructions[0x8A] = Some(NativeInstruction{
+            address_mode: AddressingMode::Implied,
+            mnemonic: "TXA",
+            operation: operations::txa,
+            width: Byte,
+            cycle_increase: 2,
+            cycle_increases_on_page_cross: false
+        });
+
+        //TXS
+        instructions[0x9A] = Some(NativeInstruction{
+            address_mode: AddressingMode::Implied,
+            mnemonic: "TXS",
+            operation: operations::txs,
+            width: Byte,
+            cycle_increase: 2,
+            cycle_increases_on_page_cross: false
+        });
+
+        //TXY
+        instructions[0x9B] = Some(NativeInstruction{
+            address_mode: AddressingMode::Implied,
+            mnemonic: "TXY",
+            operation: operations::txy,
+            width: Index,
+            cycle_increase: 2,
+            cycle_increases_on_page_cross: false
+        });
+
+        //TYA
+        instructions[0x98] = Some(NativeInstruction{
+            address_mode: AddressingMode::Implied,
+            mnemonic: "TYA",
+            operation: operations::tya,
+            width: Byte,
+            cycle_increase: 2,
+            cycle_increases_on_page_cross: false
+        });
+
+        //TYX
+        instructions[0xBB] = Some(NativeInstruction{
+            address_mode: AddressingMode::Implied,
+            mnemonic: "TYX",
+            operation: operations::tyx,
+            width: Index,
+            cycle_increase: 2,
+            cycle_increases_on_page_cross: false
+        });
+
+        //WAI
+        instructions[0xCB] = Some(NativeInstruction{
+            address_mode: AddressingMode::Implied,
+            mnemonic: "WAI",
+            operation: operations::wai,
+            width: Byte,
+            cycle_increase: 3,
+            cycle_increases_on_page_cross: false
+        });
+
+        //WDM
+        instructions[0x42] = Some(NativeInstruction{
+            address_mode: AddressingMode::Immediate,
+            mnemonic: "WDM",
+            operation: operations::wdm,
+            width: Byte,
+            cycle_increase: 2,
+            cycle_increases_on_page_cross: false
+        });
+
+        //XBA
+        instructions[0xEB] = Some(NativeInstruction{
+            address_mode: AddressingMode::Implied,
+            mnemonic: "XBA",
+            operation: operations::xba,
+            width: Byte,
+            cycle_increase: 3,
+            cycle_increases_on_page_cross: false
+        });
+
+        //XCE
+        instructions[0xFB] = Some(NativeInstruction{
+            address_mode: AddressingMode::Implied,
+            mnemonic: "XCE",
+            operation: operations::xce,
+            width: Byte,
+            cycle_increase: 2,
+            cycle_increases_on_page_cross: false
+        });
+
+        instructions
+    }
+}
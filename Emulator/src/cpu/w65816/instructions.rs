@@ -0,0 +1,327 @@
+use crate::cpu::bus::{Bus, Ram};
+use crate::cpu::cpu::CPU;
+use crate::cpu::execution::{ExecutionError, Fault, StepOutcome};
+use crate::cpu::instructions::AddressingMode;
+
+//What the addressing mode resolved to. Operations read and write through it at the width they need.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Operand {
+    None,
+    Accumulator,
+    Immediate(u16),
+    Address(u32),
+    BlockMove { source: u8, destination: u8 }
+}
+
+//Which flag decides the size of an immediate operand.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum OperandWidth {
+    Byte,
+    Memory,
+    Index
+}
+
+type NativeFn<B> = fn(&mut CPU<B>, operand: Operand) -> Result<(), Fault>;
+
+pub type NativeInstructionSet<B> = [Option<NativeInstruction<B>>; 256];
+
+pub struct NativeInstruction<B: Bus = Ram> {
+    pub address_mode: AddressingMode,
+    pub mnemonic: &'static str,
+    pub operation: NativeFn<B>,
+    pub width: OperandWidth,
+    //Cycles with 8-bit registers, the direct page on a page boundary and no page crossed.
+    pub cycle_increase: u32,
+    pub cycle_increases_on_page_cross: bool
+}
+
+impl<B: Bus> CPU<B> {
+
+    pub(crate) fn execute_native_instruction(&mut self) -> Result<StepOutcome, ExecutionError> {
+        let pc = self.registers.pc;
+        let opcode = self.get_next_program_byte();
+        let instruction = Self::get_native_instruction(opcode)
+            .ok_or(ExecutionError::UnknownOpcode { pc, opcode })?;
+        let operand = self.resolve_operand(instruction).map_err(|fault| fault.at(pc, opcode))?;
+        (instruction.operation)(self, operand).map_err(|fault| fault.at(pc, opcode))?;
+
+        if !self.cycle_accurate {
+            self.memory.tick(self.cycles);
+        }
+
+        Ok(StepOutcome::Executed { pc, opcode })
+    }
+
+    //Copies the program into memory starting at a 24-bit address.
+    pub fn load_program_long(&mut self, bytes: &[u8], origin: u32){
+        for (offset, &byte) in bytes.iter().enumerate() {
+            self.memory.write_long(origin.wrapping_add(offset as u32) & 0xFFFFFF, byte);
+        }
+    }
+
+    pub fn get_program_address(&self) -> u32 {
+        ((self.registers.program_bank as u32) << 16) | self.registers.pc as u32
+    }
+
+    //Instruction fetches wrap inside the program bank.
+    pub(crate) fn get_next_program_byte(&mut self) -> u8 {
+        let byte = self.read_long_byte(self.get_program_address());
+        self.registers.pc = self.registers.pc.wrapping_add(1);
+
+        byte
+    }
+
+    pub(crate) fn get_next_program_word(&mut self) -> u16 {
+        let low_byte = self.get_next_program_byte() as u16;
+        let high_byte = self.get_next_program_byte() as u16;
+        (high_byte << 8) | low_byte
+    }
+
+    fn get_next_program_long(&mut self) -> u32 {
+        let word = self.get_next_program_word() as u32;
+        let bank = self.get_next_program_byte() as u32;
+        (bank << 16) | word
+    }
+
+    fn resolve_operand(&mut self, instruction: &NativeInstruction<B>) -> Result<Operand, Fault> {
+        let operand = match instruction.address_mode {
+            AddressingMode::Implied => {
+                self.idle_cycle();
+                Operand::None
+            }
+            AddressingMode::Accumulator => {
+                self.idle_cycle();
+                Operand::Accumulator
+            }
+            AddressingMode::Immediate => {
+                let wide = match instruction.width {
+                    OperandWidth::Byte => false,
+                    OperandWidth::Memory => self.accumulator_is_16bit(),
+                    OperandWidth::Index => self.index_is_16bit()
+                };
+                let value = if wide { self.get_next_program_word() } else { self.get_next_program_byte() as u16 };
+                Operand::Immediate(value)
+            }
+            AddressingMode::Absolute => {
+                let address = self.get_next_program_word();
+                Operand::Address(self.data_bank_address(address as u32))
+            }
+            AddressingMode::XIndexedAbsolute => {
+                let address = self.get_next_program_word();
+                let index = self.get_x();
+                Operand::Address(self.index_data_bank_address(instruction, address, index))
+            }
+            AddressingMode::YIndexedAbsolute => {
+                let address = self.get_next_program_word();
+                let index = self.get_y();
+                Operand::Address(self.index_data_bank_address(instruction, address, index))
+            }
+            AddressingMode::AbsoluteLong => {
+                Operand::Address(self.get_next_program_long())
+            }
+            AddressingMode::XIndexedAbsoluteLong => {
+                let address = self.get_next_program_long();
+                Operand::Address(address.wrapping_add(self.get_x() as u32) & 0xFFFFFF)
+            }
+            AddressingMode::AbsoluteIndirect => {
+                let pointer = self.get_next_program_word();
+                let target = self.read_long_word(pointer as u32);
+                Operand::Address(self.program_bank_address(target))
+            }
+            AddressingMode::AbsoluteIndirectLong => {
+                let pointer = self.get_next_program_word() as u32;
+                let target = self.read_long_word(pointer) as u32;
+                let bank = self.read_long_byte(pointer.wrapping_add(2) & 0xFFFF) as u32;
+                Operand::Address((bank << 16) | target)
+            }
+            AddressingMode::XIndexedAbsoluteIndirect => {
+                let pointer = self.get_next_program_word().wrapping_add(self.get_x());
+                self.idle_cycle();
+                let target = self.read_long_word(self.program_bank_address(pointer));
+                Operand::Address(self.program_bank_address(target))
+            }
+            AddressingMode::ZeroPage => {
+                let offset = self.get_next_direct_offset();
+                Operand::Address(self.direct_address(offset, 0))
+            }
+            AddressingMode::XIndexedZeroPage => {
+                let offset = self.get_next_direct_offset();
+                self.idle_cycle();
+                Operand::Address(self.direct_address(offset, self.get_x()))
+            }
+            AddressingMode::YIndexedZeroPage => {
+                let offset = self.get_next_direct_offset();
+                self.idle_cycle();
+                Operand::Address(self.direct_address(offset, self.get_y()))
+            }
+            AddressingMode::ZeroPageIndirect => {
+                let offset = self.get_next_direct_offset();
+                let pointer = self.read_direct_word(offset, 0);
+                Operand::Address(self.data_bank_address(pointer as u32))
+            }
+            AddressingMode::XIndexedZeroPageIndirect => {
+                let offset = self.get_next_direct_offset();
+                self.idle_cycle();
+                let pointer = self.read_direct_word(offset, self.get_x());
+                Operand::Address(self.data_bank_address(pointer as u32))
+            }
+            AddressingMode::ZeroPageIndirectYIndexed => {
+                let offset = self.get_next_direct_offset();
+                let pointer = self.read_direct_word(offset, 0);
+                let index = self.get_y();
+                Operand::Address(self.index_data_bank_address(instruction, pointer, index))
+            }
+            AddressingMode::ZeroPageIndirectLong => {
+                let offset = self.get_next_direct_offset();
+                Operand::Address(self.read_direct_long(offset))
+            }
+            AddressingMode::ZeroPageIndirectLongYIndexed => {
+                let offset = self.get_next_direct_offset();
+                let pointer = self.read_direct_long(offset);
+                Operand::Address(pointer.wrapping_add(self.get_y() as u32) & 0xFFFFFF)
+            }
+            AddressingMode::StackRelative => {
+                let offset = self.get_next_program_byte() as u16;
+                self.idle_cycle();
+                Operand::Address(self.get_s().wrapping_add(offset) as u32)
+            }
+            AddressingMode::StackRelativeIndirectYIndexed => {
+                let offset = self.get_next_program_byte() as u16;
+                self.idle_cycle();
+                let pointer = self.read_long_word(self.get_s().wrapping_add(offset) as u32);
+                self.idle_cycle();
+                let address = self.data_bank_address(pointer as u32);
+                Operand::Address(address.wrapping_add(self.get_y() as u32) & 0xFFFFFF)
+            }
+            AddressingMode::Relative => {
+                let offset = self.get_next_program_byte() as i8 as u16;
+                Operand::Address(self.program_bank_address(self.registers.pc.wrapping_add(offset)))
+            }
+            AddressingMode::RelativeLong => {
+                let offset = self.get_next_program_word();
+                Operand::Address(self.program_bank_address(self.registers.pc.wrapping_add(offset)))
+            }
+            AddressingMode::BlockMove => {
+                let destination = self.get_next_program_byte();
+                let source = self.get_next_program_byte();
+                Operand::BlockMove { source, destination }
+            }
+            _ => return Err(Fault::UnsupportedAddressingMode)
+        };
+
+        Ok(operand)
+    }
+
+    fn data_bank_address(&self, address: u32) -> u32 {
+        (((self.registers.data_bank as u32) << 16) + address) & 0xFFFFFF
+    }
+
+    fn program_bank_address(&self, address: u16) -> u32 {
+        ((self.registers.program_bank as u32) << 16) | address as u32
+    }
+
+    //Indexing carries into the bank. The extra cycle is spent on a page cross, with 16-bit index registers,
+    //or always for instructions that write.
+    fn index_data_bank_address(&mut self, instruction: &NativeInstruction<B>, address: u16, index: u16) -> u32 {
+        let indexed = address as u32 + index as u32;
+        let page_crossed = (address as u32 & 0xFF00) != (indexed & 0xFF00);
+        if !instruction.cycle_increases_on_page_cross || self.index_is_16bit() || page_crossed {
+            self.idle_cycle();
+        }
+
+        self.data_bank_address(indexed)
+    }
+
+    //A direct page that doesn't start on a page boundary costs a cycle.
+    fn get_next_direct_offset(&mut self) -> u16 {
+        let offset = self.get_next_program_byte() as u16;
+        if self.registers.direct_page & 0x00FF != 0 {
+            self.idle_cycle();
+        }
+
+        offset
+    }
+
+    //In emulation mode with the direct page on a page boundary, indexing wraps inside the page like on the 6502.
+    fn direct_address(&self, offset: u16, index: u16) -> u32 {
+        let direct_page = self.registers.direct_page;
+        let address = if self.flags.emulation && direct_page & 0x00FF == 0 {
+            direct_page | (offset.wrapping_add(index) & 0x00FF)
+        } else {
+            direct_page.wrapping_add(offset).wrapping_add(index)
+        };
+
+        address as u32
+    }
+
+    fn read_direct_word(&mut self, offset: u16, index: u16) -> u16 {
+        let low_byte = self.read_long_byte(self.direct_address(offset, index)) as u16;
+        let high_byte = self.read_long_byte(self.direct_address(offset.wrapping_add(1), index)) as u16;
+        (high_byte << 8) | low_byte
+    }
+
+    fn read_direct_long(&mut self, offset: u16) -> u32 {
+        let word = self.read_direct_word(offset, 0) as u32;
+        let bank = self.read_long_byte(self.direct_address(offset.wrapping_add(2), 0)) as u32;
+        (bank << 16) | word
+    }
+
+    //Pointers wrap inside their bank.
+    fn read_long_word(&mut self, address: u32) -> u16 {
+        let low_byte = self.read_long_byte(address) as u16;
+        let high_address = (address & 0xFF0000) | ((address as u16).wrapping_add(1) as u32);
+        let high_byte = self.read_long_byte(high_address) as u16;
+        (high_byte << 8) | low_byte
+    }
+
+    //Reads the operand at the given width, 16-bit data continues into the next bank.
+    pub(crate) fn read_operand(&mut self, operand: Operand, wide: bool) -> Result<u16, Fault> {
+        match operand {
+            Operand::Immediate(value) => Ok(value),
+            Operand::Accumulator => Ok(self.get_accumulator()),
+            Operand::Address(address) => {
+                let low_byte = self.read_long_byte(address) as u16;
+                if !wide {
+                    return Ok(low_byte);
+                }
+                let high_byte = self.read_long_byte(address.wrapping_add(1) & 0xFFFFFF) as u16;
+                Ok((high_byte << 8) | low_byte)
+            }
+            _ => Err(Fault::MissingValue(None))
+        }
+    }
+
+    pub(crate) fn write_operand(&mut self, operand: Operand, value: u16, wide: bool) -> Result<(), Fault> {
+        match operand {
+            Operand::Accumulator => self.set_accumulator(value),
+            Operand::Address(address) => {
+                self.write_long_byte(address, value as u8);
+                if wide {
+                    self.write_long_byte(address.wrapping_add(1) & 0xFFFFFF, (value >> 8) as u8);
+                }
+            }
+            _ => return Err(Fault::MissingAddress)
+        }
+        Ok(())
+    }
+
+    //Read-modify-write at the accumulator's width, with the internal cycle between the read and the write.
+    //16-bit results are written high byte first.
+    pub(crate) fn modify_operand(&mut self, operand: Operand, operation: fn(&mut Self, u16, bool) -> u16) -> Result<(), Fault> {
+        let wide = self.accumulator_is_16bit();
+        let value = self.read_operand(operand, wide)?;
+        let result = operation(self, value, wide);
+
+        match operand {
+            Operand::Address(address) => {
+                self.idle_cycle();
+                if wide {
+                    self.write_long_byte(address.wrapping_add(1) & 0xFFFFFF, (result >> 8) as u8);
+                }
+                self.write_long_byte(address, result as u8);
+                Ok(())
+            }
+            _ => self.write_operand(operand, result, wide)
+        }
+    }
+}
@@ -0,0 +1,701 @@
+use crate::cpu::bus::Bus;
+use crate::cpu::cpu::CPU;
+use crate::cpu::execution::Fault;
+use crate::cpu::interrupts::{COP_VECTOR, IRQ_VECTOR};
+use crate::cpu::w65816::instructions::Operand;
+
+//The 65816 operations. Each one looks at M or X to decide how wide it works, the 8-bit forms behave like the 65C02.
+
+fn mask(wide: bool) -> u32 {
+    if wide { 0xFFFF } else { 0xFF }
+}
+
+fn sign_bit(wide: bool) -> u32 {
+    if wide { 0x8000 } else { 0x80 }
+}
+
+fn target_address(operand: Operand) -> Result<u32, Fault> {
+    match operand {
+        Operand::Address(address) => Ok(address),
+        _ => Err(Fault::MissingAddress)
+    }
+}
+
+//Binary or BCD addition, digit by digit in decimal mode. V comes from the sum before the top digit is adjusted.
+//Subtraction adds the complement, its decimal adjustment subtracts instead.
+fn add_with_carry<B: Bus>(cpu: &mut CPU<B>, value: u16, subtract: bool){
+    let wide = cpu.accumulator_is_16bit();
+    let acc = cpu.get_accumulator() as i32;
+    let value = if subtract { !value as u32 & mask(wide) } else { value as u32 } as i32;
+    let mut carry = cpu.flags.carry as i32;
+
+    let digits = if wide { 4 } else { 2 };
+    let mut result = acc + value + carry;
+    let mut overflow_result = result;
+    if cpu.flags.decimal {
+        result = 0;
+        for digit in 0..digits {
+            let shift = digit * 4;
+            let digit_mask = 0xF << shift;
+            let lower_mask = (1 << shift) - 1;
+
+            result = (acc & digit_mask) + (value & digit_mask) + (carry << shift) + (result & lower_mask);
+            overflow_result = result;
+            if subtract && result <= (digit_mask | lower_mask) {
+                result -= 0x6 << shift;
+            }
+            if !subtract && result > ((0x9 << shift) | lower_mask) {
+                result += 0x6 << shift;
+            }
+            carry = (result > (digit_mask | lower_mask)) as i32;
+        }
+    }
+
+    let sign = sign_bit(wide) as i32;
+    cpu.flags.overflow = !(acc ^ value) & (acc ^ overflow_result) & sign != 0;
+    cpu.flags.carry = result > mask(wide) as i32;
+
+    let result = (result as u32 & mask(wide)) as u16;
+    cpu.set_accumulator(result);
+    cpu.set_zero_and_negative(result, wide);
+}
+
+fn compare<B: Bus>(cpu: &mut CPU<B>, register: u16, value: u16, wide: bool){
+    let result = register.wrapping_sub(value);
+    cpu.flags.carry = register >= value;
+    cpu.set_zero_and_negative(result, wide);
+}
+
+fn branch<B: Bus>(cpu: &mut CPU<B>, operand: Operand, condition: bool) -> Result<(), Fault> {
+    if !condition {
+        return Ok(());
+    }
+
+    //The page cross penalty only exists in emulation mode.
+    let target = target_address(operand)? as u16;
+    cpu.idle_cycle();
+    if cpu.flags.emulation && (target & 0xFF00) != (cpu.registers.pc & 0xFF00) {
+        cpu.idle_cycle();
+    }
+    cpu.registers.pc = target;
+    Ok(())
+}
+
+fn push_register<B: Bus>(cpu: &mut CPU<B>, value: u16, wide: bool){
+    if wide {
+        cpu.push_native_word(value);
+    } else {
+        cpu.push_native_byte(value as u8);
+    }
+}
+
+fn pull_register<B: Bus>(cpu: &mut CPU<B>, wide: bool) -> u16 {
+    cpu.idle_cycle();
+    let value = if wide { cpu.pull_native_word() } else { cpu.pull_native_byte() as u16 };
+    cpu.set_zero_and_negative(value, wide);
+
+    value
+}
+
+fn block_move<B: Bus>(cpu: &mut CPU<B>, operand: Operand, step: u16) -> Result<(), Fault> {
+    let Operand::BlockMove { source, destination } = operand else {
+        return Err(Fault::UnsupportedAddressingMode);
+    };
+
+    let value = cpu.read_long_byte(((source as u32) << 16) | cpu.get_x() as u32);
+    cpu.write_long_byte(((destination as u32) << 16) | cpu.get_y() as u32, value);
+    cpu.registers.data_bank = destination;
+
+    cpu.set_x(cpu.get_x().wrapping_add(step));
+    cpu.set_y(cpu.get_y().wrapping_add(step));
+    cpu.set_c(cpu.get_c().wrapping_sub(1));
+    cpu.idle_cycle();
+    cpu.idle_cycle();
+
+    //One byte moves per execution, the instruction repeats itself until C wraps to $FFFF.
+    if cpu.get_c() != 0xFFFF {
+        cpu.registers.pc = cpu.registers.pc.wrapping_sub(3);
+    }
+    Ok(())
+}
+
+pub fn adc<B: Bus>(cpu: &mut CPU<B>, operand: Operand) -> Result<(), Fault> {
+    let value = cpu.read_operand(operand, cpu.accumulator_is_16bit())?;
+    add_with_carry(cpu, value, false);
+    Ok(())
+}
+
+pub fn and<B: Bus>(cpu: &mut CPU<B>, operand: Operand) -> Result<(), Fault> {
+    let wide = cpu.accumulator_is_16bit();
+    let result = cpu.get_accumulator() & cpu.read_operand(operand, wide)?;
+    cpu.set_accumulator(result);
+    cpu.set_zero_and_negative(result, wide);
+    Ok(())
+}
+
+pub fn asl<B: Bus>(cpu: &mut CPU<B>, operand: Operand) -> Result<(), Fault> {
+    cpu.modify_operand(operand, |cpu, value, wide| {
+        cpu.flags.carry = value as u32 & sign_bit(wide) != 0;
+        let result = ((value as u32) << 1 & mask(wide)) as u16;
+        cpu.set_zero_and_negative(result, wide);
+
+        result
+    })
+}
+
+pub fn bcc<B: Bus>(cpu: &mut CPU<B>, operand: Operand) -> Result<(), Fault> {
+    branch(cpu, operand, !cpu.flags.carry)
+}
+
+pub fn bcs<B: Bus>(cpu: &mut CPU<B>, operand: Operand) -> Result<(), Fault> {
+    branch(cpu, operand, cpu.flags.carry)
+}
+
+pub fn beq<B: Bus>(cpu: &mut CPU<B>, operand: Operand) -> Result<(), Fault> {
+    branch(cpu, operand, cpu.flags.zero)
+}
+
+//BIT immediate only sets Z.
+pub fn bit<B: Bus>(cpu: &mut CPU<B>, operand: Operand) -> Result<(), Fault> {
+    let wide = cpu.accumulator_is_16bit();
+    let value = cpu.read_operand(operand, wide)?;
+    cpu.flags.zero = cpu.get_accumulator() & value == 0;
+
+    if let Operand::Immediate(_) = operand {
+        return Ok(());
+    }
+    cpu.flags.negative = value as u32 & sign_bit(wide) != 0;
+    cpu.flags.overflow = value as u32 & (sign_bit(wide) >> 1) != 0;
+    Ok(())
+}
+
+pub fn bmi<B: Bus>(cpu: &mut CPU<B>, operand: Operand) -> Result<(), Fault> {
+    branch(cpu, operand, cpu.flags.negative)
+}
+
+pub fn bne<B: Bus>(cpu: &mut CPU<B>, operand: Operand) -> Result<(), Fault> {
+    branch(cpu, operand, !cpu.flags.zero)
+}
+
+pub fn bpl<B: Bus>(cpu: &mut CPU<B>, operand: Operand) -> Result<(), Fault> {
+    branch(cpu, operand, !cpu.flags.negative)
+}
+
+pub fn bra<B: Bus>(cpu: &mut CPU<B>, operand: Operand) -> Result<(), Fault> {
+    branch(cpu, operand, true)
+}
+
+//BRK and COP skip their signature byte as an immediate operand, so PC already points past it.
+pub fn brk<B: Bus>(cpu: &mut CPU<B>, _: Operand) -> Result<(), Fault> {
+    cpu.enter_interrupt(cpu.registers.pc, true, IRQ_VECTOR);
+    Ok(())
+}
+
+pub fn brl<B: Bus>(cpu: &mut CPU<B>, operand: Operand) -> Result<(), Fault> {
+    cpu.idle_cycle();
+    cpu.registers.pc = target_address(operand)? as u16;
+    Ok(())
+}
+
+pub fn bvc<B: Bus>(cpu: &mut CPU<B>, operand: Operand) -> Result<(), Fault> {
+    branch(cpu, operand, !cpu.flags.overflow)
+}
+
+pub fn bvs<B: Bus>(cpu: &mut CPU<B>, operand: Operand) -> Result<(), Fault> {
+    branch(cpu, operand, cpu.flags.overflow)
+}
+
+pub fn clc<B: Bus>(cpu: &mut CPU<B>, _: Operand) -> Result<(), Fault> {
+    cpu.flags.carry = false;
+    Ok(())
+}
+
+pub fn cld<B: Bus>(cpu: &mut CPU<B>, _: Operand) -> Result<(), Fault> {
+    cpu.flags.decimal = false;
+    Ok(())
+}
+
+pub fn cli<B: Bus>(cpu: &mut CPU<B>, _: Operand) -> Result<(), Fault> {
+    cpu.flags.interrupt = false;
+    Ok(())
+}
+
+pub fn clv<B: Bus>(cpu: &mut CPU<B>, _: Operand) -> Result<(), Fault> {
+    cpu.flags.overflow = false;
+    Ok(())
+}
+
+pub fn cmp<B: Bus>(cpu: &mut CPU<B>, operand: Operand) -> Result<(), Fault> {
+    let wide = cpu.accumulator_is_16bit();
+    let value = cpu.read_operand(operand, wide)?;
+    compare(cpu, cpu.get_accumulator(), value, wide);
+    Ok(())
+}
+
+pub fn cop<B: Bus>(cpu: &mut CPU<B>, _: Operand) -> Result<(), Fault> {
+    cpu.enter_interrupt(cpu.registers.pc, false, COP_VECTOR);
+    Ok(())
+}
+
+pub fn cpx<B: Bus>(cpu: &mut CPU<B>, operand: Operand) -> Result<(), Fault> {
+    let wide = cpu.index_is_16bit();
+    let value = cpu.read_operand(operand, wide)?;
+    compare(cpu, cpu.get_x(), value, wide);
+    Ok(())
+}
+
+pub fn cpy<B: Bus>(cpu: &mut CPU<B>, operand: Operand) -> Result<(), Fault> {
+    let wide = cpu.index_is_16bit();
+    let value = cpu.read_operand(operand, wide)?;
+    compare(cpu, cpu.get_y(), value, wide);
+    Ok(())
+}
+
+pub fn dec<B: Bus>(cpu: &mut CPU<B>, operand: Operand) -> Result<(), Fault> {
+    cpu.modify_operand(operand, |cpu, value, wide| {
+        let result = ((value as u32).wrapping_sub(1) & mask(wide)) as u16;
+        cpu.set_zero_and_negative(result, wide);
+
+        result
+    })
+}
+
+pub fn dex<B: Bus>(cpu: &mut CPU<B>, _: Operand) -> Result<(), Fault> {
+    cpu.set_x(cpu.get_x().wrapping_sub(1));
+    cpu.set_zero_and_negative(cpu.get_x(), cpu.index_is_16bit());
+    Ok(())
+}
+
+pub fn dey<B: Bus>(cpu: &mut CPU<B>, _: Operand) -> Result<(), Fault> {
+    cpu.set_y(cpu.get_y().wrapping_sub(1));
+    cpu.set_zero_and_negative(cpu.get_y(), cpu.index_is_16bit());
+    Ok(())
+}
+
+pub fn eor<B: Bus>(cpu: &mut CPU<B>, operand: Operand) -> Result<(), Fault> {
+    let wide = cpu.accumulator_is_16bit();
+    let result = cpu.get_accumulator() ^ cpu.read_operand(operand, wide)?;
+    cpu.set_accumulator(result);
+    cpu.set_zero_and_negative(result, wide);
+    Ok(())
+}
+
+pub fn inc<B: Bus>(cpu: &mut CPU<B>, operand: Operand) -> Result<(), Fault> {
+    cpu.modify_operand(operand, |cpu, value, wide| {
+        let result = ((value as u32 + 1) & mask(wide)) as u16;
+        cpu.set_zero_and_negative(result, wide);
+
+        result
+    })
+}
+
+pub fn inx<B: Bus>(cpu: &mut CPU<B>, _: Operand) -> Result<(), Fault> {
+    cpu.set_x(cpu.get_x().wrapping_add(1));
+    cpu.set_zero_and_negative(cpu.get_x(), cpu.index_is_16bit());
+    Ok(())
+}
+
+pub fn iny<B: Bus>(cpu: &mut CPU<B>, _: Operand) -> Result<(), Fault> {
+    cpu.set_y(cpu.get_y().wrapping_add(1));
+    cpu.set_zero_and_negative(cpu.get_y(), cpu.index_is_16bit());
+    Ok(())
+}
+
+//JMP stays in the program bank, JML loads it too.
+pub fn jmp<B: Bus>(cpu: &mut CPU<B>, operand: Operand) -> Result<(), Fault> {
+    cpu.registers.pc = target_address(operand)? as u16;
+    Ok(())
+}
+
+pub fn jml<B: Bus>(cpu: &mut CPU<B>, operand: Operand) -> Result<(), Fault> {
+    let target = target_address(operand)?;
+    cpu.registers.program_bank = (target >> 16) as u8;
+    cpu.registers.pc = target as u16;
+    Ok(())
+}
+
+pub fn jsl<B: Bus>(cpu: &mut CPU<B>, operand: Operand) -> Result<(), Fault> {
+    cpu.push_native_byte(cpu.registers.program_bank);
+    cpu.idle_cycle();
+    cpu.push_native_word(cpu.registers.pc.wrapping_sub(1));
+    jml(cpu, operand)
+}
+
+pub fn jsr<B: Bus>(cpu: &mut CPU<B>, operand: Operand) -> Result<(), Fault> {
+    cpu.idle_cycle();
+    jsr_indexed_indirect(cpu, operand)
+}
+
+//JSR (abs,X) spends its internal cycle while indexing the pointer.
+pub fn jsr_indexed_indirect<B: Bus>(cpu: &mut CPU<B>, operand: Operand) -> Result<(), Fault> {
+    cpu.push_native_word(cpu.registers.pc.wrapping_sub(1));
+    jmp(cpu, operand)
+}
+
+pub fn lda<B: Bus>(cpu: &mut CPU<B>, operand: Operand) -> Result<(), Fault> {
+    let wide = cpu.accumulator_is_16bit();
+    let value = cpu.read_operand(operand, wide)?;
+    cpu.set_accumulator(value);
+    cpu.set_zero_and_negative(value, wide);
+    Ok(())
+}
+
+pub fn ldx<B: Bus>(cpu: &mut CPU<B>, operand: Operand) -> Result<(), Fault> {
+    let wide = cpu.index_is_16bit();
+    let value = cpu.read_operand(operand, wide)?;
+    cpu.set_x(value);
+    cpu.set_zero_and_negative(value, wide);
+    Ok(())
+}
+
+pub fn ldy<B: Bus>(cpu: &mut CPU<B>, operand: Operand) -> Result<(), Fault> {
+    let wide = cpu.index_is_16bit();
+    let value = cpu.read_operand(operand, wide)?;
+    cpu.set_y(value);
+    cpu.set_zero_and_negative(value, wide);
+    Ok(())
+}
+
+pub fn lsr<B: Bus>(cpu: &mut CPU<B>, operand: Operand) -> Result<(), Fault> {
+    cpu.modify_operand(operand, |cpu, value, wide| {
+        cpu.flags.carry = value & 1 != 0;
+        let result = value >> 1;
+        cpu.set_zero_and_negative(result, wide);
+
+        result
+    })
+}
+
+//MVN copies upwards from X to Y, MVP downwards.
+pub fn mvn<B: Bus>(cpu: &mut CPU<B>, operand: Operand) -> Result<(), Fault> {
+    block_move(cpu, operand, 1)
+}
+
+pub fn mvp<B: Bus>(cpu: &mut CPU<B>, operand: Operand) -> Result<(), Fault> {
+    block_move(cpu, operand, 0xFFFF)
+}
+
+pub fn nop<B: Bus>(_: &mut CPU<B>, _: Operand) -> Result<(), Fault> {
+    Ok(())
+}
+
+pub fn ora<B: Bus>(cpu: &mut CPU<B>, operand: Operand) -> Result<(), Fault> {
+    let wide = cpu.accumulator_is_16bit();
+    let result = cpu.get_accumulator() | cpu.read_operand(operand, wide)?;
+    cpu.set_accumulator(result);
+    cpu.set_zero_and_negative(result, wide);
+    Ok(())
+}
+
+//PEA, PEI and PER push a 16-bit value: the operand, a direct page word and a PC relative address.
+pub fn pea<B: Bus>(cpu: &mut CPU<B>, operand: Operand) -> Result<(), Fault> {
+    cpu.push_native_word(target_address(operand)? as u16);
+    Ok(())
+}
+
+pub fn pei<B: Bus>(cpu: &mut CPU<B>, operand: Operand) -> Result<(), Fault> {
+    let value = cpu.read_operand(operand, true)?;
+    cpu.push_native_word(value);
+    Ok(())
+}
+
+pub fn per<B: Bus>(cpu: &mut CPU<B>, operand: Operand) -> Result<(), Fault> {
+    cpu.idle_cycle();
+    cpu.push_native_word(target_address(operand)? as u16);
+    Ok(())
+}
+
+pub fn pha<B: Bus>(cpu: &mut CPU<B>, _: Operand) -> Result<(), Fault> {
+    push_register(cpu, cpu.get_accumulator(), cpu.accumulator_is_16bit());
+    Ok(())
+}
+
+pub fn phb<B: Bus>(cpu: &mut CPU<B>, _: Operand) -> Result<(), Fault> {
+    cpu.push_native_byte(cpu.registers.data_bank);
+    Ok(())
+}
+
+pub fn phd<B: Bus>(cpu: &mut CPU<B>, _: Operand) -> Result<(), Fault> {
+    cpu.push_native_word(cpu.registers.direct_page);
+    Ok(())
+}
+
+pub fn phk<B: Bus>(cpu: &mut CPU<B>, _: Operand) -> Result<(), Fault> {
+    cpu.push_native_byte(cpu.registers.program_bank);
+    Ok(())
+}
+
+pub fn php<B: Bus>(cpu: &mut CPU<B>, _: Operand) -> Result<(), Fault> {
+    cpu.push_native_byte(cpu.flags.to_native_byte(true));
+    Ok(())
+}
+
+pub fn phx<B: Bus>(cpu: &mut CPU<B>, _: Operand) -> Result<(), Fault> {
+    push_register(cpu, cpu.get_x(), cpu.index_is_16bit());
+    Ok(())
+}
+
+pub fn phy<B: Bus>(cpu: &mut CPU<B>, _: Operand) -> Result<(), Fault> {
+    push_register(cpu, cpu.get_y(), cpu.index_is_16bit());
+    Ok(())
+}
+
+pub fn pla<B: Bus>(cpu: &mut CPU<B>, _: Operand) -> Result<(), Fault> {
+    let value = pull_register(cpu, cpu.accumulator_is_16bit());
+    cpu.set_accumulator(value);
+    Ok(())
+}
+
+pub fn plb<B: Bus>(cpu: &mut CPU<B>, _: Operand) -> Result<(), Fault> {
+    cpu.registers.data_bank = pull_register(cpu, false) as u8;
+    Ok(())
+}
+
+pub fn pld<B: Bus>(cpu: &mut CPU<B>, _: Operand) -> Result<(), Fault> {
+    cpu.registers.direct_page = pull_register(cpu, true);
+    Ok(())
+}
+
+pub fn plp<B: Bus>(cpu: &mut CPU<B>, _: Operand) -> Result<(), Fault> {
+    cpu.idle_cycle();
+    let status = cpu.pull_native_byte();
+    cpu.set_native_status(status);
+    Ok(())
+}
+
+pub fn plx<B: Bus>(cpu: &mut CPU<B>, _: Operand) -> Result<(), Fault> {
+    let value = pull_register(cpu, cpu.index_is_16bit());
+    cpu.set_x(value);
+    Ok(())
+}
+
+pub fn ply<B: Bus>(cpu: &mut CPU<B>, _: Operand) -> Result<(), Fault> {
+    let value = pull_register(cpu, cpu.index_is_16bit());
+    cpu.set_y(value);
+    Ok(())
+}
+
+//REP clears and SEP sets the bits of P given as operand.
+pub fn rep<B: Bus>(cpu: &mut CPU<B>, operand: Operand) -> Result<(), Fault> {
+    let bits = cpu.read_operand(operand, false)? as u8;
+    cpu.idle_cycle();
+    cpu.set_native_status(cpu.flags.to_native_byte(false) & !bits);
+    Ok(())
+}
+
+pub fn rol<B: Bus>(cpu: &mut CPU<B>, operand: Operand) -> Result<(), Fault> {
+    cpu.modify_operand(operand, |cpu, value, wide| {
+        let result = ((((value as u32) << 1) | cpu.flags.carry as u32) & mask(wide)) as u16;
+        cpu.flags.carry = value as u32 & sign_bit(wide) != 0;
+        cpu.set_zero_and_negative(result, wide);
+
+        result
+    })
+}
+
+pub fn ror<B: Bus>(cpu: &mut CPU<B>, operand: Operand) -> Result<(), Fault> {
+    cpu.modify_operand(operand, |cpu, value, wide| {
+        let carry = if cpu.flags.carry { sign_bit(wide) } else { 0 };
+        let result = ((value as u32 >> 1) | carry) as u16;
+        cpu.flags.carry = value & 1 != 0;
+        cpu.set_zero_and_negative(result, wide);
+
+        result
+    })
+}
+
+pub fn rti<B: Bus>(cpu: &mut CPU<B>, _: Operand) -> Result<(), Fault> {
+    cpu.idle_cycle();
+    let status = cpu.pull_native_byte();
+    cpu.set_native_status(status);
+    cpu.registers.pc = cpu.pull_native_word();
+    if !cpu.flags.emulation {
+        cpu.registers.program_bank = cpu.pull_native_byte();
+    }
+    Ok(())
+}
+
+pub fn rtl<B: Bus>(cpu: &mut CPU<B>, _: Operand) -> Result<(), Fault> {
+    cpu.idle_cycle();
+    cpu.registers.pc = cpu.pull_native_word().wrapping_add(1);
+    cpu.registers.program_bank = cpu.pull_native_byte();
+    Ok(())
+}
+
+pub fn rts<B: Bus>(cpu: &mut CPU<B>, _: Operand) -> Result<(), Fault> {
+    cpu.idle_cycle();
+    cpu.registers.pc = cpu.pull_native_word().wrapping_add(1);
+    cpu.idle_cycle();
+    Ok(())
+}
+
+pub fn sbc<B: Bus>(cpu: &mut CPU<B>, operand: Operand) -> Result<(), Fault> {
+    let value = cpu.read_operand(operand, cpu.accumulator_is_16bit())?;
+    add_with_carry(cpu, value, true);
+    Ok(())
+}
+
+pub fn sec<B: Bus>(cpu: &mut CPU<B>, _: Operand) -> Result<(), Fault> {
+    cpu.flags.carry = true;
+    Ok(())
+}
+
+pub fn sed<B: Bus>(cpu: &mut CPU<B>, _: Operand) -> Result<(), Fault> {
+    cpu.flags.decimal = true;
+    Ok(())
+}
+
+pub fn sei<B: Bus>(cpu: &mut CPU<B>, _: Operand) -> Result<(), Fault> {
+    cpu.flags.interrupt = true;
+    Ok(())
+}
+
+pub fn sep<B: Bus>(cpu: &mut CPU<B>, operand: Operand) -> Result<(), Fault> {
+    let bits = cpu.read_operand(operand, false)? as u8;
+    cpu.idle_cycle();
+    cpu.set_native_status(cpu.flags.to_native_byte(false) | bits);
+    Ok(())
+}
+
+pub fn sta<B: Bus>(cpu: &mut CPU<B>, operand: Operand) -> Result<(), Fault> {
+    cpu.write_operand(operand, cpu.get_accumulator(), cpu.accumulator_is_16bit())
+}
+
+pub fn stp<B: Bus>(cpu: &mut CPU<B>, _: Operand) -> Result<(), Fault> {
+    cpu.idle_cycle();
+    cpu.jammed = true;
+    cpu.registers.pc = cpu.registers.pc.wrapping_sub(1);
+    Ok(())
+}
+
+pub fn stx<B: Bus>(cpu: &mut CPU<B>, operand: Operand) -> Result<(), Fault> {
+    cpu.write_operand(operand, cpu.get_x(), cpu.index_is_16bit())
+}
+
+pub fn sty<B: Bus>(cpu: &mut CPU<B>, operand: Operand) -> Result<(), Fault> {
+    cpu.write_operand(operand, cpu.get_y(), cpu.index_is_16bit())
+}
+
+pub fn stz<B: Bus>(cpu: &mut CPU<B>, operand: Operand) -> Result<(), Fault> {
+    cpu.write_operand(operand, 0, cpu.accumulator_is_16bit())
+}
+
+//Transfers take the width of the destination, the 16-bit registers C, D and S are always moved whole.
+pub fn tax<B: Bus>(cpu: &mut CPU<B>, _: Operand) -> Result<(), Fault> {
+    cpu.set_x(cpu.get_c());
+    cpu.set_zero_and_negative(cpu.get_x(), cpu.index_is_16bit());
+    Ok(())
+}
+
+pub fn tay<B: Bus>(cpu: &mut CPU<B>, _: Operand) -> Result<(), Fault> {
+    cpu.set_y(cpu.get_c());
+    cpu.set_zero_and_negative(cpu.get_y(), cpu.index_is_16bit());
+    Ok(())
+}
+
+pub fn tcd<B: Bus>(cpu: &mut CPU<B>, _: Operand) -> Result<(), Fault> {
+    cpu.registers.direct_page = cpu.get_c();
+    cpu.set_zero_and_negative(cpu.registers.direct_page, true);
+    Ok(())
+}
+
+pub fn tcs<B: Bus>(cpu: &mut CPU<B>, _: Operand) -> Result<(), Fault> {
+    cpu.set_s(cpu.get_c());
+    Ok(())
+}
+
+pub fn tdc<B: Bus>(cpu: &mut CPU<B>, _: Operand) -> Result<(), Fault> {
+    cpu.set_c(cpu.registers.direct_page);
+    cpu.set_zero_and_negative(cpu.get_c(), true);
+    Ok(())
+}
+
+pub fn trb<B: Bus>(cpu: &mut CPU<B>, operand: Operand) -> Result<(), Fault> {
+    cpu.modify_operand(operand, |cpu, value, _| {
+        let acc = cpu.get_accumulator();
+        cpu.flags.zero = acc & value == 0;
+
+        value & !acc
+    })
+}
+
+pub fn tsb<B: Bus>(cpu: &mut CPU<B>, operand: Operand) -> Result<(), Fault> {
+    cpu.modify_operand(operand, |cpu, value, _| {
+        let acc = cpu.get_accumulator();
+        cpu.flags.zero = acc & value == 0;
+
+        value | acc
+    })
+}
+
+pub fn tsc<B: Bus>(cpu: &mut CPU<B>, _: Operand) -> Result<(), Fault> {
+    cpu.set_c(cpu.get_s());
+    cpu.set_zero_and_negative(cpu.get_c(), true);
+    Ok(())
+}
+
+pub fn tsx<B: Bus>(cpu: &mut CPU<B>, _: Operand) -> Result<(), Fault> {
+    cpu.set_x(cpu.get_s());
+    cpu.set_zero_and_negative(cpu.get_x(), cpu.index_is_16bit());
+    Ok(())
+}
+
+pub fn txa<B: Bus>(cpu: &mut CPU<B>, _: Operand) -> Result<(), Fault> {
+    cpu.set_accumulator(cpu.get_x());
+    cpu.set_zero_and_negative(cpu.get_accumulator(), cpu.accumulator_is_16bit());
+    Ok(())
+}
+
+pub fn txs<B: Bus>(cpu: &mut CPU<B>, _: Operand) -> Result<(), Fault> {
+    cpu.set_s(cpu.get_x());
+    Ok(())
+}
+
+pub fn txy<B: Bus>(cpu: &mut CPU<B>, _: Operand) -> Result<(), Fault> {
+    cpu.set_y(cpu.get_x());
+    cpu.set_zero_and_negative(cpu.get_y(), cpu.index_is_16bit());
+    Ok(())
+}
+
+pub fn tya<B: Bus>(cpu: &mut CPU<B>, _: Operand) -> Result<(), Fault> {
+    cpu.set_accumulator(cpu.get_y());
+    cpu.set_zero_and_negative(cpu.get_accumulator(), cpu.accumulator_is_16bit());
+    Ok(())
+}
+
+pub fn tyx<B: Bus>(cpu: &mut CPU<B>, _: Operand) -> Result<(), Fault> {
+    cpu.set_x(cpu.get_y());
+    cpu.set_zero_and_negative(cpu.get_x(), cpu.index_is_16bit());
+    Ok(())
+}
+
+pub fn wai<B: Bus>(cpu: &mut CPU<B>, _: Operand) -> Result<(), Fault> {
+    cpu.idle_cycle();
+    cpu.waiting = true;
+    Ok(())
+}
+
+//WDM is reserved for future opcodes and skips its operand.
+pub fn wdm<B: Bus>(_: &mut CPU<B>, _: Operand) -> Result<(), Fault> {
+    Ok(())
+}
+
+//XBA swaps A and B, N and Z follow the new A.
+pub fn xba<B: Bus>(cpu: &mut CPU<B>, _: Operand) -> Result<(), Fault> {
+    cpu.idle_cycle();
+    std::mem::swap(&mut cpu.registers.acc, &mut cpu.registers.acc_high);
+    cpu.set_zero_and_negative(cpu.registers.acc as u16, false);
+    Ok(())
+}
+
+//XCE swaps C and E. Leaving emulation mode keeps M and X set, so the registers stay 8-bit until REP.
+pub fn xce<B: Bus>(cpu: &mut CPU<B>, _: Operand) -> Result<(), Fault> {
+    let emulation = cpu.flags.carry;
+    cpu.flags.carry = cpu.flags.emulation;
+    if emulation {
+        cpu.enter_emulation_mode();
+    } else {
+        cpu.flags.emulation = false;
+    }
+    Ok(())
+}
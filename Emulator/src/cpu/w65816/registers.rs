@@ -0,0 +1,162 @@
+use crate::cpu::bus::Bus;
+use crate::cpu::cpu::{CPU, Flags};
+use crate::helpers::bitwise::get_msb;
+
+impl Flags {
+
+    //P as the 65816 pushes it, NVMXDIZC in native mode. In emulation mode bit 5 reads as 1 and bit 4 is B.
+    pub(crate) fn to_native_byte(&self, brk: bool) -> u8 {
+        let mut byte = 0u8;
+
+        if self.negative  { byte |= 1 << 7 }
+        if self.overflow  { byte |= 1 << 6 }
+        if self.decimal   { byte |= 1 << 3 }
+        if self.interrupt { byte |= 1 << 2 }
+        if self.zero      { byte |= 1 << 1 }
+        if self.carry     { byte |= 1 << 0 }
+
+        if self.emulation {
+            byte |= 1 << 5;
+            if brk { byte |= 1 << 4 }
+        } else {
+            if self.memory_select { byte |= 1 << 5 }
+            if self.index_select  { byte |= 1 << 4 }
+        }
+
+        byte
+    }
+
+    //In emulation mode M and X stay set whatever the byte says.
+    pub(crate) fn load_native_byte(&mut self, byte: u8) {
+        self.negative = byte & (1 << 7) != 0;
+        self.overflow = byte & (1 << 6) != 0;
+        self.decimal = byte & (1 << 3) != 0;
+        self.interrupt = byte & (1 << 2) != 0;
+        self.zero = byte & (1 << 1) != 0;
+        self.carry = byte & (1 << 0) != 0;
+
+        if !self.emulation {
+            self.memory_select = byte & (1 << 5) != 0;
+            self.index_select = byte & (1 << 4) != 0;
+        }
+    }
+}
+
+impl<B: Bus> CPU<B> {
+
+    //The 65816 starts in emulation mode with 8-bit registers and the stack in page 1.
+    pub(crate) fn enter_emulation_mode(&mut self){
+        self.flags.emulation = true;
+        self.flags.memory_select = true;
+        self.flags.index_select = true;
+        self.registers.sp_high = 0x01;
+        self.clear_index_high_bytes();
+    }
+
+    //Loads P and keeps the registers consistent with the new M and X.
+    pub(crate) fn set_native_status(&mut self, byte: u8){
+        self.flags.load_native_byte(byte);
+        self.clear_index_high_bytes();
+    }
+
+    //Setting X drops the high bytes of the index registers.
+    fn clear_index_high_bytes(&mut self){
+        if self.flags.index_select {
+            self.registers.xr_high = 0;
+            self.registers.yr_high = 0;
+        }
+    }
+
+    pub fn accumulator_is_16bit(&self) -> bool {
+        !self.flags.memory_select
+    }
+
+    pub fn index_is_16bit(&self) -> bool {
+        !self.flags.index_select
+    }
+
+    //C is the full 16-bit accumulator, A its low byte and B its high byte.
+    pub fn get_c(&self) -> u16 {
+        ((self.registers.acc_high as u16) << 8) | self.registers.acc as u16
+    }
+
+    pub fn set_c(&mut self, value: u16){
+        self.registers.acc = value as u8;
+        self.registers.acc_high = (value >> 8) as u8;
+    }
+
+    pub fn get_x(&self) -> u16 {
+        ((self.registers.xr_high as u16) << 8) | self.registers.xr as u16
+    }
+
+    pub fn set_x(&mut self, value: u16){
+        self.registers.xr = value as u8;
+        self.registers.xr_high = if self.index_is_16bit() { (value >> 8) as u8 } else { 0 };
+    }
+
+    pub fn get_y(&self) -> u16 {
+        ((self.registers.yr_high as u16) << 8) | self.registers.yr as u16
+    }
+
+    pub fn set_y(&mut self, value: u16){
+        self.registers.yr = value as u8;
+        self.registers.yr_high = if self.index_is_16bit() { (value >> 8) as u8 } else { 0 };
+    }
+
+    pub fn get_s(&self) -> u16 {
+        ((self.registers.sp_high as u16) << 8) | self.registers.sp as u16
+    }
+
+    //In emulation mode the stack never leaves page 1.
+    pub fn set_s(&mut self, value: u16){
+        self.registers.sp = value as u8;
+        self.registers.sp_high = if self.flags.emulation { 0x01 } else { (value >> 8) as u8 };
+    }
+
+    //The accumulator as wide as M makes it. An 8-bit write leaves B alone.
+    pub fn get_accumulator(&self) -> u16 {
+        if self.accumulator_is_16bit() { self.get_c() } else { self.registers.acc as u16 }
+    }
+
+    pub fn set_accumulator(&mut self, value: u16){
+        if self.accumulator_is_16bit() {
+            self.set_c(value);
+        } else {
+            self.registers.acc = value as u8;
+        }
+    }
+
+    pub(crate) fn set_zero_and_negative(&mut self, value: u16, wide: bool){
+        if wide {
+            self.flags.zero = value == 0;
+            self.flags.negative = value & 0x8000 != 0;
+        } else {
+            self.flags.zero = value as u8 == 0;
+            self.flags.negative = get_msb(value as u8) != 0;
+        }
+    }
+
+    //The 65816 stack lives in bank 0 and can be anywhere in it in native mode.
+    pub(crate) fn push_native_byte(&mut self, value: u8){
+        let s = self.get_s();
+        self.write_long_byte(s as u32, value);
+        self.set_s(s.wrapping_sub(1));
+    }
+
+    pub(crate) fn push_native_word(&mut self, value: u16){
+        self.push_native_byte((value >> 8) as u8);
+        self.push_native_byte(value as u8);
+    }
+
+    pub(crate) fn pull_native_byte(&mut self) -> u8 {
+        let s = self.get_s().wrapping_add(1);
+        self.set_s(s);
+        self.read_long_byte(self.get_s() as u32)
+    }
+
+    pub(crate) fn pull_native_word(&mut self) -> u16 {
+        let low_byte = self.pull_native_byte() as u16;
+        let high_byte = self.pull_native_byte() as u16;
+        (high_byte << 8) | low_byte
+    }
+}
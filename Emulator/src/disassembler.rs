@@ -0,0 +1,224 @@
+use std::collections::HashMap;
+use std::fmt;
+use crate::cpu::bus::Ram;
+use crate::cpu::cpu::{Variant, CPU};
+use crate::cpu::instructions::AddressingMode;
+use crate::cpu::w65816::instructions::OperandWidth;
+
+//One instruction, or a .byte for a byte that doesn't start one.
+#[derive(Debug, Clone, PartialEq)]
+pub struct DisassembledInstruction {
+    pub address: u32,
+    pub bytes: Vec<u8>,
+    pub mnemonic: &'static str,
+    //None for a .byte.
+    pub address_mode: Option<AddressingMode>,
+    //The operand in assembler syntax, empty for implied instructions.
+    pub operand: String,
+    //The symbol at the address, listings print it as a label.
+    pub label: Option<String>,
+}
+
+impl DisassembledInstruction {
+
+    //The address, bytes and instruction, like C000  B1 20       LDA ($20),Y.
+    pub fn listing_line(&self) -> String {
+        let bytes = self.bytes.iter().map(|byte| format!("{:02X}", byte)).collect::<Vec<_>>().join(" ");
+        format!("{}  {:<11} {}", format_address(self.address), bytes, self)
+    }
+}
+
+impl fmt::Display for DisassembledInstruction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.operand.is_empty() {
+            write!(f, "{}", self.mnemonic)
+        } else {
+            write!(f, "{} {}", self.mnemonic, self.operand)
+        }
+    }
+}
+
+//Decodes instructions one at a time. Instructions don't cross the end of the bytes, what is left becomes .byte lines.
+pub struct Disassembler<'a> {
+    bytes: &'a [u8],
+    origin: u32,
+    position: usize,
+    variant: Variant,
+    symbols: HashMap<u32, String>,
+    //65816 immediates are two bytes when the register they go to is 16-bit. REP and SEP update these as they are seen.
+    wide_accumulator: bool,
+    wide_index: bool
+}
+
+impl<'a> Disassembler<'a> {
+
+    pub fn new(bytes: &'a [u8], origin: u32) -> Self {
+        Disassembler {
+            bytes,
+            origin,
+            position: 0,
+            variant: Variant::Nmos6502,
+            symbols: HashMap::new(),
+            wide_accumulator: false,
+            wide_index: false
+        }
+    }
+
+    pub fn with_variant(mut self, variant: Variant) -> Self {
+        self.variant = variant;
+        self
+    }
+
+    //Addresses with a name are written as the name, and instructions at them get it as their label.
+    pub fn with_symbols(mut self, symbols: HashMap<u32, String>) -> Self {
+        self.symbols = symbols;
+        self
+    }
+
+    //The register widths the 65816 is in at the origin, both 8-bit unless set.
+    pub fn with_register_widths(mut self, accumulator_16bit: bool, index_16bit: bool) -> Self {
+        self.wide_accumulator = accumulator_16bit;
+        self.wide_index = index_16bit;
+        self
+    }
+
+    //Program addresses wrap inside the bank of the origin.
+    fn address_of(&self, position: usize) -> u32 {
+        (self.origin & 0xFF0000) | (self.origin as u16).wrapping_add(position as u16) as u32
+    }
+
+    //The addressing mode, mnemonic and operand length of the opcode.
+    fn decode(&self, opcode: u8) -> Option<(AddressingMode, &'static str, usize)> {
+        if self.variant == Variant::Wdc65816 {
+            CPU::<Ram>::get_native_instruction(opcode).map(|instruction| {
+                let wide = match instruction.width {
+                    OperandWidth::Byte => false,
+                    OperandWidth::Memory => self.wide_accumulator,
+                    OperandWidth::Index => self.wide_index
+                };
+                let length = instruction.address_mode.operand_length() as usize + (wide && instruction.address_mode == AddressingMode::Immediate) as usize;
+                (instruction.address_mode, instruction.mnemonic, length)
+            })
+        } else {
+            CPU::<Ram>::get_instruction(self.variant, opcode)
+                .map(|instruction| (instruction.address_mode, instruction.mnemonic, instruction.address_mode.operand_length() as usize))
+        }
+    }
+
+    fn track_register_widths(&mut self, mnemonic: &str, operand: &[u8]){
+        let Some(&bits) = operand.first() else { return };
+        let wide = match mnemonic {
+            "REP" => true,
+            "SEP" => false,
+            _ => return
+        };
+        if bits & 0x20 != 0 { self.wide_accumulator = wide }
+        if bits & 0x10 != 0 { self.wide_index = wide }
+    }
+}
+
+impl Iterator for Disassembler<'_> {
+    type Item = DisassembledInstruction;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let opcode = *self.bytes.get(self.position)?;
+        let address = self.address_of(self.position);
+        let label = self.symbols.get(&address).cloned();
+
+        let decoded = self.decode(opcode).filter(|&(_, _, length)| self.position + length < self.bytes.len());
+        let instruction = match decoded {
+            Some((mode, mnemonic, length)) => {
+                let bytes = self.bytes[self.position..=self.position + length].to_vec();
+                let operand = format_operand(mode, &bytes[1..], address, &self.symbols);
+                if self.variant == Variant::Wdc65816 {
+                    self.track_register_widths(mnemonic, &bytes[1..]);
+                }
+                DisassembledInstruction { address, bytes, mnemonic, address_mode: Some(mode), operand, label }
+            }
+            None => DisassembledInstruction {
+                address,
+                bytes: vec![opcode],
+                mnemonic: ".byte",
+                address_mode: None,
+                operand: format!("${:02X}", opcode),
+                label
+            }
+        };
+
+        self.position += instruction.bytes.len();
+        Some(instruction)
+    }
+}
+
+//Disassembles the bytes as NMOS 6502 code loaded at origin.
+pub fn disassemble(bytes: &[u8], origin: u32) -> Vec<DisassembledInstruction> {
+    Disassembler::new(bytes, origin).collect()
+}
+
+//One listing line per instruction, with a label line before the instructions that have one.
+pub fn format_listing<I: IntoIterator<Item = DisassembledInstruction>>(instructions: I) -> String {
+    let mut listing = String::new();
+    for instruction in instructions {
+        if let Some(label) = &instruction.label {
+            listing.push_str(label);
+            listing.push_str(":\n");
+        }
+        listing.push_str(&instruction.listing_line());
+        listing.push('\n');
+    }
+
+    listing
+}
+
+fn format_address(address: u32) -> String {
+    if address > 0xFFFF { format!("{:06X}", address) } else { format!("{:04X}", address) }
+}
+
+//The operand bytes in assembler syntax. pc is the address of the opcode, branches are written as their target.
+fn format_operand(mode: AddressingMode, operand: &[u8], pc: u32, symbols: &HashMap<u32, String>) -> String {
+    let byte = |index: usize| operand.get(index).copied().unwrap_or(0);
+    let word = (byte(1) as u16) << 8 | byte(0) as u16;
+    let long = (byte(2) as u32) << 16 | word as u32;
+    let name = |address: u32, digits: usize| match symbols.get(&address) {
+        Some(symbol) => symbol.clone(),
+        None => format!("${:0digits$X}", address, digits = digits)
+    };
+    let zero_page = name(byte(0) as u32, 2);
+    let absolute = name(word as u32, 4);
+    //Branch targets stay in the bank of the branch.
+    let next_pc = (pc as u16).wrapping_add(1 + operand.len() as u16);
+    let target = |offset: u16| {
+        let target = next_pc.wrapping_add(offset);
+        symbols.get(&((pc & 0xFF0000) | target as u32)).cloned().unwrap_or_else(|| format!("${:04X}", target))
+    };
+
+    match mode {
+        AddressingMode::Implied | AddressingMode::OpcodeOnly => String::new(),
+        AddressingMode::Accumulator => "A".to_string(),
+        AddressingMode::Immediate if operand.len() == 2 => format!("#${:04X}", word),
+        AddressingMode::Immediate => format!("#${:02X}", byte(0)),
+        AddressingMode::Absolute => absolute,
+        AddressingMode::AbsoluteIndirect => format!("({})", absolute),
+        AddressingMode::XIndexedAbsolute => format!("{},X", absolute),
+        AddressingMode::YIndexedAbsolute => format!("{},Y", absolute),
+        AddressingMode::ZeroPage => zero_page,
+        AddressingMode::XIndexedZeroPage => format!("{},X", zero_page),
+        AddressingMode::YIndexedZeroPage => format!("{},Y", zero_page),
+        AddressingMode::XIndexedZeroPageIndirect => format!("({},X)", zero_page),
+        AddressingMode::ZeroPageIndirectYIndexed => format!("({}),Y", zero_page),
+        AddressingMode::Relative => target(byte(0) as i8 as u16),
+        AddressingMode::ZeroPageIndirect => format!("({})", zero_page),
+        AddressingMode::XIndexedAbsoluteIndirect => format!("({},X)", absolute),
+        AddressingMode::ZeroPageRelative => format!("{},{}", zero_page, target(byte(1) as i8 as u16)),
+        AddressingMode::AbsoluteLong => name(long, 6),
+        AddressingMode::XIndexedAbsoluteLong => format!("{},X", name(long, 6)),
+        AddressingMode::AbsoluteIndirectLong => format!("[{}]", absolute),
+        AddressingMode::ZeroPageIndirectLong => format!("[{}]", zero_page),
+        AddressingMode::ZeroPageIndirectLongYIndexed => format!("[{}],Y", zero_page),
+        AddressingMode::StackRelative => format!("${:02X},S", byte(0)),
+        AddressingMode::StackRelativeIndirectYIndexed => format!("(${:02X},S),Y", byte(0)),
+        AddressingMode::RelativeLong => target(word),
+        //The destination bank comes first in memory, assemblers write the source first.
+        AddressingMode::BlockMove => format!("${:02X},${:02X}", byte(1), byte(0)),
+    }
+}
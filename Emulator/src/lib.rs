@@ -0,0 +1,29 @@
+#![allow(clippy::module_inception, clippy::upper_case_acronyms)]
+
+mod assembler;
+mod cpu;
+mod disassembler;
+mod helpers;
+mod monitor;
+#[cfg(test)]
+mod tests;
+
+pub use crate::assembler::assembler::{assemble, Assembler, Assembly, ListingLine};
+pub use crate::assembler::error::AssemblyError;
+pub use crate::assembler::linker::{link, LinkError, MemoryArea, MemoryConfig, SegmentRule, SegmentType};
+pub use crate::assembler::object::{Field, ObjectFile, ObjectSegment, Relocation, SymbolValue, OBJECT_VERSION};
+pub use crate::cpu::bus::{Bus, Ram};
+pub use crate::cpu::cpu::{CPU, Flags, Registers, Variant};
+pub use crate::cpu::devices::{Device, DeviceBus};
+pub use crate::cpu::execution::{ExecutionError, StepOutcome};
+pub use crate::cpu::instructions::{AddressingMode, Instruction, InstructionParameter, InstructionSet, ValueOrAddress};
+pub use crate::cpu::interrupts::{COP_VECTOR, IRQ_VECTOR, NMI_VECTOR, RESET_VECTOR};
+pub use crate::cpu::memory::MEMORY_SIZE;
+pub use crate::cpu::rewind::{Rewind, RewindError, WriteRecord};
+pub use crate::cpu::run::StopReason;
+pub use crate::cpu::save_state::{SaveStateError, StateReader, StateWriter, SAVE_STATE_VERSION};
+pub use crate::cpu::trace::{TraceFormat, TraceLine};
+pub use crate::cpu::undocumented::UnstableConstants;
+pub use crate::cpu::w65816::instructions::{NativeInstruction, NativeInstructionSet, Operand, OperandWidth};
+pub use crate::disassembler::{disassemble, format_listing, DisassembledInstruction, Disassembler};
+pub use crate::monitor::Monitor;
@@ -1,40 +1,286 @@
-use crate::cpu::cpu::CPU;
+use std::collections::HashMap;
+use std::env;
+use std::fs;
+use std::io::{self, BufRead, Write};
+use std::path::Path;
+use std::process;
+use emulator::{assemble, link, Assembler, Assembly, CPU, Disassembler, MemoryConfig, Monitor, ObjectFile, Ram, Variant, format_listing};
 
-mod cpu;
-mod helpers;
-mod tests;
+const DEMO_PROGRAM: &str = "
+    * = $0600
+        JSR init
+        JSR loop
+        JSR end
+    init:
+        LDX #$00
+        RTS
+    loop:
+        INX
+        CPX #$05
+        BNE loop
+        RTS
+    end:
+        BRK
+";
+
+const USAGE: &str = "Usage: Emulator [monitor [<file> [--origin <hex>]] [--cpu 6502|65c02|65816]]
+       Emulator [disassemble <file> [--origin <hex>] [--cpu 6502|65c02|65816] [--symbols <file>]]
+       Emulator [assemble <file> [-o <file>] [--cpu 6502|65c02|65816] [--listing <file>] [--symbols <file>] [--object]]
+       Emulator [link <object>... --config <file> [-o <file>] [--symbols <file>]]";
 
 fn main() {
+    let args: Vec<String> = env::args().skip(1).collect();
+    match args.first().map(String::as_str) {
+        None => run_monitor(demo_monitor()),
+        Some("monitor") => match monitor(&args[1..]) {
+            Ok(monitor) => run_monitor(monitor),
+            Err(error) => {
+                eprintln!("{}", error);
+                eprintln!("{}", USAGE);
+                process::exit(1);
+            }
+        },
+        Some("disassemble") => {
+            if let Err(error) = disassemble(&args[1..]) {
+                eprintln!("{}", error);
+                eprintln!("{}", USAGE);
+                process::exit(1);
+            }
+        }
+        Some("assemble") => {
+            if let Err(error) = assemble_file(&args[1..]) {
+                eprintln!("{}", error);
+                process::exit(1);
+            }
+        }
+        Some("link") => {
+            if let Err(error) = link_files(&args[1..]) {
+                eprintln!("{}", error);
+                process::exit(1);
+            }
+        }
+        Some(_) => {
+            eprintln!("{}", USAGE);
+            process::exit(1);
+        }
+    }
+}
+
+//The monitor with the demo program loaded and PC on it.
+fn demo_monitor() -> Monitor {
     let mut cpu = CPU::new();
-    let instruction_set = cpu.get_instruction_set();
-
-    let bytes = [
-        0x20, 0x09, 0x00, 0x20, 0x0c, 0x00, 0x20, 0x12, 0x00, 0xa2, 0x00, 0x60, 0xe8, 0xe0, 0x05, 0xd0, 0xfb, 0x60, 0x00
-    ];
-
-    cpu.set_interrupt_vector(0xFF, 0xFF);
-    while (cpu.registers.pc as usize) < bytes.len() {
-        let opcode = cpu.get_next_byte(&mut bytes.as_slice());
-        let instruction = instruction_set.get(&opcode).unwrap_or_else(|| {
-            panic!("Instruction {:#04X?} not found.", opcode)
-        });
-        cpu.execute_instruction(instruction, bytes.as_slice());
-    }
-
-    println!("PC {:#04X?}, SP: {:#04X?}", cpu.registers.pc, cpu.registers.sp);
-    println!("ACC: {:#04X?}, XR: {:#04X?}, YR: {:#04X?}", cpu.registers.acc, cpu.registers.xr, cpu.registers.yr);
-    println!("Carry: {}, Zero: {}, Interrupt: {}, Decimal: {}, Overflow: {}, Negative: {}", cpu.flags.carry, cpu.flags.zero, cpu.flags.interrupt, cpu.flags.decimal, cpu.flags.overflow, cpu.flags.negative);
-    println!("Used Memory:");
-    println!("============");
-    print_used_memory(cpu);
-    println!("============");
-}
-
-fn print_used_memory(cpu: CPU) {
-    cpu.memory.iter()
-        .enumerate()
-        .filter(|&(_idx, &value)| value != 0)
-        .for_each(|(index, &value)| {
-            println!("Index: {}, Value: {}", index, value);
-        });
+    let assembly = assemble(DEMO_PROGRAM).expect("The demo program assembles.");
+    assembly.load(&mut cpu);
+    cpu.registers.pc = assembly.origin as u16;
+    println!("The demo program is at ${:04X}, g runs it and ? lists the commands.", assembly.origin);
+
+    Monitor::new(cpu)
+}
+
+//The monitor with a raw binary loaded at the origin ($0000 unless given), PC is set to the origin.
+fn monitor(args: &[String]) -> Result<Monitor, String> {
+    let mut path = None;
+    let mut origin = 0;
+    let mut variant = Variant::Nmos6502;
+
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        let mut value = || args.next().ok_or(format!("{} needs a value.", arg));
+        match arg.as_str() {
+            "--origin" => origin = parse_hex(value()?)?,
+            "--cpu" => variant = parse_variant(value()?)?,
+            _ if path.is_none() => path = Some(arg),
+            _ => return Err(format!("Unexpected argument {}.", arg))
+        }
+    }
+
+    let mut cpu = CPU::with_variant(variant);
+    if let Some(path) = path {
+        let bytes = fs::read(path).map_err(|error| format!("{}: {}", path, error))?;
+        //The binary has to fit in the address space. The 65816 gets RAM up to the last bank it reaches and starts in
+        //the bank of the origin.
+        let end = if variant == Variant::Wdc65816 { 0x1000000 } else { 0x10000 };
+        if origin >= end {
+            return Err(format!("The origin ${:X} is outside the address space.", origin));
+        }
+        if origin as usize + bytes.len() > end as usize {
+            return Err(format!("{} bytes at ${:X} go past the end of the address space at ${:X}.", bytes.len(), origin, end));
+        }
+        let banks = (origin as usize + bytes.len()).div_ceil(0x10000);
+        cpu = CPU::with_bus_and_variant(Ram::with_banks(banks), variant);
+        cpu.load_program_long(&bytes, origin);
+        cpu.registers.pc = origin as u16;
+        cpu.registers.program_bank = (origin >> 16) as u8;
+        println!("{} bytes loaded at ${:04X}, ? lists the commands.", bytes.len(), origin);
+    }
+
+    Ok(Monitor::new(cpu))
+}
+
+//Reads commands until x or the end of the input.
+fn run_monitor(mut monitor: Monitor) {
+    let mut input = io::stdin().lock();
+    let mut line = String::new();
+    while !monitor.is_finished() {
+        print!("{}", monitor.prompt());
+        io::stdout().flush().ok();
+        line.clear();
+        match input.read_line(&mut line) {
+            Ok(0) => break,
+            Ok(_) => print!("{}", monitor.execute(&line)),
+            Err(error) => {
+                eprintln!("{}", error);
+                break;
+            }
+        }
+    }
+}
+
+//Prints a listing of a raw binary, loaded at the origin ($0000 unless given).
+fn disassemble(args: &[String]) -> Result<(), String> {
+    let mut path = None;
+    let mut origin = 0;
+    let mut variant = Variant::Nmos6502;
+    let mut symbols = HashMap::new();
+
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        let mut value = || args.next().ok_or(format!("{} needs a value.", arg));
+        match arg.as_str() {
+            "--origin" => origin = parse_hex(value()?)?,
+            "--cpu" => variant = parse_variant(value()?)?,
+            "--symbols" => symbols = read_symbols(value()?)?,
+            _ if path.is_none() => path = Some(arg),
+            _ => return Err(format!("Unexpected argument {}.", arg))
+        }
+    }
+
+    let path = path.ok_or("No file to disassemble.")?;
+    let bytes = fs::read(path).map_err(|error| format!("{}: {}", path, error))?;
+    let disassembler = Disassembler::new(&bytes, origin).with_variant(variant).with_symbols(symbols);
+    print!("{}", format_listing(disassembler));
+
+    Ok(())
+}
+
+//Writes the image to the output file (the source with .bin by default), and optionally a listing and the symbols
+//in the format --symbols of disassemble reads. With --object it writes an object file for link instead (.o).
+fn assemble_file(args: &[String]) -> Result<(), String> {
+    let mut path = None;
+    let mut output = None;
+    let mut variant = Variant::Nmos6502;
+    let mut listing = None;
+    let mut symbols = None;
+    let mut object = false;
+
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        let mut value = || args.next().ok_or(format!("{} needs a value.", arg));
+        match arg.as_str() {
+            "-o" => output = Some(value()?),
+            "--cpu" => variant = parse_variant(value()?)?,
+            "--listing" => listing = Some(value()?),
+            "--symbols" => symbols = Some(value()?),
+            "--object" => object = true,
+            _ if path.is_none() => path = Some(arg),
+            _ => return Err(format!("Unexpected argument {}.\n{}", arg, USAGE))
+        }
+    }
+
+    let path = Path::new(path.ok_or(format!("No file to assemble.\n{}", USAGE))?);
+    let assembler = Assembler::new().with_variant(variant);
+    if object {
+        if listing.is_some() || symbols.is_some() {
+            return Err("--listing and --symbols are for images, link the object file to get its symbols.".to_string());
+        }
+        let object = assembler.assemble_object_file(path).map_err(|error| error.to_string())?;
+        let output = output.map_or_else(|| path.with_extension("o"), |output| output.into());
+        object.write_to_file(&output).map_err(|error| format!("{}: {}", output.display(), error))?;
+        println!("{} segments written to {}", object.segments.len(), output.display());
+        return Ok(());
+    }
+
+    let assembly = assembler.assemble_file(path).map_err(|error| error.to_string())?;
+    let output = output.map_or_else(|| path.with_extension("bin"), |output| output.into());
+    fs::write(&output, &assembly.image).map_err(|error| format!("{}: {}", output.display(), error))?;
+    if let Some(listing) = listing {
+        fs::write(listing, assembly.listing()).map_err(|error| format!("{}: {}", listing, error))?;
+    }
+    if let Some(symbols) = symbols {
+        write_symbols(&assembly, symbols)?;
+    }
+    println!("{} bytes at ${:04X} written to {}", assembly.image.len(), assembly.origin, output.display());
+
+    Ok(())
+}
+
+//Links object files into an image (the first object with .bin by default) that loads at its lowest address.
+fn link_files(args: &[String]) -> Result<(), String> {
+    let mut paths = Vec::new();
+    let mut config = None;
+    let mut output = None;
+    let mut symbols = None;
+
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        let mut value = || args.next().ok_or(format!("{} needs a value.", arg));
+        match arg.as_str() {
+            "--config" => config = Some(value()?),
+            "-o" => output = Some(value()?),
+            "--symbols" => symbols = Some(value()?),
+            _ => paths.push(Path::new(arg))
+        }
+    }
+
+    let config = config.ok_or(format!("No memory configuration.\n{}", USAGE))?;
+    let config = MemoryConfig::from_file(config).map_err(|error| format!("{}: {}", config, error))?;
+    let first = paths.first().ok_or(format!("No object files to link.\n{}", USAGE))?;
+    let objects = paths.iter()
+        .map(|path| ObjectFile::read_from_file(path).map_err(|error| format!("{}: {}", path.display(), error)))
+        .collect::<Result<Vec<_>, _>>()?;
+    let assembly = link(&config, &objects).map_err(|error| error.to_string())?;
+    let output = output.map_or_else(|| first.with_extension("bin"), |output| output.into());
+    fs::write(&output, &assembly.image).map_err(|error| format!("{}: {}", output.display(), error))?;
+    if let Some(symbols) = symbols {
+        write_symbols(&assembly, symbols)?;
+    }
+    println!("{} bytes at ${:04X} written to {}", assembly.image.len(), assembly.origin, output.display());
+
+    Ok(())
+}
+
+fn write_symbols(assembly: &Assembly, path: &str) -> Result<(), String> {
+    let text: String = assembly.symbols.iter().map(|(name, value)| format!("{} = ${:04X}\n", name, value)).collect();
+    fs::write(path, text).map_err(|error| format!("{}: {}", path, error))
+}
+
+fn parse_variant(name: &str) -> Result<Variant, String> {
+    match name.to_ascii_lowercase().as_str() {
+        "6502" => Ok(Variant::Nmos6502),
+        "65c02" => Ok(Variant::Wdc65C02),
+        "65816" => Ok(Variant::Wdc65816),
+        other => Err(format!("Unknown CPU {}.", other))
+    }
+}
+
+//Hex with an optional $ or 0x in front.
+fn parse_hex(text: &str) -> Result<u32, String> {
+    let digits = text.strip_prefix('$').or_else(|| text.strip_prefix("0x")).unwrap_or(text);
+    u32::from_str_radix(digits, 16).map_err(|_| format!("{} isn't a hex number.", text))
+}
+
+//One NAME = $ADDRESS per line, ; starts a comment.
+fn read_symbols(path: &str) -> Result<HashMap<u32, String>, String> {
+    let text = fs::read_to_string(path).map_err(|error| format!("{}: {}", path, error))?;
+    let mut symbols = HashMap::new();
+    for (number, line) in text.lines().enumerate() {
+        let line = line.split(';').next().unwrap_or("").trim();
+        if line.is_empty() {
+            continue;
+        }
+        let (name, address) = line.split_once('=').ok_or(format!("{}:{}: expected NAME = $ADDRESS.", path, number + 1))?;
+        symbols.insert(parse_hex(address.trim())?, name.trim().to_string());
+    }
+
+    Ok(symbols)
 }
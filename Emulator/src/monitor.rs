@@ -0,0 +1,590 @@
+use std::fs;
+use crate::assembler::assembler::Assembler;
+use crate::cpu::bus::{Bus, Ram};
+use crate::cpu::cpu::{Variant, CPU};
+use crate::cpu::execution::ExecutionError;
+use crate::cpu::run::StopReason;
+use crate::cpu::trace::TraceFormat;
+use crate::disassembler::Disassembler;
+
+//How many instructions g runs before it gives up on reaching a breakpoint.
+const GO_LIMIT: usize = 10_000_000;
+//How many instructions z steps at most, it prints a line for each one.
+const STEP_LIMIT: u32 = 10_000;
+const DUMP_ROWS: u32 = 8;
+const DISASSEMBLY_LINES: usize = 16;
+
+const HELP: &str = "\
+m [start [end]]          show memory, continuing where the last m stopped
+> address byte...        write bytes
+a address [instruction]  assemble, an empty line ends assembly
+d [start [end]]          disassemble, from PC unless given
+r [name=value...]        show registers, or set pc a x y sp p and the flags n v d i z c
+                         on the 65816 also pbr and dbr, and d sets the direct page
+z [count]                step
+n                        step, running a subroutine call to its return
+g [address]              run until a breakpoint, BRK or a jump to itself
+b [address]              set a breakpoint, or list them
+bc [address]             clear a breakpoint, or all of them
+f start end byte...      fill with a pattern
+t start end destination  copy
+c start end destination  compare
+h start end byte...|\"text\"  hunt
+l file [address]         load a binary
+reset                    reset the CPU
+history, !n, !!          list or repeat commands
+x                        exit
+Numbers are hex, with an optional $.";
+
+//A machine language monitor in the style of Wozmon and VICE. Each line is a command, and execute returns
+//what it prints.
+pub struct Monitor<B: Bus = Ram> {
+    pub cpu: CPU<B>,
+    history: Vec<String>,
+    //Where m and d continue when they aren't given an address. d starts at PC when it is None.
+    next_dump: u32,
+    next_disassembly: Option<u32>,
+    //While assembling line by line, where the next line goes.
+    assembling: Option<u32>,
+    finished: bool,
+}
+
+impl<B: Bus> Monitor<B> {
+
+    pub fn new(cpu: CPU<B>) -> Self {
+        Monitor { cpu, history: Vec::new(), next_dump: 0, next_disassembly: None, assembling: None, finished: false }
+    }
+
+    //Whether x was given.
+    pub fn is_finished(&self) -> bool {
+        self.finished
+    }
+
+    //(C:$0600) normally, and the address while assembling.
+    pub fn prompt(&self) -> String {
+        match self.assembling {
+            Some(address) => format!("A {} ", self.format_address(address)),
+            None => format!("(C:${:04X}) ", self.cpu.registers.pc)
+        }
+    }
+
+    pub fn history(&self) -> &[String] {
+        &self.history
+    }
+
+    pub fn execute(&mut self, line: &str) -> String {
+        let line = line.trim();
+        if let Some(address) = self.assembling {
+            if line.is_empty() {
+                self.assembling = None;
+                return String::new();
+            }
+            return self.assemble_line(address, line).unwrap_or_else(|error| format!("{}\n", error));
+        }
+
+        let line = match line {
+            "" => return String::new(),
+            "history" => {
+                return self.history.iter().enumerate().map(|(index, command)| format!("{:>4}  {}\n", index + 1, command)).collect();
+            }
+            "!!" => match self.history.last() {
+                Some(command) => command.clone(),
+                None => return "There is no command to repeat.\n".to_string()
+            },
+            _ if line.starts_with('!') => match line[1..].parse::<usize>().ok().and_then(|number| self.history.get(number.wrapping_sub(1))) {
+                Some(command) => command.clone(),
+                None => return format!("There is no command {} in the history.\n", &line[1..])
+            },
+            _ => line.to_string()
+        };
+        self.history.push(line.clone());
+
+        let (command, rest) = match line.find(|character: char| character.is_whitespace()) {
+            Some(index) => (&line[..index], line[index..].trim()),
+            None => (line.as_str(), "")
+        };
+        let arguments: Vec<&str> = rest.split_whitespace().collect();
+        //> takes its address straight after it too, as in >0200 A9.
+        let (command, arguments) = match command.strip_prefix('>') {
+            Some(address) if !address.is_empty() => (">", [vec![address], arguments].concat()),
+            _ => (command, arguments)
+        };
+
+        let result = match command.to_ascii_lowercase().as_str() {
+            "m" => self.memory(&arguments),
+            ">" => self.modify(&arguments),
+            "a" => self.assemble(&arguments, rest),
+            "d" => self.disassemble(&arguments),
+            "r" => self.registers(&arguments),
+            "z" => self.step(&arguments),
+            "n" => self.step_over(),
+            "g" => self.go(&arguments),
+            "b" => self.breakpoint(&arguments),
+            "bc" => self.clear_breakpoint(&arguments),
+            "f" => self.fill(&arguments),
+            "t" => self.transfer(&arguments),
+            "c" => self.compare(&arguments),
+            "h" => self.hunt(&arguments, rest),
+            "l" => self.load(&arguments),
+            "reset" => {
+                self.cpu.reset();
+                self.next_disassembly = None;
+                Ok(self.register_line())
+            }
+            "x" | "q" => {
+                self.finished = true;
+                Ok(String::new())
+            }
+            "?" | "help" => Ok(format!("{}\n", HELP)),
+            _ => Err(format!("Unknown command {}, ? lists the commands.", command))
+        };
+
+        result.unwrap_or_else(|error| format!("{}\n", error))
+    }
+
+    fn highest_address(&self) -> u32 {
+        if self.cpu.variant == Variant::Wdc65816 { 0xFFFFFF } else { 0xFFFF }
+    }
+
+    fn format_address(&self, address: u32) -> String {
+        if self.cpu.variant == Variant::Wdc65816 { format!("{:06X}", address) } else { format!("{:04X}", address) }
+    }
+
+    fn address(&self, text: &str) -> Result<u32, String> {
+        let address = parse_hex(text)?;
+        if address > self.highest_address() {
+            return Err(format!("${:X} is outside the address space.", address));
+        }
+        Ok(address)
+    }
+
+    //start and end, both included.
+    fn range(&self, start: &str, end: &str) -> Result<(u32, u32), String> {
+        let (start, end) = (self.address(start)?, self.address(end)?);
+        if end < start {
+            return Err(format!("The range ends at ${:X}, before it starts.", end));
+        }
+        Ok((start, end))
+    }
+
+    fn peek(&self, address: u32) -> u8 {
+        self.cpu.memory.peek_long(address)
+    }
+
+    fn poke(&mut self, address: u32, value: u8){
+        self.cpu.memory.write_long(address, value);
+    }
+
+    fn memory(&mut self, arguments: &[&str]) -> Result<String, String> {
+        let (start, end) = match arguments {
+            [] => (self.next_dump, self.next_dump + 16 * DUMP_ROWS - 1),
+            [start] => {
+                let start = self.address(start)?;
+                (start, start + 16 * DUMP_ROWS - 1)
+            }
+            [start, end] => self.range(start, end)?,
+            _ => return Err("m takes a start and an end.".to_string())
+        };
+        let end = end.min(self.highest_address());
+
+        let mut output = String::new();
+        for row in (start..=end).step_by(16) {
+            let bytes: Vec<u8> = (row..=end.min(row + 15)).map(|address| self.peek(address)).collect();
+            let hex = bytes.iter().map(|byte| format!("{:02X}", byte)).collect::<Vec<_>>().join(" ");
+            let text: String = bytes.iter().map(|&byte| if (0x20..0x7F).contains(&byte) { byte as char } else { '.' }).collect();
+            output.push_str(&format!("{}  {:<47}  {}\n", self.format_address(row), hex, text));
+        }
+        self.next_dump = if end >= self.highest_address() { 0 } else { end + 1 };
+
+        Ok(output)
+    }
+
+    fn modify(&mut self, arguments: &[&str]) -> Result<String, String> {
+        let [address, bytes @ ..] = arguments else {
+            return Err("> takes an address and bytes.".to_string());
+        };
+        let address = self.address(address)?;
+        let bytes = parse_bytes(bytes)?;
+        if bytes.is_empty() {
+            return Err("> takes an address and bytes.".to_string());
+        }
+        for (offset, &byte) in bytes.iter().enumerate() {
+            self.poke((address + offset as u32) & self.highest_address(), byte);
+        }
+
+        Ok(String::new())
+    }
+
+    //a 0600 LDA #$01 assembles one line and keeps assembling after it, a 0600 starts with an empty line.
+    fn assemble(&mut self, arguments: &[&str], rest: &str) -> Result<String, String> {
+        let Some(address) = arguments.first() else {
+            return Err("a takes an address.".to_string());
+        };
+        let address = self.address(address)?;
+        let instruction = rest[address_length(rest)..].trim();
+        self.assembling = Some(address);
+        if instruction.is_empty() {
+            return Ok(String::new());
+        }
+        self.assemble_line(address, instruction)
+    }
+
+    fn assemble_line(&mut self, address: u32, line: &str) -> Result<String, String> {
+        let source = format!(".org ${:X}\n{}", address, line);
+        let assembly = Assembler::new().with_variant(self.cpu.variant).assemble(&source).map_err(|error| error.message)?;
+        for (offset, &byte) in assembly.image.iter().enumerate() {
+            self.poke(address + offset as u32, byte);
+        }
+        self.assembling = Some((address + assembly.image.len() as u32) & self.highest_address());
+
+        Ok(assembly.listing.last().map_or(String::new(), |line| format!("{}\n", line.to_string().trim_end())))
+    }
+
+    fn disassemble(&mut self, arguments: &[&str]) -> Result<String, String> {
+        let pc = if self.cpu.variant == Variant::Wdc65816 { self.cpu.get_program_address() } else { self.cpu.registers.pc as u32 };
+        let (start, end) = match arguments {
+            [] => (self.next_disassembly.unwrap_or(pc), None),
+            [start] => (self.address(start)?, None),
+            [start, end] => {
+                let (start, end) = self.range(start, end)?;
+                (start, Some(end))
+            }
+            _ => return Err("d takes a start and an end.".to_string())
+        };
+
+        //Enough bytes for the longest instruction after the last line.
+        let last = end.unwrap_or(start + DISASSEMBLY_LINES as u32 * 4).min(self.highest_address());
+        let bytes: Vec<u8> = (start..=(last + 3).min(self.highest_address())).map(|address| self.peek(address)).collect();
+        let instructions: Vec<_> = Disassembler::new(&bytes, start)
+            .with_variant(self.cpu.variant)
+            .take_while(|instruction| instruction.address <= last)
+            .take(if end.is_some() { usize::MAX } else { DISASSEMBLY_LINES })
+            .collect();
+        if let Some(instruction) = instructions.last() {
+            self.next_disassembly = Some((instruction.address + instruction.bytes.len() as u32) & self.highest_address());
+        }
+
+        Ok(instructions.iter().map(|instruction| format!("{}\n", instruction.listing_line())).collect())
+    }
+
+    fn register_line(&self) -> String {
+        let line = self.cpu.trace_line();
+        let hex = |value: u16, wide: bool| if wide { format!("{:04X}", value) } else { format!("{:02X}", value) };
+        let banks = if self.cpu.variant == Variant::Wdc65816 {
+            format!(" DBR:{:02X} D:{:04X}", self.cpu.registers.data_bank, self.cpu.registers.direct_page)
+        } else {
+            String::new()
+        };
+        format!("PC:{} A:{} X:{} Y:{} SP:{} P:{}{}\n",
+            self.format_address(line.pc),
+            hex(line.acc, line.wide_accumulator),
+            hex(line.xr, line.wide_index),
+            hex(line.yr, line.wide_index),
+            hex(line.sp, line.wide_stack),
+            line.flags,
+            banks
+        )
+    }
+
+    //On the 65816 A, X, Y and SP take as many bits as M, X and E give them, and d is the direct page register.
+    fn registers(&mut self, arguments: &[&str]) -> Result<String, String> {
+        let native = self.cpu.variant == Variant::Wdc65816;
+        for argument in arguments {
+            let (name, value) = argument.split_once('=').ok_or(format!("Expected name=value, not {}.", argument))?;
+            let value = parse_hex(value)?;
+            let byte = || u8::try_from(value).map_err(|_| format!("{} is a byte, ${:X} doesn't fit.", name, value));
+            let word = || u16::try_from(value).map_err(|_| format!("{} is a word, ${:X} doesn't fit.", name, value));
+            let sized = |wide: bool| if wide { word() } else { byte().map(u16::from) };
+            let bit = || match value {
+                0 => Ok(false),
+                1 => Ok(true),
+                _ => Err(format!("{} is a flag, set it to 0 or 1.", name))
+            };
+            let cpu = &mut self.cpu;
+            match name.to_ascii_lowercase().as_str() {
+                "pc" => {
+                    cpu.registers.pc = u16::try_from(value).map_err(|_| format!("${:X} doesn't fit in PC.", value))?;
+                    cpu.jammed = false;
+                }
+                "a" if native => {
+                    let value = sized(cpu.accumulator_is_16bit())?;
+                    cpu.set_accumulator(value);
+                }
+                "x" if native => {
+                    let value = sized(cpu.index_is_16bit())?;
+                    cpu.set_x(value);
+                }
+                "y" if native => {
+                    let value = sized(cpu.index_is_16bit())?;
+                    cpu.set_y(value);
+                }
+                "sp" if native => {
+                    let value = sized(!cpu.flags.emulation)?;
+                    cpu.set_s(value);
+                }
+                "p" if native && !cpu.flags.emulation => cpu.set_native_status(byte()?),
+                "pbr" if native => cpu.registers.program_bank = byte()?,
+                "dbr" if native => cpu.registers.data_bank = byte()?,
+                "d" if native => cpu.registers.direct_page = word()?,
+                "a" => cpu.registers.acc = byte()?,
+                "x" => cpu.registers.xr = byte()?,
+                "y" => cpu.registers.yr = byte()?,
+                "sp" => cpu.registers.sp = byte()?,
+                "p" => cpu.flags.load_from_byte(byte()?),
+                "n" => cpu.flags.negative = bit()?,
+                "v" => cpu.flags.overflow = bit()?,
+                "d" => cpu.flags.decimal = bit()?,
+                "i" => cpu.flags.interrupt = bit()?,
+                "z" => cpu.flags.zero = bit()?,
+                "c" => cpu.flags.carry = bit()?,
+                _ => return Err(format!("Unknown register {}.", name))
+            }
+        }
+        if !arguments.is_empty() {
+            self.next_disassembly = None;
+        }
+
+        Ok(self.register_line())
+    }
+
+    //A jammed CPU stays where it stopped until it is reset or PC is set, which clears the jam.
+    fn check_jammed(&self) -> Result<(), String> {
+        if self.cpu.jammed {
+            return Err(format!("The CPU is jammed at ${:04X}, reset or set PC to go on.", self.cpu.registers.pc));
+        }
+        Ok(())
+    }
+
+    //Each instruction as it runs, then the registers after the last one.
+    fn step(&mut self, arguments: &[&str]) -> Result<String, String> {
+        let count = match arguments {
+            [] => 1,
+            [count] => parse_hex(count)?,
+            _ => return Err("z takes a count.".to_string())
+        };
+        self.check_jammed()?;
+        let mut output = String::new();
+        let mut stopped = false;
+        for _ in 0..count.min(STEP_LIMIT) {
+            output.push_str(&format!("{}\n", self.cpu.trace_line().format(&TraceFormat::Nestest)));
+            if let Err(error) = self.cpu.step() {
+                output.push_str(&format!("{}\n", error));
+                stopped = true;
+                break;
+            }
+            if self.cpu.jammed {
+                output.push_str(&format!("The CPU halted at ${:04X}.\n", self.cpu.registers.pc));
+                stopped = true;
+                break;
+            }
+        }
+        if count > STEP_LIMIT && !stopped {
+            output.push_str(&format!("Stopped after {} steps, z takes a count of at most ${:X}.\n", STEP_LIMIT, STEP_LIMIT));
+        }
+        self.next_disassembly = None;
+        output.push_str(&self.register_line());
+
+        Ok(output)
+    }
+
+    //JSR and JSL run until they return, other instructions are stepped.
+    fn step_over(&mut self) -> Result<String, String> {
+        self.check_jammed()?;
+        let line = self.cpu.trace_line();
+        if !matches!(line.mnemonic, "JSR" | "JSL") {
+            return self.step(&[]);
+        }
+        let return_address = (line.pc + line.bytes.len() as u32) as u16;
+        let mut budget = GO_LIMIT;
+        let result = self.cpu.run_until(|cpu| {
+            budget -= 1;
+            cpu.registers.pc == return_address || budget == 0
+        });
+        self.next_disassembly = None;
+
+        Ok(format!("{}{}", stop_message(result, budget == 0), self.register_line()))
+    }
+
+    fn go(&mut self, arguments: &[&str]) -> Result<String, String> {
+        match arguments {
+            [] => self.check_jammed()?,
+            [address] => {
+                self.cpu.registers.pc = self.address(address)? as u16;
+                self.cpu.jammed = false;
+            }
+            _ => return Err("g takes an address.".to_string())
+        }
+        let mut budget = GO_LIMIT;
+        let result = self.cpu.run_until(|_| {
+            budget -= 1;
+            budget == 0
+        });
+        self.next_disassembly = None;
+
+        Ok(format!("{}{}", stop_message(result, budget == 0), self.register_line()))
+    }
+
+    fn breakpoint(&mut self, arguments: &[&str]) -> Result<String, String> {
+        match arguments {
+            [] => {
+                let mut breakpoints: Vec<_> = self.cpu.breakpoints.iter().copied().collect();
+                breakpoints.sort_unstable();
+                Ok(breakpoints.iter().map(|address| format!("${:04X}\n", address)).collect())
+            }
+            [address] => {
+                let address = parse_hex(address)?;
+                let address = u16::try_from(address).map_err(|_| format!("Breakpoints are on 16-bit addresses, ${:X} isn't one.", address))?;
+                self.cpu.add_breakpoint(address);
+                Ok(String::new())
+            }
+            _ => Err("b takes an address.".to_string())
+        }
+    }
+
+    fn clear_breakpoint(&mut self, arguments: &[&str]) -> Result<String, String> {
+        match arguments {
+            [] => self.cpu.breakpoints.clear(),
+            [address] => {
+                let address = parse_hex(address)?;
+                if address > 0xFFFF || !self.cpu.breakpoints.remove(&(address as u16)) {
+                    return Err(format!("There is no breakpoint at ${:X}.", address));
+                }
+            }
+            _ => return Err("bc takes an address.".to_string())
+        }
+
+        Ok(String::new())
+    }
+
+    fn fill(&mut self, arguments: &[&str]) -> Result<String, String> {
+        let [start, end, pattern @ ..] = arguments else {
+            return Err("f takes a start, an end and bytes.".to_string());
+        };
+        let (start, end) = self.range(start, end)?;
+        let pattern = parse_bytes(pattern)?;
+        if pattern.is_empty() {
+            return Err("f takes a start, an end and bytes.".to_string());
+        }
+        for address in start..=end {
+            self.poke(address, pattern[(address - start) as usize % pattern.len()]);
+        }
+
+        Ok(String::new())
+    }
+
+    //Copies through a buffer, so the ranges can overlap.
+    fn transfer(&mut self, arguments: &[&str]) -> Result<String, String> {
+        let [start, end, destination] = arguments else {
+            return Err("t takes a start, an end and a destination.".to_string());
+        };
+        let (start, end) = self.range(start, end)?;
+        let destination = self.address(destination)?;
+        if destination + (end - start) > self.highest_address() {
+            return Err("The copy would go past the end of memory.".to_string());
+        }
+        let bytes: Vec<u8> = (start..=end).map(|address| self.peek(address)).collect();
+        for (offset, byte) in bytes.into_iter().enumerate() {
+            self.poke(destination + offset as u32, byte);
+        }
+
+        Ok(String::new())
+    }
+
+    //Lists every address that differs, with both bytes.
+    fn compare(&mut self, arguments: &[&str]) -> Result<String, String> {
+        let [start, end, other] = arguments else {
+            return Err("c takes a start, an end and the start of the other range.".to_string());
+        };
+        let (start, end) = self.range(start, end)?;
+        let other = self.address(other)?;
+        if other + (end - start) > self.highest_address() {
+            return Err("The other range goes past the end of memory.".to_string());
+        }
+
+        let differences: String = (start..=end)
+            .map(|address| (address, other + (address - start)))
+            .filter(|&(address, other)| self.peek(address) != self.peek(other))
+            .map(|(address, other)| format!("{} {:02X}  {} {:02X}\n", self.format_address(address), self.peek(address), self.format_address(other), self.peek(other)))
+            .collect();
+        if differences.is_empty() {
+            return Ok("The ranges are the same.\n".to_string());
+        }
+
+        Ok(differences)
+    }
+
+    fn hunt(&mut self, arguments: &[&str], rest: &str) -> Result<String, String> {
+        let [start, end, pattern @ ..] = arguments else {
+            return Err("h takes a start, an end and bytes or \"text\".".to_string());
+        };
+        let (start, end) = self.range(start, end)?;
+        let text = rest.find('"').map(|quote| rest[quote + 1..].trim_end().trim_end_matches('"'));
+        let pattern = match text {
+            Some(text) => text.as_bytes().to_vec(),
+            None => parse_bytes(pattern)?
+        };
+        if pattern.is_empty() {
+            return Err("h takes a start, an end and bytes or \"text\".".to_string());
+        }
+
+        let found: Vec<String> = (start..=end)
+            .filter(|&address| address + pattern.len() as u32 - 1 <= end)
+            .filter(|&address| pattern.iter().enumerate().all(|(offset, &byte)| self.peek(address + offset as u32) == byte))
+            .map(|address| self.format_address(address))
+            .collect();
+        if found.is_empty() {
+            return Ok("Not found.\n".to_string());
+        }
+
+        Ok(format!("{}\n", found.join(" ")))
+    }
+
+    fn load(&mut self, arguments: &[&str]) -> Result<String, String> {
+        let (path, address) = match arguments {
+            [path] => (path, 0),
+            [path, address] => (path, self.address(address)?),
+            _ => return Err("l takes a file and an address.".to_string())
+        };
+        let bytes = fs::read(path).map_err(|error| format!("{}: {}", path, error))?;
+        if address + bytes.len() as u32 > self.highest_address() + 1 {
+            return Err(format!("{} is {} bytes, it doesn't fit at ${:X}.", path, bytes.len(), address));
+        }
+        for (offset, &byte) in bytes.iter().enumerate() {
+            self.poke(address + offset as u32, byte);
+        }
+
+        Ok(format!("{} bytes loaded at ${}.\n", bytes.len(), self.format_address(address)))
+    }
+}
+
+//Why g or n stopped, nothing when n reached the return address.
+fn stop_message(result: Result<StopReason, ExecutionError>, out_of_budget: bool) -> String {
+    match result {
+        Ok(StopReason::Condition) if out_of_budget => format!("Still running after {} instructions.\n", GO_LIMIT),
+        Ok(StopReason::Condition) => String::new(),
+        Ok(StopReason::Breakpoint { pc }) => format!("Breakpoint at ${:04X}.\n", pc),
+        Ok(StopReason::Brk { pc }) => format!("BRK at ${:04X}.\n", pc),
+        Ok(StopReason::Jammed { pc }) => format!("The CPU halted at ${:04X}.\n", pc),
+        Ok(StopReason::SelfJump { pc }) => format!("Stopped at ${:04X}, it jumps to itself.\n", pc),
+        Ok(StopReason::ReturnFromTopFrame { pc }) => format!("Returned from the top frame at ${:04X}.\n", pc),
+        Ok(reason) => format!("Stopped: {:?}.\n", reason),
+        Err(error) => format!("{}\n", error)
+    }
+}
+
+//Hex with an optional $ in front.
+fn parse_hex(text: &str) -> Result<u32, String> {
+    let digits = text.strip_prefix('$').unwrap_or(text);
+    u32::from_str_radix(digits, 16).map_err(|_| format!("{} isn't a hex number.", text))
+}
+
+fn parse_bytes(texts: &[&str]) -> Result<Vec<u8>, String> {
+    texts.iter()
+        .map(|text| parse_hex(text).and_then(|value| u8::try_from(value).map_err(|_| format!("{} doesn't fit in a byte.", text))))
+        .collect()
+}
+
+//The length of the first word, the address a was given.
+fn address_length(rest: &str) -> usize {
+    rest.find(|character: char| character.is_whitespace()).unwrap_or(rest.len())
+}
@@ -1,5 +1,8 @@
+#![allow(clippy::bool_assert_comparison, clippy::identity_op)]
+
 mod adc_test;
 mod and_test;
+mod assembler_test;
 mod test_lib;
 mod asl_test;
 mod bcc_test;
@@ -9,35 +12,57 @@ mod bit_test;
 mod bmi_test;
 mod bne_test;
 mod bpl_test;
+mod branch_test;
 mod brk_test;
+mod bus_test;
 mod bvc_test;
 mod bvs_test;
 mod clc_test;
 mod cli_test;
 mod clv_test;
 mod cld_test;
+mod cmos_test;
 mod cmp_test;
 mod cpx_test;
 mod cpy_test;
+mod cycle_test;
 mod dec_test;
+mod decimal_test;
+mod device_test;
+mod disassembler_test;
 mod dex_test;
 mod dey_test;
 mod eor_test;
+mod execution_test;
 mod inc_test;
 mod inx_test;
 mod iny_test;
+mod instruction_set_test;
+mod interrupt_test;
 mod jmp_test;
 mod jsr_test;
+mod linker_test;
+mod load_program_test;
+mod monitor_test;
+mod preprocessor_test;
+mod reset_test;
+mod rewind_test;
+mod run_test;
+mod save_state_test;
 mod sbc_test;
 mod sed_test;
 mod sec_test;
 mod sei_test;
 mod sta_test;
+mod status_test;
 mod stx_test;
 mod sty_test;
 mod tax_test;
+mod trace_test;
 mod tay_test;
 mod tsx_test;
 mod txa_test;
 mod txs_test;
-mod tya_test;
\ No newline at end of file
+mod tya_test;
+mod undocumented_test;
+mod w65816_test;
\ No newline at end of file
@@ -13,7 +13,7 @@ mod adc_test {
 
         cpu.registers.acc = 0x80;
         cpu.flags.carry = true;
-        cpu.execute_instruction_sequence(&mut bytes.as_slice());
+        cpu.execute_instruction_sequence(&bytes);
 
         assert_eq!(cpu.registers.acc, 0x00);
         assert_eq!(cpu.flags.carry, true);
@@ -32,7 +32,7 @@ mod adc_test {
 
         cpu.registers.acc = 0x50;
         cpu.flags.carry = false;
-        cpu.execute_instruction_sequence(&mut bytes.as_slice());
+        cpu.execute_instruction_sequence(&bytes);
 
         assert_eq!(cpu.registers.acc, 0xA0);
         assert_eq!(cpu.flags.carry, false);
@@ -50,7 +50,7 @@ mod adc_test {
             0x6D, low_byte, high_byte
         ];
 
-        cpu.execute_instruction_sequence(&mut bytes.as_slice());
+        cpu.execute_instruction_sequence(&bytes);
 
         assert_eq!(cpu.registers.acc, 0x20);
     }
@@ -66,7 +66,7 @@ mod adc_test {
 
         //X + ACC
         cpu.registers.xr = 3;
-        cpu.execute_instruction_sequence(&mut bytes.as_slice());
+        cpu.execute_instruction_sequence(&bytes);
 
         assert_eq!(cpu.registers.acc, 0x20);
     }
@@ -82,7 +82,7 @@ mod adc_test {
 
         //Y + ACC
         cpu.registers.yr = 3;
-        cpu.execute_instruction_sequence(&mut bytes.as_slice());
+        cpu.execute_instruction_sequence(&bytes);
 
         assert_eq!(cpu.registers.acc, 0x20);
     }
@@ -93,7 +93,7 @@ mod adc_test {
         cpu.memory[0x33] = 0x20;
         let bytes = [0x65, 0x33];
 
-        cpu.execute_instruction_sequence(&mut bytes.as_slice());
+        cpu.execute_instruction_sequence(&bytes);
 
         assert_eq!(cpu.registers.acc, 0x20);
     }
@@ -106,7 +106,7 @@ mod adc_test {
 
         //X + Zero Page (0x30 + 3 = 0x33)
         cpu.registers.xr = 3;
-        cpu.execute_instruction_sequence(&mut bytes.as_slice());
+        cpu.execute_instruction_sequence(&bytes);
 
         assert_eq!(cpu.registers.acc, 0x20);
     }
@@ -123,7 +123,7 @@ mod adc_test {
 
         cpu.registers.xr = 3;
         cpu.registers.acc = 0x10;
-        cpu.execute_instruction_sequence(&mut bytes.as_slice());
+        cpu.execute_instruction_sequence(&bytes);
 
         assert_eq!(cpu.registers.acc, 0x20);
     }
@@ -140,7 +140,7 @@ mod adc_test {
 
         cpu.registers.yr = 2;
         cpu.registers.acc = 0x10;
-        cpu.execute_instruction_sequence(&mut bytes.as_slice());
+        cpu.execute_instruction_sequence(&bytes);
 
         assert_eq!(cpu.registers.acc, 0x20);
     }
@@ -10,7 +10,7 @@ mod and_test {
         let bytes = [0x29, 0x55];
 
         cpu.registers.acc = 0xFF;
-        cpu.execute_instruction_sequence(&mut bytes.as_slice());
+        cpu.execute_instruction_sequence(&bytes);
 
         assert_eq!(cpu.registers.acc, 0xFF & 0x55);
     }
@@ -26,7 +26,7 @@ mod and_test {
         ];
 
         cpu.registers.acc = 0xFF;
-        cpu.execute_instruction_sequence(&mut bytes.as_slice());
+        cpu.execute_instruction_sequence(&bytes);
 
         assert_eq!(cpu.registers.acc, 0xFF & 0x55);
     }
@@ -43,7 +43,7 @@ mod and_test {
 
         cpu.registers.acc = 0xFF;
         cpu.registers.xr = 0x03;
-        cpu.execute_instruction_sequence(&mut bytes.as_slice());
+        cpu.execute_instruction_sequence(&bytes);
 
         assert_eq!(cpu.registers.acc, 0xFF & 0x55);
     }
@@ -60,7 +60,7 @@ mod and_test {
 
         cpu.registers.acc = 0xFF;
         cpu.registers.yr = 0x03;
-        cpu.execute_instruction_sequence(&mut bytes.as_slice());
+        cpu.execute_instruction_sequence(&bytes);
 
         assert_eq!(cpu.registers.acc, 0xFF & 0x55);
     }
@@ -73,7 +73,7 @@ mod and_test {
 
         cpu.registers.acc = 0xFF;
         cpu.memory[0x33] = 0x55;
-        cpu.execute_instruction_sequence(&mut bytes.as_slice());
+        cpu.execute_instruction_sequence(&bytes);
 
         assert_eq!(cpu.registers.acc, 0xFF & 0x55);
     }
@@ -87,7 +87,7 @@ mod and_test {
         cpu.registers.acc = 0xFF;
         cpu.registers.xr = 0x03;
         cpu.memory[0x33] = 0x55;
-        cpu.execute_instruction_sequence(&mut bytes.as_slice());
+        cpu.execute_instruction_sequence(&bytes);
 
         assert_eq!(cpu.registers.acc, 0xFF & 0x55);
     }
@@ -103,7 +103,7 @@ mod and_test {
         cpu.memory[0x20] = 0x30;
         cpu.memory[0x21] = 0x40;
         cpu.memory[0x4030] = 0x55;
-        cpu.execute_instruction_sequence(&mut bytes.as_slice());
+        cpu.execute_instruction_sequence(&bytes);
 
         assert_eq!(cpu.registers.acc, 0xFF & 0x55);
     }
@@ -120,7 +120,7 @@ mod and_test {
         cpu.memory[0x22] = 0x30;
         cpu.memory[0x23] = 0x40;
         cpu.memory[0x4032] = 0x55;
-        cpu.execute_instruction_sequence(&mut bytes.as_slice());
+        cpu.execute_instruction_sequence(&bytes);
 
         assert_eq!(cpu.registers.acc, 0xFF & 0x55);
     }
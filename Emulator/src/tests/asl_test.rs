@@ -11,7 +11,7 @@ mod asl_test {
         ];
 
         cpu.registers.acc = 0x20;
-        cpu.execute_instruction_sequence(&mut bytes.as_slice());
+        cpu.execute_instruction_sequence(&bytes);
 
         assert_eq!(cpu.registers.acc, 0x20 << 1);
     }
@@ -25,7 +25,7 @@ mod asl_test {
             0x0E, low_byte, high_byte
         ];
 
-        cpu.execute_instruction_sequence(&mut bytes.as_slice());
+        cpu.execute_instruction_sequence(&bytes);
 
         assert_eq!(cpu.memory[0x2233], 0x20 << 1);
     }
@@ -40,7 +40,7 @@ mod asl_test {
         ];
 
         cpu.registers.xr = 0x03;
-        cpu.execute_instruction_sequence(&mut bytes.as_slice());
+        cpu.execute_instruction_sequence(&bytes);
 
         assert_eq!(cpu.memory[0x2236], 0x20 << 1);
     }
@@ -53,7 +53,7 @@ mod asl_test {
             0x06, 0x33
         ];
 
-        cpu.execute_instruction_sequence(&mut bytes.as_slice());
+        cpu.execute_instruction_sequence(&bytes);
 
         assert_eq!(cpu.memory[0x33], 0x20 << 1);
     }
@@ -67,7 +67,7 @@ mod asl_test {
         ];
 
         cpu.registers.xr = 0x03;
-        cpu.execute_instruction_sequence(&mut bytes.as_slice());
+        cpu.execute_instruction_sequence(&bytes);
 
         assert_eq!(cpu.memory[0x36], 0x20 << 1);
     }
@@ -0,0 +1,358 @@
+#[cfg(test)]
+mod assembler_test {
+    use crate::assembler::assembler::{assemble, Assembler};
+    use crate::cpu::cpu::{Variant, CPU};
+    use crate::disassembler::Disassembler;
+
+    fn bytes(source: &str) -> Vec<u8> {
+        assemble(source).unwrap().image
+    }
+
+    fn error(source: &str) -> (usize, usize, String) {
+        let error = assemble(source).unwrap_err();
+        (error.line, error.column, error.message)
+    }
+
+    #[test]
+    fn assembler_test_addressing_modes() {
+        let source = "
+            NOP
+            LDA #$10
+            ASL A
+            ASL
+            LDA $0300
+            LDA $0300,X
+            LDA $0300,Y
+            LDA $10
+            LDA $10,x
+            LDX $10,Y
+            LDA ($20,X)
+            LDA ($20),Y
+            JMP ($1234)
+        ";
+
+        assert_eq!(bytes(source), vec![
+            0xEA, 0xA9, 0x10, 0x0A, 0x0A, 0xAD, 0x00, 0x03, 0xBD, 0x00, 0x03, 0xB9, 0x00, 0x03, 0xA5, 0x10, 0xB5, 0x10, 0xB6, 0x10,
+            0xA1, 0x20, 0xB1, 0x20, 0x6C, 0x34, 0x12
+        ]);
+    }
+
+    #[test]
+    fn assembler_test_labels_and_forward_references() {
+        let assembly = assemble("
+            * = $0600
+                JSR init
+                JSR loop
+                JSR end
+            init:  LDX #$00
+                RTS
+            loop:  INX
+                CPX #$05
+                BNE loop
+                RTS
+            end:   BRK
+        ").unwrap();
+
+        assert_eq!(assembly.origin, 0x0600);
+        assert_eq!(assembly.image, vec![
+            0x20, 0x09, 0x06, 0x20, 0x0c, 0x06, 0x20, 0x12, 0x06, 0xa2, 0x00, 0x60, 0xe8, 0xe0, 0x05, 0xd0, 0xfb, 0x60, 0x00
+        ]);
+        assert_eq!(assembly.symbol("loop"), Some(0x060C));
+        assert_eq!(assembly.symbol("end"), Some(0x0612));
+    }
+
+    #[test]
+    fn assembler_test_zero_page_is_picked_only_when_known() {
+        //counter is defined after its first use, so that one is assembled as absolute.
+        let source = "
+            STA counter
+            counter = $10
+            STA counter
+        ";
+
+        assert_eq!(bytes(source), vec![0x8D, 0x10, 0x00, 0x85, 0x10]);
+    }
+
+    #[test]
+    fn assembler_test_expressions() {
+        let source = "
+            table = $1234
+            size = (table + 2) * 2 - $2468
+            LDA #<table
+            LDX #>table
+            LDY #size
+            LDA table+1,X
+            LDA #%1010 | 1 << 4
+            LDA #'A' + 1
+            LDA #-1
+            LDA #10 % 3
+            LDA #(2 > 1) + (3 == 3)
+            JMP *
+        ";
+
+        assert_eq!(bytes(source), vec![
+            0xA9, 0x34, 0xA2, 0x12, 0xA0, 0x04, 0xBD, 0x35, 0x12, 0xA9, 0x1A, 0xA9, 0x42, 0xA9, 0xFF, 0xA9, 0x01, 0xA9, 0x02,
+            0x4C, 0x13, 0x00
+        ]);
+    }
+
+    #[test]
+    fn assembler_test_assignments_can_use_later_symbols() {
+        let assembly = assemble("
+            end_of_code = last + 1
+            double = end_of_code * 2
+            LDA #double
+            last: RTS
+        ").unwrap();
+
+        assert_eq!(assembly.symbol("end_of_code"), Some(3));
+        assert_eq!(assembly.image, vec![0xA9, 0x06, 0x60]);
+    }
+
+    #[test]
+    fn assembler_test_parenthesised_expression_is_not_indirect() {
+        assert_eq!(bytes("LDA ($10+2)*2"), vec![0xA5, 0x24]);
+        assert_eq!(bytes("LDA ($10+2)*2,X"), vec![0xB5, 0x24]);
+    }
+
+    #[test]
+    fn assembler_test_data_directives() {
+        let source = r#"
+            .org $0200
+            .byte 1, $FF, -1, "AB"
+            .word $1234, label
+            .text "hi\n"
+            .res 3
+            .res 2, $EA
+            label:
+        "#;
+        let assembly = assemble(source).unwrap();
+
+        assert_eq!(assembly.origin, 0x0200);
+        assert_eq!(assembly.image, vec![
+            0x01, 0xFF, 0xFF, 0x41, 0x42, 0x34, 0x12, 0x11, 0x02, 0x68, 0x69, 0x0A, 0x00, 0x00, 0x00, 0xEA, 0xEA
+        ]);
+    }
+
+    #[test]
+    fn assembler_test_gaps_between_origins_are_zero() {
+        let assembly = assemble("
+            .org $10
+            .byte 1
+            .org $14
+            .byte 2
+            .org $12
+            .byte 3
+        ").unwrap();
+
+        assert_eq!(assembly.origin, 0x10);
+        assert_eq!(assembly.image, vec![1, 0, 3, 0, 2]);
+    }
+
+    #[test]
+    fn assembler_test_branches() {
+        let source = "
+            start: BNE forward
+            BEQ start
+            forward: BCC *
+        ";
+
+        assert_eq!(bytes(source), vec![0xD0, 0x02, 0xF0, 0xFC, 0x90, 0xFE]);
+    }
+
+    #[test]
+    fn assembler_test_errors_have_line_and_column() {
+        assert_eq!(error("  NOP\n  LDA missing"), (2, 7, "missing isn't defined.".to_string()));
+        assert_eq!(error("  FOO #1"), (1, 3, "Unknown instruction FOO.".to_string()));
+        assert_eq!(error("a: NOP\na: NOP"), (2, 1, "a is already defined.".to_string()));
+        assert_eq!(error("LDA #$100"), (1, 1, "256 doesn't fit in a byte.".to_string()));
+        assert_eq!(error("LDA ($1234),Y"), (1, 1, "$1234 isn't a zero page address.".to_string()));
+        assert_eq!(error("JMP $10,X"), (1, 1, "JMP can't be used with this operand.".to_string()));
+        assert_eq!(error("LDA #1 +"), (1, 9, "Expected an expression.".to_string()));
+        assert_eq!(error(".org later\nlater:"), (1, 1, "The origin has to be known in the first pass.".to_string()));
+        assert_eq!(error(".byte \"open"), (1, 7, "Unterminated string.".to_string()));
+        assert_eq!(error(".fill 3"), (1, 1, "Unknown directive .fill.".to_string()));
+        assert_eq!(error("LDA #1/0"), (1, 1, "Division by zero.".to_string()));
+    }
+
+    #[test]
+    fn assembler_test_end_of_address_space() {
+        assert_eq!(error(".org $FFFE\n.byte 1,2,3"), (2, 1, "Byte 3 of this line would be at $10000, past the end of the address space at $FFFF.".to_string()));
+        assert_eq!(error(".org $10000\nNOP"), (2, 1, "$10000 is outside the address space.".to_string()));
+        assert_eq!(error("LDA -1"), (1, 1, "-$1 doesn't fit in 16 bits.".to_string()));
+        assert_eq!(error("LDA (-2),Y"), (1, 1, "-$2 isn't a zero page address.".to_string()));
+
+        //Filling memory up to the last byte, and a label after it, are fine.
+        let assembly = assemble(".org $FFFE\n.byte 1,2\nend:").unwrap();
+        assert_eq!((assembly.origin, assembly.image.len(), assembly.symbol("end")), (0xFFFE, 2, Some(0x10000)));
+        //The 65816 goes on into the next bank.
+        let assembly = Assembler::new().with_variant(Variant::Wdc65816).assemble(".org $FFFE\n.byte 1,2,3").unwrap();
+        assert_eq!(assembly.image, vec![1, 2, 3]);
+    }
+
+    #[test]
+    fn assembler_test_branch_out_of_range() {
+        let source = "
+            BNE far
+            .res 200
+            far: RTS
+        ";
+
+        assert_eq!(error(source), (2, 13, "The branch target is 200 bytes away, branches reach -128 to 127.".to_string()));
+    }
+
+    #[test]
+    fn assembler_test_undocumented_opcodes() {
+        //The documented NOP and SBC win over their undocumented copies.
+        assert_eq!(bytes("NOP\nSBC #1\nLAX $10\nJAM"), vec![0xEA, 0xE9, 0x01, 0xA7, 0x10, 0x02]);
+    }
+
+    #[test]
+    fn assembler_test_65c02() {
+        let assembler = Assembler::new().with_variant(Variant::Wdc65C02);
+        let assembly = assembler.assemble("
+            start: LDA ($10)
+            BRA start
+            BBR0 $10,start
+            STZ $1234,X
+            JMP ($1234,X)
+        ").unwrap();
+
+        assert_eq!(assembly.image, vec![0xB2, 0x10, 0x80, 0xFC, 0x0F, 0x10, 0xF9, 0x9E, 0x34, 0x12, 0x7C, 0x34, 0x12]);
+        assert!(assemble("BRA *").is_err());
+    }
+
+    #[test]
+    fn assembler_test_65816() {
+        let assembler = Assembler::new().with_variant(Variant::Wdc65816);
+        let assembly = assembler.assemble("
+            .org $018000
+            REP #$30
+            LDA #$1234
+            LDX #$0100
+            SEP #$20
+            LDA #$12
+            LDA $123456
+            LDA [$10],Y
+            LDA $03,S
+            LDA ($03,S),Y
+            MVN $02,$01
+            .i8
+            LDY #1
+            JML [$1234]
+            PEI ($12)
+            BRL *
+            BRK
+        ").unwrap();
+
+        assert_eq!(assembly.origin, 0x018000);
+        assert_eq!(assembly.image, vec![
+            0xC2, 0x30, 0xA9, 0x34, 0x12, 0xA2, 0x00, 0x01, 0xE2, 0x20, 0xA9, 0x12, 0xAF, 0x56, 0x34, 0x12, 0xB7, 0x10, 0xA3, 0x03,
+            0xB3, 0x03, 0x54, 0x01, 0x02, 0xA0, 0x01, 0xDC, 0x34, 0x12, 0xD4, 0x12, 0x82, 0xFD, 0xFF, 0x00, 0x00
+        ]);
+        assert!(assemble(".a16").is_err());
+    }
+
+    #[test]
+    fn assembler_test_disassembles_back_to_the_source() {
+        let source = "LDA #$10\nSTA $0300,X\nLDA ($20),Y\nROR A\nBNE $0600\nJSR $1234";
+        let assembly = assemble(&format!(".org $0600\n{}", source)).unwrap();
+        let text: Vec<String> = Disassembler::new(&assembly.image, assembly.origin).map(|instruction| instruction.to_string()).collect();
+
+        assert_eq!(text.join("\n"), source);
+    }
+
+    #[test]
+    fn assembler_test_symbols_for_the_disassembler() {
+        let assembly = assemble(".org $0600\nstart: JMP start\ncounter = $10").unwrap();
+        let symbols = assembly.symbols_by_address();
+
+        assert_eq!(symbols.get(&0x0600).map(String::as_str), Some("start"));
+        assert_eq!(symbols.get(&0x0010).map(String::as_str), Some("counter"));
+    }
+
+    #[test]
+    fn assembler_test_load_and_run() {
+        let mut cpu = CPU::new();
+        let assembly = assemble("
+            .org $0200
+            LDX #0
+            loop: INX
+            CPX #count
+            BNE loop
+            done: JMP done
+            count = 5
+        ").unwrap();
+
+        assembly.load(&mut cpu);
+        cpu.registers.pc = assembly.origin as u16;
+        cpu.run_until(|cpu| cpu.registers.pc as i64 == assembly.symbol("done").unwrap()).unwrap();
+
+        assert_eq!(cpu.registers.xr, 5);
+    }
+
+    #[test]
+    fn assembler_test_scoped_labels() {
+        let assembly = assemble("
+            first:
+            @loop: DEX
+                BNE @loop
+            second:
+            @loop: DEY
+                BNE @loop
+                JMP first@loop
+        ").unwrap();
+
+        assert_eq!(assembly.image, vec![0xCA, 0xD0, 0xFD, 0x88, 0xD0, 0xFD, 0x4C, 0x00, 0x00]);
+        assert_eq!(assembly.symbol("second@loop"), Some(3));
+    }
+
+    #[test]
+    fn assembler_test_anonymous_labels() {
+        let source = "
+            :   DEX
+                BNE :-
+                BEQ :++
+            :   INX
+            :   BNE :--
+                JMP :+
+            :   RTS
+        ";
+        let assembly = assemble(source).unwrap();
+
+        assert_eq!(assembly.image, vec![0xCA, 0xD0, 0xFD, 0xF0, 0x01, 0xE8, 0xD0, 0xFD, 0x4C, 0x0B, 0x00, 0x60]);
+        assert!(assembly.symbols.is_empty());
+        assert_eq!(error("BNE :-"), (1, 5, "There is no anonymous label in that direction.".to_string()));
+        assert_eq!(error("BNE :+"), (1, 5, "There is no anonymous label in that direction.".to_string()));
+    }
+
+    #[test]
+    fn assembler_test_listing() {
+        let assembly = assemble("\
+    .org $0600
+start:
+    LDA #$01 ; one
+    JSR start
+    .byte 1, 2, 3, 4, 5, 6
+    .macro twice
+        INX
+        INX
+    .endmacro
+    twice
+").unwrap();
+
+        assert_eq!(assembly.listing(), "\
+0600              .org $0600
+0600              start:
+0600  A9 01           LDA #$01 ; one
+0602  20 00 06        JSR start
+0605  01 02 03 04     .byte 1, 2, 3, 4, 5, 6
+0609  05 06
+060B                  twice
+060B  E8                  INX
+060C  E8                  INX
+");
+        assert_eq!(assembly.listing[2].line, 3);
+        assert_eq!(assembly.listing[7].line, 8);
+    }
+}
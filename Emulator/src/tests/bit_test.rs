@@ -14,7 +14,7 @@ mod bit_test {
 
         cpu.memory[0x2233] = 0xC0;
         cpu.registers.acc = 0xFF;
-        cpu.execute_instruction_sequence(&mut bytes.as_slice());
+        cpu.execute_instruction_sequence(&bytes);
 
         assert_eq!(cpu.flags.zero, cpu.registers.acc & cpu.memory[0x2233] == 0);
         assert_eq!(cpu.flags.overflow, (cpu.memory[0x2233] & 0x40) != 0);
@@ -31,7 +31,7 @@ mod bit_test {
 
         cpu.memory[0x33] = 0xC0;
         cpu.registers.acc = 0xFF;
-        cpu.execute_instruction_sequence(&mut bytes.as_slice());
+        cpu.execute_instruction_sequence(&bytes);
 
         assert_eq!(cpu.flags.zero, cpu.registers.acc & cpu.memory[0x33] == 0);
         assert_eq!(cpu.flags.overflow, (cpu.memory[0x33] & 0x40) != 0);
@@ -1,33 +1,45 @@
 #[cfg(test)]
 mod bne_test {
     use crate::cpu::cpu::CPU;
-    use crate::helpers::bitwise::split_word_into_bytes;
 
     #[test]
     fn bne_test_relative(){
         let mut cpu = CPU::new();
-        let (high_byte, low_byte) = split_word_into_bytes(0x2233);
         let bytes = [
-            0xD0, low_byte, high_byte
+            0xD0, 0x10
         ];
 
         cpu.flags.zero = false;
-        cpu.execute_instruction_sequence(&mut bytes.as_slice());
+        cpu.execute_instruction_sequence(&bytes);
 
-        assert_eq!(cpu.registers.pc, 0x2233 + 0x03);
+        assert_eq!(cpu.registers.pc, 0x10 + 0x02);
+    }
+
+    #[test]
+    fn bne_test_relative_backward(){
+        let mut cpu = CPU::new();
+        let bytes = [
+            0xD0, 0xFC
+        ];
+
+        cpu.flags.zero = false;
+        cpu.load_program(&bytes, 0x0200);
+        cpu.registers.pc = 0x0200;
+        cpu.execute_instructions(1);
+
+        assert_eq!(cpu.registers.pc, 0x0202 - 0x04);
     }
 
     #[test]
     fn bne_test_relative_false(){
         let mut cpu = CPU::new();
-        let (high_byte, low_byte) = split_word_into_bytes(0x2233);
         let bytes = [
-            0xD0, low_byte, high_byte
+            0xD0, 0x10
         ];
 
         cpu.flags.zero = true;
-        cpu.execute_instruction_sequence(&mut bytes.as_slice());
+        cpu.execute_instruction_sequence(&bytes);
 
-        assert_eq!(cpu.registers.pc, 0x03);
+        assert_eq!(cpu.registers.pc, 0x02);
     }
 }
\ No newline at end of file
@@ -0,0 +1,72 @@
+#[cfg(test)]
+mod branch_test {
+    use crate::cpu::cpu::{CPU, Flags, Variant};
+
+    type SetFlag = fn(&mut Flags, bool);
+
+    //Each branch with a function that sets its flag so the branch is taken or not.
+    const BRANCHES: [(u8, SetFlag); 8] = [
+        (0x10, |flags, taken| flags.negative = !taken), //BPL
+        (0x30, |flags, taken| flags.negative = taken),  //BMI
+        (0x50, |flags, taken| flags.overflow = !taken), //BVC
+        (0x70, |flags, taken| flags.overflow = taken),  //BVS
+        (0x90, |flags, taken| flags.carry = !taken),    //BCC
+        (0xB0, |flags, taken| flags.carry = taken),     //BCS
+        (0xD0, |flags, taken| flags.zero = !taken),     //BNE
+        (0xF0, |flags, taken| flags.zero = taken),      //BEQ
+    ];
+
+    //Origin, offset, taken, expected PC and cycles.
+    const CASES: [(u16, u8, bool, u16, u32); 6] = [
+        (0x0200, 0x10, false, 0x0202, 2),
+        (0x0200, 0x10, true, 0x0212, 3),
+        (0x0210, 0xF0, true, 0x0202, 3),
+        (0x0200, 0x00, true, 0x0202, 3),
+        (0x02F0, 0x20, true, 0x0312, 4),
+        (0x0200, 0x80, true, 0x0182, 4),
+    ];
+
+    fn run_branch(variant: Variant, opcode: u8, origin: u16, offset: u8, set_flags: impl Fn(&mut Flags)) -> CPU {
+        let mut cpu = CPU::with_variant(variant);
+        cpu.load_program(&[opcode, offset], origin);
+        cpu.registers.pc = origin;
+        set_flags(&mut cpu.flags);
+        cpu.step().unwrap();
+        cpu
+    }
+
+    #[test]
+    fn branch_test_matrix() {
+        for variant in [Variant::Nmos6502, Variant::Wdc65C02] {
+            for (opcode, set_flag) in BRANCHES {
+                for (origin, offset, taken, pc, cycles) in CASES {
+                    let cpu = run_branch(variant, opcode, origin, offset, |flags| set_flag(flags, taken));
+
+                    let case = format!("{:?} opcode {:#04X} at {:#06X} offset {:#04X} taken {}", variant, opcode, origin, offset, taken);
+                    assert_eq!(cpu.registers.pc, pc, "{}", case);
+                    assert_eq!(cpu.cycles, cycles, "{}", case);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn branch_test_not_taken_ignores_page_cross() {
+        for (opcode, set_flag) in BRANCHES {
+            let cpu = run_branch(Variant::Nmos6502, opcode, 0x02FE, 0x10, |flags| set_flag(flags, false));
+
+            assert_eq!(cpu.registers.pc, 0x0300);
+            assert_eq!(cpu.cycles, 2);
+        }
+    }
+
+    #[test]
+    fn branch_test_bra() {
+        for (origin, offset, _, pc, cycles) in CASES.into_iter().filter(|case| case.2) {
+            let cpu = run_branch(Variant::Wdc65C02, 0x80, origin, offset, |_| {});
+
+            assert_eq!(cpu.registers.pc, pc);
+            assert_eq!(cpu.cycles, cycles);
+        }
+    }
+}
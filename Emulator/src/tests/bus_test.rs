@@ -0,0 +1,98 @@
+#[cfg(test)]
+mod bus_test {
+    use crate::cpu::bus::Bus;
+    use crate::cpu::cpu::CPU;
+
+    //2 KiB of RAM mirrored through $0000-$1FFF, a read counter at $2000 and ROM from $8000.
+    struct TestBus {
+        ram: [u8; 0x800],
+        rom: [u8; 0x8000],
+        reads: u8
+    }
+
+    impl TestBus {
+        fn new(program: &[u8]) -> Self {
+            let mut rom = [0; 0x8000];
+            rom[..program.len()].copy_from_slice(program);
+            TestBus { ram: [0; 0x800], rom, reads: 0 }
+        }
+    }
+
+    impl Bus for TestBus {
+        fn read(&mut self, address: u16) -> u8 {
+            if address == 0x2000 {
+                self.reads += 1;
+            }
+            self.peek(address)
+        }
+
+        fn write(&mut self, address: u16, value: u8) {
+            if address < 0x2000 {
+                self.ram[(address & 0x7FF) as usize] = value;
+            }
+        }
+
+        fn peek(&self, address: u16) -> u8 {
+            match address {
+                0x0000..=0x1FFF => self.ram[(address & 0x7FF) as usize],
+                0x2000 => self.reads,
+                0x8000..=0xFFFF => self.rom[(address - 0x8000) as usize],
+                _ => 0
+            }
+        }
+    }
+
+    #[test]
+    fn bus_test_mirrored_ram() {
+        let bytes = [
+            0xA9, 0x42, 0x8D, 0x10, 0x08, //LDA #$42, STA $0810
+            0xAD, 0x10, 0x18 //LDA $1810
+        ];
+        let mut cpu = CPU::with_bus(TestBus::new(&bytes));
+
+        cpu.registers.pc = 0x8000;
+        cpu.execute_instructions(3);
+
+        assert_eq!(cpu.registers.acc, 0x42);
+        assert_eq!(cpu.memory.peek(0x0010), 0x42);
+    }
+
+    #[test]
+    fn bus_test_rom_ignores_writes() {
+        let bytes = [
+            0xA9, 0x42, 0x8D, 0x00, 0x80 //LDA #$42, STA $8000
+        ];
+        let mut cpu = CPU::with_bus(TestBus::new(&bytes));
+
+        cpu.registers.pc = 0x8000;
+        cpu.execute_instructions(2);
+
+        assert_eq!(cpu.memory.peek(0x8000), 0xA9);
+    }
+
+    #[test]
+    fn bus_test_read_side_effects() {
+        let bytes = [
+            0xAD, 0x00, 0x20, //LDA $2000
+            0x8D, 0x00, 0x20 //STA $2000
+        ];
+        let mut cpu = CPU::with_bus(TestBus::new(&bytes));
+
+        cpu.registers.pc = 0x8000;
+        cpu.execute_instructions(2);
+
+        assert_eq!(cpu.memory.peek(0x2000), 0x01); //Stores don't read their target.
+        assert_eq!(cpu.memory.peek(0x2000), 0x01); //Peeking has no side effects.
+    }
+
+    #[test]
+    fn bus_test_default_ram() {
+        let mut cpu = CPU::new();
+
+        cpu.write_byte(0xFFFF, 0x12);
+
+        assert_eq!(cpu.read_byte(0xFFFF), 0x12);
+        assert_eq!(cpu.memory.peek(0xFFFF), 0x12);
+        assert_eq!(cpu.memory[0xFFFF], 0x12);
+    }
+}
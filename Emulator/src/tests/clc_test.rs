@@ -10,7 +10,7 @@ mod clc_test {
         ];
 
         cpu.flags.carry = true;
-        cpu.execute_instruction_sequence(&mut bytes.as_slice());
+        cpu.execute_instruction_sequence(&bytes);
 
         assert_eq!(cpu.flags.carry, false);
     }
@@ -10,7 +10,7 @@ mod cld_test {
         ];
 
         cpu.flags.decimal = true;
-        cpu.execute_instruction_sequence(&mut bytes.as_slice());
+        cpu.execute_instruction_sequence(&bytes);
 
         assert_eq!(cpu.flags.decimal, false);
     }
@@ -10,7 +10,7 @@ mod cli_test {
         ];
 
         cpu.flags.interrupt = true;
-        cpu.execute_instruction_sequence(&mut bytes.as_slice());
+        cpu.execute_instruction_sequence(&bytes);
 
         assert_eq!(cpu.flags.interrupt, false);
     }
@@ -10,7 +10,7 @@ mod clv_test {
         ];
 
         cpu.flags.overflow = true;
-        cpu.execute_instruction_sequence(&mut bytes.as_slice());
+        cpu.execute_instruction_sequence(&bytes);
 
         assert_eq!(cpu.flags.overflow, false);
     }
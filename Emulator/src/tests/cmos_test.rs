@@ -0,0 +1,351 @@
+#[cfg(test)]
+mod cmos_test {
+    use crate::cpu::cpu::{Variant, CPU};
+
+    #[test]
+    fn cmos_test_stz() {
+        let mut cpu = CPU::with_variant(Variant::Wdc65C02);
+
+        let bytes = [
+            0x9C, 0x00, 0x02, //STZ $0200
+            0x9E, 0x00, 0x02  //STZ $0200,X
+        ];
+
+        cpu.memory[0x0200] = 0x42;
+        cpu.memory[0x0201] = 0x42;
+        cpu.registers.xr = 0x01;
+        cpu.execute_instruction_sequence(&bytes);
+
+        assert_eq!(cpu.memory[0x0200], 0x00);
+        assert_eq!(cpu.memory[0x0201], 0x00);
+    }
+
+    #[test]
+    fn cmos_test_tsb_trb() {
+        let mut cpu = CPU::with_variant(Variant::Wdc65C02);
+
+        let bytes = [
+            0x0C, 0x00, 0x02, //TSB $0200
+            0x1C, 0x01, 0x02  //TRB $0201
+        ];
+
+        cpu.registers.acc = 0x0F;
+        cpu.memory[0x0200] = 0x30;
+        cpu.memory[0x0201] = 0x33;
+        cpu.load_program(&bytes, 0x0000);
+        cpu.execute_instructions(1);
+
+        assert_eq!(cpu.memory[0x0200], 0x3F);
+        assert_eq!(cpu.flags.zero, true);
+
+        cpu.execute_instructions(1);
+
+        assert_eq!(cpu.memory[0x0201], 0x30);
+        assert_eq!(cpu.flags.zero, false);
+    }
+
+    #[test]
+    fn cmos_test_push_pull_index_registers() {
+        let mut cpu = CPU::with_variant(Variant::Wdc65C02);
+
+        let bytes = [
+            0xDA, //PHX
+            0x5A, //PHY
+            0xFA, //PLX
+            0x7A  //PLY
+        ];
+
+        cpu.registers.xr = 0x80;
+        cpu.registers.yr = 0x01;
+        cpu.execute_instruction_sequence(&bytes);
+
+        assert_eq!(cpu.registers.xr, 0x01);
+        assert_eq!(cpu.registers.yr, 0x80);
+        assert_eq!(cpu.registers.sp, 0xFF);
+        assert_eq!(cpu.flags.negative, true);
+    }
+
+    #[test]
+    fn cmos_test_accumulator_increment_decrement() {
+        let mut cpu = CPU::with_variant(Variant::Wdc65C02);
+
+        let bytes = [
+            0x1A, //INC A
+            0x1A, //INC A
+            0x3A  //DEC A
+        ];
+
+        cpu.registers.acc = 0x10;
+        cpu.execute_instruction_sequence(&bytes);
+
+        assert_eq!(cpu.registers.acc, 0x11);
+        assert_eq!(cpu.cycles, 6);
+    }
+
+    #[test]
+    fn cmos_test_bit_immediate() {
+        let mut cpu = CPU::with_variant(Variant::Wdc65C02);
+
+        let bytes = [
+            0x89, 0xC0
+        ];
+
+        cpu.registers.acc = 0x0F;
+        cpu.execute_instruction_sequence(&bytes);
+
+        assert_eq!(cpu.flags.zero, true);
+        assert_eq!(cpu.flags.negative, false);
+        assert_eq!(cpu.flags.overflow, false);
+    }
+
+    #[test]
+    fn cmos_test_bit_indexed() {
+        let mut cpu = CPU::with_variant(Variant::Wdc65C02);
+
+        let bytes = [
+            0x3C, 0x00, 0x02
+        ];
+
+        cpu.registers.xr = 0x01;
+        cpu.registers.acc = 0x01;
+        cpu.memory[0x0201] = 0xC1;
+        cpu.execute_instruction_sequence(&bytes);
+
+        assert_eq!(cpu.flags.zero, false);
+        assert_eq!(cpu.flags.negative, true);
+        assert_eq!(cpu.flags.overflow, true);
+    }
+
+    #[test]
+    fn cmos_test_zero_page_indirect() {
+        let mut cpu = CPU::with_variant(Variant::Wdc65C02);
+
+        let bytes = [
+            0xB2, 0x10, //LDA ($10)
+            0x92, 0x12  //STA ($12)
+        ];
+
+        cpu.memory[0x0010] = 0x00;
+        cpu.memory[0x0011] = 0x02;
+        cpu.memory[0x0012] = 0x00;
+        cpu.memory[0x0013] = 0x03;
+        cpu.memory[0x0200] = 0x42;
+        cpu.load_program(&bytes, 0x0000);
+        cpu.execute_instructions(1);
+
+        assert_eq!(cpu.registers.acc, 0x42);
+        assert_eq!(cpu.cycles, 5);
+
+        cpu.execute_instructions(1);
+
+        assert_eq!(cpu.memory[0x0300], 0x42);
+    }
+
+    #[test]
+    fn cmos_test_jmp_indexed_indirect() {
+        let mut cpu = CPU::with_variant(Variant::Wdc65C02);
+
+        let bytes = [
+            0x7C, 0x00, 0x02
+        ];
+
+        cpu.registers.xr = 0x02;
+        cpu.memory[0x0202] = 0x34;
+        cpu.memory[0x0203] = 0x12;
+        cpu.execute_instruction_sequence(&bytes);
+
+        assert_eq!(cpu.registers.pc, 0x1234);
+        assert_eq!(cpu.cycles, 6);
+    }
+
+    #[test]
+    fn cmos_test_jmp_indirect_page_wrap() {
+        let bytes = [
+            0x6C, 0xFF, 0x02
+        ];
+
+        let mut nmos = CPU::new();
+        let mut cmos = CPU::with_variant(Variant::Wdc65C02);
+        for cpu in [&mut nmos, &mut cmos] {
+            cpu.memory[0x02FF] = 0x34;
+            cpu.memory[0x0200] = 0x56;
+            cpu.memory[0x0300] = 0x12;
+            cpu.execute_instruction_sequence(&bytes);
+        }
+
+        assert_eq!(nmos.registers.pc, 0x5634);
+        assert_eq!(nmos.cycles, 5);
+        assert_eq!(cmos.registers.pc, 0x1234);
+        assert_eq!(cmos.cycles, 6);
+    }
+
+    #[test]
+    fn cmos_test_rmb_smb() {
+        let mut cpu = CPU::with_variant(Variant::Wdc65C02);
+
+        let bytes = [
+            0x37, 0x10, //RMB3 $10
+            0xF7, 0x11  //SMB7 $11
+        ];
+
+        cpu.memory[0x0010] = 0xFF;
+        cpu.execute_instruction_sequence(&bytes);
+
+        assert_eq!(cpu.memory[0x0010], 0xF7);
+        assert_eq!(cpu.memory[0x0011], 0x80);
+        assert_eq!(cpu.cycles, 10);
+    }
+
+    #[test]
+    fn cmos_test_bbr_bbs() {
+        let mut cpu = CPU::with_variant(Variant::Wdc65C02);
+
+        let bytes = [
+            0x1F, 0x10, 0x05, //BBR1 $10,+5
+            0x9F, 0x10, 0x02  //BBS1 $10,+2
+        ];
+
+        cpu.memory[0x0010] = 0x02;
+        cpu.load_program(&bytes, 0x0000);
+        cpu.execute_instructions(1);
+
+        assert_eq!(cpu.registers.pc, 0x0003);
+        assert_eq!(cpu.cycles, 5);
+
+        cpu.execute_instructions(1);
+
+        assert_eq!(cpu.registers.pc, 0x0008);
+        assert_eq!(cpu.cycles, 11);
+    }
+
+    #[test]
+    fn cmos_test_bbr_backwards() {
+        let mut cpu = CPU::with_variant(Variant::Wdc65C02);
+
+        let bytes = [
+            0x0F, 0x10, 0xFD //BBR0 $10,-3
+        ];
+
+        cpu.load_program(&bytes, 0x0000);
+        cpu.execute_instructions(1);
+
+        assert_eq!(cpu.registers.pc, 0x0000);
+    }
+
+    #[test]
+    fn cmos_test_bra() {
+        let mut cpu = CPU::with_variant(Variant::Wdc65C02);
+        let bytes = [
+            0x80, 0x10
+        ];
+
+        cpu.execute_instruction_sequence(&bytes);
+
+        assert_eq!(cpu.registers.pc, 0x10 + 0x02);
+    }
+
+    #[test]
+    fn cmos_test_decimal_flags() {
+        let bytes = [
+            0x69, 0x01 //ADC #$01
+        ];
+
+        let mut nmos = CPU::new();
+        let mut cmos = CPU::with_variant(Variant::Wdc65C02);
+        for cpu in [&mut nmos, &mut cmos] {
+            cpu.registers.acc = 0x99;
+            cpu.flags.decimal = true;
+            cpu.execute_instruction_sequence(&bytes);
+
+            assert_eq!(cpu.registers.acc, 0x00);
+            assert_eq!(cpu.flags.carry, true);
+        }
+
+        assert_eq!(nmos.flags.zero, false);
+        assert_eq!(nmos.flags.negative, true);
+        assert_eq!(nmos.cycles, 2);
+        assert_eq!(cmos.flags.zero, true);
+        assert_eq!(cmos.flags.negative, false);
+        assert_eq!(cmos.cycles, 3);
+    }
+
+    #[test]
+    fn cmos_test_interrupt_clears_decimal() {
+        let mut cpu = CPU::with_variant(Variant::Wdc65C02);
+
+        let bytes = [
+            0x00
+        ];
+
+        cpu.flags.decimal = true;
+        cpu.set_interrupt_vector(0x04, 0x00);
+        cpu.execute_instruction_sequence(&bytes);
+
+        assert_eq!(cpu.registers.pc, 0x0400);
+        assert_eq!(cpu.flags.decimal, false);
+    }
+
+    #[test]
+    fn cmos_test_wai() {
+        let mut cpu = CPU::with_variant(Variant::Wdc65C02);
+
+        let bytes = [
+            0xCB, //WAI
+            0xE8  //INX
+        ];
+
+        cpu.flags.interrupt = true;
+        cpu.load_program(&bytes, 0x0000);
+        cpu.execute_instructions(5);
+
+        assert_eq!(cpu.waiting, true);
+        assert_eq!(cpu.registers.pc, 0x0001);
+        assert_eq!(cpu.cycles, 3 + 4);
+
+        //With I set the IRQ only wakes the CPU.
+        cpu.set_irq_line(true);
+        cpu.execute_instructions(1);
+
+        assert_eq!(cpu.waiting, false);
+        assert_eq!(cpu.registers.xr, 0x01);
+    }
+
+    #[test]
+    fn cmos_test_stp() {
+        let mut cpu = CPU::with_variant(Variant::Wdc65C02);
+
+        let bytes = [
+            0xDB, //STP
+            0xE8  //INX
+        ];
+
+        cpu.memory[0xFFFC] = 0x00;
+        cpu.memory[0xFFFD] = 0x04;
+        cpu.load_program(&bytes, 0x0000);
+        cpu.execute_instructions(3);
+
+        assert_eq!(cpu.jammed, true);
+        assert_eq!(cpu.registers.xr, 0x00);
+
+        cpu.set_reset_line(true);
+        cpu.set_reset_line(false);
+        cpu.execute_instructions(1);
+
+        assert_eq!(cpu.jammed, false);
+        assert_eq!(cpu.registers.pc, 0x0400);
+    }
+
+    #[test]
+    fn cmos_test_single_cycle_nop() {
+        let mut cpu = CPU::with_variant(Variant::Wdc65C02);
+
+        let bytes = [
+            0x03, 0x0B, 0xFB
+        ];
+
+        cpu.execute_instruction_sequence(&bytes);
+
+        assert_eq!(cpu.registers.pc, 0x0003);
+        assert_eq!(cpu.cycles, 3);
+    }
+}
@@ -11,7 +11,7 @@ mod cmp_test {
         ];
 
         cpu.registers.acc = 0x20;
-        cpu.execute_instruction_sequence(&mut bytes.as_slice());
+        cpu.execute_instruction_sequence(&bytes);
 
         assert_eq!(cpu.flags.zero, true);
         assert_eq!(cpu.flags.carry, true);
@@ -26,7 +26,7 @@ mod cmp_test {
         ];
 
         cpu.registers.acc = 0x20;
-        cpu.execute_instruction_sequence(&mut bytes.as_slice());
+        cpu.execute_instruction_sequence(&bytes);
 
         assert_eq!(cpu.flags.zero, false);
         assert_eq!(cpu.flags.carry, false);
@@ -43,7 +43,7 @@ mod cmp_test {
         ];
 
         cpu.registers.acc = 0x20;
-        cpu.execute_instruction_sequence(&mut bytes.as_slice());
+        cpu.execute_instruction_sequence(&bytes);
 
         assert_eq!(cpu.flags.zero, true);
         assert_eq!(cpu.flags.carry, true);
@@ -61,7 +61,7 @@ mod cmp_test {
 
         cpu.registers.xr = 3;
         cpu.registers.acc = 0x20;
-        cpu.execute_instruction_sequence(&mut bytes.as_slice());
+        cpu.execute_instruction_sequence(&bytes);
 
         assert_eq!(cpu.flags.zero, true);
         assert_eq!(cpu.flags.carry, true);
@@ -79,7 +79,7 @@ mod cmp_test {
 
         cpu.registers.yr = 3;
         cpu.registers.acc = 0x20;
-        cpu.execute_instruction_sequence(&mut bytes.as_slice());
+        cpu.execute_instruction_sequence(&bytes);
 
         assert_eq!(cpu.flags.zero, true);
         assert_eq!(cpu.flags.carry, true);
@@ -95,7 +95,7 @@ mod cmp_test {
         ];
 
         cpu.registers.acc = 0x20;
-        cpu.execute_instruction_sequence(&mut bytes.as_slice());
+        cpu.execute_instruction_sequence(&bytes);
 
         assert_eq!(cpu.flags.zero, true);
         assert_eq!(cpu.flags.carry, true);
@@ -112,7 +112,7 @@ mod cmp_test {
 
         cpu.registers.xr = 3;
         cpu.registers.acc = 0x20;
-        cpu.execute_instruction_sequence(&mut bytes.as_slice());
+        cpu.execute_instruction_sequence(&bytes);
 
         assert_eq!(cpu.flags.zero, true);
         assert_eq!(cpu.flags.carry, true);
@@ -133,7 +133,7 @@ mod cmp_test {
 
         cpu.registers.xr = 3;
         cpu.registers.acc = 0x20;
-        cpu.execute_instruction_sequence(&mut bytes.as_slice());
+        cpu.execute_instruction_sequence(&bytes);
 
         assert_eq!(cpu.flags.zero, true);
         assert_eq!(cpu.flags.carry, true);
@@ -154,7 +154,7 @@ mod cmp_test {
 
         cpu.registers.yr = 2;
         cpu.registers.acc = 0x20;
-        cpu.execute_instruction_sequence(&mut bytes.as_slice());
+        cpu.execute_instruction_sequence(&bytes);
 
         assert_eq!(cpu.flags.zero, true);
         assert_eq!(cpu.flags.carry, true);
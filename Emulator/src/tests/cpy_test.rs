@@ -11,7 +11,7 @@ mod cpy_test {
         ];
 
         cpu.registers.yr = 0x20;
-        cpu.execute_instruction_sequence(&mut bytes.as_slice());
+        cpu.execute_instruction_sequence(&bytes);
 
         assert_eq!(cpu.flags.zero, true);
         assert_eq!(cpu.flags.carry, true);
@@ -26,7 +26,7 @@ mod cpy_test {
         ];
 
         cpu.registers.yr = 0x20;
-        cpu.execute_instruction_sequence(&mut bytes.as_slice());
+        cpu.execute_instruction_sequence(&bytes);
 
         assert_eq!(cpu.flags.zero, false);
         assert_eq!(cpu.flags.carry, false);
@@ -43,7 +43,7 @@ mod cpy_test {
         ];
 
         cpu.registers.yr = 0x20;
-        cpu.execute_instruction_sequence(&mut bytes.as_slice());
+        cpu.execute_instruction_sequence(&bytes);
 
         assert_eq!(cpu.flags.zero, true);
         assert_eq!(cpu.flags.carry, true);
@@ -59,7 +59,7 @@ mod cpy_test {
         ];
 
         cpu.registers.yr = 0x20;
-        cpu.execute_instruction_sequence(&mut bytes.as_slice());
+        cpu.execute_instruction_sequence(&bytes);
 
         assert_eq!(cpu.flags.zero, true);
         assert_eq!(cpu.flags.carry, true);
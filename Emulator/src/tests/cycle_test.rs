@@ -0,0 +1,185 @@
+#[cfg(test)]
+mod cycle_test {
+    use std::cell::RefCell;
+    use std::rc::Rc;
+    use crate::cpu::bus::{Bus, Ram};
+    use crate::cpu::cpu::{Variant, CPU};
+    use crate::cpu::instructions::AddressingMode;
+
+    #[derive(Debug, PartialEq)]
+    enum Access {
+        Read(u16),
+        Write(u16, u8)
+    }
+
+    //Flat RAM that records every access and the cycle it was ticked with.
+    struct RecordingBus {
+        ram: Ram,
+        cycles: u32,
+        accesses: AccessLog
+    }
+
+    impl Bus for RecordingBus {
+        fn read(&mut self, address: u16) -> u8 {
+            self.accesses.borrow_mut().push((self.cycles, Access::Read(address)));
+            self.ram.read(address)
+        }
+
+        fn write(&mut self, address: u16, value: u8) {
+            self.accesses.borrow_mut().push((self.cycles, Access::Write(address, value)));
+            self.ram.write(address, value);
+        }
+
+        fn peek(&self, address: u16) -> u8 {
+            self.ram.peek(address)
+        }
+
+        fn tick(&mut self, cycles: u32) {
+            self.cycles = cycles;
+        }
+    }
+
+    type AccessLog = Rc<RefCell<Vec<(u32, Access)>>>;
+
+    fn recording_cpu(bytes: &[u8]) -> (CPU<RecordingBus>, AccessLog) {
+        let accesses = Rc::new(RefCell::new(Vec::new()));
+        let mut cpu = CPU::with_bus(RecordingBus { ram: Ram::new(), cycles: 0, accesses: accesses.clone() });
+        cpu.load_program(bytes, 0x0200);
+        cpu.registers.pc = 0x0200;
+        cpu.cycle_accurate = true;
+        accesses.borrow_mut().clear();
+        (cpu, accesses)
+    }
+
+    fn accesses_without_cycles(accesses: &AccessLog) -> Vec<Access> {
+        accesses.borrow_mut().drain(..).map(|(_, access)| access).collect()
+    }
+
+    #[test]
+    fn cycle_test_matches_instruction_table() {
+        for variant in [Variant::Nmos6502, Variant::Wdc65C02] {
+            for opcode in 0..=255u8 {
+                let Some(instruction) = <CPU>::get_instruction(variant, opcode) else { continue };
+                if let AddressingMode::Relative | AddressingMode::ZeroPageRelative = instruction.address_mode {
+                    continue;
+                }
+
+                let mut cpu = CPU::with_variant(variant);
+                cpu.load_program(&[opcode, 0x10, 0x02], 0x0200);
+                cpu.registers.pc = 0x0200;
+                cpu.execute_instructions(1);
+
+                assert_eq!(cpu.cycles, instruction.cycle_increase, "{:?} opcode {:#04X?}", variant, opcode);
+            }
+        }
+    }
+
+    #[test]
+    fn cycle_test_page_cross_read() {
+        let mut cpu = CPU::new();
+        let bytes = [0xBD, 0xFF, 0x20]; //LDA $20FF,X
+
+        cpu.registers.xr = 1;
+        cpu.execute_instruction_sequence(&bytes);
+
+        assert_eq!(cpu.cycles, 5);
+    }
+
+    #[test]
+    fn cycle_test_page_cross_dummy_read() {
+        let (mut cpu, accesses) = recording_cpu(&[0xBD, 0xFF, 0x20]); //LDA $20FF,X
+
+        cpu.registers.xr = 1;
+        cpu.execute_instructions(1);
+
+        assert_eq!(accesses_without_cycles(&accesses), vec![
+            Access::Read(0x0200),
+            Access::Read(0x0201),
+            Access::Read(0x0202),
+            Access::Read(0x2000), //High byte not yet corrected.
+            Access::Read(0x2100)
+        ]);
+    }
+
+    #[test]
+    fn cycle_test_store_indexed_dummy_read() {
+        let (mut cpu, accesses) = recording_cpu(&[0x9D, 0x00, 0x20]); //STA $2000,X
+
+        cpu.registers.xr = 1;
+        cpu.registers.acc = 0x42;
+        cpu.execute_instructions(1);
+
+        assert_eq!(cpu.cycles, 5);
+        assert_eq!(accesses_without_cycles(&accesses), vec![
+            Access::Read(0x0200),
+            Access::Read(0x0201),
+            Access::Read(0x0202),
+            Access::Read(0x2001),
+            Access::Write(0x2001, 0x42)
+        ]);
+    }
+
+    #[test]
+    fn cycle_test_read_modify_write_double_write() {
+        let (mut cpu, accesses) = recording_cpu(&[0xEE, 0x00, 0x20]); //INC $2000
+
+        cpu.memory.write(0x2000, 0x41);
+        accesses.borrow_mut().clear();
+        cpu.execute_instructions(1);
+
+        assert_eq!(cpu.cycles, 6);
+        assert_eq!(accesses_without_cycles(&accesses), vec![
+            Access::Read(0x0200),
+            Access::Read(0x0201),
+            Access::Read(0x0202),
+            Access::Read(0x2000),
+            Access::Write(0x2000, 0x41),
+            Access::Write(0x2000, 0x42)
+        ]);
+    }
+
+    #[test]
+    fn cycle_test_bus_ticked_every_cycle() {
+        let (mut cpu, accesses) = recording_cpu(&[0xA9, 0x42, 0x85, 0x10]); //LDA #$42, STA $10
+
+        cpu.execute_instructions(2);
+
+        assert_eq!(*accesses.borrow(), vec![
+            (0, Access::Read(0x0200)),
+            (1, Access::Read(0x0201)),
+            (2, Access::Read(0x0202)),
+            (3, Access::Read(0x0203)),
+            (4, Access::Write(0x0010, 0x42))
+        ]);
+    }
+
+    #[test]
+    fn cycle_test_bus_ticked_every_instruction() {
+        let (mut cpu, accesses) = recording_cpu(&[0xA9, 0x42, 0x85, 0x10]); //LDA #$42, STA $10
+
+        cpu.cycle_accurate = false;
+        cpu.execute_instructions(2);
+
+        assert_eq!(accesses.borrow()[4], (2, Access::Write(0x0010, 0x42)));
+        assert_eq!(cpu.cycles, 5);
+    }
+
+    #[test]
+    fn cycle_test_rts_order() {
+        let (mut cpu, accesses) = recording_cpu(&[0x60]); //RTS
+
+        cpu.push_word_to_stack(0x1233);
+        accesses.borrow_mut().clear();
+        cpu.execute_instructions(1);
+
+        assert_eq!(cpu.registers.pc, 0x1234);
+        assert_eq!(accesses_without_cycles(&accesses), vec![
+            Access::Read(0x0200),
+            Access::Read(0x0201),
+            Access::Read(0x01FD),
+            Access::Read(0x01FE),
+            Access::Read(0x01FF),
+            Access::Read(0x1233)
+        ]);
+    }
+}
@@ -12,7 +12,7 @@ mod dec_test {
             0xCE, low_byte, high_byte
         ];
 
-        cpu.execute_instruction_sequence(&mut bytes.as_slice());
+        cpu.execute_instruction_sequence(&bytes);
 
         assert_eq!(cpu.memory[0x2233], 0x20 - 1);
         assert_eq!(cpu.flags.zero, false);
@@ -28,7 +28,7 @@ mod dec_test {
             0xCE, low_byte, high_byte
         ];
 
-        cpu.execute_instruction_sequence(&mut bytes.as_slice());
+        cpu.execute_instruction_sequence(&bytes);
 
         assert_eq!(cpu.memory[0x2233], 0xFF);
         assert_eq!(cpu.flags.zero, false);
@@ -44,7 +44,7 @@ mod dec_test {
             0xCE, low_byte, high_byte
         ];
 
-        cpu.execute_instruction_sequence(&mut bytes.as_slice());
+        cpu.execute_instruction_sequence(&bytes);
 
         assert_eq!(cpu.memory[0x2233], 0x01 - 1);
         assert_eq!(cpu.flags.zero, true);
@@ -61,7 +61,7 @@ mod dec_test {
         ];
 
         cpu.registers.xr = 3;
-        cpu.execute_instruction_sequence(&mut bytes.as_slice());
+        cpu.execute_instruction_sequence(&bytes);
 
         assert_eq!(cpu.memory[0x2233], 0x20 - 1);
     }
@@ -74,7 +74,7 @@ mod dec_test {
             0xC6, 0x33
         ];
 
-        cpu.execute_instruction_sequence(&mut bytes.as_slice());
+        cpu.execute_instruction_sequence(&bytes);
 
         assert_eq!(cpu.memory[0x33], 0x20 - 1);
     }
@@ -88,7 +88,7 @@ mod dec_test {
         ];
 
         cpu.registers.xr = 3;
-        cpu.execute_instruction_sequence(&mut bytes.as_slice());
+        cpu.execute_instruction_sequence(&bytes);
 
         assert_eq!(cpu.memory[0x33], 0x20 - 1);
     }
@@ -0,0 +1,187 @@
+#[cfg(test)]
+mod decimal_test {
+    use crate::cpu::cpu::CPU;
+
+    #[derive(Debug, PartialEq)]
+    struct Outcome {
+        acc: u8,
+        carry: bool,
+        zero: bool,
+        negative: bool,
+        overflow: bool
+    }
+
+    //NMOS decimal ADC as VICE computes it, a different formulation from the one the CPU uses.
+    fn reference_adc(acc: u8, value: u8, carry: bool) -> Outcome {
+        let (acc, value, carry) = (acc as u32, value as u32, carry as u32);
+        let mut tmp = (acc & 0x0F) + (value & 0x0F) + carry;
+        if tmp > 0x09 {
+            tmp += 0x06;
+        }
+        tmp = if tmp <= 0x0F {
+            (tmp & 0x0F) + (acc & 0xF0) + (value & 0xF0)
+        } else {
+            (tmp & 0x0F) + (acc & 0xF0) + (value & 0xF0) + 0x10
+        };
+        let zero = (acc + value + carry) & 0xFF == 0;
+        let negative = tmp & 0x80 != 0;
+        let overflow = (acc ^ tmp) & 0x80 != 0 && (acc ^ value) & 0x80 == 0;
+        if tmp & 0x1F0 > 0x90 {
+            tmp += 0x60;
+        }
+
+        Outcome { acc: tmp as u8, carry: tmp & 0xFF0 > 0xF0, zero, negative, overflow }
+    }
+
+    //NMOS decimal SBC as VICE computes it.
+    fn reference_sbc(acc: u8, value: u8, carry: bool) -> Outcome {
+        let (acc, value, borrow) = (acc as u32, value as u32, !carry as u32);
+        let binary = acc.wrapping_sub(value).wrapping_sub(borrow);
+        let mut tmp = (acc & 0x0F).wrapping_sub(value & 0x0F).wrapping_sub(borrow);
+        tmp = if tmp & 0x10 != 0 {
+            (tmp.wrapping_sub(6) & 0x0F) | (acc & 0xF0).wrapping_sub(value & 0xF0).wrapping_sub(0x10)
+        } else {
+            (tmp & 0x0F) | (acc & 0xF0).wrapping_sub(value & 0xF0)
+        };
+        if tmp & 0x100 != 0 {
+            tmp = tmp.wrapping_sub(0x60);
+        }
+
+        Outcome {
+            acc: tmp as u8,
+            carry: binary < 0x100,
+            zero: binary & 0xFF == 0,
+            negative: binary & 0x80 != 0,
+            overflow: (acc ^ binary) & 0x80 != 0 && (acc ^ value) & 0x80 != 0
+        }
+    }
+
+    fn execute(cpu: &mut CPU, opcode: u8, acc: u8, value: u8, carry: bool) -> Outcome {
+        cpu.registers.pc = 0x0000;
+        cpu.registers.acc = acc;
+        cpu.flags.carry = carry;
+        cpu.execute_instruction_sequence(&[opcode, value]);
+
+        Outcome {
+            acc: cpu.registers.acc,
+            carry: cpu.flags.carry,
+            zero: cpu.flags.zero,
+            negative: cpu.flags.negative,
+            overflow: cpu.flags.overflow
+        }
+    }
+
+    fn decimal(opcode: u8, acc: u8, value: u8, carry: bool) -> Outcome {
+        let mut cpu = CPU::new();
+        cpu.flags.decimal = true;
+        execute(&mut cpu, opcode, acc, value, carry)
+    }
+
+    #[test]
+    fn decimal_test_adc_exhaustive() {
+        let mut cpu = CPU::new();
+        cpu.flags.decimal = true;
+
+        for acc in 0..=255u8 {
+            for value in 0..=255u8 {
+                for carry in [false, true] {
+                    assert_eq!(execute(&mut cpu, 0x69, acc, value, carry), reference_adc(acc, value, carry),
+                               "{:#04X?} + {:#04X?} + {}", acc, value, carry);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn decimal_test_sbc_exhaustive() {
+        let mut cpu = CPU::new();
+        cpu.flags.decimal = true;
+
+        for acc in 0..=255u8 {
+            for value in 0..=255u8 {
+                for carry in [false, true] {
+                    assert_eq!(execute(&mut cpu, 0xE9, acc, value, carry), reference_sbc(acc, value, carry),
+                               "{:#04X?} - {:#04X?} - {}", acc, value, !carry);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn decimal_test_sbc_binary_exhaustive() {
+        let mut cpu = CPU::new();
+
+        for acc in 0..=255u8 {
+            for value in 0..=255u8 {
+                for carry in [false, true] {
+                    let result = execute(&mut cpu, 0xE9, acc, value, carry);
+                    let expected = acc as i16 - value as i16 - !carry as i16;
+                    let signed = acc as i8 as i16 - value as i8 as i16 - !carry as i16;
+
+                    assert_eq!(result.acc, expected as u8);
+                    assert_eq!(result.carry, expected >= 0);
+                    assert_eq!(result.overflow, !(-128..=127).contains(&signed));
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn decimal_test_adc() {
+        let result = decimal(0x69, 0x58, 0x46, true);
+
+        assert_eq!(result.acc, 0x05);
+        assert_eq!(result.carry, true);
+    }
+
+    #[test]
+    fn decimal_test_adc_carry_out() {
+        let result = decimal(0x69, 0x99, 0x01, false);
+
+        assert_eq!(result.acc, 0x00);
+        assert_eq!(result.carry, true);
+        assert_eq!(result.zero, false); //Z follows the binary sum $9A on NMOS.
+    }
+
+    #[test]
+    fn decimal_test_adc_negative_and_overflow() {
+        let result = decimal(0x69, 0x79, 0x00, true);
+
+        assert_eq!(result.acc, 0x80);
+        assert_eq!(result.negative, true);
+        assert_eq!(result.overflow, true);
+    }
+
+    #[test]
+    fn decimal_test_adc_invalid_bcd() {
+        let result = decimal(0x69, 0x0F, 0x0F, false);
+
+        assert_eq!(result.acc, 0x14);
+        assert_eq!(result.carry, false);
+    }
+
+    #[test]
+    fn decimal_test_sbc() {
+        let result = decimal(0xE9, 0x46, 0x12, true);
+
+        assert_eq!(result.acc, 0x34);
+        assert_eq!(result.carry, true);
+    }
+
+    #[test]
+    fn decimal_test_sbc_borrow() {
+        let result = decimal(0xE9, 0x00, 0x01, true);
+
+        assert_eq!(result.acc, 0x99);
+        assert_eq!(result.carry, false);
+        assert_eq!(result.negative, true); //N follows the binary result $FF.
+    }
+
+    #[test]
+    fn decimal_test_sbc_with_borrow_in() {
+        let result = decimal(0xE9, 0x40, 0x13, false);
+
+        assert_eq!(result.acc, 0x26);
+        assert_eq!(result.carry, true);
+    }
+}
@@ -0,0 +1,137 @@
+#[cfg(test)]
+mod device_test {
+    use std::cell::RefCell;
+    use std::rc::Rc;
+    use crate::cpu::bus::Bus;
+    use crate::cpu::cpu::CPU;
+    use crate::cpu::devices::{Device, DeviceBus};
+
+    //Returns the current raster line, one line every 63 cycles.
+    struct RasterDevice {
+        line: u8
+    }
+
+    impl Device for RasterDevice {
+        fn read(&mut self, _offset: u16, _cycles: u32) -> u8 {
+            self.line
+        }
+
+        fn write(&mut self, _offset: u16, _value: u8, _cycles: u32) {}
+
+        fn peek(&self, _offset: u16) -> u8 {
+            self.line
+        }
+
+        fn tick(&mut self, cycles: u32) {
+            self.line = (cycles / 63) as u8;
+        }
+    }
+
+    //Records every write as (offset, value, cycles).
+    struct RecordingDevice {
+        writes: Rc<RefCell<Vec<(u16, u8, u32)>>>
+    }
+
+    impl Device for RecordingDevice {
+        fn read(&mut self, offset: u16, _cycles: u32) -> u8 {
+            offset as u8
+        }
+
+        fn write(&mut self, offset: u16, value: u8, cycles: u32) {
+            self.writes.borrow_mut().push((offset, value, cycles));
+        }
+
+        fn peek(&self, offset: u16) -> u8 {
+            offset as u8
+        }
+    }
+
+    //Returns the cycle it was read on.
+    struct CycleDevice;
+
+    impl Device for CycleDevice {
+        fn read(&mut self, _offset: u16, cycles: u32) -> u8 {
+            cycles as u8
+        }
+
+        fn write(&mut self, _offset: u16, _value: u8, _cycles: u32) {}
+
+        fn peek(&self, _offset: u16) -> u8 {
+            0
+        }
+    }
+
+    #[test]
+    fn device_test_live_read() {
+        let mut cpu = CPU::with_bus(DeviceBus::new());
+        cpu.memory.attach(0xD012..=0xD012, 0xFFFF, Box::new(RasterDevice { line: 0 }));
+
+        cpu.cycles = 126;
+        cpu.memory.tick(cpu.cycles);
+        let bytes = [0xAD, 0x12, 0xD0]; //LDA $D012
+        cpu.execute_instruction_sequence(&bytes);
+
+        assert_eq!(cpu.registers.acc, 0x02);
+        assert_eq!(cpu.memory.peek(0xD012), 0x02);
+    }
+
+    #[test]
+    fn device_test_write_receives_cycles() {
+        let writes = Rc::new(RefCell::new(Vec::new()));
+        let mut cpu = CPU::with_bus(DeviceBus::new());
+        cpu.memory.attach(0xD000..=0xD3FF, 0x0F, Box::new(RecordingDevice { writes: writes.clone() }));
+
+        let bytes = [
+            0xA9, 0x01, 0x8D, 0x20, 0xD0, //LDA #$01, STA $D020
+            0xA9, 0x02, 0x8D, 0x03, 0xD0 //LDA #$02, STA $D003
+        ];
+        cpu.execute_instruction_sequence(&bytes);
+
+        assert_eq!(*writes.borrow(), vec![(0x00, 0x01, 5), (0x03, 0x02, 11)]);
+        assert_eq!(cpu.memory[0xD020], 0x00); //Never reaches the underlying memory.
+    }
+
+    #[test]
+    fn device_test_read_in_the_middle_of_an_instruction() {
+        let mut cpu = CPU::with_bus(DeviceBus::new());
+        cpu.memory.attach(0xD000..=0xD000, 0xFFFF, Box::new(CycleDevice));
+
+        cpu.cycles = 100;
+        let bytes = [0xAD, 0x00, 0xD0]; //LDA $D000, the read is its fourth cycle
+        cpu.execute_instruction_sequence(&bytes);
+
+        assert_eq!(cpu.registers.acc, 103);
+    }
+
+    #[test]
+    fn device_test_mirroring() {
+        let mut cpu = CPU::with_bus(DeviceBus::new());
+        cpu.memory.attach(0xD000..=0xD3FF, 0x3F, Box::new(RecordingDevice { writes: Rc::new(RefCell::new(Vec::new())) }));
+
+        assert_eq!(cpu.read_byte(0xD005), 0x05);
+        assert_eq!(cpu.read_byte(0xD045), 0x05);
+        assert_eq!(cpu.read_byte(0xD3FF), 0x3F);
+    }
+
+    #[test]
+    fn device_test_unmapped_falls_through() {
+        let mut cpu = CPU::with_bus(DeviceBus::new());
+        cpu.memory.attach(0xD000..=0xD3FF, 0x3F, Box::new(RecordingDevice { writes: Rc::new(RefCell::new(Vec::new())) }));
+
+        cpu.write_byte(0xD400, 0x12);
+
+        assert_eq!(cpu.read_byte(0xD400), 0x12);
+        assert_eq!(cpu.memory[0xD400], 0x12);
+    }
+
+    #[test]
+    fn device_test_last_attached_wins() {
+        let mut cpu = CPU::with_bus(DeviceBus::new());
+        cpu.memory.attach(0xD000..=0xD3FF, 0x3F, Box::new(RecordingDevice { writes: Rc::new(RefCell::new(Vec::new())) }));
+        let raster = cpu.memory.attach(0xD012..=0xD012, 0xFFFF, Box::new(RasterDevice { line: 0x40 }));
+
+        assert_eq!(cpu.read_byte(0xD011), 0x11);
+        assert_eq!(cpu.read_byte(0xD012), 0x40);
+        assert_eq!(cpu.memory.device(raster).unwrap().peek(0), 0x40);
+    }
+}
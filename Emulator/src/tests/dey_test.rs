@@ -10,7 +10,7 @@ mod dey_test {
         ];
 
         cpu.registers.yr = 0x20;
-        cpu.execute_instruction_sequence(&mut bytes.as_slice());
+        cpu.execute_instruction_sequence(&bytes);
 
         assert_eq!(cpu.registers.yr, 0x20 - 1);
         assert_eq!(cpu.flags.zero, false);
@@ -25,7 +25,7 @@ mod dey_test {
         ];
 
         cpu.registers.yr = 0x00;
-        cpu.execute_instruction_sequence(&mut bytes.as_slice());
+        cpu.execute_instruction_sequence(&bytes);
 
         assert_eq!(cpu.registers.yr, 0xFF);
         assert_eq!(cpu.flags.zero, false);
@@ -40,7 +40,7 @@ mod dey_test {
         ];
 
         cpu.registers.yr = 0x01;
-        cpu.execute_instruction_sequence(&mut bytes.as_slice());
+        cpu.execute_instruction_sequence(&bytes);
 
         assert_eq!(cpu.registers.yr, 0x01 - 1);
         assert_eq!(cpu.flags.zero, true);
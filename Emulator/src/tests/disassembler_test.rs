@@ -0,0 +1,124 @@
+#[cfg(test)]
+mod disassembler_test {
+    use std::collections::HashMap;
+    use crate::cpu::cpu::Variant;
+    use crate::disassembler::{disassemble, format_listing, Disassembler};
+
+    fn lines(disassembler: Disassembler) -> Vec<String> {
+        disassembler.map(|instruction| instruction.to_string()).collect()
+    }
+
+    #[test]
+    fn disassembler_test_addressing_modes() {
+        let bytes = [
+            0xEA,             //NOP
+            0xA9, 0x10,       //LDA #$10
+            0x0A,             //ASL A
+            0xAD, 0x00, 0x03, //LDA $0300
+            0xBD, 0x00, 0x03, //LDA $0300,X
+            0xB9, 0x00, 0x03, //LDA $0300,Y
+            0xA5, 0x10,       //LDA $10
+            0xB5, 0x10,       //LDA $10,X
+            0xB6, 0x10,       //LDX $10,Y
+            0xA1, 0x20,       //LDA ($20,X)
+            0xB1, 0x20,       //LDA ($20),Y
+            0x6C, 0x34, 0x12, //JMP ($1234)
+            0xD0, 0xFE,       //BNE to itself
+            0x10, 0x10,       //BPL forward
+        ];
+
+        assert_eq!(lines(Disassembler::new(&bytes, 0xC000)), vec![
+            "NOP", "LDA #$10", "ASL A", "LDA $0300", "LDA $0300,X", "LDA $0300,Y", "LDA $10", "LDA $10,X", "LDX $10,Y",
+            "LDA ($20,X)", "LDA ($20),Y", "JMP ($1234)", "BNE $C01A", "BPL $C02E"
+        ]);
+    }
+
+    #[test]
+    fn disassembler_test_addresses_and_bytes() {
+        let instructions = disassemble(&[0xA2, 0x00, 0x4C, 0x00, 0x06], 0x0600);
+
+        assert_eq!(instructions.len(), 2);
+        assert_eq!(instructions[1].address, 0x0602);
+        assert_eq!(instructions[1].bytes, vec![0x4C, 0x00, 0x06]);
+        assert_eq!(instructions[1].mnemonic, "JMP");
+        assert_eq!(instructions[1].operand, "$0600");
+    }
+
+    #[test]
+    fn disassembler_test_truncated_instruction() {
+        let instructions = disassemble(&[0xEA, 0xAD, 0x00], 0x0600);
+        let text: Vec<String> = instructions.iter().map(|instruction| instruction.to_string()).collect();
+
+        assert_eq!(text, vec!["NOP", ".byte $AD", "BRK"]);
+        assert_eq!(instructions[1].address_mode, None);
+    }
+
+    #[test]
+    fn disassembler_test_symbols() {
+        let bytes = [0x20, 0x06, 0x06, 0xD0, 0xFB, 0x60, 0x85, 0x10, 0x60];
+        let symbols = HashMap::from([
+            (0x0600, "start".to_string()),
+            (0x0606, "store".to_string()),
+            (0x0010, "counter".to_string())
+        ]);
+
+        let listing = format_listing(Disassembler::new(&bytes, 0x0600).with_symbols(symbols));
+
+        assert_eq!(listing, "\
+start:
+0600  20 06 06    JSR store
+0603  D0 FB       BNE start
+0605  60          RTS
+store:
+0606  85 10       STA counter
+0608  60          RTS
+");
+    }
+
+    #[test]
+    fn disassembler_test_listing_line() {
+        let instructions = disassemble(&[0x4C, 0xF5, 0xC5], 0xC000);
+
+        assert_eq!(instructions[0].listing_line(), "C000  4C F5 C5    JMP $C5F5");
+    }
+
+    #[test]
+    fn disassembler_test_iterator_is_lazy() {
+        let bytes = [0xE8; 0x10000];
+        let mut disassembler = Disassembler::new(&bytes, 0x0000).skip(0xFFFE);
+
+        assert_eq!(disassembler.next().unwrap().address, 0xFFFE);
+        assert_eq!(disassembler.next().unwrap().address, 0xFFFF);
+        assert_eq!(disassembler.next(), None);
+    }
+
+    #[test]
+    fn disassembler_test_variants() {
+        let bytes = [0x04, 0x10, 0xB2, 0x10, 0x0F, 0x10, 0xFD];
+
+        assert_eq!(lines(Disassembler::new(&bytes, 0x0200)), vec!["NOP $10", "JAM", "BPL $0214", "BPL $0204"]);
+        assert_eq!(
+            lines(Disassembler::new(&bytes, 0x0200).with_variant(Variant::Wdc65C02)),
+            vec!["TSB $10", "LDA ($10)", "BBR0 $10,$0204"]
+        );
+    }
+
+    #[test]
+    fn disassembler_test_65816() {
+        let bytes = [
+            0xC2, 0x30,             //REP #$30
+            0xA9, 0x34, 0x12,       //LDA #$1234
+            0xA2, 0x00, 0x01,       //LDX #$0100
+            0xE2, 0x20,             //SEP #$20
+            0xA9, 0x12,             //LDA #$12
+            0xAF, 0x56, 0x34, 0x12, //LDA $123456
+            0x54, 0x01, 0x02,       //MVN $02,$01
+            0xA3, 0x03,             //LDA $03,S
+            0x82, 0xFD, 0xFF,       //BRL to itself
+        ];
+
+        assert_eq!(lines(Disassembler::new(&bytes, 0x018000).with_variant(Variant::Wdc65816)), vec![
+            "REP #$30", "LDA #$1234", "LDX #$0100", "SEP #$20", "LDA #$12", "LDA $123456", "MVN $02,$01", "LDA $03,S", "BRL $8015"
+        ]);
+    }
+}
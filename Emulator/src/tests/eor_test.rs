@@ -12,7 +12,7 @@ mod eor_test {
         ];
 
         cpu.registers.acc = 0xFF;
-        cpu.execute_instruction_sequence(&mut bytes.as_slice());
+        cpu.execute_instruction_sequence(&bytes);
 
         assert_eq!(cpu.registers.acc, 0xFF ^ 0x55);
         assert_eq!(cpu.flags.zero, false);
@@ -28,7 +28,7 @@ mod eor_test {
         ];
 
         cpu.registers.acc = 0xFF;
-        cpu.execute_instruction_sequence(&mut bytes.as_slice());
+        cpu.execute_instruction_sequence(&bytes);
 
         assert_eq!(cpu.registers.acc, 0xFF ^ 0xFF);
         assert_eq!(cpu.flags.zero, true);
@@ -46,7 +46,7 @@ mod eor_test {
         ];
 
         cpu.registers.acc = 0xFF;
-        cpu.execute_instruction_sequence(&mut bytes.as_slice());
+        cpu.execute_instruction_sequence(&bytes);
 
         assert_eq!(cpu.registers.acc, 0xFF ^ 0x55);
     }
@@ -63,7 +63,7 @@ mod eor_test {
 
         cpu.registers.acc = 0xFF;
         cpu.registers.xr = 0x03;
-        cpu.execute_instruction_sequence(&mut bytes.as_slice());
+        cpu.execute_instruction_sequence(&bytes);
 
         assert_eq!(cpu.registers.acc, 0xFF ^ 0x55);
     }
@@ -80,7 +80,7 @@ mod eor_test {
 
         cpu.registers.acc = 0xFF;
         cpu.registers.yr = 0x03;
-        cpu.execute_instruction_sequence(&mut bytes.as_slice());
+        cpu.execute_instruction_sequence(&bytes);
 
         assert_eq!(cpu.registers.acc, 0xFF ^ 0x55);
     }
@@ -95,7 +95,7 @@ mod eor_test {
 
         cpu.registers.acc = 0xFF;
         cpu.memory[0x33] = 0x55;
-        cpu.execute_instruction_sequence(&mut bytes.as_slice());
+        cpu.execute_instruction_sequence(&bytes);
 
         assert_eq!(cpu.registers.acc, 0xFF ^ 0x55);
     }
@@ -111,7 +111,7 @@ mod eor_test {
         cpu.registers.acc = 0xFF;
         cpu.registers.xr = 0x03;
         cpu.memory[0x33] = 0x55;
-        cpu.execute_instruction_sequence(&mut bytes.as_slice());
+        cpu.execute_instruction_sequence(&bytes);
 
         assert_eq!(cpu.registers.acc, 0xFF ^ 0x55);
     }
@@ -129,7 +129,7 @@ mod eor_test {
         cpu.memory[0x20] = 0x30;
         cpu.memory[0x21] = 0x40;
         cpu.memory[0x4030] = 0x55;
-        cpu.execute_instruction_sequence(&mut bytes.as_slice());
+        cpu.execute_instruction_sequence(&bytes);
 
         assert_eq!(cpu.registers.acc, 0xFF ^ 0x55);
     }
@@ -148,7 +148,7 @@ mod eor_test {
         cpu.memory[0x22] = 0x30;
         cpu.memory[0x23] = 0x40;
         cpu.memory[0x4032] = 0x55;
-        cpu.execute_instruction_sequence(&mut bytes.as_slice());
+        cpu.execute_instruction_sequence(&bytes);
 
         assert_eq!(cpu.registers.acc, 0xFF ^ 0x55);
     }
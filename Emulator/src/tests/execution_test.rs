@@ -0,0 +1,103 @@
+#[cfg(test)]
+mod execution_test {
+    use crate::cpu::bus::Ram;
+    use crate::cpu::cpu::{CPU, Variant};
+    use crate::cpu::execution::{ExecutionError, Fault, StepOutcome};
+    use crate::cpu::instructions::{AddressingMode, Instruction, InstructionParameter, ValueOrAddress};
+
+    fn no_result(_: &mut CPU, _: InstructionParameter) -> Option<u8> {
+        None
+    }
+
+    fn missing_value(_: &mut CPU, _: Option<u8>, address: Option<u16>) -> Result<(), Fault> {
+        Err(Fault::MissingValue(address))
+    }
+
+    #[test]
+    fn execution_test_executed_reports_pc_and_opcode() {
+        let mut cpu = CPU::new();
+        cpu.load_program(&[0xEA, 0xA9, 0x01], 0x0200);
+        cpu.registers.pc = 0x0200;
+
+        assert_eq!(cpu.step(), Ok(StepOutcome::Executed { pc: 0x0200, opcode: 0xEA }));
+        assert_eq!(cpu.step(), Ok(StepOutcome::Executed { pc: 0x0201, opcode: 0xA9 }));
+    }
+
+    #[test]
+    fn execution_test_jammed_cpu_is_halted() {
+        let mut cpu = CPU::new();
+        cpu.load_program(&[0x02], 0x0200);
+        cpu.registers.pc = 0x0200;
+
+        assert_eq!(cpu.step(), Ok(StepOutcome::Executed { pc: 0x0200, opcode: 0x02 }));
+        assert_eq!(cpu.step(), Ok(StepOutcome::Halted));
+    }
+
+    #[test]
+    fn execution_test_wai_is_waiting() {
+        let mut cpu = CPU::with_variant(Variant::Wdc65C02);
+        cpu.load_program(&[0xCB], 0x0200);
+        cpu.registers.pc = 0x0200;
+
+        cpu.step().unwrap();
+        assert_eq!(cpu.step(), Ok(StepOutcome::Waiting));
+    }
+
+    #[test]
+    fn execution_test_interrupt_and_reset() {
+        let mut cpu = CPU::new();
+        cpu.load_program(&[0xEA], 0x0200);
+        cpu.registers.pc = 0x0200;
+        cpu.flags.interrupt = false;
+
+        cpu.set_irq_line(true);
+        assert_eq!(cpu.step(), Ok(StepOutcome::Interrupt));
+
+        cpu.set_reset_line(true);
+        assert_eq!(cpu.step(), Ok(StepOutcome::Reset));
+    }
+
+    #[test]
+    fn execution_test_fault_carries_pc_and_opcode() {
+        let mut cpu = CPU::new();
+        let instruction: Instruction<Ram> = Instruction {
+            address_mode: AddressingMode::ZeroPage,
+            mnemonic: "TST",
+            operation: no_result,
+            result_handler: missing_value,
+            value_or_address: ValueOrAddress::Value,
+            cycle_increase: 3,
+            cycle_increases_on_page_cross: false
+        };
+        cpu.load_program(&[0x10], 0x0200);
+        cpu.registers.pc = 0x0200;
+
+        let fault = cpu.execute_instruction(&instruction).unwrap_err();
+        let error = fault.at(0x01FF, 0xA5);
+        assert_eq!(error, ExecutionError::MissingValue { pc: 0x01FF, opcode: 0xA5, address: Some(0x0010) });
+        assert_eq!(error.pc(), 0x01FF);
+        assert_eq!(error.opcode(), 0xA5);
+    }
+
+    #[test]
+    fn execution_test_unsupported_addressing_mode() {
+        let mut cpu = CPU::new();
+        let instruction: Instruction<Ram> = Instruction {
+            address_mode: AddressingMode::AbsoluteLong,
+            mnemonic: "TST",
+            operation: no_result,
+            result_handler: missing_value,
+            value_or_address: ValueOrAddress::Address,
+            cycle_increase: 4,
+            cycle_increases_on_page_cross: false
+        };
+
+        assert_eq!(cpu.execute_instruction(&instruction), Err(Fault::UnsupportedAddressingMode));
+    }
+
+    #[test]
+    fn execution_test_error_display() {
+        let error = ExecutionError::UnknownOpcode { pc: 0x0600, opcode: 0x02 };
+        assert_eq!(error.to_string(), "Unknown opcode 0x02 at 0x0600.");
+    }
+}
@@ -12,7 +12,7 @@ mod inc_test {
             0xEE, low_byte, high_byte
         ];
 
-        cpu.execute_instruction_sequence(&mut bytes.as_slice());
+        cpu.execute_instruction_sequence(&bytes);
 
         assert_eq!(cpu.memory[0x2233], 0x20 + 1);
         assert_eq!(cpu.flags.zero, false);
@@ -28,7 +28,7 @@ mod inc_test {
             0xEE, low_byte, high_byte
         ];
 
-        cpu.execute_instruction_sequence(&mut bytes.as_slice());
+        cpu.execute_instruction_sequence(&bytes);
 
         assert_eq!(cpu.memory[0x2233], 0x00);
         assert_eq!(cpu.flags.zero, true);
@@ -45,7 +45,7 @@ mod inc_test {
         ];
 
         cpu.registers.xr = 3;
-        cpu.execute_instruction_sequence(&mut bytes.as_slice());
+        cpu.execute_instruction_sequence(&bytes);
 
         assert_eq!(cpu.memory[0x2233], 0x20 + 1);
     }
@@ -58,7 +58,7 @@ mod inc_test {
             0xE6, 0x33
         ];
 
-        cpu.execute_instruction_sequence(&mut bytes.as_slice());
+        cpu.execute_instruction_sequence(&bytes);
 
         assert_eq!(cpu.memory[0x33], 0x20 + 1);
     }
@@ -72,7 +72,7 @@ mod inc_test {
         ];
 
         cpu.registers.xr = 3;
-        cpu.execute_instruction_sequence(&mut bytes.as_slice());
+        cpu.execute_instruction_sequence(&bytes);
 
         assert_eq!(cpu.memory[0x33], 0x20 + 1);
     }
@@ -0,0 +1,38 @@
+#[cfg(test)]
+mod instruction_set_test {
+    use std::time::Instant;
+    use crate::cpu::cpu::{Variant, CPU};
+
+    #[test]
+    fn instruction_set_test_all_opcodes() {
+        for variant in [Variant::Nmos6502, Variant::Wdc65C02] {
+            let defined = <CPU>::get_instruction_set(variant).iter().filter(|instruction| instruction.is_some()).count();
+
+            assert_eq!(defined, 256);
+            assert!(<CPU>::get_instruction(variant, 0x69).is_some());
+            assert!(<CPU>::get_instruction(variant, 0x02).is_some());
+            assert!(<CPU>::get_instruction(variant, 0xFF).is_some());
+        }
+    }
+
+    //Run with: cargo test --release -- --ignored --nocapture instruction_set_test_throughput
+    #[test]
+    #[ignore]
+    fn instruction_set_test_throughput() {
+        let mut cpu = CPU::new();
+        let bytes = [
+            0xE8, 0xBD, 0x00, 0x02, //INX, LDA $0200,X
+            0x69, 0x01, 0x9D, 0x00, 0x03, //ADC #$01, STA $0300,X
+            0x4C, 0x00, 0x06 //JMP $0600
+        ];
+        cpu.load_program(&bytes, 0x0600);
+        cpu.registers.pc = 0x0600;
+
+        let count = 20_000_000;
+        let start = Instant::now();
+        cpu.execute_instructions(count);
+        let elapsed = start.elapsed().as_secs_f64();
+
+        eprintln!("{:.1} M instructions/s", count as f64 / elapsed / 1_000_000.0);
+    }
+}
@@ -0,0 +1,264 @@
+#[cfg(test)]
+mod interrupt_test {
+    use crate::cpu::bus::{Bus, Ram};
+    use crate::cpu::cpu::{CPU, Flags};
+
+    //Flat RAM that pulls NMI from the given cycle on.
+    struct NmiBus {
+        ram: Ram,
+        cycles: u32,
+        nmi_at: u32
+    }
+
+    impl Bus for NmiBus {
+        fn write(&mut self, address: u16, value: u8) {
+            self.ram.write(address, value);
+        }
+
+        fn peek(&self, address: u16) -> u8 {
+            self.ram.peek(address)
+        }
+
+        fn tick(&mut self, cycles: u32) {
+            self.cycles = cycles;
+        }
+
+        fn nmi(&self) -> bool {
+            self.cycles >= self.nmi_at
+        }
+    }
+
+    fn set_vectors<B: Bus>(cpu: &mut CPU<B>) {
+        cpu.memory.write(0xFFFA, 0x00);
+        cpu.memory.write(0xFFFB, 0x90);
+        cpu.memory.write(0xFFFE, 0x00);
+        cpu.memory.write(0xFFFF, 0xA0);
+    }
+
+    fn interrupt_cpu(bytes: &[u8]) -> CPU {
+        let mut cpu = CPU::new();
+        set_vectors(&mut cpu);
+        cpu.load_program(bytes, 0x0200);
+        cpu.registers.pc = 0x0200;
+        cpu
+    }
+
+    #[test]
+    fn interrupt_test_irq() {
+        let mut cpu = interrupt_cpu(&[0xEA]);
+
+        cpu.set_irq_line(true);
+        cpu.step().unwrap();
+        assert_eq!(cpu.cycles, 7);
+
+        let stored_status = cpu.pop_byte_from_stack();
+        let stored_flags = Flags::from_byte(stored_status);
+        assert_eq!(cpu.registers.pc, 0xA000);
+        assert_eq!(cpu.flags.interrupt, true);
+        assert_eq!(stored_status & Flags::BREAK, 0);
+        assert_eq!(stored_flags.interrupt, false);
+        assert_eq!(cpu.pop_word_from_stack(), 0x0200);
+    }
+
+    #[test]
+    fn interrupt_test_irq_masked() {
+        let mut cpu = interrupt_cpu(&[0xEA]);
+
+        cpu.flags.interrupt = true;
+        cpu.set_irq_line(true);
+        cpu.step().unwrap();
+
+        assert_eq!(cpu.registers.pc, 0x0201);
+    }
+
+    #[test]
+    fn interrupt_test_irq_level_triggered() {
+        let mut cpu = interrupt_cpu(&[0x40]); //RTI
+        cpu.memory.write(0xA000, 0x40);
+
+        cpu.set_irq_line(true);
+        cpu.step().unwrap(); //IRQ
+        cpu.step().unwrap(); //RTI at $A000 restores I = 0
+        cpu.step().unwrap(); //Still asserted, taken again
+
+        assert_eq!(cpu.registers.pc, 0xA000);
+        assert_eq!(cpu.registers.sp, 0xFC);
+    }
+
+    #[test]
+    fn interrupt_test_handler_runs_one_instruction() {
+        let mut cpu = interrupt_cpu(&[0xEA]);
+        cpu.memory.write(0xA000, 0xE8); //INX
+
+        cpu.set_irq_line(true);
+        cpu.step().unwrap();
+        cpu.set_nmi_line(true);
+        cpu.step().unwrap();
+
+        assert_eq!(cpu.registers.xr, 0x01);
+        cpu.step().unwrap();
+        assert_eq!(cpu.registers.pc, 0x9000);
+    }
+
+    #[test]
+    fn interrupt_test_nmi() {
+        let mut cpu = interrupt_cpu(&[0xEA, 0xEA]);
+        cpu.memory.write(0x9000, 0x40); //RTI
+
+        cpu.flags.interrupt = true;
+        cpu.set_nmi_line(true);
+        cpu.step().unwrap();
+
+        let stored_status = cpu.pop_byte_from_stack();
+        let stored_flags = Flags::from_byte(stored_status);
+        assert_eq!(cpu.registers.pc, 0x9000);
+        assert_eq!(stored_status & Flags::BREAK, 0);
+        assert_eq!(stored_flags.interrupt, true);
+
+        //Still asserted, but only the edge counts.
+        cpu.registers.sp = cpu.registers.sp.wrapping_sub(1);
+        cpu.step().unwrap();
+        cpu.step().unwrap();
+        assert_eq!(cpu.registers.pc, 0x0201);
+    }
+
+    #[test]
+    fn interrupt_test_nmi_edge() {
+        let mut cpu = interrupt_cpu(&[0xEA]);
+        cpu.memory.write(0x9000, 0x40); //RTI
+
+        cpu.set_nmi_line(true);
+        cpu.step().unwrap();
+        cpu.step().unwrap();
+        cpu.set_nmi_line(false);
+        cpu.set_nmi_line(true);
+        cpu.step().unwrap();
+
+        assert_eq!(cpu.registers.pc, 0x9000);
+    }
+
+    #[test]
+    fn interrupt_test_nmi_priority() {
+        let mut cpu = interrupt_cpu(&[0xEA]);
+
+        cpu.set_irq_line(true);
+        cpu.set_nmi_line(true);
+        cpu.step().unwrap();
+
+        assert_eq!(cpu.registers.pc, 0x9000);
+    }
+
+    #[test]
+    fn interrupt_test_cli_latency() {
+        let mut cpu = interrupt_cpu(&[0x58, 0xE8, 0xE8]); //CLI, INX, INX
+
+        cpu.flags.interrupt = true;
+        cpu.set_irq_line(true);
+        cpu.step().unwrap();
+        cpu.step().unwrap();
+
+        assert_eq!(cpu.registers.xr, 0x01);
+        cpu.step().unwrap();
+        assert_eq!(cpu.registers.pc, 0xA000);
+    }
+
+    #[test]
+    fn interrupt_test_sei_latency() {
+        let mut cpu = interrupt_cpu(&[0x78, 0xE8]); //SEI, INX
+
+        cpu.step().unwrap();
+        cpu.set_irq_line(true);
+        cpu.step().unwrap();
+
+        let stored_flags = Flags::from_byte(cpu.pop_byte_from_stack());
+        assert_eq!(cpu.registers.pc, 0xA000);
+        assert_eq!(stored_flags.interrupt, true);
+    }
+
+    #[test]
+    fn interrupt_test_plp_latency() {
+        let mut cpu = interrupt_cpu(&[0x28, 0xE8, 0xE8]); //PLP, INX, INX
+
+        cpu.flags.interrupt = true;
+        cpu.push_byte_to_stack(Flags::from_byte(0).to_byte());
+        cpu.set_irq_line(true);
+        cpu.step().unwrap();
+        cpu.step().unwrap();
+
+        assert_eq!(cpu.registers.xr, 0x01);
+        cpu.step().unwrap();
+        assert_eq!(cpu.registers.pc, 0xA000);
+    }
+
+    #[test]
+    fn interrupt_test_rti_no_latency() {
+        let mut cpu = interrupt_cpu(&[0x40, 0xE8]); //RTI, INX
+
+        cpu.flags.interrupt = true;
+        cpu.push_word_to_stack(0x0201);
+        cpu.push_byte_to_stack(Flags::from_byte(0).to_byte());
+        cpu.set_irq_line(true);
+        cpu.step().unwrap();
+        cpu.step().unwrap();
+
+        assert_eq!(cpu.registers.xr, 0x00);
+        assert_eq!(cpu.registers.pc, 0xA000);
+    }
+
+    #[test]
+    fn interrupt_test_nmi_hijacks_brk() {
+        let mut cpu = CPU::with_bus(NmiBus { ram: Ram::new(), cycles: 0, nmi_at: 3 });
+        set_vectors(&mut cpu);
+        cpu.load_program(&[0x00], 0x0200);
+        cpu.registers.pc = 0x0200;
+        cpu.cycle_accurate = true;
+
+        cpu.step().unwrap();
+        assert_eq!(cpu.cycles, 7);
+
+        let stored_status = cpu.pop_byte_from_stack();
+        assert_eq!(cpu.registers.pc, 0x9000);
+        assert_eq!(stored_status & Flags::BREAK, Flags::BREAK);
+        assert_eq!(cpu.pop_word_from_stack(), 0x0202);
+    }
+
+    #[test]
+    fn interrupt_test_nmi_hijacks_irq() {
+        let mut cpu = CPU::with_bus(NmiBus { ram: Ram::new(), cycles: 0, nmi_at: 4 });
+        set_vectors(&mut cpu);
+        cpu.registers.pc = 0x0200;
+        cpu.cycle_accurate = true;
+
+        cpu.set_irq_line(true);
+        cpu.step().unwrap();
+
+        let stored_status = cpu.pop_byte_from_stack();
+        assert_eq!(cpu.registers.pc, 0x9000);
+        assert_eq!(stored_status & Flags::BREAK, 0);
+    }
+
+    #[test]
+    fn interrupt_test_irq_from_device_bus() {
+        struct IrqBus(Ram);
+
+        impl Bus for IrqBus {
+            fn write(&mut self, address: u16, value: u8) {
+                self.0.write(address, value);
+            }
+
+            fn peek(&self, address: u16) -> u8 {
+                self.0.peek(address)
+            }
+
+            fn irq(&self) -> bool {
+                true
+            }
+        }
+
+        let mut cpu = CPU::with_bus(IrqBus(Ram::new()));
+        set_vectors(&mut cpu);
+        cpu.step().unwrap();
+
+        assert_eq!(cpu.registers.pc, 0xA000);
+    }
+}
@@ -10,7 +10,7 @@ mod iny_test {
         ];
 
         cpu.registers.yr = 0x20;
-        cpu.execute_instruction_sequence(&mut bytes.as_slice());
+        cpu.execute_instruction_sequence(&bytes);
 
         assert_eq!(cpu.registers.yr, 0x20 + 1);
         assert_eq!(cpu.flags.zero, false);
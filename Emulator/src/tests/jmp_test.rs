@@ -11,7 +11,7 @@ mod jmp_test {
             0x4C, low_byte, high_byte, 0xE8, 0xE8, 0xC8
         ];
 
-        cpu.execute_instruction_sequence(&mut bytes.as_slice());
+        cpu.execute_instruction_sequence(&bytes);
 
         assert_eq!(cpu.registers.xr, 0x00); //INX (0xE8) should be jumped over.
         assert_eq!(cpu.registers.yr, 0x01);
@@ -26,7 +26,7 @@ mod jmp_test {
             0x6C, low_byte, high_byte, 0xE8, 0xE8, 0xC8
         ];
 
-        cpu.execute_instruction_sequence(&mut bytes.as_slice());
+        cpu.execute_instruction_sequence(&bytes);
 
         assert_eq!(cpu.registers.xr, 0x00); //INX (0xE8) should be jumped over.
         assert_eq!(cpu.registers.yr, 0x01);
@@ -0,0 +1,211 @@
+#[cfg(test)]
+mod linker_test {
+    use crate::assembler::assembler::{assemble, Assembler};
+    use crate::assembler::expression::{Expression, UnaryOperator};
+    use crate::assembler::linker::{link, LinkError, MemoryConfig, SegmentType};
+    use crate::assembler::object::{Field, ObjectFile, SymbolValue};
+    use crate::cpu::cpu::CPU;
+
+    const CONFIG: &str = "
+        # A 32K ROM at the top of memory.
+        MEMORY {
+            ZP:  start = $0000, size = $0100;
+            RAM: start = $0200, size = $0600;
+            ROM: start = $8000, size = $8000, fill = yes, fillval = $FF;
+        }
+        SEGMENTS {
+            ZEROPAGE: load = ZP, type = zp;
+            DATA:     load = RAM, type = rw;
+            BSS:      load = RAM, type = bss, define = yes;
+            CODE:     load = ROM, type = ro;
+            VECTORS:  load = ROM, type = ro, start = $FFFA;
+        }
+    ";
+
+    fn object(source: &str) -> ObjectFile {
+        Assembler::new().assemble_object(source).unwrap()
+    }
+
+    fn link_error(sources: &[&str]) -> String {
+        let objects: Vec<_> = sources.iter().map(|source| object(source)).collect();
+        link(&MemoryConfig::parse(CONFIG).unwrap(), &objects).unwrap_err().to_string()
+    }
+
+    fn config_error(config: &str) -> (usize, String) {
+        match MemoryConfig::parse(config) {
+            Err(LinkError::Config { line, message }) => (line, message),
+            other => panic!("Expected a configuration error, got {:?}", other)
+        }
+    }
+
+    #[test]
+    fn linker_test_object_segments_and_relocations() {
+        let object = object("
+            .import far
+            start:
+                JMP start
+                LDA far+1
+                BNE start
+                .word end - start
+            end:
+            .data
+            table: .word start, table
+        ");
+        let code = object.segment("CODE").unwrap();
+        let data = object.segment("DATA").unwrap();
+
+        //The branch and the difference of two labels don't depend on where CODE goes.
+        assert_eq!(code.bytes, vec![0x4C, 0x00, 0x00, 0xAD, 0x00, 0x00, 0xD0, 0xF8, 0x0A, 0x00]);
+        assert_eq!(code.relocations.iter().map(|relocation| (relocation.offset, relocation.field, relocation.line)).collect::<Vec<_>>(),
+            vec![(1, Field::Absolute, 4), (4, Field::Absolute, 5)]);
+        assert_eq!(data.bytes, vec![0; 4]);
+        assert_eq!(data.relocations.iter().map(|relocation| (relocation.offset, relocation.field)).collect::<Vec<_>>(),
+            vec![(0, Field::Word), (2, Field::Word)]);
+        assert_eq!(object.imports, vec!["far".to_string()]);
+    }
+
+    #[test]
+    fn linker_test_exports() {
+        let object = object("
+            .export start, SIZE, value
+            SIZE = 4
+            .segment \"DATA\"
+            .byte 0
+            value: .byte 1
+            .code
+            start: RTS
+        ");
+
+        assert_eq!(object.exports.get("SIZE"), Some(&SymbolValue::Absolute(4)));
+        assert_eq!(object.exports.get("value"), Some(&SymbolValue::Relative { segment: "DATA".to_string(), offset: 1 }));
+        assert_eq!(object.exports.get("start"), Some(&SymbolValue::Relative { segment: "CODE".to_string(), offset: 0 }));
+    }
+
+    #[test]
+    fn linker_test_object_round_trip() {
+        let object = object(".import far\n.importzp pointer\n.export start\nstart: LDA (pointer),Y\nJMP far\n.zeropage\nvalue: .res 2");
+        let mut bytes = object.to_bytes();
+
+        assert_eq!(ObjectFile::from_bytes(&bytes).unwrap(), object);
+        bytes[10] ^= 1;
+        assert!(matches!(ObjectFile::from_bytes(&bytes), Err(LinkError::InvalidObject(_))));
+        assert!(matches!(ObjectFile::from_bytes(b"not an object"), Err(LinkError::InvalidObject(_))));
+    }
+
+    //Expressions nest at most 256 deep in an object file.
+    #[test]
+    fn linker_test_object_expression_depth() {
+        let mut object = object(".import far\n.word far");
+        for _ in 0..255 {
+            let expression = object.segments[0].relocations[0].expression.clone();
+            object.segments[0].relocations[0].expression = Expression::Unary(UnaryOperator::Complement, Box::new(expression));
+        }
+        assert_eq!(ObjectFile::from_bytes(&object.to_bytes()).unwrap(), object);
+
+        let expression = object.segments[0].relocations[0].expression.clone();
+        object.segments[0].relocations[0].expression = Expression::Unary(UnaryOperator::Complement, Box::new(expression));
+        match ObjectFile::from_bytes(&object.to_bytes()) {
+            Err(LinkError::InvalidObject(message)) => assert_eq!(message, "The object file has an expression that nests too deep."),
+            other => panic!("Expected an invalid object, got {:?}", other)
+        }
+    }
+
+    #[test]
+    fn linker_test_object_mode_errors() {
+        let error = |source: &str| {
+            let error = Assembler::new().assemble_object(source).unwrap_err();
+            (error.line, error.column, error.message)
+        };
+
+        assert_eq!(error(".org $0600"), (1, 1, "Object files can't set the origin, the linker places the segments.".to_string()));
+        assert_eq!(error(".export missing"), (1, 9, "missing isn't defined.".to_string()));
+        assert_eq!(error(".import far\n.export far"), (2, 9, "far is imported, it can't be exported too.".to_string()));
+        assert_eq!(error("start:\n.import start"), (2, 9, "start is already defined.".to_string()));
+        assert_eq!(error("start:\n.res start"), (2, 1, "The size of .res has to be known in the first pass.".to_string()));
+        assert_eq!(assemble(".code").unwrap_err().message, "Segments, imports and exports are only for object files.");
+    }
+
+    #[test]
+    fn linker_test_memory_config() {
+        let config = MemoryConfig::parse(CONFIG).unwrap();
+
+        assert_eq!(config.memory.len(), 3);
+        assert_eq!((config.memory[2].start, config.memory[2].size, config.memory[2].fill, config.memory[2].fill_value), (0x8000, 0x8000, true, 0xFF));
+        assert_eq!(config.segments[2].segment_type, SegmentType::Bss);
+        assert_eq!(config.segments[2].define, true);
+        assert_eq!(config.segments[4].start, Some(0xFFFA));
+    }
+
+    #[test]
+    fn linker_test_memory_config_errors() {
+        assert_eq!(config_error("MEMORY {\n  RAM: start = $0200;\n}"), (2, "RAM needs a size.".to_string()));
+        assert_eq!(config_error("MEMORY {\n  RAM: start = $0200, size = $100, colour = red;\n}"), (2, "RAM has an unknown attribute colour.".to_string()));
+        assert_eq!(config_error("MEMORY { RAM: start = 0, size = 1; }\nSEGMENTS {\n  CODE: load = ROM;\n}"),
+            (3, "Segment CODE loads into ROM, which isn't in MEMORY.".to_string()));
+        assert_eq!(config_error("SEGMENTS {\n  CODE: load = RAM, type = rx;\n}"), (2, "Unknown segment type rx, expected ro, rw, bss or zp.".to_string()));
+        assert_eq!(config_error("MEMORY {\n  RAM start = 0;\n}"), (2, "Expected :.".to_string()));
+    }
+
+    #[test]
+    fn linker_test_link_and_boot() {
+        let main = object("
+            .import add_numbers
+            .importzp operand
+            .export result
+            .zeropage
+            temporary: .res 1
+            .segment \"DATA\"
+            values: .byte 3, 4
+            .bss
+            result: .res 1
+            .code
+            reset:
+                LDA values
+                STA operand
+                LDA values+1
+                JSR add_numbers
+                STA result
+            loop:
+                JMP loop
+            .segment \"VECTORS\"
+                .word 0, reset, 0
+        ");
+        let library = object("
+            .export add_numbers, operand
+            .zeropage
+            operand: .res 1
+            .code
+            add_numbers:
+                CLC
+                ADC operand
+                RTS
+        ");
+        let assembly = link(&MemoryConfig::parse(CONFIG).unwrap(), &[main, library]).unwrap();
+        let mut cpu = CPU::new();
+
+        assembly.load(&mut cpu);
+        cpu.reset();
+        cpu.run_instructions(20).unwrap();
+
+        assert_eq!(assembly.origin, 0x0200);
+        assert_eq!(assembly.image.len(), 0x10000 - 0x0200);
+        assert_eq!(assembly.symbol("operand"), Some(0x0001));
+        assert_eq!(assembly.symbol("result"), Some(0x0202));
+        assert_eq!(assembly.symbol("add_numbers"), Some(0x8011));
+        assert_eq!((assembly.symbol("__BSS_LOAD__"), assembly.symbol("__BSS_SIZE__")), (Some(0x0202), Some(1)));
+        //Unused ROM is filled, and the zero page only reserves space.
+        assert_eq!(assembly.image[0x8015 - 0x0200], 0xFF);
+        assert_eq!(cpu.memory[0x0202], 7);
+    }
+
+    #[test]
+    fn linker_test_link_errors() {
+        assert_eq!(link_error(&[".import missing\nJMP missing"]), "source imports missing, but nothing exports it.");
+        assert_eq!(link_error(&[".export start\nstart: RTS", ".export start\nstart: RTS"]), "start is exported more than once, again by source.");
+        assert_eq!(link_error(&[".segment \"ROM2\"\nRTS"]), "source uses segment ROM2, which isn't in the memory configuration.");
+        assert_eq!(link_error(&[".zeropage\n.res $101"]), "Segment ZEROPAGE doesn't fit in ZP, it needs 1 more bytes.");
+        assert_eq!(link_error(&[".bss\n.byte 1"]), "source puts data in segment BSS, which is bss.");
+        assert_eq!(link_error(&[".import far\nNOP\nLDA #far", ".export far\nfar = $1234"]), "source:3: 4660 doesn't fit in a byte.");
+        assert_eq!(link_error(&[".import far\nBNE far", ".export far\n.data\nfar: NOP"]), "source:2: The branch target is -32258 bytes away, branches reach -128 to 127.");
+    }
+}
@@ -0,0 +1,63 @@
+#[cfg(test)]
+mod load_program_test {
+    use crate::cpu::cpu::CPU;
+
+    #[test]
+    fn load_program_test_origin() {
+        let mut cpu = CPU::new();
+        let bytes = [0xE8, 0xE8, 0xC8];
+
+        cpu.load_program(&bytes, 0xC000);
+        cpu.registers.pc = 0xC000;
+        cpu.execute_instructions(3);
+
+        assert_eq!(cpu.memory[0xC000..0xC003], bytes);
+        assert_eq!(cpu.registers.pc, 0xC003);
+        assert_eq!(cpu.registers.xr, 0x02);
+        assert_eq!(cpu.registers.yr, 0x01);
+    }
+
+    #[test]
+    fn load_program_test_wraps_address_space() {
+        let mut cpu = CPU::new();
+        let bytes = [0xE8, 0xE8, 0xC8];
+
+        cpu.load_program(&bytes, 0xFFFF);
+
+        assert_eq!(cpu.memory[0xFFFF], 0xE8);
+        assert_eq!(cpu.memory[0x0000], 0xE8);
+        assert_eq!(cpu.memory[0x0001], 0xC8);
+    }
+
+    #[test]
+    fn load_program_test_jump_to_written_code() {
+        let mut cpu = CPU::new();
+        let bytes = [
+            0xA9, 0xE8, 0x8D, 0x00, 0x03, //LDA #$E8, STA $0300 (INX)
+            0xA9, 0x60, 0x8D, 0x01, 0x03, //LDA #$60, STA $0301 (RTS)
+            0x20, 0x00, 0x03, //JSR $0300
+            0xC8 //INY
+        ];
+
+        cpu.load_program(&bytes, 0x0600);
+        cpu.registers.pc = 0x0600;
+        cpu.execute_instructions(8);
+
+        assert_eq!(cpu.registers.xr, 0x01);
+        assert_eq!(cpu.registers.yr, 0x01);
+        assert_eq!(cpu.registers.pc, 0x060E);
+    }
+
+    #[test]
+    fn load_program_test_jsr_pushes_memory_address() {
+        let mut cpu = CPU::new();
+        let bytes = [0x20, 0x00, 0x03];
+
+        cpu.load_program(&bytes, 0x0600);
+        cpu.registers.pc = 0x0600;
+        cpu.execute_instructions(1);
+
+        assert_eq!(cpu.registers.pc, 0x0300);
+        assert_eq!(cpu.pop_word_from_stack(), 0x0602);
+    }
+}
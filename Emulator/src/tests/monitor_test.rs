@@ -0,0 +1,195 @@
+#[cfg(test)]
+mod monitor_test {
+    use crate::cpu::cpu::{Variant, CPU};
+    use crate::monitor::Monitor;
+
+    fn monitor() -> Monitor {
+        let mut cpu = CPU::new();
+        cpu.registers.pc = 0x0600;
+        Monitor::new(cpu)
+    }
+
+    //Runs each line and returns what the last one printed.
+    fn run(monitor: &mut Monitor, lines: &[&str]) -> String {
+        lines.iter().fold(String::new(), |_, line| monitor.execute(line))
+    }
+
+    #[test]
+    fn monitor_test_examine_and_modify_memory() {
+        let mut monitor = monitor();
+
+        assert_eq!(monitor.execute("> 0200 48 49 00 ff"), "");
+        assert_eq!(monitor.execute("m 0200 0203"), "0200  48 49 00 FF                                      HI..\n");
+        assert_eq!(monitor.execute(">0210 01"), "");
+        assert_eq!(monitor.cpu.memory[0x0210], 0x01);
+        //m without an address goes on from where the last one stopped.
+        assert!(monitor.execute("m").starts_with("0204  00 00 00 00 00 00 00 00 00 00 00 00 01 00 00 00  ............"));
+        assert_eq!(monitor.execute("m").lines().count(), 8);
+        assert_eq!(monitor.execute("> 0200 100"), "100 doesn't fit in a byte.\n");
+        assert_eq!(monitor.execute("m 0300 0200"), "The range ends at $200, before it starts.\n");
+    }
+
+    #[test]
+    fn monitor_test_assemble_and_disassemble() {
+        let mut monitor = monitor();
+
+        assert_eq!(monitor.execute("d 0600 0600"), "0600  00          BRK\n");
+        assert_eq!(monitor.execute("a 0600 LDA #$01"), "0600  A9 01       LDA #$01\n");
+        assert_eq!(monitor.prompt(), "A 0602 ");
+        assert_eq!(monitor.execute("STA $0200"), "0602  8D 00 02    STA $0200\n");
+        assert_eq!(monitor.execute("LDA #$300"), "768 doesn't fit in a byte.\n");
+        assert_eq!(monitor.execute("BRK"), "0605  00          BRK\n");
+        assert_eq!(monitor.execute(""), "");
+        assert_eq!(monitor.prompt(), "(C:$0600) ");
+        assert_eq!(monitor.execute("d 0600 0605"), "0600  A9 01       LDA #$01\n0602  8D 00 02    STA $0200\n0605  00          BRK\n");
+        //d without an address goes on from the last listing, and starts at PC once the registers change.
+        assert_eq!(monitor.execute("d").lines().next(), Some("0606  00          BRK"));
+        monitor.execute("r pc=0602");
+        assert_eq!(monitor.execute("d").lines().next(), Some("0602  8D 00 02    STA $0200"));
+        assert_eq!(monitor.execute("d").lines().count(), 16);
+    }
+
+    #[test]
+    fn monitor_test_registers() {
+        let mut monitor = monitor();
+
+        assert_eq!(monitor.execute("r"), "PC:0600 A:00 X:00 Y:00 SP:FF P:nv-bdizc\n");
+        assert_eq!(monitor.execute("r a=12 x=$34 y=56 sp=fd c=1 n=1"), "PC:0600 A:12 X:34 Y:56 SP:FD P:Nv-bdizC\n");
+        assert_eq!(monitor.execute("r pc=0700 p=0"), "PC:0700 A:12 X:34 Y:56 SP:FD P:nv-bdizc\n");
+        assert_eq!(monitor.execute("r a=100"), "a is a byte, $100 doesn't fit.\n");
+        assert_eq!(monitor.execute("r c=2"), "c is a flag, set it to 0 or 1.\n");
+        assert_eq!(monitor.execute("r q=1"), "Unknown register q.\n");
+    }
+
+    #[test]
+    fn monitor_test_65816_registers() {
+        let mut monitor = Monitor::new(CPU::with_variant(Variant::Wdc65816));
+
+        //Emulation mode keeps everything 8-bit.
+        assert_eq!(monitor.execute("r a=1234"), "a is a byte, $1234 doesn't fit.\n");
+        assert_eq!(monitor.execute("r sp=1ff"), "sp is a byte, $1FF doesn't fit.\n");
+        assert_eq!(monitor.execute("r pbr=01 dbr=7e d=2000"), "PC:010000 A:00 X:00 Y:00 SP:1FF P:nv-bdizc DBR:7E D:2000\n");
+
+        monitor.cpu.flags.emulation = false;
+        assert_eq!(monitor.execute("r p=0 a=1234 x=5678 y=9abc sp=1fe"), "PC:010000 A:1234 X:5678 Y:9ABC SP:01FE P:nv-bdizc DBR:7E D:2000\n");
+        assert_eq!(monitor.execute("r p=10 x=1234"), "x is a byte, $1234 doesn't fit.\n");
+        assert_eq!(monitor.cpu.get_x(), 0x0078);
+        assert_eq!(monitor.execute("r d=10000"), "d is a word, $10000 doesn't fit.\n");
+        assert_eq!(monitor.execute("r dbr=100"), "dbr is a byte, $100 doesn't fit.\n");
+    }
+
+    #[test]
+    fn monitor_test_step_and_go() {
+        let mut monitor = monitor();
+        run(&mut monitor, &["a 0600 JSR $0610", "LDX #$02", "BRK", "", "a 0610 INX", "RTS", ""]);
+
+        let step = monitor.execute("z");
+        assert!(step.starts_with("0600  20 10 06  JSR $0610"));
+        assert!(step.ends_with("PC:0610 A:00 X:00 Y:00 SP:FD P:nv-bdizc\n"));
+        assert_eq!(monitor.execute("z 2").lines().count(), 3);
+        assert_eq!(monitor.cpu.registers.pc, 0x0603);
+
+        //n runs the whole subroutine.
+        monitor.execute("r pc=0600 sp=ff x=0");
+        assert_eq!(monitor.execute("n"), "PC:0603 A:00 X:01 Y:00 SP:FF P:nv-bdizc\n");
+
+        monitor.execute("b 0610");
+        assert_eq!(monitor.execute("b"), "$0610\n");
+        assert!(monitor.execute("g 0600").starts_with("Breakpoint at $0610.\n"));
+        monitor.execute("bc 0610");
+        assert_eq!(monitor.execute("b"), "");
+        assert_eq!(monitor.execute("bc 0610"), "There is no breakpoint at $610.\n");
+        //Going on from inside the subroutine stops when it returns to code that called it before the run.
+        assert!(monitor.execute("g").starts_with("Returned from the top frame at $0611.\n"));
+        assert!(monitor.execute("g").starts_with("BRK at $0605.\n"));
+        assert_eq!(monitor.cpu.registers.xr, 2);
+    }
+
+    #[test]
+    fn monitor_test_step_limit() {
+        let mut monitor = monitor();
+        run(&mut monitor, &["a 0600 loop: JMP loop", ""]);
+
+        let step = monitor.execute("z ffffffff");
+        assert_eq!(step.lines().count(), 10_002);
+        assert!(step.ends_with("Stopped after 10000 steps, z takes a count of at most $2710.\nPC:0600 A:00 X:00 Y:00 SP:FF P:nv-bdizc\n"));
+        assert_eq!(monitor.cpu.cycles, 30_000);
+    }
+
+    #[test]
+    fn monitor_test_go_stops_on_a_jump_to_itself() {
+        let mut monitor = monitor();
+        run(&mut monitor, &["a 0600 loop: JMP loop", ""]);
+
+        assert_eq!(monitor.execute("g"), "Stopped at $0600, it jumps to itself.\nPC:0600 A:00 X:00 Y:00 SP:FF P:nv-bdizc\n");
+    }
+
+    #[test]
+    fn monitor_test_jammed_cpu() {
+        let mut monitor = monitor();
+        run(&mut monitor, &["a 0600 JAM", "", "a 0700 LDA #$10", "JAM", ""]);
+
+        //The address of the stop and PC agree, and the CPU stays put until PC is set.
+        assert_eq!(monitor.execute("g"), "The CPU halted at $0600.\nPC:0600 A:00 X:00 Y:00 SP:FF P:nv-bdizc\n");
+        assert_eq!(monitor.execute("g"), "The CPU is jammed at $0600, reset or set PC to go on.\n");
+        assert_eq!(monitor.execute("z"), "The CPU is jammed at $0600, reset or set PC to go on.\n");
+        assert_eq!(monitor.execute("g 0700"), "The CPU halted at $0702.\nPC:0702 A:10 X:00 Y:00 SP:FF P:nv-bdizc\n");
+
+        monitor.execute("r pc=0700 a=0");
+        let step = monitor.execute("z 5");
+        assert_eq!(step.lines().count(), 4);
+        assert!(step.ends_with("The CPU halted at $0702.\nPC:0702 A:10 X:00 Y:00 SP:FF P:nv-bdizc\n"));
+    }
+
+    #[test]
+    fn monitor_test_fill_transfer_compare_hunt() {
+        let mut monitor = monitor();
+
+        monitor.execute("f 0300 0307 aa bb");
+        assert_eq!(monitor.execute("m 0300 0307"), "0300  AA BB AA BB AA BB AA BB                          ........\n");
+        monitor.execute("t 0300 0307 0400");
+        assert_eq!(monitor.execute("c 0300 0307 0400"), "The ranges are the same.\n");
+        monitor.execute("> 0402 00");
+        assert_eq!(monitor.execute("c 0300 0307 0400"), "0302 AA  0402 00\n");
+
+        monitor.execute("> 0500 48 45 4c 4c 4f 00 48 45");
+        assert_eq!(monitor.execute("h 0500 05ff \"HE\""), "0500 0506\n");
+        assert_eq!(monitor.execute("h 0500 05ff 4c 4f"), "0503\n");
+        assert_eq!(monitor.execute("h 0500 0506 48 45"), "0500\n");
+        assert_eq!(monitor.execute("h 0500 05ff ee"), "Not found.\n");
+    }
+
+    #[test]
+    fn monitor_test_overlapping_transfer() {
+        let mut monitor = monitor();
+
+        monitor.execute("> 0300 01 02 03 04");
+        monitor.execute("t 0300 0303 0301");
+        assert_eq!(monitor.execute("m 0300 0304"), "0300  01 01 02 03 04                                   .....\n");
+    }
+
+    #[test]
+    fn monitor_test_history() {
+        let mut monitor = monitor();
+
+        assert_eq!(monitor.execute("!!"), "There is no command to repeat.\n");
+        monitor.execute("r a=1");
+        monitor.execute("m 0200 0201");
+        assert_eq!(monitor.execute("!1"), "PC:0600 A:01 X:00 Y:00 SP:FF P:nv-bdizc\n");
+        assert_eq!(monitor.execute("!!"), "PC:0600 A:01 X:00 Y:00 SP:FF P:nv-bdizc\n");
+        assert_eq!(monitor.execute("!9"), "There is no command 9 in the history.\n");
+        assert_eq!(monitor.history(), ["r a=1", "m 0200 0201", "r a=1", "r a=1"]);
+        assert_eq!(monitor.execute("history"), "   1  r a=1\n   2  m 0200 0201\n   3  r a=1\n   4  r a=1\n");
+    }
+
+    #[test]
+    fn monitor_test_exit_and_unknown_commands() {
+        let mut monitor = monitor();
+
+        assert_eq!(monitor.execute("foo"), "Unknown command foo, ? lists the commands.\n");
+        assert!(monitor.execute("?").contains("hunt"));
+        assert!(!monitor.is_finished());
+        monitor.execute("x");
+        assert!(monitor.is_finished());
+    }
+}
@@ -0,0 +1,204 @@
+#[cfg(test)]
+mod preprocessor_test {
+    use std::fs;
+    use std::path::PathBuf;
+    use std::process;
+    use crate::assembler::assembler::{assemble, Assembler};
+
+    fn bytes(source: &str) -> Vec<u8> {
+        assemble(source).unwrap().image
+    }
+
+    fn error(source: &str) -> (usize, usize, String) {
+        let error = assemble(source).unwrap_err();
+        (error.line, error.column, error.message)
+    }
+
+    //A directory of its own for each test, so tests running in parallel don't share files.
+    fn directory(test: &str) -> PathBuf {
+        let directory = std::env::temp_dir().join(format!("emulator_{}_{}", test, process::id()));
+        fs::create_dir_all(&directory).unwrap();
+        directory
+    }
+
+    #[test]
+    fn preprocessor_test_macro_with_parameters() {
+        let source = "
+            .macro add16 address, value
+                CLC
+                LDA address
+                ADC #<value
+                STA address
+                LDA address+1
+                ADC #>value
+                STA address+1
+            .endmacro
+            add16 $10, $1234
+        ";
+
+        assert_eq!(bytes(source), vec![0x18, 0xA5, 0x10, 0x69, 0x34, 0x85, 0x10, 0xA5, 0x11, 0x69, 0x12, 0x85, 0x11]);
+    }
+
+    #[test]
+    fn preprocessor_test_macro_arguments_keep_brackets() {
+        let source = "
+            .macro load operand
+                LDA operand
+            .endm
+            load ($20,X)
+            load #1 ; a comment
+        ";
+
+        assert_eq!(bytes(source), vec![0xA1, 0x20, 0xA9, 0x01]);
+    }
+
+    #[test]
+    fn preprocessor_test_macro_local_labels() {
+        //Each expansion gets its own @wait, and @wait after the macro calls still belongs to start.
+        let source = "
+            .macro delay count
+                LDX #count
+            @wait:
+                DEX
+                BNE @wait
+            .endmacro
+            start:
+                delay 2
+                delay 3
+            @wait:
+                JMP @wait
+        ";
+        let assembly = assemble(source).unwrap();
+
+        assert_eq!(assembly.image, vec![0xA2, 0x02, 0xCA, 0xD0, 0xFD, 0xA2, 0x03, 0xCA, 0xD0, 0xFD, 0x4C, 0x0A, 0x00]);
+        assert_eq!(assembly.symbol("start@wait"), Some(0x0A));
+        assert_eq!(assembly.symbols.len(), 2);
+    }
+
+    #[test]
+    fn preprocessor_test_macro_errors() {
+        assert_eq!(error(".macro m a, b\n.endmacro\nm 1"), (3, 1, "m takes 2 arguments, not 1.".to_string()));
+        assert_eq!(error(".macro m\nNOP"), (1, 1, ".macro without .endmacro.".to_string()));
+        assert_eq!(error(".endmacro"), (1, 1, ".endmacro without a block to end.".to_string()));
+        assert_eq!(error(".macro m\n.endm\n.macro m\n.endm"), (3, 8, "Macro m is already defined.".to_string()));
+        assert_eq!(error(".macro m\nm\n.endm\nm"), (2, 1, "Macros, repeats and includes nest too deep.".to_string()));
+        //Errors in an expansion point at the line in the macro.
+        assert_eq!(error(".macro m\nLDA missing\n.endm\nm"), (2, 5, "missing isn't defined.".to_string()));
+    }
+
+    #[test]
+    fn preprocessor_test_conditionals() {
+        let source = "
+            DEBUG = 1
+            LEVEL = 2
+            .if DEBUG
+                .byte 1
+            .else
+                .byte 2
+            .endif
+            .if LEVEL == 1
+                .byte 3
+            .elseif LEVEL == 2
+                .byte 4
+                .if 0
+                    .byte 5
+                .else
+                    .byte 6
+                .endif
+            .else
+                .byte 7
+            .endif
+            .ifdef DEBUG
+                .byte 8
+            .endif
+            .ifndef RELEASE
+                .byte 9
+            .endif
+            .if 0
+                this isn't assembled \"
+            .endif
+        ";
+
+        assert_eq!(bytes(source), vec![1, 4, 6, 8, 9]);
+    }
+
+    #[test]
+    fn preprocessor_test_conditional_errors() {
+        assert_eq!(error("NOP\n  .if 1\nNOP"), (2, 3, ".if without .endif.".to_string()));
+        assert_eq!(error(".else"), (1, 1, ".else without .if.".to_string()));
+        assert_eq!(error(".endif"), (1, 1, ".endif without .if.".to_string()));
+        assert_eq!(error(".if 1\n.else\n.else\n.endif"), (3, 1, ".else after .else.".to_string()));
+        assert_eq!(error(".if later\n.endif\nlater:"), (1, 5, "later has to be a constant defined before this line.".to_string()));
+    }
+
+    #[test]
+    fn preprocessor_test_repeat() {
+        let source = "
+            COUNT = 3
+            .repeat COUNT, i
+                .byte i * 2
+            .endrepeat
+            .rept 2
+                :
+                DEX
+                BNE :-
+            .endr
+        ";
+
+        assert_eq!(bytes(source), vec![0, 2, 4, 0xCA, 0xD0, 0xFD, 0xCA, 0xD0, 0xFD]);
+    }
+
+    #[test]
+    fn preprocessor_test_nested_repeat() {
+        let source = "
+            .repeat 2, row
+                .repeat 3, column
+                    .byte row * 10 + column
+                .endrepeat
+            .endrepeat
+        ";
+
+        assert_eq!(bytes(source), vec![0, 1, 2, 10, 11, 12]);
+    }
+
+    #[test]
+    fn preprocessor_test_repeat_limits() {
+        assert_eq!(error(".repeat $7FFFFFFF\nNOP\n.endrepeat"), (1, 1, "The repeat count can't be more than 1000000.".to_string()));
+        assert_eq!(error(".repeat -1\n.endrepeat"), (1, 1, "The repeat count can't be negative.".to_string()));
+        //Each count is allowed on its own, but together they expand too far.
+        assert_eq!(error(".repeat 1000\n.repeat 1000\n.endrepeat\n.endrepeat"), (2, 1, "Macros, repeats and includes expand to more than 1000000 lines.".to_string()));
+        assert_eq!(error(".macro m\n.repeat 1000\nNOP\n.endrepeat\n.endm\n.repeat 1000\nm\n.endrepeat").2, "Macros, repeats and includes expand to more than 1000000 lines.");
+    }
+
+    #[test]
+    fn preprocessor_test_include_and_incbin() {
+        let directory = directory("include");
+        fs::create_dir_all(directory.join("lib")).unwrap();
+        fs::write(directory.join("main.s"), ".org $0600\n.include \"lib/constants.s\"\nLDA #VALUE\n.incbin \"data.bin\"\n.incbin \"data.bin\", 1, 2\n").unwrap();
+        fs::write(directory.join("lib/constants.s"), "VALUE = 7\n.include \"more.s\"\n").unwrap();
+        fs::write(directory.join("lib/more.s"), "NOP\n").unwrap();
+        fs::write(directory.join("data.bin"), [1, 2, 3, 4]).unwrap();
+
+        let assembly = Assembler::new().assemble_file(directory.join("main.s")).unwrap();
+
+        assert_eq!(assembly.origin, 0x0600);
+        assert_eq!(assembly.image, vec![0xEA, 0xA9, 0x07, 1, 2, 3, 4, 2, 3]);
+        fs::remove_dir_all(directory).unwrap();
+    }
+
+    #[test]
+    fn preprocessor_test_errors_name_the_included_file() {
+        let directory = directory("include_error");
+        fs::write(directory.join("main.s"), "NOP\n.include \"other.s\"\n").unwrap();
+        fs::write(directory.join("other.s"), "NOP\n  LDA #$100\n").unwrap();
+
+        let error = Assembler::new().assemble_file(directory.join("main.s")).unwrap_err();
+        let missing = assemble(".include \"no such file.s\"").unwrap_err();
+
+        assert_eq!(error.file, Some(directory.join("other.s")));
+        assert_eq!(error.to_string(), format!("{}:2:3: 256 doesn't fit in a byte.", directory.join("other.s").display()));
+        assert_eq!((missing.line, missing.column), (1, 1));
+        assert!(missing.message.starts_with("Can't read no such file.s"));
+        fs::remove_dir_all(directory).unwrap();
+    }
+}
@@ -0,0 +1,104 @@
+#[cfg(test)]
+mod reset_test {
+    use crate::cpu::cpu::CPU;
+
+    fn set_reset_vector(cpu: &mut CPU, address: u16) {
+        cpu.memory[0xFFFC] = address as u8;
+        cpu.memory[0xFFFD] = (address >> 8) as u8;
+    }
+
+    #[test]
+    fn reset_test_power_on() {
+        let mut cpu = CPU::new();
+        set_reset_vector(&mut cpu, 0xE000);
+        cpu.memory[0x0200] = 0x12;
+        cpu.registers.acc = 0x34;
+
+        cpu.power_on();
+
+        assert_eq!(cpu.registers.sp, 0xFD);
+        assert_eq!(cpu.registers.pc, 0xE000);
+        assert_eq!(cpu.registers.acc, 0x00);
+        assert_eq!(cpu.memory[0x0200], 0x12); //Loaded memory survives.
+        assert_eq!(cpu.flags.interrupt, true);
+        assert_eq!(cpu.cycles, 7);
+    }
+
+    #[test]
+    fn reset_test_warm_reset() {
+        let mut cpu = CPU::new();
+        set_reset_vector(&mut cpu, 0xE000);
+        cpu.memory[0x0200] = 0x12;
+        cpu.memory[0x01FF] = 0x56;
+        cpu.registers.acc = 0x34;
+        cpu.registers.sp = 0xFF;
+
+        cpu.reset();
+
+        assert_eq!(cpu.registers.pc, 0xE000);
+        assert_eq!(cpu.registers.sp, 0xFC);
+        assert_eq!(cpu.registers.acc, 0x34);
+        assert_eq!(cpu.memory[0x0200], 0x12);
+        assert_eq!(cpu.memory[0x01FF], 0x56); //Pushes are suppressed.
+        assert_eq!(cpu.flags.interrupt, true);
+        assert_eq!(cpu.cycles, 7);
+    }
+
+    #[test]
+    fn reset_test_boot_rom() {
+        let mut cpu = CPU::new();
+        let rom = [
+            0xA2, 0x03, //LDX #$03
+            0xE8, //INX
+            0x4C, 0x02, 0xE0 //JMP $E002
+        ];
+
+        cpu.load_program(&rom, 0xE000);
+        set_reset_vector(&mut cpu, 0xE000);
+        cpu.power_on();
+
+        assert_eq!(cpu.registers.pc, 0xE000);
+        assert_eq!(cpu.registers.sp, 0xFD);
+
+        cpu.execute_instructions(5);
+
+        assert_eq!(cpu.registers.xr, 0x05);
+        assert_eq!(cpu.registers.pc, 0xE002);
+    }
+
+    #[test]
+    fn reset_test_reset_line() {
+        let mut cpu = CPU::new();
+        set_reset_vector(&mut cpu, 0xE000);
+        cpu.memory[0x0000] = 0xE8; //INX
+        cpu.memory[0xE000] = 0xC8; //INY
+
+        cpu.set_reset_line(true);
+        cpu.step().unwrap();
+        cpu.step().unwrap();
+
+        assert_eq!(cpu.registers.xr, 0x00);
+        assert_eq!(cpu.registers.pc, 0x0000);
+
+        cpu.set_reset_line(false);
+        cpu.step().unwrap();
+        assert_eq!(cpu.registers.pc, 0xE000);
+
+        cpu.step().unwrap();
+        assert_eq!(cpu.registers.yr, 0x01);
+    }
+
+    #[test]
+    fn reset_test_clears_pending_nmi() {
+        let mut cpu = CPU::new();
+        set_reset_vector(&mut cpu, 0xE000);
+        cpu.memory[0xE000] = 0xEA;
+        cpu.memory[0xE001] = 0xEA;
+
+        cpu.set_nmi_line(true);
+        cpu.reset();
+        cpu.execute_instructions(2);
+
+        assert_eq!(cpu.registers.pc, 0xE002);
+    }
+}
@@ -0,0 +1,135 @@
+#[cfg(test)]
+mod rewind_test {
+    use crate::cpu::cpu::CPU;
+    use crate::cpu::rewind::RewindError;
+
+    //Counts up in $10 and X, and stores X to $11 every time X wraps to 0.
+    const COUNTER: [u8; 12] = [
+        0xE6, 0x10,       //INC $10
+        0xE8,             //INX
+        0xD0, 0x02,       //BNE +2
+        0x86, 0x11,       //STX $11
+        0xEA,             //NOP
+        0x4C, 0x00, 0x02, //JMP $0200
+        0x00
+    ];
+
+    fn rewind_cpu(interval: u64, snapshots: usize) -> CPU {
+        let mut cpu = CPU::new();
+        cpu.load_program(&COUNTER, 0x0200);
+        cpu.registers.pc = 0x0200;
+        cpu.enable_rewind(interval, snapshots);
+        cpu
+    }
+
+    #[test]
+    fn rewind_test_step_back() {
+        let mut cpu = rewind_cpu(16, 8);
+        cpu.run_instructions(30).unwrap();
+        let (pc, counter, cycles) = (cpu.registers.pc, cpu.memory[0x10], cpu.cycles);
+        cpu.run_instructions(7).unwrap();
+
+        cpu.step_back(7).unwrap();
+
+        assert_eq!(cpu.registers.pc, pc);
+        assert_eq!(cpu.memory[0x10], counter);
+        assert_eq!(cpu.cycles, cycles);
+        assert_eq!(cpu.rewind().unwrap().available_steps(), 30);
+    }
+
+    #[test]
+    fn rewind_test_step_back_then_forward() {
+        let mut cpu = rewind_cpu(4, 8);
+        cpu.run_instructions(20).unwrap();
+        let state = cpu.save_state();
+
+        cpu.step_back(13).unwrap();
+        cpu.run_instructions(13).unwrap();
+
+        assert_eq!(cpu.save_state(), state);
+    }
+
+    #[test]
+    fn rewind_test_step_back_cycles() {
+        let mut cpu = rewind_cpu(16, 8);
+        cpu.run_instructions(10).unwrap();
+        let (pc, cycles) = (cpu.registers.pc, cpu.cycles);
+        cpu.run_instructions(1).unwrap();
+        let instruction_cycles = cpu.cycles - cycles;
+        cpu.run_instructions(5).unwrap();
+
+        //Any cycle of an instruction goes back to its start.
+        let elapsed = cpu.cycles - cycles;
+        cpu.step_back_cycles(elapsed - (instruction_cycles - 1)).unwrap();
+
+        assert_eq!(cpu.registers.pc, pc);
+        assert_eq!(cpu.cycles, cycles);
+    }
+
+    #[test]
+    fn rewind_test_out_of_history() {
+        let mut cpu = rewind_cpu(4, 2);
+        cpu.run_instructions(20).unwrap();
+        let pc = cpu.registers.pc;
+
+        //Only the last two snapshots are kept, the history starts at step 12.
+        assert_eq!(cpu.rewind().unwrap().available_steps(), 8);
+        assert!(matches!(cpu.step_back(9), Err(RewindError::OutOfHistory { available: 8 })));
+        assert_eq!(cpu.registers.pc, pc);
+        cpu.step_back(8).unwrap();
+    }
+
+    #[test]
+    fn rewind_test_disabled() {
+        let mut cpu = CPU::new();
+
+        assert!(matches!(cpu.step_back(1), Err(RewindError::Disabled)));
+        assert!(matches!(cpu.reverse_continue_to_write(0x10), Err(RewindError::Disabled)));
+    }
+
+    #[test]
+    fn rewind_test_journal() {
+        let mut cpu = rewind_cpu(64, 4);
+        cpu.run_instructions(6).unwrap();
+
+        let writes: Vec<_> = cpu.rewind().unwrap().journal().map(|record| (record.address, record.old_value, record.new_value)).collect();
+        assert_eq!(writes, vec![(0x10, 0x00, 0x00), (0x10, 0x00, 0x01), (0x10, 0x01, 0x01), (0x10, 0x01, 0x02)]);
+    }
+
+    #[test]
+    fn rewind_test_reverse_continue_to_write() {
+        let mut cpu = rewind_cpu(32, 64);
+        //X wraps on the 256th loop, which takes one more step for the STX $11. Then run five more loops.
+        cpu.run_instructions(256 * 5 + 1 + 5 * 5).unwrap();
+        assert_eq!(cpu.memory[0x10], 0x05);
+        assert_eq!(cpu.registers.pc, 0x0200);
+
+        let record = cpu.reverse_continue_to_write(0x11).unwrap().unwrap();
+
+        assert_eq!(cpu.registers.pc, 0x0205);
+        assert_eq!(record.old_value, 0x00);
+        assert_eq!(record.new_value, 0x00);
+        assert_eq!(cpu.memory[0x10], 0x00);
+        assert_eq!(cpu.reverse_continue_to_write(0x11).unwrap(), None);
+        assert_eq!(cpu.registers.pc, 0x0205);
+
+        //Stepping forward makes the write again.
+        cpu.step().unwrap();
+        assert_eq!(cpu.rewind().unwrap().journal().last().unwrap().address, 0x11);
+    }
+
+    #[test]
+    fn rewind_test_stack_writes() {
+        let mut cpu = CPU::new();
+        cpu.load_program(&[0xA9, 0x42, 0x48, 0xA9, 0x43, 0x48, 0xEA], 0x0200); //LDA #$42, PHA, LDA #$43, PHA, NOP
+        cpu.registers.pc = 0x0200;
+        cpu.enable_rewind(8, 4);
+        cpu.run_instructions(5).unwrap();
+
+        let record = cpu.reverse_continue_to_write(0x01FF).unwrap().unwrap();
+
+        assert_eq!(record.new_value, 0x42);
+        assert_eq!(cpu.registers.pc, 0x0202);
+        assert_eq!(cpu.registers.sp, 0xFF);
+    }
+}
@@ -0,0 +1,124 @@
+#[cfg(test)]
+mod run_test {
+    use crate::cpu::cpu::{Variant, CPU};
+    use crate::cpu::run::StopReason;
+
+    fn run_cpu(bytes: &[u8]) -> CPU {
+        let mut cpu = CPU::new();
+        cpu.load_program(bytes, 0x0200);
+        cpu.registers.pc = 0x0200;
+        cpu
+    }
+
+    #[test]
+    fn run_test_cycle_budget() {
+        let mut cpu = run_cpu(&[0xEA; 16]);
+
+        assert_eq!(cpu.run_cycles(5), Ok(StopReason::CycleBudget));
+        assert_eq!(cpu.cycles, 6);
+        assert_eq!(cpu.registers.pc, 0x0203);
+    }
+
+    #[test]
+    fn run_test_cycle_budget_with_reset_held() {
+        let mut cpu = run_cpu(&[0xE8; 16]);
+        cpu.memory[0xFFFC] = 0x00;
+        cpu.memory[0xFFFD] = 0x02;
+
+        cpu.set_reset_line(true);
+        assert_eq!(cpu.run_cycles(100), Ok(StopReason::CycleBudget));
+        assert_eq!(cpu.cycles, 100);
+        assert_eq!(cpu.registers.xr, 0);
+
+        cpu.set_reset_line(false);
+        cpu.run_instructions(2).unwrap();
+        assert_eq!(cpu.registers.pc, 0x0201);
+        assert_eq!(cpu.registers.xr, 1);
+    }
+
+    #[test]
+    fn run_test_instruction_budget() {
+        let mut cpu = run_cpu(&[0xE8; 16]);
+
+        assert_eq!(cpu.run_instructions(4), Ok(StopReason::InstructionBudget));
+        assert_eq!(cpu.registers.xr, 4);
+    }
+
+    #[test]
+    fn run_test_condition() {
+        let mut cpu = run_cpu(&[0xE8; 16]);
+
+        assert_eq!(cpu.run_until(|cpu| cpu.registers.xr == 3), Ok(StopReason::Condition));
+        assert_eq!(cpu.registers.pc, 0x0203);
+        assert_eq!(cpu.run_until(|cpu| cpu.registers.xr == 3), Ok(StopReason::Condition));
+        assert_eq!(cpu.registers.pc, 0x0203);
+    }
+
+    #[test]
+    fn run_test_brk() {
+        let mut cpu = run_cpu(&[0xE8, 0x00, 0x00]);
+        cpu.set_interrupt_vector(0x90, 0x00);
+
+        assert_eq!(cpu.run_instructions(10), Ok(StopReason::Brk { pc: 0x0201 }));
+        assert_eq!(cpu.registers.pc, 0x9000);
+    }
+
+    #[test]
+    fn run_test_jam() {
+        let mut cpu = run_cpu(&[0xE8, 0x02, 0xE8]);
+
+        //Stopping again reports the same address, PC stays on the JAM.
+        assert_eq!(cpu.run_instructions(10), Ok(StopReason::Jammed { pc: 0x0201 }));
+        assert_eq!(cpu.run_instructions(10), Ok(StopReason::Jammed { pc: 0x0201 }));
+        assert_eq!(cpu.registers.pc, 0x0201);
+        assert_eq!(cpu.registers.xr, 1);
+    }
+
+    #[test]
+    fn run_test_stp() {
+        let mut cpu = CPU::with_variant(Variant::Wdc65C02);
+        cpu.load_program(&[0xDB], 0x0200);
+        cpu.registers.pc = 0x0200;
+
+        assert_eq!(cpu.run_instructions(10), Ok(StopReason::Jammed { pc: 0x0200 }));
+    }
+
+    #[test]
+    fn run_test_breakpoint() {
+        let mut cpu = run_cpu(&[0xE8; 16]);
+        cpu.add_breakpoint(0x0202);
+
+        assert_eq!(cpu.run_instructions(10), Ok(StopReason::Breakpoint { pc: 0x0202 }));
+        assert_eq!(cpu.registers.xr, 2);
+
+        //Resuming from the breakpoint executes the instruction there.
+        assert_eq!(cpu.run_instructions(1), Ok(StopReason::InstructionBudget));
+        assert_eq!(cpu.registers.xr, 3);
+
+        cpu.remove_breakpoint(0x0202);
+        cpu.registers.pc = 0x0200;
+        assert_eq!(cpu.run_instructions(4), Ok(StopReason::InstructionBudget));
+    }
+
+    #[test]
+    fn run_test_self_jump() {
+        let mut cpu = run_cpu(&[0xE8, 0x4C, 0x01, 0x02]);
+
+        assert_eq!(cpu.run_instructions(10), Ok(StopReason::SelfJump { pc: 0x0201 }));
+        assert_eq!(cpu.registers.xr, 1);
+    }
+
+    #[test]
+    fn run_test_return_from_top_frame() {
+        let mut cpu = run_cpu(&[
+            0x20, 0x04, 0x02, //JSR $0204
+            0x60,             //RTS
+            0xE8,             //INX
+            0x60              //RTS
+        ]);
+
+        //The nested RTS stays inside the frame the run started in.
+        assert_eq!(cpu.run_instructions(10), Ok(StopReason::ReturnFromTopFrame { pc: 0x0203 }));
+        assert_eq!(cpu.registers.xr, 1);
+    }
+}
@@ -0,0 +1,284 @@
+#[cfg(test)]
+mod save_state_test {
+    use crate::cpu::bus::{Bus, Ram};
+    use crate::cpu::cpu::{CPU, Variant};
+    use crate::cpu::devices::{Device, DeviceBus};
+    use crate::cpu::save_state::{checksum, SaveStateError, StateReader, StateWriter};
+
+    //Counts the writes made to it.
+    struct CountingDevice {
+        writes: u32
+    }
+
+    impl Device for CountingDevice {
+        fn read(&mut self, _offset: u16, _cycles: u32) -> u8 {
+            self.writes as u8
+        }
+
+        fn write(&mut self, _offset: u16, _value: u8, _cycles: u32) {
+            self.writes += 1;
+        }
+
+        fn peek(&self, _offset: u16) -> u8 {
+            self.writes as u8
+        }
+
+        fn save_state(&self, state: &mut StateWriter) {
+            state.write_u32(self.writes);
+        }
+
+        fn load_state(&mut self, state: &mut StateReader) -> Result<(), SaveStateError> {
+            self.writes = state.read_u32()?;
+            Ok(())
+        }
+    }
+
+    //Refuses every state.
+    struct BrokenDevice;
+
+    impl Device for BrokenDevice {
+        fn read(&mut self, _offset: u16, _cycles: u32) -> u8 {
+            0
+        }
+
+        fn write(&mut self, _offset: u16, _value: u8, _cycles: u32) {}
+
+        fn peek(&self, _offset: u16) -> u8 {
+            0
+        }
+
+        fn load_state(&mut self, _state: &mut StateReader) -> Result<(), SaveStateError> {
+            Err(SaveStateError::Invalid("broken".to_string()))
+        }
+    }
+
+    //Counts from 0 upwards in $10 and loops forever.
+    const COUNTER: [u8; 7] = [
+        0xE6, 0x10,       //INC $10
+        0xE8,             //INX
+        0x4C, 0x00, 0x02, //JMP $0200
+        0x00
+    ];
+
+    fn counter_cpu() -> CPU {
+        let mut cpu = CPU::new();
+        cpu.load_program(&COUNTER, 0x0200);
+        cpu.registers.pc = 0x0200;
+        cpu
+    }
+
+    //Rewrites the version and the checksum of a save state.
+    fn with_version(mut state: Vec<u8>, version: u16) -> Vec<u8> {
+        state[4..6].copy_from_slice(&version.to_le_bytes());
+        let length = state.len() - 4;
+        let crc = checksum(&state[..length]);
+        state[length..].copy_from_slice(&crc.to_le_bytes());
+        state
+    }
+
+    #[test]
+    fn save_state_test_round_trip() {
+        let mut cpu = counter_cpu();
+        cpu.run_instructions(25).unwrap();
+        let state = cpu.save_state();
+
+        cpu.run_instructions(40).unwrap();
+        let (pc, xr, counter, cycles) = (cpu.registers.pc, cpu.registers.xr, cpu.memory[0x10], cpu.cycles);
+
+        let mut restored = CPU::new();
+        restored.load_state(&state).unwrap();
+        assert_eq!(restored.save_state(), state);
+
+        restored.run_instructions(40).unwrap();
+        assert_eq!(restored.registers.pc, pc);
+        assert_eq!(restored.registers.xr, xr);
+        assert_eq!(restored.memory[0x10], counter);
+        assert_eq!(restored.cycles, cycles);
+        assert_eq!(restored.flags, cpu.flags);
+    }
+
+    #[test]
+    fn save_state_test_65816() {
+        let mut cpu = CPU::with_bus_and_variant(Ram::with_banks(2), Variant::Wdc65816);
+        cpu.execute_instruction_sequence(&[0x18, 0xFB, 0xC2, 0x30, 0xA9, 0x34, 0x12, 0x8F, 0x00, 0x80, 0x01]);
+        let state = cpu.save_state();
+
+        let mut restored = CPU::with_bus(Ram::with_banks(2));
+        restored.load_state(&state).unwrap();
+
+        assert_eq!(restored.variant, Variant::Wdc65816);
+        assert_eq!(restored.flags.emulation, false);
+        assert_eq!(restored.get_c(), 0x1234);
+        assert_eq!(restored.memory.peek_long(0x018000), 0x34);
+        assert_eq!(restored.memory.peek_long(0x018001), 0x12);
+    }
+
+    #[test]
+    fn save_state_test_interrupt_lines() {
+        let mut cpu = counter_cpu();
+        cpu.set_irq_line(true);
+        cpu.set_nmi_line(true);
+        let state = cpu.save_state();
+
+        let mut restored = counter_cpu();
+        restored.load_state(&state).unwrap();
+        restored.memory.write(0xFFFA, 0x00);
+        restored.memory.write(0xFFFB, 0x90);
+        restored.step().unwrap();
+
+        assert_eq!(restored.registers.pc, 0x9000);
+    }
+
+    #[test]
+    fn save_state_test_devices() {
+        let mut bus = DeviceBus::new();
+        bus.attach(0xD000..=0xD000, 0x00, Box::new(CountingDevice { writes: 0 }));
+        let mut cpu = CPU::with_bus(bus);
+        cpu.execute_instruction_sequence(&[0x8D, 0x00, 0xD0, 0x8D, 0x00, 0xD0, 0x8D, 0x00, 0x30]); //STA $D000 twice, STA $3000
+        let state = cpu.save_state();
+
+        let mut bus = DeviceBus::new();
+        bus.attach(0xD000..=0xD000, 0x00, Box::new(CountingDevice { writes: 0 }));
+        let mut restored = CPU::with_bus(bus);
+        restored.load_state(&state).unwrap();
+
+        assert_eq!(restored.memory.peek(0xD000), 2);
+        assert_eq!(restored.memory.peek(0x0001), 0x00);
+        assert_eq!(restored.memory.peek(0x0000), 0x8D);
+    }
+
+    #[test]
+    fn save_state_test_device_count_mismatch() {
+        let mut bus = DeviceBus::new();
+        bus.attach(0xD000..=0xD000, 0x00, Box::new(CountingDevice { writes: 0 }));
+        let state = CPU::with_bus(bus).save_state();
+
+        let mut restored = CPU::with_bus(DeviceBus::new());
+        assert!(matches!(restored.load_state(&state), Err(SaveStateError::Invalid(_))));
+    }
+
+    //A device refusing its state puts back the memory and the devices that loaded before it.
+    #[test]
+    fn save_state_test_failing_device() {
+        let mut bus = DeviceBus::new();
+        bus.attach(0xD000..=0xD000, 0x00, Box::new(CountingDevice { writes: 0 }));
+        bus.attach(0xD001..=0xD001, 0x00, Box::new(CountingDevice { writes: 0 }));
+        let mut cpu = CPU::with_bus(bus);
+        cpu.execute_instruction_sequence(&[0x8D, 0x00, 0xD0, 0x8D, 0x00, 0x30]); //STA $D000, STA $3000
+        let state = cpu.save_state();
+
+        let mut bus = DeviceBus::new();
+        bus.attach(0xD000..=0xD000, 0x00, Box::new(CountingDevice { writes: 5 }));
+        bus.attach(0xD001..=0xD001, 0x00, Box::new(BrokenDevice));
+        let mut restored = CPU::with_bus(bus);
+        restored.memory.write(0x3000, 0x56);
+
+        assert!(matches!(restored.load_state(&state), Err(SaveStateError::Invalid(_))));
+        assert_eq!(restored.memory.peek(0xD000), 5);
+        assert_eq!(restored.memory.peek(0x3000), 0x56);
+        assert_eq!(restored.memory.peek(0x0000), 0x00);
+        assert_eq!(restored.cycles, 0);
+    }
+
+    //A RAM section of the wrong size is refused, and nothing is restored from the rest of the state.
+    #[test]
+    fn save_state_test_ram_size_mismatch() {
+        let mut cpu = counter_cpu();
+        cpu.run_instructions(10).unwrap();
+        let state = cpu.save_state();
+
+        let mut restored = CPU::with_bus(Ram::with_banks(2));
+        restored.registers.pc = 0x1234;
+        restored.memory.write_long(0x018000, 0x56);
+
+        assert!(matches!(restored.load_state(&state), Err(SaveStateError::Invalid(_))));
+        assert_eq!(restored.registers.pc, 0x1234);
+        assert_eq!(restored.registers.xr, 0x00);
+        assert_eq!(restored.cycles, 0);
+        assert_eq!(restored.memory.peek_long(0x018000), 0x56);
+        assert_eq!(restored.memory[0x10], 0x00);
+    }
+
+    //A short RAM section with a valid checksum is refused instead of shrinking the memory.
+    #[test]
+    fn save_state_test_short_ram() {
+        let mut writer = StateWriter::new();
+        writer.write_bytes(&[0; 16]);
+        let mut ram = Ram::new();
+
+        assert!(matches!(ram.load_state(&mut StateReader::new(&writer.into_bytes())), Err(SaveStateError::Invalid(_))));
+        assert_eq!(ram.len(), 0x10000);
+        assert_eq!(ram.peek(0xFFFF), 0x00);
+    }
+
+    #[test]
+    fn save_state_test_checksum() {
+        let mut state = counter_cpu().save_state();
+        state[20] ^= 0x01;
+
+        assert!(matches!(counter_cpu().load_state(&state), Err(SaveStateError::ChecksumMismatch { .. })));
+    }
+
+    #[test]
+    fn save_state_test_header() {
+        let state = counter_cpu().save_state();
+
+        assert!(matches!(counter_cpu().load_state(&state[..12]), Err(SaveStateError::ChecksumMismatch { .. })));
+        assert!(matches!(counter_cpu().load_state(&state[..4]), Err(SaveStateError::Truncated)));
+        assert!(matches!(counter_cpu().load_state(b"NOPE-not-a-state"), Err(SaveStateError::BadMagic)));
+        assert!(matches!(counter_cpu().load_state(&with_version(state, 2)), Err(SaveStateError::UnsupportedVersion(2))));
+    }
+
+    #[test]
+    fn save_state_test_skips_unknown_sections() {
+        let mut cpu = counter_cpu();
+        cpu.run_instructions(10).unwrap();
+        let mut state = cpu.save_state();
+
+        //A section added by a later version, inserted before the checksum.
+        state.truncate(state.len() - 4);
+        state.extend_from_slice(b"NEW ");
+        state.extend_from_slice(&[3, 0, 0, 0, 1, 2, 3]);
+        state.extend_from_slice(&[0; 4]);
+        let state = with_version(state, 1);
+
+        let mut restored = CPU::new();
+        restored.load_state(&state).unwrap();
+        assert_eq!(restored.registers.pc, cpu.registers.pc);
+        assert_eq!(restored.memory[0x10], cpu.memory[0x10]);
+    }
+
+    #[test]
+    fn save_state_test_skips_unknown_fields() {
+        let mut cpu = counter_cpu();
+        cpu.run_instructions(10).unwrap();
+        let mut state = cpu.save_state();
+
+        //Two bytes added to the end of the CPU section, which comes first.
+        let length = u32::from_le_bytes([state[10], state[11], state[12], state[13]]) as usize;
+        state[10..14].copy_from_slice(&(length as u32 + 2).to_le_bytes());
+        state.splice(14 + length..14 + length, [0xAA, 0xBB]);
+        let state = with_version(state, 1);
+
+        let mut restored = CPU::new();
+        restored.load_state(&state).unwrap();
+        assert_eq!(restored.registers.pc, cpu.registers.pc);
+        assert_eq!(restored.registers.xr, cpu.registers.xr);
+        assert_eq!(restored.memory[0x10], cpu.memory[0x10]);
+    }
+
+    #[test]
+    fn save_state_test_file() {
+        let mut cpu = counter_cpu();
+        cpu.run_instructions(10).unwrap();
+        let path = std::env::temp_dir().join(format!("r6502e_save_state_test_{}.bin", std::process::id()));
+
+        cpu.save_state_to_file(&path).unwrap();
+        let mut restored = CPU::new();
+        let result = restored.load_state_from_file(&path);
+        std::fs::remove_file(&path).unwrap();
+
+        result.unwrap();
+        assert_eq!(restored.save_state(), cpu.save_state());
+    }
+}
@@ -12,7 +12,7 @@ mod sbc_test {
 
         cpu.registers.acc = 0x30;
         cpu.flags.carry = true;
-        cpu.execute_instruction_sequence(&mut bytes.as_slice());
+        cpu.execute_instruction_sequence(&bytes);
 
         assert_eq!(cpu.registers.acc, 0x10);
         assert_eq!(cpu.flags.zero, false);
@@ -28,7 +28,7 @@ mod sbc_test {
 
         cpu.registers.acc = 0x01;
         cpu.flags.carry = true;
-        cpu.execute_instruction_sequence(&mut bytes.as_slice());
+        cpu.execute_instruction_sequence(&bytes);
 
         assert_eq!(cpu.registers.acc, 0x00);
         assert_eq!(cpu.flags.zero, true);
@@ -44,7 +44,7 @@ mod sbc_test {
 
         cpu.registers.acc = 0x01;
         cpu.flags.carry = true;
-        cpu.execute_instruction_sequence(&mut bytes.as_slice());
+        cpu.execute_instruction_sequence(&bytes);
 
         assert_eq!(cpu.registers.acc, 0xFF);
         assert_eq!(cpu.flags.zero, false);
@@ -60,7 +60,7 @@ mod sbc_test {
 
         cpu.registers.acc = 0x30;
         cpu.flags.carry = false;
-        cpu.execute_instruction_sequence(&mut bytes.as_slice());
+        cpu.execute_instruction_sequence(&bytes);
 
         assert_eq!(cpu.registers.acc, 0x0F);
         assert_eq!(cpu.flags.zero, false);
@@ -78,7 +78,7 @@ mod sbc_test {
 
         cpu.registers.acc = 0x30;
         cpu.flags.carry = true;
-        cpu.execute_instruction_sequence(&mut bytes.as_slice());
+        cpu.execute_instruction_sequence(&bytes);
 
         assert_eq!(cpu.registers.acc, 0x10);
     }
@@ -95,7 +95,7 @@ mod sbc_test {
         cpu.registers.xr = 3;
         cpu.registers.acc = 0x30;
         cpu.flags.carry = true;
-        cpu.execute_instruction_sequence(&mut bytes.as_slice());
+        cpu.execute_instruction_sequence(&bytes);
 
         assert_eq!(cpu.registers.acc, 0x10);
     }
@@ -113,7 +113,7 @@ mod sbc_test {
         cpu.registers.yr = 3;
         cpu.registers.acc = 0x30;
         cpu.flags.carry = true;
-        cpu.execute_instruction_sequence(&mut bytes.as_slice());
+        cpu.execute_instruction_sequence(&bytes);
 
         assert_eq!(cpu.registers.acc, 0x10);
     }
@@ -128,7 +128,7 @@ mod sbc_test {
 
         cpu.registers.acc = 0x30;
         cpu.flags.carry = true;
-        cpu.execute_instruction_sequence(&mut bytes.as_slice());
+        cpu.execute_instruction_sequence(&bytes);
 
         assert_eq!(cpu.registers.acc, 0x10);
     }
@@ -144,7 +144,7 @@ mod sbc_test {
         cpu.registers.xr = 3;
         cpu.registers.acc = 0x30;
         cpu.flags.carry = true;
-        cpu.execute_instruction_sequence(&mut bytes.as_slice());
+        cpu.execute_instruction_sequence(&bytes);
 
         assert_eq!(cpu.registers.acc, 0x10);
     }
@@ -163,7 +163,7 @@ mod sbc_test {
         cpu.memory[0x21] = 0x40;
         cpu.memory[0x4030] = 0x20;
         cpu.flags.carry = true;
-        cpu.execute_instruction_sequence(&mut bytes.as_slice());
+        cpu.execute_instruction_sequence(&bytes);
 
         assert_eq!(cpu.registers.acc, 0x10);
     }
@@ -182,7 +182,7 @@ mod sbc_test {
         cpu.memory[0x22] = 0x30;
         cpu.memory[0x23] = 0x40;
         cpu.flags.carry = true;
-        cpu.execute_instruction_sequence(&mut bytes.as_slice());
+        cpu.execute_instruction_sequence(&bytes);
 
         assert_eq!(cpu.registers.acc, 0x10);
     }
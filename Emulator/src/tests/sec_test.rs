@@ -10,7 +10,7 @@ mod sec_test {
         ];
 
         cpu.flags.carry = false;
-        cpu.execute_instruction_sequence(&mut bytes.as_slice());
+        cpu.execute_instruction_sequence(&bytes);
 
         assert_eq!(cpu.flags.carry, true);
     }
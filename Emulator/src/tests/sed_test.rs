@@ -10,7 +10,7 @@ mod sed_test {
         ];
 
         cpu.flags.decimal = false;
-        cpu.execute_instruction_sequence(&mut bytes.as_slice());
+        cpu.execute_instruction_sequence(&bytes);
 
         assert_eq!(cpu.flags.decimal, true);
     }
@@ -10,7 +10,7 @@ mod sei_test {
         ];
 
         cpu.flags.interrupt = false;
-        cpu.execute_instruction_sequence(&mut bytes.as_slice());
+        cpu.execute_instruction_sequence(&bytes);
 
         assert_eq!(cpu.flags.interrupt, true);
     }
@@ -13,7 +13,7 @@ mod sta_test {
         ];
 
         cpu.registers.acc = 0x20;
-        cpu.execute_instruction_sequence(&mut bytes.as_slice());
+        cpu.execute_instruction_sequence(&bytes);
 
         assert_eq!(cpu.memory[0x2233], 0x20);
         assert_eq!(cpu.registers.acc, 0x00);
@@ -31,7 +31,7 @@ mod sta_test {
         //X + ACC
         cpu.registers.xr = 3;
         cpu.registers.acc = 0x20;
-        cpu.execute_instruction_sequence(&mut bytes.as_slice());
+        cpu.execute_instruction_sequence(&bytes);
 
         assert_eq!(cpu.memory[0x2233], 0x20);
         assert_eq!(cpu.registers.acc, 0x00);
@@ -49,7 +49,7 @@ mod sta_test {
         //Y + ACC
         cpu.registers.yr = 3;
         cpu.registers.acc = 0x20;
-        cpu.execute_instruction_sequence(&mut bytes.as_slice());
+        cpu.execute_instruction_sequence(&bytes);
 
         assert_eq!(cpu.memory[0x2233], 0x20);
         assert_eq!(cpu.registers.acc, 0x00);
@@ -64,7 +64,7 @@ mod sta_test {
         ];
 
         cpu.registers.acc = 0x20;
-        cpu.execute_instruction_sequence(&mut bytes.as_slice());
+        cpu.execute_instruction_sequence(&bytes);
 
         assert_eq!(cpu.memory[0x33], 0x20);
         assert_eq!(cpu.registers.acc, 0x00);
@@ -81,7 +81,7 @@ mod sta_test {
         //X + Zero Page (0x30 + 3 = 0x33)
         cpu.registers.xr = 3;
         cpu.registers.acc = 0x20;
-        cpu.execute_instruction_sequence(&mut bytes.as_slice());
+        cpu.execute_instruction_sequence(&bytes);
 
         assert_eq!(cpu.memory[0x33], 0x20);
         assert_eq!(cpu.registers.acc, 0x00);
@@ -100,7 +100,7 @@ mod sta_test {
         cpu.memory[0x4030] = 0x00;
         cpu.memory[0x20] = 0x30;
         cpu.memory[0x21] = 0x40;
-        cpu.execute_instruction_sequence(&mut bytes.as_slice());
+        cpu.execute_instruction_sequence(&bytes);
 
         assert_eq!(cpu.memory[0x4030], 0x20);
         assert_eq!(cpu.registers.acc, 0x00);
@@ -119,7 +119,7 @@ mod sta_test {
         cpu.memory[0x4032] = 0x00;
         cpu.memory[0x22] = 0x30;
         cpu.memory[0x23] = 0x40;
-        cpu.execute_instruction_sequence(&mut bytes.as_slice());
+        cpu.execute_instruction_sequence(&bytes);
 
         assert_eq!(cpu.memory[0x4032], 0x20);
         assert_eq!(cpu.registers.acc, 0x00);
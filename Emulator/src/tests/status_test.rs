@@ -0,0 +1,75 @@
+#[cfg(test)]
+mod status_test {
+    use crate::cpu::cpu::{CPU, Flags};
+
+    #[test]
+    fn status_test_layout() {
+        let flags = Flags { negative: true, carry: true, ..Flags::default() };
+
+        assert_eq!(flags.to_byte(), 0b1000_0001);
+        assert_eq!(Flags::from_byte(0b1000_0001), flags);
+        assert_eq!(Flags::from_byte(0xFF).to_byte(), 0b1100_1111);
+    }
+
+    #[test]
+    fn status_test_pushed_byte() {
+        let flags = Flags { zero: true, ..Flags::default() };
+
+        assert_eq!(flags.to_pushed_byte(false), 0b0010_0010);
+        assert_eq!(flags.to_pushed_byte(true), 0b0011_0010);
+    }
+
+    #[test]
+    fn status_test_php_pla() {
+        let mut cpu = CPU::new();
+        let bytes = [
+            0x38,       //SEC
+            0x08,       //PHP
+            0x68,       //PLA
+            0x29, 0x01  //AND #$01
+        ];
+
+        cpu.execute_instruction_sequence(&bytes);
+
+        assert_eq!(cpu.registers.acc, 0x01);
+    }
+
+    #[test]
+    fn status_test_php_pushes_b_and_bit_5() {
+        let mut cpu = CPU::new();
+        let bytes = [
+            0xF8, //SED
+            0x08, //PHP
+            0x68  //PLA
+        ];
+
+        cpu.execute_instruction_sequence(&bytes);
+
+        assert_eq!(cpu.registers.acc, 0b0011_1000);
+    }
+
+    #[test]
+    fn status_test_plp_ignores_b_and_bit_5() {
+        let mut cpu = CPU::new();
+        let bytes = [
+            0xA9, 0xFF, //LDA #$FF
+            0x48,       //PHA
+            0x28,       //PLP
+            0x08,       //PHP
+            0x68        //PLA
+        ];
+
+        cpu.execute_instruction_sequence(&bytes);
+
+        assert_eq!(cpu.flags.decimal, true);
+        assert_eq!(cpu.registers.acc, 0xFF);
+    }
+
+    #[test]
+    fn status_test_display() {
+        let flags = Flags { interrupt: true, ..Flags::default() };
+
+        assert_eq!(flags.to_string(), "nv-bdIzc");
+        assert_eq!(format!("{:?}", Flags::from_byte(0xFF)), "NV-bDIZC");
+    }
+}
@@ -13,7 +13,7 @@ mod stx_test {
         ];
 
         cpu.registers.xr = 0x20;
-        cpu.execute_instruction_sequence(&mut bytes.as_slice());
+        cpu.execute_instruction_sequence(&bytes);
 
         assert_eq!(cpu.memory[0x2233], 0x20);
         assert_eq!(cpu.registers.xr, 0x00);
@@ -28,7 +28,7 @@ mod stx_test {
         ];
 
         cpu.registers.xr = 0x20;
-        cpu.execute_instruction_sequence(&mut bytes.as_slice());
+        cpu.execute_instruction_sequence(&bytes);
 
         assert_eq!(cpu.memory[0x33], 0x20);
         assert_eq!(cpu.registers.xr, 0x00);
@@ -45,7 +45,7 @@ mod stx_test {
         //Y + Zero Page (0x30 + 3 = 0x33)
         cpu.registers.yr = 0x03;
         cpu.registers.xr = 0x20;
-        cpu.execute_instruction_sequence(&mut bytes.as_slice());
+        cpu.execute_instruction_sequence(&bytes);
 
         assert_eq!(cpu.memory[0x33], 0x20);
         assert_eq!(cpu.registers.xr, 0x00);
@@ -11,7 +11,7 @@ mod tax_test {
 
         cpu.registers.acc = 0x20;
         cpu.registers.xr = 0;
-        cpu.execute_instruction_sequence(&mut bytes.as_slice());
+        cpu.execute_instruction_sequence(&bytes);
 
         assert_eq!(cpu.registers.xr, 0x20);
     }
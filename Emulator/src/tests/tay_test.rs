@@ -11,7 +11,7 @@ mod tay_test {
 
         cpu.registers.acc = 0x20;
         cpu.registers.yr = 0;
-        cpu.execute_instruction_sequence(&mut bytes.as_slice());
+        cpu.execute_instruction_sequence(&bytes);
 
         assert_eq!(cpu.registers.yr, 0x20);
     }
@@ -1,16 +1,25 @@
+use crate::assembler::assembler::assemble;
+use crate::cpu::bus::Bus;
 use crate::cpu::cpu::CPU;
+use crate::cpu::run::StopReason;
 
-impl CPU {
+impl<B: Bus> CPU<B> {
 
-    pub(crate) fn execute_instruction_sequence(&mut self, mut bytes: &[u8]){
-        let instruction_set = self.get_instruction_set();
-        while (self.registers.pc as usize) < bytes.len() {
-            let opcode = self.get_next_byte(&mut bytes);
-            let instruction = instruction_set.get(&opcode).unwrap_or_else(|| {
-                panic!("Instruction {:#04X?} not found.", opcode)
-            });
-            self.execute_instruction(instruction, bytes);
-        }
+    //Loads the program at $0000 and runs it from the current PC until execution leaves the program or stops.
+    pub(crate) fn execute_instruction_sequence(&mut self, bytes: &[u8]) -> StopReason {
+        self.load_program(bytes, 0x0000);
+        self.run_until(|cpu| cpu.registers.pc as usize >= bytes.len()).unwrap()
     }
 
-}
\ No newline at end of file
+    //Assembles the source at $0000 and runs it like execute_instruction_sequence.
+    pub(crate) fn execute_source(&mut self, source: &str) -> StopReason {
+        let assembly = assemble(source).unwrap();
+        assert_eq!(assembly.origin, 0x0000);
+        self.execute_instruction_sequence(&assembly.image)
+    }
+
+    pub(crate) fn execute_instructions(&mut self, count: usize) -> StopReason {
+        self.run_instructions(count).unwrap()
+    }
+
+}
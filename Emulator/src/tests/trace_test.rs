@@ -0,0 +1,201 @@
+#[cfg(test)]
+mod trace_test {
+    use std::cell::RefCell;
+    use std::io::{self, Write};
+    use std::rc::Rc;
+    use crate::cpu::cpu::{Variant, CPU};
+    use crate::cpu::trace::TraceFormat;
+
+    #[derive(Clone, Default)]
+    struct SharedBuffer {
+        bytes: Rc<RefCell<Vec<u8>>>
+    }
+
+    impl Write for SharedBuffer {
+        fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+            self.bytes.borrow_mut().extend_from_slice(buf);
+            Ok(buf.len())
+        }
+
+        fn flush(&mut self) -> io::Result<()> {
+            Ok(())
+        }
+    }
+
+    struct FailingWriter;
+
+    impl Write for FailingWriter {
+        fn write(&mut self, _: &[u8]) -> io::Result<usize> {
+            Err(io::Error::other("disk full"))
+        }
+
+        fn flush(&mut self) -> io::Result<()> {
+            Ok(())
+        }
+    }
+
+    fn collect_lines(cpu: &mut CPU, format: TraceFormat) -> Rc<RefCell<Vec<String>>> {
+        let lines = Rc::new(RefCell::new(Vec::new()));
+        let sink = lines.clone();
+        cpu.trace_with(format, move |line| sink.borrow_mut().push(line.to_string()));
+        lines
+    }
+
+    #[test]
+    fn trace_test_nestest_line() {
+        let mut cpu = CPU::new();
+        cpu.load_program(&[0x4C, 0xF5, 0xC5], 0xC000);
+        cpu.registers.pc = 0xC000;
+        cpu.registers.sp = 0xFD;
+        cpu.flags.interrupt = true;
+        cpu.cycles = 7;
+
+        assert_eq!(
+            cpu.trace_line().format(&TraceFormat::Nestest),
+            "C000  4C F5 C5  JMP $C5F5                       A:00 X:00 Y:00 P:24 SP:FD CYC:7"
+        );
+    }
+
+    #[test]
+    fn trace_test_undocumented_opcode() {
+        let mut cpu = CPU::new();
+        cpu.load_program(&[0x04, 0xA9], 0x0600);
+        cpu.registers.pc = 0x0600;
+
+        assert!(cpu.trace_line().format(&TraceFormat::Nestest).starts_with("0600  04 A9    *NOP $A9 = 00    "));
+
+        let mut cmos = CPU::with_variant(Variant::Wdc65C02);
+        cmos.load_program(&[0x04, 0xA9], 0x0600);
+        cmos.registers.pc = 0x0600;
+        assert!(cmos.trace_line().format(&TraceFormat::Nestest).starts_with("0600  04 A9     TSB $A9 = 00    "));
+    }
+
+    #[test]
+    fn trace_test_resolved_operands() {
+        let mut cpu = CPU::new();
+        cpu.load_program(&[
+            0xA2, 0x02,       //LDX #$02
+            0xB5, 0x10,       //LDA $10,X
+            0x9D, 0x00, 0x03, //STA $0300,X
+            0xA1, 0x20,       //LDA ($20,X)
+            0xB1, 0x24,       //LDA ($24),Y
+            0x6C, 0x30, 0x00, //JMP ($0030)
+        ], 0x0600);
+        cpu.memory[0x12] = 0x55;
+        cpu.memory[0x22] = 0x00;
+        cpu.memory[0x23] = 0x04;
+        cpu.memory[0x0400] = 0x77;
+        cpu.memory[0x24] = 0x00;
+        cpu.memory[0x25] = 0x05;
+        cpu.memory[0x0503] = 0x88;
+        cpu.memory[0x30] = 0x00;
+        cpu.memory[0x31] = 0x06;
+        cpu.registers.pc = 0x0600;
+        cpu.registers.yr = 0x03;
+        let lines = collect_lines(&mut cpu, TraceFormat::Template("{pc} {disassembly}".to_string()));
+
+        cpu.run_instructions(6).unwrap();
+
+        assert_eq!(*lines.borrow(), vec![
+            "0600 LDX #$02",
+            "0602 LDA $10,X @ 12 = 55",
+            "0604 STA $0300,X @ 0302 = 00",
+            "0607 LDA ($20,X) @ 22 = 0400 = 77",
+            "0609 LDA ($24),Y = 0500 @ 0503 = 88",
+            "060B JMP ($0030) = 0600",
+        ]);
+    }
+
+    #[test]
+    fn trace_test_branch_target() {
+        let mut cpu = CPU::new();
+        cpu.load_program(&[0xEA, 0xD0, 0xFD], 0x0600);
+        cpu.registers.pc = 0x0601;
+
+        assert_eq!(cpu.trace_line().disassembly(), "BNE $0600");
+    }
+
+    #[test]
+    fn trace_test_template() {
+        let mut cpu = CPU::new();
+        cpu.load_program(&[0xA9, 0x34], 0x0600);
+        cpu.registers.pc = 0x0600;
+        cpu.registers.acc = 0x12;
+        let format = TraceFormat::Template("{pc}:{bytes}|{mnemonic}|{operand}|{a}{x}{y}|{p}|{flags}|{sp}|{cycles}|{unknown}".to_string());
+
+        assert_eq!(cpu.trace_line().format(&format), "0600:A9 34|LDA|#$34|120000|20|nv-bdizc|FF|0|{unknown}");
+    }
+
+    #[test]
+    fn trace_test_writer() {
+        let mut cpu = CPU::new();
+        cpu.load_program(&[0xE8, 0xE8, 0xE8], 0x0600);
+        cpu.registers.pc = 0x0600;
+        let buffer = SharedBuffer::default();
+        cpu.trace_to_writer(buffer.clone(), TraceFormat::Template("{disassembly} X:{x}".to_string()));
+
+        cpu.run_instructions(3).unwrap();
+        cpu.stop_trace().unwrap();
+        cpu.step().unwrap();
+
+        assert!(!cpu.is_tracing());
+        assert_eq!(String::from_utf8(buffer.bytes.borrow().clone()).unwrap(), "INX X:00\nINX X:01\nINX X:02\n");
+    }
+
+    #[test]
+    fn trace_test_write_error() {
+        let mut cpu = CPU::new();
+        cpu.trace_to_writer(FailingWriter, TraceFormat::Nestest);
+
+        cpu.step().unwrap();
+
+        assert_eq!(cpu.stop_trace().unwrap_err().to_string(), "disk full");
+    }
+
+    #[test]
+    fn trace_test_does_not_change_execution() {
+        let program = [0xE6, 0x10, 0xE8, 0xD0, 0xFB, 0x4C, 0x00, 0x02];
+        let mut traced = CPU::new();
+        let mut untraced = CPU::new();
+        for cpu in [&mut traced, &mut untraced] {
+            cpu.load_program(&program, 0x0200);
+            cpu.registers.pc = 0x0200;
+        }
+        let lines = collect_lines(&mut traced, TraceFormat::Nestest);
+
+        traced.run_instructions(100).unwrap();
+        untraced.run_instructions(100).unwrap();
+
+        assert_eq!(lines.borrow().len(), 100);
+        assert_eq!(traced.save_state(), untraced.save_state());
+    }
+
+    #[test]
+    fn trace_test_rewind_replay_is_not_traced() {
+        let mut cpu = CPU::new();
+        cpu.load_program(&[0xE8, 0x4C, 0x00, 0x02], 0x0200);
+        cpu.registers.pc = 0x0200;
+        cpu.enable_rewind(4, 4);
+        let lines = collect_lines(&mut cpu, TraceFormat::Nestest);
+
+        cpu.run_instructions(10).unwrap();
+        cpu.step_back(3).unwrap();
+
+        assert_eq!(lines.borrow().len(), 10);
+    }
+
+    #[test]
+    fn trace_test_65816_wide_registers() {
+        let mut cpu = CPU::with_variant(Variant::Wdc65816);
+        cpu.load_program(&[0x18, 0xFB, 0xC2, 0x30, 0xA9, 0x34, 0x12], 0x0200); //CLC, XCE, REP #$30, LDA #$1234
+        cpu.registers.pc = 0x0200;
+        cpu.run_instructions(3).unwrap();
+
+        let line = cpu.trace_line();
+
+        assert_eq!(line.bytes, vec![0xA9, 0x34, 0x12]);
+        assert_eq!(line.disassembly(), "LDA #$1234");
+        //XCE moved the emulation bit into carry.
+        assert_eq!(line.format(&TraceFormat::Template("{pc} A:{a} X:{x} P:{p}".to_string())), "0204 A:0000 X:0000 P:01");
+    }
+}
@@ -10,7 +10,7 @@ mod tsx_test {
         ];
 
         cpu.registers.xr = 0;
-        cpu.execute_instruction_sequence(&mut bytes.as_slice());
+        cpu.execute_instruction_sequence(&bytes);
 
         assert_eq!(cpu.registers.xr, cpu.registers.sp);
     }
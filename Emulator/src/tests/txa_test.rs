@@ -11,7 +11,7 @@ mod txa_test {
 
         cpu.registers.xr = 0x20;
         cpu.registers.acc = 0;
-        cpu.execute_instruction_sequence(&mut bytes.as_slice());
+        cpu.execute_instruction_sequence(&bytes);
 
         assert_eq!(cpu.registers.acc, 0x20);
     }
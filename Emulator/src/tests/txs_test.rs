@@ -10,7 +10,7 @@ mod txs_test {
         ];
 
         cpu.registers.xr = 0x20;
-        cpu.execute_instruction_sequence(&mut bytes.as_slice());
+        cpu.execute_instruction_sequence(&bytes);
 
         assert_eq!(cpu.registers.xr, 0x00);
         assert_eq!(cpu.registers.sp, 0x20);
@@ -10,7 +10,7 @@ mod tya_test {
         ];
 
         cpu.registers.yr = 0x20;
-        cpu.execute_instruction_sequence(&mut bytes.as_slice());
+        cpu.execute_instruction_sequence(&bytes);
 
         assert_eq!(cpu.registers.acc, 0x20);
     }
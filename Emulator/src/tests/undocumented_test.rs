@@ -0,0 +1,347 @@
+#[cfg(test)]
+mod undocumented_test {
+    use crate::cpu::cpu::CPU;
+
+    #[test]
+    fn undocumented_test_lax_absolute() {
+        let mut cpu = CPU::new();
+
+        let bytes = [
+            0xAF, 0x00, 0x02
+        ];
+
+        cpu.memory[0x0200] = 0x80;
+        cpu.execute_instruction_sequence(&bytes);
+
+        assert_eq!(cpu.registers.acc, 0x80);
+        assert_eq!(cpu.registers.xr, 0x80);
+        assert_eq!(cpu.flags.negative, true);
+        assert_eq!(cpu.flags.zero, false);
+        assert_eq!(cpu.cycles, 4);
+    }
+
+    #[test]
+    fn undocumented_test_lax_page_cross() {
+        let mut cpu = CPU::new();
+
+        let bytes = [
+            0xBF, 0xFF, 0x02
+        ];
+
+        cpu.registers.yr = 0x01;
+        cpu.memory[0x0300] = 0x00;
+        cpu.execute_instruction_sequence(&bytes);
+
+        assert_eq!(cpu.registers.acc, 0x00);
+        assert_eq!(cpu.registers.xr, 0x00);
+        assert_eq!(cpu.flags.zero, true);
+        assert_eq!(cpu.cycles, 5);
+    }
+
+    #[test]
+    fn undocumented_test_sax() {
+        let mut cpu = CPU::new();
+
+        let bytes = [
+            0x8F, 0x00, 0x02
+        ];
+
+        cpu.registers.acc = 0xF0;
+        cpu.registers.xr = 0x3C;
+        cpu.execute_instruction_sequence(&bytes);
+
+        assert_eq!(cpu.memory[0x0200], 0x30);
+        assert_eq!(cpu.flags.zero, false);
+    }
+
+    #[test]
+    fn undocumented_test_dcp() {
+        let mut cpu = CPU::new();
+
+        let bytes = [
+            0xCF, 0x00, 0x02
+        ];
+
+        cpu.registers.acc = 0x41;
+        cpu.memory[0x0200] = 0x42;
+        cpu.execute_instruction_sequence(&bytes);
+
+        assert_eq!(cpu.memory[0x0200], 0x41);
+        assert_eq!(cpu.flags.zero, true);
+        assert_eq!(cpu.flags.carry, true);
+        assert_eq!(cpu.cycles, 6);
+    }
+
+    #[test]
+    fn undocumented_test_isc() {
+        let mut cpu = CPU::new();
+
+        let bytes = [
+            0xEF, 0x00, 0x02
+        ];
+
+        cpu.registers.acc = 0x10;
+        cpu.flags.carry = true;
+        cpu.memory[0x0200] = 0x04;
+        cpu.execute_instruction_sequence(&bytes);
+
+        assert_eq!(cpu.memory[0x0200], 0x05);
+        assert_eq!(cpu.registers.acc, 0x0B);
+        assert_eq!(cpu.flags.carry, true);
+    }
+
+    #[test]
+    fn undocumented_test_slo() {
+        let mut cpu = CPU::new();
+
+        let bytes = [
+            0x0F, 0x00, 0x02
+        ];
+
+        cpu.registers.acc = 0x01;
+        cpu.memory[0x0200] = 0x81;
+        cpu.execute_instruction_sequence(&bytes);
+
+        assert_eq!(cpu.memory[0x0200], 0x02);
+        assert_eq!(cpu.registers.acc, 0x03);
+        assert_eq!(cpu.flags.carry, true);
+    }
+
+    #[test]
+    fn undocumented_test_rla() {
+        let mut cpu = CPU::new();
+
+        let bytes = [
+            0x2F, 0x00, 0x02
+        ];
+
+        cpu.registers.acc = 0x0F;
+        cpu.flags.carry = true;
+        cpu.memory[0x0200] = 0x84;
+        cpu.execute_instruction_sequence(&bytes);
+
+        assert_eq!(cpu.memory[0x0200], 0x09);
+        assert_eq!(cpu.registers.acc, 0x09);
+        assert_eq!(cpu.flags.carry, true);
+    }
+
+    #[test]
+    fn undocumented_test_sre() {
+        let mut cpu = CPU::new();
+
+        let bytes = [
+            0x4F, 0x00, 0x02
+        ];
+
+        cpu.registers.acc = 0xFF;
+        cpu.memory[0x0200] = 0x03;
+        cpu.execute_instruction_sequence(&bytes);
+
+        assert_eq!(cpu.memory[0x0200], 0x01);
+        assert_eq!(cpu.registers.acc, 0xFE);
+        assert_eq!(cpu.flags.carry, true);
+        assert_eq!(cpu.flags.negative, true);
+    }
+
+    #[test]
+    fn undocumented_test_rra() {
+        let mut cpu = CPU::new();
+
+        let bytes = [
+            0x6F, 0x00, 0x02
+        ];
+
+        cpu.registers.acc = 0x10;
+        cpu.flags.carry = false;
+        cpu.memory[0x0200] = 0x03;
+        cpu.execute_instruction_sequence(&bytes);
+
+        //ROR leaves $01 with carry set, ADC then adds both.
+        assert_eq!(cpu.memory[0x0200], 0x01);
+        assert_eq!(cpu.registers.acc, 0x12);
+        assert_eq!(cpu.flags.carry, false);
+    }
+
+    #[test]
+    fn undocumented_test_anc() {
+        let mut cpu = CPU::new();
+
+        let bytes = [
+            0x0B, 0xF0
+        ];
+
+        cpu.registers.acc = 0x81;
+        cpu.execute_instruction_sequence(&bytes);
+
+        assert_eq!(cpu.registers.acc, 0x80);
+        assert_eq!(cpu.flags.negative, true);
+        assert_eq!(cpu.flags.carry, true);
+    }
+
+    #[test]
+    fn undocumented_test_alr() {
+        let mut cpu = CPU::new();
+
+        let bytes = [
+            0x4B, 0x03
+        ];
+
+        cpu.registers.acc = 0xFF;
+        cpu.execute_instruction_sequence(&bytes);
+
+        assert_eq!(cpu.registers.acc, 0x01);
+        assert_eq!(cpu.flags.carry, true);
+        assert_eq!(cpu.flags.negative, false);
+    }
+
+    #[test]
+    fn undocumented_test_arr() {
+        let mut cpu = CPU::new();
+
+        let bytes = [
+            0x6B, 0xC0
+        ];
+
+        cpu.registers.acc = 0xFF;
+        cpu.flags.carry = true;
+        cpu.execute_instruction_sequence(&bytes);
+
+        assert_eq!(cpu.registers.acc, 0xE0);
+        assert_eq!(cpu.flags.negative, true);
+        assert_eq!(cpu.flags.carry, true);
+        assert_eq!(cpu.flags.overflow, false);
+    }
+
+    #[test]
+    fn undocumented_test_arr_decimal() {
+        let mut cpu = CPU::new();
+
+        let bytes = [
+            0x6B, 0xFF
+        ];
+
+        cpu.registers.acc = 0xFF;
+        cpu.flags.decimal = true;
+        cpu.execute_instruction_sequence(&bytes);
+
+        assert_eq!(cpu.registers.acc, 0xD5);
+        assert_eq!(cpu.flags.negative, false);
+        assert_eq!(cpu.flags.carry, true);
+        assert_eq!(cpu.flags.overflow, false);
+    }
+
+    #[test]
+    fn undocumented_test_sbx() {
+        let mut cpu = CPU::new();
+
+        let bytes = [
+            0xCB, 0x10
+        ];
+
+        cpu.registers.acc = 0x3F;
+        cpu.registers.xr = 0xF3;
+        cpu.execute_instruction_sequence(&bytes);
+
+        assert_eq!(cpu.registers.xr, 0x23);
+        assert_eq!(cpu.registers.acc, 0x3F);
+        assert_eq!(cpu.flags.carry, true);
+    }
+
+    #[test]
+    fn undocumented_test_unstable_constants() {
+        let mut cpu = CPU::new();
+
+        let bytes = [
+            0x8B, 0xFF, //ANE #$FF
+            0xAB, 0x0F  //LXA #$0F
+        ];
+
+        cpu.unstable_constants.ane = 0x00;
+        cpu.unstable_constants.lxa = 0xFF;
+        cpu.registers.acc = 0x0F;
+        cpu.registers.xr = 0x3C;
+        cpu.load_program(&bytes, 0x0000);
+        cpu.execute_instructions(1);
+
+        assert_eq!(cpu.registers.acc, 0x0C);
+
+        cpu.execute_instructions(1);
+
+        assert_eq!(cpu.registers.acc, 0x0F);
+        assert_eq!(cpu.registers.xr, 0x0F);
+    }
+
+    #[test]
+    fn undocumented_test_shx_page_cross() {
+        let mut cpu = CPU::new();
+
+        let bytes = [
+            0x9E, 0xFF, 0x02
+        ];
+
+        cpu.registers.xr = 0x01;
+        cpu.registers.yr = 0x01;
+        cpu.execute_instruction_sequence(&bytes);
+
+        //The value is X & ($02 + 1) and it also replaces the high byte of $0300.
+        assert_eq!(cpu.memory[0x0100], 0x01);
+        assert_eq!(cpu.memory[0x0300], 0x00);
+    }
+
+    #[test]
+    fn undocumented_test_nop_operands() {
+        let mut cpu = CPU::new();
+
+        let bytes = [
+            0x1A,             //NOP
+            0x80, 0xFF,       //NOP #$FF
+            0x04, 0xFF,       //NOP $FF
+            0x14, 0xFF,       //NOP $FF,X
+            0x0C, 0x00, 0x02, //NOP $0200
+            0x1C, 0x00, 0x02  //NOP $0200,X
+        ];
+
+        cpu.execute_instruction_sequence(&bytes);
+
+        assert_eq!(cpu.registers.pc, 0x000D);
+        assert_eq!(cpu.registers.acc, 0x00);
+        assert_eq!(cpu.cycles, 2 + 2 + 3 + 4 + 4 + 4);
+    }
+
+    #[test]
+    fn undocumented_test_jam() {
+        let mut cpu = CPU::new();
+
+        let bytes = [
+            0x02, 0xE8
+        ];
+
+        cpu.execute_instruction_sequence(&bytes);
+        let cycles = cpu.cycles;
+        cpu.execute_instructions(5);
+
+        assert_eq!(cpu.jammed, true);
+        assert_eq!(cpu.registers.pc, 0x0000);
+        assert_eq!(cpu.registers.xr, 0x00);
+        assert_eq!(cpu.cycles, cycles);
+    }
+
+    #[test]
+    fn undocumented_test_jam_reset() {
+        let mut cpu = CPU::new();
+
+        let bytes = [
+            0x02
+        ];
+
+        cpu.memory[0xFFFC] = 0x00;
+        cpu.memory[0xFFFD] = 0x04;
+        cpu.execute_instruction_sequence(&bytes);
+        cpu.set_reset_line(true);
+        cpu.set_reset_line(false);
+        cpu.execute_instructions(1);
+
+        assert_eq!(cpu.jammed, false);
+        assert_eq!(cpu.registers.pc, 0x0400);
+    }
+}
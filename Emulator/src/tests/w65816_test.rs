@@ -0,0 +1,477 @@
+#[cfg(test)]
+mod w65816_test {
+    use crate::cpu::bus::Ram;
+    use crate::cpu::cpu::{Variant, CPU};
+    use crate::cpu::execution::Fault;
+    use crate::cpu::instructions::AddressingMode;
+    use crate::cpu::w65816::instructions::Operand;
+    use crate::cpu::w65816::operations;
+    use crate::cpu::run::StopReason;
+
+    //A 65816 that already ran CLC, XCE and REP #$31, so A, X and Y are 16-bit and C is clear.
+    fn native_cpu() -> CPU {
+        let mut cpu = CPU::with_variant(Variant::Wdc65816);
+        cpu.execute_instruction_sequence(&[0x18, 0xFB, 0xC2, 0x31]);
+        cpu.cycles = 0;
+        cpu
+    }
+
+    #[test]
+    fn w65816_test_matches_instruction_table() {
+        for opcode in 0..=255u8 {
+            let Some(instruction) = <CPU>::get_native_instruction(opcode) else { continue };
+            if let AddressingMode::Relative = instruction.address_mode {
+                continue;
+            }
+
+            let mut cpu = CPU::with_variant(Variant::Wdc65816);
+            cpu.load_program(&[opcode, 0x10, 0x02, 0x00], 0x0200);
+            cpu.registers.pc = 0x0200;
+            cpu.execute_instructions(1);
+
+            assert_eq!(cpu.cycles, instruction.cycle_increase, "Opcode {:#04X?}", opcode);
+        }
+    }
+
+    #[test]
+    fn w65816_test_starts_in_emulation_mode() {
+        let cpu = CPU::with_variant(Variant::Wdc65816);
+
+        assert_eq!(cpu.flags.emulation, true);
+        assert_eq!(cpu.flags.memory_select, true);
+        assert_eq!(cpu.flags.index_select, true);
+        assert_eq!(cpu.get_s(), 0x01FF);
+    }
+
+    #[test]
+    fn w65816_test_native_mode_16bit_load() {
+        let mut cpu = native_cpu();
+
+        let bytes = [
+            0xA9, 0x34, 0x12, //LDA #$1234
+            0xA2, 0x00, 0x80  //LDX #$8000
+        ];
+
+        cpu.load_program(&bytes, 0x0000);
+        cpu.registers.pc = 0x0000;
+        cpu.execute_instructions(2);
+
+        assert_eq!(cpu.flags.emulation, false);
+        assert_eq!(cpu.get_c(), 0x1234);
+        assert_eq!(cpu.get_x(), 0x8000);
+        assert_eq!(cpu.flags.negative, true);
+        assert_eq!(cpu.cycles, 6);
+    }
+
+    #[test]
+    fn w65816_test_8bit_accumulator_keeps_b() {
+        let mut cpu = native_cpu();
+
+        let bytes = [
+            0xA9, 0x34, 0x12, //LDA #$1234
+            0xE2, 0x20,       //SEP #$20
+            0xA9, 0xFF,       //LDA #$FF
+            0xEB              //XBA
+        ];
+
+        cpu.load_program(&bytes, 0x0000);
+        cpu.registers.pc = 0x0000;
+        cpu.execute_instructions(3);
+
+        assert_eq!(cpu.get_c(), 0x12FF);
+
+        cpu.execute_instructions(1);
+
+        assert_eq!(cpu.get_c(), 0xFF12);
+        assert_eq!(cpu.flags.negative, false);
+    }
+
+    #[test]
+    fn w65816_test_setting_x_clears_index_high_bytes() {
+        let mut cpu = native_cpu();
+
+        let bytes = [
+            0xA2, 0x34, 0x12, //LDX #$1234
+            0xE2, 0x10        //SEP #$10
+        ];
+
+        cpu.load_program(&bytes, 0x0000);
+        cpu.registers.pc = 0x0000;
+        cpu.execute_instructions(2);
+
+        assert_eq!(cpu.get_x(), 0x0034);
+    }
+
+    #[test]
+    fn w65816_test_16bit_adc() {
+        let mut cpu = native_cpu();
+
+        let bytes = [
+            0x69, 0x01, 0x00 //ADC #$0001
+        ];
+
+        cpu.set_c(0x7FFF);
+        cpu.load_program(&bytes, 0x0000);
+        cpu.registers.pc = 0x0000;
+        cpu.execute_instructions(1);
+
+        assert_eq!(cpu.get_c(), 0x8000);
+        assert_eq!(cpu.flags.overflow, true);
+        assert_eq!(cpu.flags.negative, true);
+        assert_eq!(cpu.flags.carry, false);
+    }
+
+    #[test]
+    fn w65816_test_16bit_decimal() {
+        let mut cpu = native_cpu();
+
+        let bytes = [
+            0xF8,             //SED
+            0x69, 0x01, 0x00, //ADC #$0001
+            0x38,             //SEC
+            0xE9, 0x01, 0x00  //SBC #$0001
+        ];
+
+        cpu.set_c(0x1999);
+        cpu.load_program(&bytes, 0x0000);
+        cpu.registers.pc = 0x0000;
+        cpu.execute_instructions(2);
+
+        assert_eq!(cpu.get_c(), 0x2000);
+        assert_eq!(cpu.flags.carry, false);
+
+        cpu.execute_instructions(2);
+
+        assert_eq!(cpu.get_c(), 0x1999);
+        assert_eq!(cpu.flags.carry, true);
+    }
+
+    #[test]
+    fn w65816_test_decimal_carry_out() {
+        let mut cpu = native_cpu();
+
+        let bytes = [
+            0xF8,             //SED
+            0x69, 0x01, 0x00  //ADC #$0001
+        ];
+
+        cpu.set_c(0x9999);
+        cpu.load_program(&bytes, 0x0000);
+        cpu.registers.pc = 0x0000;
+        cpu.execute_instructions(2);
+
+        assert_eq!(cpu.get_c(), 0x0000);
+        assert_eq!(cpu.flags.carry, true);
+        assert_eq!(cpu.flags.zero, true);
+    }
+
+    #[test]
+    fn w65816_test_long_addressing() {
+        let mut cpu = CPU::with_bus_and_variant(Ram::with_banks(0x20), Variant::Wdc65816);
+
+        let bytes = [
+            0xAF, 0x56, 0x34, 0x12, //LDA $123456
+            0x9F, 0x00, 0x00, 0x13  //STA $130000,X
+        ];
+
+        cpu.load_program_long(&[0x42], 0x123456);
+        cpu.registers.xr = 0x02;
+        cpu.execute_instruction_sequence(&bytes);
+
+        assert_eq!(cpu.registers.acc, 0x42);
+        assert_eq!(cpu.memory[0x130002], 0x42);
+    }
+
+    #[test]
+    fn w65816_test_data_bank() {
+        let mut cpu = CPU::with_bus_and_variant(Ram::with_banks(0x04), Variant::Wdc65816);
+
+        let bytes = [
+            0xA9, 0x03, //LDA #$03
+            0x48,       //PHA
+            0xAB,       //PLB
+            0xAD, 0x00, 0x10 //LDA $1000
+        ];
+
+        cpu.load_program_long(&[0x99], 0x031000);
+        cpu.execute_instruction_sequence(&bytes);
+
+        assert_eq!(cpu.registers.data_bank, 0x03);
+        assert_eq!(cpu.registers.acc, 0x99);
+    }
+
+    #[test]
+    fn w65816_test_direct_page() {
+        let mut cpu = native_cpu();
+
+        let bytes = [
+            0xA9, 0x01, 0x03, //LDA #$0301
+            0x5B,             //TCD
+            0xA5, 0x10        //LDA $10
+        ];
+
+        cpu.memory[0x0311] = 0xCD;
+        cpu.memory[0x0312] = 0xAB;
+        cpu.load_program(&bytes, 0x0000);
+        cpu.registers.pc = 0x0000;
+        cpu.execute_instructions(2);
+        let cycles = cpu.cycles;
+        cpu.execute_instructions(1);
+
+        assert_eq!(cpu.registers.direct_page, 0x0301);
+        assert_eq!(cpu.get_c(), 0xABCD);
+        //16-bit data and the direct page off a page boundary both add a cycle.
+        assert_eq!(cpu.cycles - cycles, 5);
+    }
+
+    #[test]
+    fn w65816_test_stack_relative() {
+        let mut cpu = native_cpu();
+
+        let bytes = [
+            0xF4, 0x34, 0x12, //PEA $1234
+            0xA3, 0x01,       //LDA 1,S
+            0xA9, 0x00, 0x20, //LDA #$2000
+            0x83, 0x01        //STA 1,S
+        ];
+
+        cpu.load_program(&bytes, 0x0000);
+        cpu.registers.pc = 0x0000;
+        cpu.execute_instructions(2);
+
+        assert_eq!(cpu.get_c(), 0x1234);
+        assert_eq!(cpu.get_s(), 0x01FD);
+
+        cpu.execute_instructions(2);
+
+        assert_eq!(cpu.memory[0x01FE], 0x00);
+        assert_eq!(cpu.memory[0x01FF], 0x20);
+    }
+
+    #[test]
+    fn w65816_test_stack_relative_indirect_indexed() {
+        let mut cpu = native_cpu();
+
+        let bytes = [
+            0xF4, 0x00, 0x30, //PEA $3000
+            0xA0, 0x02, 0x00, //LDY #$0002
+            0xB3, 0x01        //LDA (1,S),Y
+        ];
+
+        cpu.memory[0x3002] = 0x11;
+        cpu.memory[0x3003] = 0x22;
+        cpu.load_program(&bytes, 0x0000);
+        cpu.registers.pc = 0x0000;
+        cpu.execute_instructions(3);
+
+        assert_eq!(cpu.get_c(), 0x2211);
+    }
+
+    #[test]
+    fn w65816_test_mvn() {
+        let mut cpu = CPU::with_bus_and_variant(Ram::with_banks(0x03), Variant::Wdc65816);
+
+        let bytes = [
+            0x18,             //CLC
+            0xFB,             //XCE
+            0xC2, 0x30,       //REP #$30
+            0xA9, 0x02, 0x00, //LDA #$0002
+            0xA2, 0x00, 0x10, //LDX #$1000
+            0xA0, 0x00, 0x20, //LDY #$2000
+            0x54, 0x02, 0x01  //MVN $01,$02
+        ];
+
+        cpu.load_program_long(&[0xAA, 0xBB, 0xCC, 0xDD], 0x011000);
+        cpu.execute_instruction_sequence(&bytes);
+
+        assert_eq!(cpu.memory[0x022000], 0xAA);
+        assert_eq!(cpu.memory[0x022001], 0xBB);
+        assert_eq!(cpu.memory[0x022002], 0xCC);
+        assert_eq!(cpu.memory[0x022003], 0x00);
+        assert_eq!(cpu.get_c(), 0xFFFF);
+        assert_eq!(cpu.get_x(), 0x1003);
+        assert_eq!(cpu.get_y(), 0x2003);
+        assert_eq!(cpu.registers.data_bank, 0x02);
+    }
+
+    #[test]
+    fn w65816_test_jsl_rtl() {
+        let mut cpu = CPU::with_bus_and_variant(Ram::with_banks(0x02), Variant::Wdc65816);
+
+        let bytes = [
+            0x22, 0x00, 0x80, 0x01, //JSL $018000
+            0xE8                    //INX
+        ];
+
+        cpu.load_program_long(&[0xC8, 0x6B], 0x018000); //INY, RTL
+        cpu.load_program(&bytes, 0x0000);
+        cpu.execute_instructions(1);
+
+        assert_eq!(cpu.registers.program_bank, 0x01);
+        assert_eq!(cpu.registers.pc, 0x8000);
+        assert_eq!(cpu.cycles, 8);
+
+        //The RTL leaves the frame the run started in.
+        assert_eq!(cpu.execute_instructions(3), StopReason::ReturnFromTopFrame { pc: 0x8001 });
+        cpu.execute_instructions(1);
+
+        assert_eq!(cpu.registers.program_bank, 0x00);
+        assert_eq!(cpu.registers.pc, 0x0005);
+        assert_eq!(cpu.registers.xr, 0x01);
+        assert_eq!(cpu.registers.yr, 0x01);
+    }
+
+    #[test]
+    fn w65816_test_native_brk() {
+        let mut cpu = native_cpu();
+
+        let bytes = [
+            0x00, 0xEA //BRK #$EA
+        ];
+
+        cpu.memory[0xFFE6] = 0x00;
+        cpu.memory[0xFFE7] = 0x90;
+        cpu.memory[0x9000] = 0x40; //RTI
+        cpu.registers.program_bank = 0x00;
+        cpu.load_program(&bytes, 0x0000);
+        cpu.registers.pc = 0x0000;
+        cpu.execute_instructions(1);
+
+        assert_eq!(cpu.registers.pc, 0x9000);
+        assert_eq!(cpu.flags.interrupt, true);
+        assert_eq!(cpu.cycles, 8);
+        assert_eq!(cpu.get_s(), 0x01FB);
+
+        cpu.execute_instructions(1);
+
+        assert_eq!(cpu.registers.pc, 0x0002);
+        assert_eq!(cpu.flags.interrupt, false);
+        assert_eq!(cpu.flags.memory_select, false);
+        assert_eq!(cpu.get_s(), 0x01FF);
+    }
+
+    #[test]
+    fn w65816_test_emulation_cop() {
+        let mut cpu = CPU::with_variant(Variant::Wdc65816);
+
+        let bytes = [
+            0x02, 0x00 //COP #$00
+        ];
+
+        cpu.memory[0xFFF4] = 0x00;
+        cpu.memory[0xFFF5] = 0x40;
+        cpu.flags.decimal = true;
+        cpu.load_program(&bytes, 0x0000);
+        cpu.execute_instructions(1);
+
+        assert_eq!(cpu.registers.pc, 0x4000);
+        assert_eq!(cpu.flags.decimal, false);
+        assert_eq!(cpu.cycles, 7);
+    }
+
+    #[test]
+    fn w65816_test_back_to_emulation_mode() {
+        let mut cpu = native_cpu();
+
+        let bytes = [
+            0xA2, 0x34, 0x12, //LDX #$1234
+            0xA9, 0x00, 0x03, //LDA #$0300
+            0x1B,             //TCS
+            0x38,             //SEC
+            0xFB              //XCE
+        ];
+
+        cpu.load_program(&bytes, 0x0000);
+        cpu.registers.pc = 0x0000;
+        cpu.execute_instructions(5);
+
+        assert_eq!(cpu.flags.emulation, true);
+        assert_eq!(cpu.flags.carry, false);
+        assert_eq!(cpu.flags.memory_select, true);
+        assert_eq!(cpu.flags.index_select, true);
+        assert_eq!(cpu.get_x(), 0x0034);
+        assert_eq!(cpu.get_s(), 0x0100);
+    }
+
+    #[test]
+    fn w65816_test_emulation_mode_ignores_rep() {
+        let mut cpu = CPU::with_variant(Variant::Wdc65816);
+
+        let bytes = [
+            0xC2, 0x30, //REP #$30
+            0xA9, 0x12  //LDA #$12
+        ];
+
+        cpu.execute_instruction_sequence(&bytes);
+
+        assert_eq!(cpu.flags.memory_select, true);
+        assert_eq!(cpu.registers.acc, 0x12);
+        assert_eq!(cpu.registers.pc, 0x0004);
+    }
+
+    #[test]
+    fn w65816_test_push_effective_addresses() {
+        let mut cpu = native_cpu();
+
+        let bytes = [
+            0xD4, 0x10,      //PEI ($10)
+            0x62, 0x00, 0x10 //PER $1000
+        ];
+
+        cpu.memory[0x0010] = 0x78;
+        cpu.memory[0x0011] = 0x56;
+        cpu.load_program(&bytes, 0x0000);
+        cpu.registers.pc = 0x0000;
+        cpu.execute_instructions(2);
+
+        assert_eq!(cpu.memory[0x01FE], 0x78);
+        assert_eq!(cpu.memory[0x01FF], 0x56);
+        assert_eq!(cpu.memory[0x01FC], 0x05);
+        assert_eq!(cpu.memory[0x01FD], 0x10);
+    }
+
+    #[test]
+    fn w65816_test_16bit_read_modify_write() {
+        let mut cpu = native_cpu();
+
+        let bytes = [
+            0xEE, 0x00, 0x20 //INC $2000
+        ];
+
+        cpu.memory[0x2000] = 0xFF;
+        cpu.memory[0x2001] = 0x00;
+        cpu.load_program(&bytes, 0x0000);
+        cpu.registers.pc = 0x0000;
+        cpu.execute_instructions(1);
+
+        assert_eq!(cpu.memory[0x2000], 0x00);
+        assert_eq!(cpu.memory[0x2001], 0x01);
+        assert_eq!(cpu.cycles, 8);
+    }
+
+    #[test]
+    fn w65816_test_reset_returns_to_emulation_mode() {
+        let mut cpu = native_cpu();
+
+        cpu.memory[0xFFFC] = 0x00;
+        cpu.memory[0xFFFD] = 0x80;
+        cpu.registers.program_bank = 0x05;
+        cpu.reset();
+
+        assert_eq!(cpu.flags.emulation, true);
+        assert_eq!(cpu.flags.memory_select, true);
+        assert_eq!(cpu.registers.program_bank, 0x00);
+        assert_eq!(cpu.registers.pc, 0x8000);
+    }
+
+    #[test]
+    fn w65816_test_operand_mismatch_is_a_fault() {
+        let mut cpu = native_cpu();
+
+        assert_eq!(operations::lda(&mut cpu, Operand::None), Err(Fault::MissingValue(None)));
+        assert_eq!(operations::sta(&mut cpu, Operand::Immediate(0x12)), Err(Fault::MissingAddress));
+        assert_eq!(operations::inc(&mut cpu, Operand::None), Err(Fault::MissingValue(None)));
+        assert_eq!(operations::jml(&mut cpu, Operand::Accumulator), Err(Fault::MissingAddress));
+        assert_eq!(operations::mvn(&mut cpu, Operand::Address(0x2000)), Err(Fault::UnsupportedAddressingMode));
+    }
+}